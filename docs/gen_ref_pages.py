@@ -6,7 +6,7 @@ import re
 import mkdocs_gen_files
 
 # Objects to document from the kaspa module
-stub_file = Path("kaspa.pyi")
+stub_file = Path("python/kaspa/kaspa.pyi")
 
 nav = mkdocs_gen_files.Nav()
 
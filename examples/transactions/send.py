@@ -0,0 +1,27 @@
+import asyncio
+from kaspa import (
+    PrivateKey,
+    Resolver,
+    RpcClient,
+    kaspa_to_sompi,
+    send,
+)
+
+
+async def main():
+    private_key = PrivateKey(
+        "389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69")
+    address = private_key.to_address("testnet")
+
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    await client.connect()
+
+    # Send 1 KAS back to ourselves; change returns to the sender address
+    txid = await send(client, private_key, address, kaspa_to_sompi(1), priority_fee=1000)
+    print(f"Submitted transaction: {txid}")
+
+    await client.disconnect()
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,75 @@
+"""
+Offline benchmark for `sign_transaction` on transactions with many inputs.
+
+Inputs are signed in parallel with the GIL released, so a second Python
+thread keeps making progress while signing runs.
+
+Usage:
+    python examples/transactions/sign_benchmark.py [input_count ...]
+"""
+import sys
+import threading
+import time
+
+from kaspa import (
+    PrivateKey,
+    create_transaction,
+    pay_to_address_script,
+    sign_transaction,
+)
+
+
+def build_transaction(private_key, input_count):
+    address = private_key.to_keypair().to_address(network="testnet")
+    script = pay_to_address_script(address)
+
+    utxos = [
+        {
+            "address": address.to_string(),
+            "outpoint": {"transactionId": f"{i:064x}", "index": 0},
+            "amount": 100_000_000,
+            "scriptPublicKey": {"version": script.version, "script": script.script},
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        }
+        for i in range(input_count)
+    ]
+    outputs = [{"address": address, "amount": 100_000_000}]
+    return create_transaction(utxos, outputs, 0, None, 1)
+
+
+def main():
+    input_counts = [int(arg) for arg in sys.argv[1:]] or [100, 1_000, 10_000]
+    private_key = PrivateKey(
+        "389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69")
+
+    for input_count in input_counts:
+        tx = build_transaction(private_key, input_count)
+
+        ticks = 0
+        done = threading.Event()
+
+        def ticker():
+            nonlocal ticks
+            while not done.is_set():
+                ticks += 1
+                time.sleep(0.001)
+
+        thread = threading.Thread(target=ticker)
+        thread.start()
+
+        start = time.perf_counter()
+        sign_transaction(tx, [private_key], False)
+        elapsed = time.perf_counter() - start
+
+        done.set()
+        thread.join()
+
+        print(
+            f"{input_count:>6} inputs: {elapsed:.3f}s "
+            f"({input_count / elapsed:,.0f} inputs/s, concurrent thread ticks: {ticks})"
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -1,5 +1,5 @@
 """
-TypedDict definitions for Kaspa RPC request/response messages & contained types.
+TypedDict definitions for Kaspa RPC request/response messages, event payloads & contained types.
 
 This file is maunally maintained and appended to kaspa.pyi file via stub gen process.
 
@@ -7,7 +7,7 @@ Long term, attempts should be made to auto generate.
 """
 
 from enum import Enum
-from typing import TypedDict
+from typing import Literal, TypedDict, Union
 
 
 # =============================================================================
@@ -962,3 +962,255 @@ class SubmitTransactionReplacementResponse(TypedDict):
     transactionId: str
     replacedTransaction: RpcTransaction
 
+
+# =============================================================================
+# Event Types (passed to event listener callbacks)
+# =============================================================================
+
+RpcEventName = Literal[
+    "all",
+    "connect",
+    "disconnect",
+    "stale-connection",
+    "block-added",
+    "virtual-chain-changed",
+    "finality-conflict",
+    "finality-conflict-resolved",
+    "utxos-changed",
+    "sink-blue-score-changed",
+    "virtual-daa-score-changed",
+    "pruning-point-utxo-set-override",
+    "new-block-template",
+]
+"""Event names accepted by `RpcClient.add_event_listener()`."""
+
+
+class RpcConnectionEvent(TypedDict):
+    """`connect` / `disconnect` event."""
+    type: str
+    rpc: str | None
+
+
+class StaleConnectionEvent(TypedDict):
+    """`stale-connection` event (see `RpcClient.set_keep_alive()`)."""
+    type: Literal["stale-connection"]
+    rpc: str | None
+    error: str
+
+
+class UtxosChangedEvent(TypedDict):
+    """`utxos-changed` event."""
+    type: str
+    added: list[RpcUtxosByAddressesEntry]
+    removed: list[RpcUtxosByAddressesEntry]
+
+
+class BlockAddedNotification(TypedDict):
+    """Data of a `block-added` event."""
+    block: RpcBlock
+
+
+class VirtualChainChangedNotification(TypedDict):
+    """Data of a `virtual-chain-changed` event."""
+    removedChainBlockHashes: list[str]
+    addedChainBlockHashes: list[str]
+    acceptedTransactionIds: list[RpcAcceptedTransactionIds]
+
+
+class FinalityConflictNotification(TypedDict):
+    """Data of a `finality-conflict` event."""
+    violatingBlockHash: str
+
+
+class FinalityConflictResolvedNotification(TypedDict):
+    """Data of a `finality-conflict-resolved` event."""
+    finalityBlockHash: str
+
+
+class SinkBlueScoreChangedNotification(TypedDict):
+    """Data of a `sink-blue-score-changed` event."""
+    sinkBlueScore: int
+
+
+class VirtualDaaScoreChangedNotification(TypedDict):
+    """Data of a `virtual-daa-score-changed` event."""
+    virtualDaaScore: int
+
+
+class PruningPointUtxoSetOverrideNotification(TypedDict):
+    """Data of a `pruning-point-utxo-set-override` event."""
+    pass
+
+
+class NewBlockTemplateNotification(TypedDict):
+    """Data of a `new-block-template` event."""
+    pass
+
+
+class BlockAddedEvent(TypedDict):
+    """`block-added` event."""
+    type: str
+    data: BlockAddedNotification
+
+
+class VirtualChainChangedEvent(TypedDict):
+    """`virtual-chain-changed` event."""
+    type: str
+    data: VirtualChainChangedNotification
+
+
+class FinalityConflictEvent(TypedDict):
+    """`finality-conflict` event."""
+    type: str
+    data: FinalityConflictNotification
+
+
+class FinalityConflictResolvedEvent(TypedDict):
+    """`finality-conflict-resolved` event."""
+    type: str
+    data: FinalityConflictResolvedNotification
+
+
+class SinkBlueScoreChangedEvent(TypedDict):
+    """`sink-blue-score-changed` event."""
+    type: str
+    data: SinkBlueScoreChangedNotification
+
+
+class VirtualDaaScoreChangedEvent(TypedDict):
+    """`virtual-daa-score-changed` event."""
+    type: str
+    data: VirtualDaaScoreChangedNotification
+
+
+class PruningPointUtxoSetOverrideEvent(TypedDict):
+    """`pruning-point-utxo-set-override` event."""
+    type: str
+    data: PruningPointUtxoSetOverrideNotification
+
+
+class NewBlockTemplateEvent(TypedDict):
+    """`new-block-template` event."""
+    type: str
+    data: NewBlockTemplateNotification
+
+
+RpcEventPayload = Union[
+    RpcConnectionEvent,
+    StaleConnectionEvent,
+    UtxosChangedEvent,
+    BlockAddedEvent,
+    VirtualChainChangedEvent,
+    FinalityConflictEvent,
+    FinalityConflictResolvedEvent,
+    SinkBlueScoreChangedEvent,
+    VirtualDaaScoreChangedEvent,
+    PruningPointUtxoSetOverrideEvent,
+    NewBlockTemplateEvent,
+]
+"""Any event passed to `RpcClient` event listeners."""
+
+
+UtxoProcessorEventName = Literal[
+    "*",
+    "all",
+    "connect",
+    "disconnect",
+    "utxo-index-not-enabled",
+    "sync-state",
+    "server-status",
+    "utxo-proc-start",
+    "utxo-proc-stop",
+    "utxo-proc-error",
+    "daa-score-change",
+    "pending",
+    "reorg",
+    "stasis",
+    "maturity",
+    "discovery",
+    "balance",
+    "error",
+]
+"""Event names accepted by `UtxoProcessor.add_event_listener()`."""
+
+
+class UtxoContextBalance(TypedDict):
+    """Balance of a `UtxoContext`, in sompi."""
+    mature: int
+    pending: int
+    outgoing: int
+    matureUtxoCount: int
+    pendingUtxoCount: int
+    stasisUtxoCount: int
+
+
+class BalanceEventData(TypedDict):
+    """Data of a `balance` event."""
+    balance: UtxoContextBalance | None
+    id: str
+
+
+class BalanceEvent(TypedDict):
+    """`balance` event."""
+    type: Literal["balance"]
+    data: BalanceEventData
+
+
+class DaaScoreChangeEventData(TypedDict):
+    """Data of a `daa-score-change` event."""
+    currentDaaScore: int
+
+
+class DaaScoreChangeEvent(TypedDict):
+    """`daa-score-change` event."""
+    type: Literal["daa-score-change"]
+    data: DaaScoreChangeEventData
+
+
+class TransactionRecordData(TypedDict, total=False):
+    """A transaction record delivered by `pending`, `reorg`, `stasis`, `maturity` and `discovery` events."""
+    id: str
+    unixtimeMsec: int | None
+    value: int
+    binding: dict
+    blockDaaScore: int
+    network: str
+    data: dict
+    note: str | None
+    metadata: str | None
+
+
+class TransactionRecordEvent(TypedDict):
+    """`pending`, `stasis`, `maturity` and `discovery` event."""
+    type: Literal["pending", "stasis", "maturity", "discovery"]
+    data: TransactionRecordData
+
+
+class AffectedTransaction(TypedDict):
+    """The last event delivered for a transaction invalidated by a `reorg`."""
+    id: str
+    type: Literal["pending", "stasis", "maturity", "discovery"]
+    blockDaaScore: int
+
+
+class ReorgEvent(TypedDict, total=False):
+    """`reorg` event. `affected` holds the last event previously delivered for the invalidated transaction."""
+    type: Literal["reorg"]
+    data: TransactionRecordData
+    affected: list[AffectedTransaction]
+
+
+class UtxoProcessorGenericEvent(TypedDict):
+    """Other `UtxoProcessor` events (`connect`, `sync-state`, `utxo-proc-start`, ...)."""
+    type: str
+    data: dict | None
+
+
+UtxoProcessorEventPayload = Union[
+    BalanceEvent,
+    DaaScoreChangeEvent,
+    TransactionRecordEvent,
+    ReorgEvent,
+    UtxoProcessorGenericEvent,
+]
+"""Any event passed to `UtxoProcessor` event listeners."""
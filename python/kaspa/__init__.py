@@ -0,0 +1,34 @@
+"""Kaspa Python SDK.
+
+The SDK is implemented in the `kaspa.kaspa` native extension module and
+re-exported here.
+"""
+
+import sys
+
+from . import kaspa as _native
+from .kaspa import *  # noqa: F403
+from . import runtime, testnet
+from .blocking import BlockingRpcClient, connect
+from .config import Config
+from .dag import export_dag
+from .failover import FailoverRpcClient
+from .health import health
+from .journal import EventJournal
+from .krc20 import KasplexClient
+from .maturity import MaturityWatcher
+from .metrics import MetricsSampler
+from .watchdog import NotificationWatchdog
+from .payouts import PayoutBatch, PayoutStore
+from .policy import SpendLimitPolicy
+from .signers import RemoteSigner
+from .utxo_index import UtxoIndex
+from .webhooks import WebhookNotifier
+
+# Native submodules are attributes of the extension module; register them so
+# `import kaspa.exceptions` and `from kaspa.exceptions import ...` work.
+for _name in ("exceptions", "compat", "dataframe"):
+    if hasattr(_native, _name):
+        sys.modules[f"{__name__}.{_name}"] = getattr(_native, _name)
+
+__all__ = [*_native.__all__, "BlockingRpcClient", "Config", "EventJournal", "FailoverRpcClient", "KasplexClient", "MaturityWatcher", "MetricsSampler", "NotificationWatchdog", "PayoutBatch", "PayoutStore", "RemoteSigner", "SpendLimitPolicy", "UtxoIndex", "WebhookNotifier", "connect", "export_dag", "health"]
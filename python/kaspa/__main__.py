@@ -0,0 +1,205 @@
+"""Command line interface: `python -m kaspa <command>`.
+
+Commands:
+    balance   Balance of one or more addresses.
+    validate  Check whether an address is valid (offline).
+    send      Send KAS to an address.
+    monitor   Print UTXO changes of addresses until interrupted.
+    info      Node and network information.
+
+Network commands connect through the public resolver unless `--url` is given.
+The `send` private key is read from the `KASPA_PRIVATE_KEY` environment
+variable, or prompted for, so it never appears in the shell history.
+"""
+
+import argparse
+import asyncio
+import decimal
+import getpass
+import json
+import os
+import sys
+
+from kaspa import (
+    Address,
+    NetworkId,
+    Resolver,
+    RpcClient,
+    parse_amount,
+    send,
+    sompi_to_kaspa_string_with_suffix,
+)
+
+PRIVATE_KEY_ENV = "KASPA_PRIVATE_KEY"
+
+
+def kas_amount(value):
+    """Parse an amount in KAS into sompi, exactly."""
+    try:
+        return parse_amount(decimal.Decimal(value))
+    except (decimal.InvalidOperation, ValueError) as err:
+        raise argparse.ArgumentTypeError(f"invalid amount {value!r}: {err}") from None
+
+
+def parse_args(argv):
+    parser = argparse.ArgumentParser(prog="python -m kaspa", description="Kaspa Python SDK command line interface.")
+    parser.add_argument("--network", default="mainnet", help="network id, e.g. mainnet or testnet-10 (default: mainnet)")
+    parser.add_argument("--url", help="node wRPC URL (default: public resolver)")
+    parser.add_argument("--json", action="store_true", help="print JSON output")
+    commands = parser.add_subparsers(dest="command", required=True)
+
+    balance = commands.add_parser("balance", help="balance of one or more addresses")
+    balance.add_argument("addresses", nargs="+", metavar="ADDRESS")
+
+    validate = commands.add_parser("validate", help="check whether an address is valid (offline)")
+    validate.add_argument("address", metavar="ADDRESS")
+
+    transfer = commands.add_parser("send", help="send KAS to an address")
+    transfer.add_argument("to", metavar="ADDRESS", help="destination address")
+    transfer.add_argument("amount", type=kas_amount, help="amount in KAS, up to 8 decimal places")
+    transfer.add_argument("--priority-fee", type=int, default=0, help="additional fee in sompi (default: 0)")
+
+    monitor = commands.add_parser("monitor", help="print UTXO changes of addresses until interrupted")
+    monitor.add_argument("addresses", nargs="+", metavar="ADDRESS")
+
+    commands.add_parser("info", help="node and network information")
+
+    return parser.parse_args(argv)
+
+
+def output(args, result, lines):
+    if args.json:
+        print(json.dumps(result, indent=2))
+    else:
+        for line in lines:
+            print(line)
+
+
+async def connect(args):
+    network_id = NetworkId(args.network)
+    if args.url:
+        client = RpcClient(url=args.url, network_id=network_id)
+    else:
+        client = RpcClient(resolver=Resolver(), network_id=network_id)
+    await client.connect()
+    return client
+
+
+def validate(args):
+    if not Address.validate(args.address):
+        output(args, {"address": args.address, "valid": False}, [f"{args.address}: invalid"])
+        return 1
+
+    address = Address(args.address)
+    result = {"address": args.address, "valid": True, "prefix": address.prefix, "version": address.version}
+    output(args, result, [f"{args.address}: valid ({address.prefix}, {address.version})"])
+    return 0
+
+
+async def balance(args):
+    network_type = NetworkId(args.network).network_type
+    client = await connect(args)
+    try:
+        response = await client.get_balances_by_addresses({"addresses": args.addresses})
+    finally:
+        await client.disconnect()
+
+    entries = response["entries"]
+    lines = [
+        f"{entry['address']}: "
+        + ("unknown" if entry["balance"] is None else sompi_to_kaspa_string_with_suffix(entry["balance"], network_type))
+        for entry in entries
+    ]
+    output(args, entries, lines)
+    return 0
+
+
+async def transfer(args):
+    private_key = os.environ.get(PRIVATE_KEY_ENV) or getpass.getpass("Private key or mnemonic: ")
+    client = await connect(args)
+    try:
+        transaction_id = await send(
+            client,
+            private_key,
+            args.to,
+            args.amount,
+            args.priority_fee,
+            network_id=NetworkId(args.network),
+        )
+    finally:
+        await client.disconnect()
+
+    output(args, {"transaction_id": transaction_id}, [transaction_id])
+    return 0
+
+
+async def monitor(args):
+    network_type = NetworkId(args.network).network_type
+
+    def on_utxos_changed(event):
+        if args.json:
+            print(json.dumps({"added": event["added"], "removed": event["removed"]}), flush=True)
+            return
+        for kind, sign in (("added", "+"), ("removed", "-")):
+            for entry in event[kind]:
+                amount = sompi_to_kaspa_string_with_suffix(entry["utxoEntry"]["amount"], network_type)
+                print(f"{sign} {entry['address']} {amount} {entry['outpoint']['transactionId']}", flush=True)
+
+    client = await connect(args)
+    try:
+        client.add_event_listener("utxos-changed", on_utxos_changed)
+        await client.subscribe_utxos_changed([Address(address) for address in args.addresses])
+        print(f"Monitoring {len(args.addresses)} address(es), press Ctrl-C to stop", file=sys.stderr)
+        await asyncio.Event().wait()
+    finally:
+        await client.disconnect()
+
+
+async def info(args):
+    client = await connect(args)
+    url = client.url
+    try:
+        server = await client.get_server_info()
+        dag = await client.get_block_dag_info()
+    finally:
+        await client.disconnect()
+
+    result = {
+        "url": url,
+        "server_version": server["serverVersion"],
+        "network_id": server["networkId"],
+        "is_synced": server["isSynced"],
+        "has_utxo_index": server["hasUtxoIndex"],
+        "virtual_daa_score": server["virtualDaaScore"],
+        "block_count": dag["blockCount"],
+        "header_count": dag["headerCount"],
+        "difficulty": dag["difficulty"],
+        "tip_hashes": dag["tipHashes"],
+    }
+    output(args, result, [f"{key}: {value}" for key, value in result.items() if key != "tip_hashes"])
+    return 0
+
+
+COMMANDS = {
+    "balance": balance,
+    "send": transfer,
+    "monitor": monitor,
+    "info": info,
+}
+
+
+def main(argv=None):
+    args = parse_args(argv)
+    try:
+        if args.command == "validate":
+            return validate(args)
+        return asyncio.run(COMMANDS[args.command](args))
+    except KeyboardInterrupt:
+        return 130
+    except Exception as err:
+        print(f"error: {err}", file=sys.stderr)
+        return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,114 @@
+"""Blocking RPC client for REPLs and synchronous scripts."""
+
+import asyncio
+import inspect
+import threading
+
+from .config import Config
+from .kaspa import Encoding, NetworkId, Resolver, RpcClient
+
+
+class BlockingRpcClient:
+    """Synchronous facade over a connected RpcClient.
+
+    RPC methods (`get_server_info()`, `get_balances_by_addresses()`, ...) return
+    their result directly instead of an awaitable. Calls run on a private event
+    loop thread, which also runs event listener callbacks. Other attributes are
+    those of the wrapped client.
+
+    Created by `kaspa.connect()`. Close it with `close()` or use it as a
+    context manager.
+    """
+
+    def __init__(self, client: RpcClient):
+        self._client = client
+        self._loop = asyncio.new_event_loop()
+        self._thread = threading.Thread(target=self._loop.run_forever, name="kaspa-rpc", daemon=True)
+        self._thread.start()
+
+    @property
+    def client(self) -> RpcClient:
+        """The wrapped (async) RpcClient."""
+        return self._client
+
+    def _run(self, function, *args, **kwargs):
+        async def call():
+            result = function(*args, **kwargs)
+            return await result if inspect.isawaitable(result) else result
+
+        return asyncio.run_coroutine_threadsafe(call(), self._loop).result()
+
+    def __getattr__(self, name):
+        attribute = getattr(self._client, name)
+        if not callable(attribute):
+            return attribute
+        return lambda *args, **kwargs: self._run(attribute, *args, **kwargs)
+
+    def __dir__(self):
+        return sorted(set(super().__dir__()) | set(dir(self._client)))
+
+    def __repr__(self):
+        return f"BlockingRpcClient(url={self._client.url!r})"
+
+    def close(self) -> None:
+        """Disconnect from the node and stop the event loop thread."""
+        if self._loop.is_closed():
+            return
+        try:
+            if self._client.is_connected:
+                self._run(self._client.disconnect)
+        finally:
+            self._loop.call_soon_threadsafe(self._loop.stop)
+            self._thread.join()
+            self._loop.close()
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *exc):
+        self.close()
+
+
+def connect(
+    network: str | NetworkId | None = None,
+    url: str | None = None,
+    resolver: Resolver | None = None,
+    encoding: str | Encoding | None = None,
+    timeout: int | None = None,
+    config: Config | None = None,
+) -> BlockingRpcClient:
+    """Create a connected, blocking RPC client in one call.
+
+    Intended for interactive exploration:
+
+        >>> rpc = kaspa.connect()
+        >>> rpc.get_block_dag_info()["virtualDaaScore"]
+
+    Args:
+        network: Network id (default: "mainnet").
+        url: Node URL (default: a node from the public resolver).
+        resolver: Resolver to use when no URL is given (default: `Resolver()`).
+        encoding: RPC encoding, "borsh" or "json" (default: "borsh").
+        timeout: Connection timeout in milliseconds (default: 10000).
+        config: Settings (e.g. from `Config.load()`) for the arguments not given.
+
+    Returns:
+        BlockingRpcClient: The connected client.
+
+    Raises:
+        Exception: If no node can be reached within the timeout.
+    """
+    config = config or Config()
+    network = network or config.network
+    network_id = network if isinstance(network, NetworkId) else NetworkId(network)
+    if url is None and resolver is None:
+        url, resolver = config.url, config.make_resolver()
+    client = RpcClient(resolver=resolver, url=url, encoding=encoding or config.encoding, network_id=network_id)
+
+    blocking = BlockingRpcClient(client)
+    try:
+        blocking._run(client.connect, strategy="fallback", timeout_duration=timeout or config.timeout)
+    except BaseException:
+        blocking.close()
+        raise
+    return blocking
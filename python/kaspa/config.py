@@ -0,0 +1,285 @@
+"""Connection and wallet settings loaded from profile files."""
+
+import json
+import logging
+import os
+from typing import Any, Mapping
+
+from .kaspa import NetworkId, Resolver
+
+DEFAULT_PATH = "~/.kaspa/sdk.toml"
+DEFAULT_TIMEOUT = 10_000
+
+SETTINGS = {"network", "url", "resolver", "tls", "encoding", "timeout", "log_level", "wallet"}
+
+# Loggers of the SDK and the Rust crates it bridges into Python logging.
+LOGGERS = ("kaspa", "kaspa_wrpc_client", "kaspa_rpc_core", "kaspa_wallet_core", "kaspa_notify", "workflow_rpc")
+
+TRUE = {"1", "true", "yes", "on"}
+FALSE = {"0", "false", "no", "off"}
+
+
+def read_file(path: str) -> dict:
+    """Parse a TOML or JSON profile file, by its extension."""
+    if path.endswith(".json"):
+        with open(path, encoding="utf-8") as file:
+            return json.load(file)
+    try:
+        import tomllib
+    except ImportError:
+        try:
+            import tomli as tomllib
+        except ImportError:
+            raise ImportError("TOML profiles require Python 3.11 or tomli: pip install tomli") from None
+    with open(path, "rb") as file:
+        return tomllib.load(file)
+
+
+def parse_bool(name: str, value: str) -> bool:
+    if value.lower() in TRUE:
+        return True
+    if value.lower() in FALSE:
+        return False
+    raise ValueError(f"{name} must be a boolean (true/false), got {value!r}")
+
+
+def parse_resolver(value: str) -> bool | list[str]:
+    """KASPA_RESOLVER: a boolean, or comma-separated resolver URLs."""
+    if value.lower() in TRUE | FALSE:
+        return parse_bool("KASPA_RESOLVER", value)
+    return [url.strip() for url in value.split(",") if url.strip()]
+
+
+def is_network(name: str) -> bool:
+    try:
+        NetworkId(name)
+    except Exception:
+        return False
+    return True
+
+
+def check_settings(settings: dict, where: str) -> None:
+    unknown = sorted(set(settings) - SETTINGS)
+    if unknown:
+        raise ValueError(f"Unknown settings in {where}: {', '.join(unknown)}")
+
+
+class Config:
+    """Connection and wallet settings shared across scripts.
+
+    Settings are usually loaded from a TOML or JSON file with `Config.load()`.
+    Top-level settings apply to every profile; each `[profiles.<name>]` table
+    overrides them for one profile, typically one per network:
+
+        encoding = "borsh"
+        profile = "testnet-10"          # loaded when no profile is given
+
+        [profiles.mainnet]
+        resolver = true
+        tls = true
+
+        [profiles.testnet-10]           # network defaults to the profile name, if it is one
+        url = "ws://127.0.0.1:17210"
+
+        [profiles.testnet-10.wallet]
+        vault_key = "treasury"
+        secret_env = "KASPA_WALLET_SECRET"
+
+    `RpcClient`, `FailoverRpcClient` and `connect()` accept a Config as
+    `config`; their explicit arguments take precedence over it.
+    """
+
+    def __init__(
+        self,
+        network: str = "mainnet",
+        url: str | None = None,
+        resolver: bool | list[str] | None = None,
+        tls: bool = False,
+        encoding: str = "borsh",
+        timeout: int = DEFAULT_TIMEOUT,
+        log_level: str | None = None,
+        wallet: dict | None = None,
+        profile: str | None = None,
+    ):
+        """Create a config.
+
+        Args:
+            network: Network id (default: "mainnet").
+            url: Node URL.
+            resolver: Resolve nodes with the public resolvers (True) or these resolver URLs (default: when no `url` is set).
+            tls: Only resolve nodes accepting TLS connections (default: False).
+            encoding: RPC encoding, "borsh" or "json" (default: "borsh").
+            timeout: Connection timeout in milliseconds (default: 10000).
+            log_level: Level of the SDK loggers, applied by `configure_logging()`, e.g. "debug".
+            wallet: Free-form wallet settings, see `secret()`.
+            profile: Name of the profile the settings were loaded from.
+
+        Raises:
+            ValueError: If `network` is not a valid network id.
+        """
+        NetworkId(network)
+        self.network = network
+        self.url = url
+        self.resolver = resolver
+        self.tls = tls
+        self.encoding = encoding
+        self.timeout = timeout
+        self.log_level = log_level
+        self.wallet = dict(wallet or {})
+        self.profile = profile
+
+    @classmethod
+    def load(cls, path: str | os.PathLike = DEFAULT_PATH, profile: str | None = None) -> "Config":
+        """Load settings from a TOML (or `.json`) profile file.
+
+        Args:
+            path: The file; `~` is expanded (default: "~/.kaspa/sdk.toml").
+            profile: The profile to load (default: the file's `profile` setting, else top-level settings only).
+
+        Returns:
+            Config: The merged top-level and profile settings.
+
+        Raises:
+            FileNotFoundError: If the file does not exist.
+            ValueError: If the profile does not exist or a setting is unknown.
+        """
+        data = read_file(os.path.expanduser(os.fspath(path)))
+        profiles = data.pop("profiles", {})
+        profile = profile or data.pop("profile", None)
+        data.pop("profile", None)
+        check_settings(data, str(path))
+        settings = dict(data)
+        if profile is not None:
+            if profile not in profiles:
+                raise ValueError(f"No profile {profile!r} in {path}; profiles: {', '.join(profiles) or 'none'}")
+            check_settings(profiles[profile], f"profile {profile!r}")
+            if is_network(profile):
+                settings["network"] = profile
+            settings.update(profiles[profile])
+            settings["wallet"] = {**data.get("wallet", {}), **profiles[profile].get("wallet", {})}
+        return cls(**settings, profile=profile)
+
+    @classmethod
+    def from_env(cls, environ: Mapping[str, str] | None = None) -> "Config":
+        """Load settings from environment variables, for container deployments.
+
+        When `KASPA_CONFIG` or `KASPA_PROFILE` is set, the profile file is
+        loaded first (`KASPA_CONFIG`, default "~/.kaspa/sdk.toml"; profile
+        `KASPA_PROFILE`). These variables then override its settings:
+
+        - `KASPA_NETWORK`: network id, e.g. "testnet-10".
+        - `KASPA_URL`: node URL.
+        - `KASPA_RESOLVER`: "true" or "false" to enable or disable the public resolvers, or comma-separated resolver URLs.
+        - `KASPA_TLS`: "true" to only resolve nodes accepting TLS connections.
+        - `KASPA_ENCODING`: "borsh" or "json".
+        - `KASPA_TIMEOUT`: connection timeout in milliseconds.
+        - `KASPA_LOG_LEVEL`: level of the SDK loggers, e.g. "debug"; applied right away.
+
+        Args:
+            environ: The variables (default: `os.environ`).
+
+        Returns:
+            Config: The settings.
+
+        Raises:
+            ValueError: If a variable has an invalid value.
+        """
+        environ = os.environ if environ is None else environ
+        if "KASPA_CONFIG" in environ or "KASPA_PROFILE" in environ:
+            config = cls.load(environ.get("KASPA_CONFIG", DEFAULT_PATH), environ.get("KASPA_PROFILE"))
+        else:
+            config = cls()
+
+        if "KASPA_NETWORK" in environ:
+            NetworkId(environ["KASPA_NETWORK"])
+            config.network = environ["KASPA_NETWORK"]
+        if "KASPA_URL" in environ:
+            config.url = environ["KASPA_URL"] or None
+        if "KASPA_RESOLVER" in environ:
+            config.resolver = parse_resolver(environ["KASPA_RESOLVER"])
+        if "KASPA_TLS" in environ:
+            config.tls = parse_bool("KASPA_TLS", environ["KASPA_TLS"])
+        if "KASPA_ENCODING" in environ:
+            config.encoding = environ["KASPA_ENCODING"]
+        if "KASPA_TIMEOUT" in environ:
+            try:
+                config.timeout = int(environ["KASPA_TIMEOUT"])
+            except ValueError:
+                raise ValueError(f"KASPA_TIMEOUT must be milliseconds, got {environ['KASPA_TIMEOUT']!r}") from None
+        if "KASPA_LOG_LEVEL" in environ:
+            config.log_level = environ["KASPA_LOG_LEVEL"]
+            config.configure_logging()
+        return config
+
+    @property
+    def network_id(self) -> NetworkId:
+        """The network as NetworkId, e.g. for `UtxoProcessor`."""
+        return NetworkId(self.network)
+
+    def secret(self, name: str = "secret") -> str | None:
+        """A wallet secret: the `name` wallet setting, else the environment variable named by `<name>_env`.
+
+        Keeping `secret_env = "VARIABLE"` in the file instead of the secret
+        itself keeps secrets out of profile files.
+
+        Returns:
+            The secret, None if neither setting is present.
+        """
+        if name in self.wallet:
+            return self.wallet[name]
+        variable = self.wallet.get(f"{name}_env")
+        return os.environ.get(variable) if variable else None
+
+    def configure_logging(self) -> None:
+        """Set the level of the SDK loggers to `log_level`, if set.
+
+        Rust log records are forwarded to Python logging; levels are read when
+        a logger is first used, so configure logging before connecting.
+
+        Raises:
+            ValueError: If `log_level` is not a logging level name.
+        """
+        if self.log_level is None:
+            return
+        level = logging.getLevelName(self.log_level.upper())
+        if not isinstance(level, int):
+            raise ValueError(f"Unknown log level {self.log_level!r}")
+        for name in LOGGERS:
+            logging.getLogger(name).setLevel(level)
+
+    def make_resolver(self) -> Resolver | None:
+        """The Resolver to connect with, None when a URL is set and resolving is not enabled."""
+        if self.resolver is False or (self.url is not None and not self.resolver):
+            return None
+        urls = self.resolver if isinstance(self.resolver, list) else None
+        return Resolver(urls, self.tls)
+
+    def rpc_options(self) -> dict:
+        """`RpcClient` constructor arguments for these settings."""
+        options: dict[str, Any] = {"encoding": self.encoding, "network_id": self.network}
+        if self.url is not None:
+            options["url"] = self.url
+        resolver = self.make_resolver()
+        if resolver is not None:
+            options["resolver"] = resolver
+        return options
+
+    def to_dict(self) -> dict:
+        """The settings as dict, with wallet secrets masked."""
+        wallet = {
+            key: "***" if key == "secret" or key.endswith("_secret") else value for key, value in self.wallet.items()
+        }
+        return {
+            "profile": self.profile,
+            "network": self.network,
+            "url": self.url,
+            "resolver": self.resolver,
+            "tls": self.tls,
+            "encoding": self.encoding,
+            "timeout": self.timeout,
+            "log_level": self.log_level,
+            "wallet": wallet,
+        }
+
+    def __repr__(self) -> str:
+        return f"Config({self.to_dict()!r})"
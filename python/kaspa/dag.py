@@ -0,0 +1,164 @@
+"""Export DAG segments as GraphML or DOT graphs for visualizing GHOSTDAG."""
+
+import os
+import xml.etree.ElementTree as ET
+from typing import Any
+
+FORMATS = ("graphml", "dot")
+
+# Fill colors of the DOT export, by GHOSTDAG color.
+DOT_COLORS = {"blue": "lightblue", "red": "salmon", "unknown": "lightgray"}
+
+
+async def fetch_segment(rpc: Any, from_hash: str, depth: int) -> list[dict]:
+    """Blocks of `from_hash` and its ancestors up to `depth` parent levels, breadth-first."""
+    blocks = []
+    level = [from_hash]
+    seen = {from_hash}
+    for distance in range(depth + 1):
+        next_level = []
+        for hash in level:
+            block = (await rpc.get_block({"hash": hash, "includeTransactions": False}))["block"]
+            blocks.append(block)
+            if distance == depth:
+                continue
+            parents = block["header"]["parentsByLevel"]
+            for parent in parents[0] if parents else []:
+                if parent not in seen:
+                    seen.add(parent)
+                    next_level.append(parent)
+        level = next_level
+    return blocks
+
+
+def colors(blocks: list[dict]) -> dict[str, str]:
+    """GHOSTDAG color of each block merged within the segment.
+
+    A block's color is taken from the mergeset of the block merging it,
+    preferring chain blocks, whose mergesets decide the colors seen by the
+    virtual. Blocks not merged by a block of the segment are "unknown".
+    """
+    result = {}
+    ordered = sorted(blocks, key=lambda block: not (block.get("verboseData") or {}).get("isChainBlock"))
+    for block in ordered:
+        data = block.get("verboseData") or {}
+        for hash in data.get("mergeSetBluesHashes", []):
+            result.setdefault(hash, "blue")
+        for hash in data.get("mergeSetRedsHashes", []):
+            result.setdefault(hash, "red")
+    return result
+
+
+def segment_graph(blocks: list[dict]) -> tuple[list[dict], list[dict]]:
+    """Nodes and parent edges of a segment; edges to blocks outside it are dropped."""
+    block_colors = colors(blocks)
+    hashes = {block["header"]["hash"] for block in blocks}
+    nodes, edges = [], []
+    for block in blocks:
+        header = block["header"]
+        data = block.get("verboseData") or {}
+        hash = header["hash"]
+        nodes.append({
+            "id": hash,
+            "color": block_colors.get(hash, "unknown"),
+            "chain": bool(data.get("isChainBlock")),
+            "blueScore": header["blueScore"],
+            "daaScore": header["daaScore"],
+            "timestamp": header["timestamp"],
+        })
+        parents = header["parentsByLevel"]
+        for parent in parents[0] if parents else []:
+            if parent in hashes:
+                edges.append({
+                    "source": hash,
+                    "target": parent,
+                    "selected": parent == data.get("selectedParentHash"),
+                })
+    return nodes, edges
+
+
+def to_graphml(nodes: list[dict], edges: list[dict]) -> str:
+    """A GraphML document of `nodes` and `edges`."""
+    root = ET.Element("graphml", xmlns="http://graphml.graphdrawing.org/xmlns")
+    keys = [
+        ("color", "node", "string"),
+        ("chain", "node", "boolean"),
+        ("blueScore", "node", "long"),
+        ("daaScore", "node", "long"),
+        ("timestamp", "node", "long"),
+        ("selected", "edge", "boolean"),
+    ]
+    for name, domain, kind in keys:
+        ET.SubElement(root, "key", {"id": name, "for": domain, "attr.name": name, "attr.type": kind})
+    graph = ET.SubElement(root, "graph", id="dag", edgedefault="directed")
+    for node in nodes:
+        element = ET.SubElement(graph, "node", id=node["id"])
+        for name in ("color", "chain", "blueScore", "daaScore", "timestamp"):
+            ET.SubElement(element, "data", key=name).text = graphml_value(node[name])
+    for index, edge in enumerate(edges):
+        element = ET.SubElement(graph, "edge", id=f"e{index}", source=edge["source"], target=edge["target"])
+        ET.SubElement(element, "data", key="selected").text = graphml_value(edge["selected"])
+    ET.indent(root)
+    return '<?xml version="1.0" encoding="UTF-8"?>\n' + ET.tostring(root, encoding="unicode") + "\n"
+
+
+def graphml_value(value: Any) -> str:
+    return str(value).lower() if isinstance(value, bool) else str(value)
+
+
+def to_dot(nodes: list[dict], edges: list[dict]) -> str:
+    """A Graphviz DOT digraph of `nodes` and `edges`, selected-parent edges bold."""
+    lines = ["digraph dag {", "    rankdir=RL;", "    node [shape=box, style=filled, fontname=monospace];"]
+    for node in nodes:
+        label = f"{node['id'][:8]}\\nblue score {node['blueScore']}"
+        border = ", penwidth=2" if node["chain"] else ""
+        lines.append(f'    "{node["id"]}" [label="{label}", fillcolor={DOT_COLORS[node["color"]]}{border}];')
+    for edge in edges:
+        style = "bold" if edge["selected"] else "dashed"
+        lines.append(f'    "{edge["source"]}" -> "{edge["target"]}" [style={style}];')
+    lines.append("}")
+    return "\n".join(lines) + "\n"
+
+
+async def export_dag(
+    rpc: Any,
+    from_hash: Any,
+    depth: int,
+    format: str = "graphml",
+    path: str | os.PathLike | None = None,
+) -> str:
+    """Export a DAG segment as a graph, colored by GHOSTDAG.
+
+    Walks the direct parents of `from_hash` breadth-first with one
+    `get_block()` call per block and builds a graph of the blocks reached:
+    nodes carry their GHOSTDAG color (`blue`, `red`, or `unknown` for blocks
+    no block of the segment merges, such as `from_hash` itself), whether they
+    are chain blocks, blue score, DAA score and timestamp; edges point from a
+    block to its parents, flagged `selected` for the selected parent.
+
+        await export_dag(client, (await client.get_sink())["sink"], 20, path="dag.graphml")
+
+    Args:
+        rpc: A connected `RpcClient`.
+        from_hash: The block to start from, e.g. the sink.
+        depth: How many parent levels to walk (0 exports `from_hash` only).
+        format: "graphml" or "dot".
+        path: A file to write the graph to.
+
+    Returns:
+        The graph document.
+
+    Raises:
+        ValueError: If `format` is unknown or `depth` is negative.
+    """
+    if format not in FORMATS:
+        raise ValueError(f"Unknown format {format!r}, expected one of {', '.join(FORMATS)}")
+    if depth < 0:
+        raise ValueError("depth must not be negative")
+    blocks = await fetch_segment(rpc, str(from_hash), depth)
+    nodes, edges = segment_graph(blocks)
+    document = to_graphml(nodes, edges) if format == "graphml" else to_dot(nodes, edges)
+    if path is not None:
+        with open(path, "w", encoding="utf-8") as file:
+            file.write(document)
+    return document
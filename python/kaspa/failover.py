@@ -0,0 +1,415 @@
+"""Multi-node RPC client with health checks and failover."""
+
+import asyncio
+import inspect
+import time
+from typing import Any, Callable, Sequence
+
+from .config import Config
+from .kaspa import Encoding, NetworkId, Resolver, RpcClient
+
+DEFAULT_HEALTH_INTERVAL = 10.0
+DEFAULT_HEALTH_TIMEOUT = 5.0
+# About a minute of DAA score at 10 blocks per second.
+DEFAULT_MAX_DAA_LAG = 600
+
+# RpcClient methods configuring the client rather than calling the node, applied to every node.
+CONFIGURATION_METHODS = {
+    "add_middleware",
+    "remove_middleware",
+    "enable_cache",
+    "disable_cache",
+    "clear_cache",
+    "set_keep_alive",
+    "set_rate_limit",
+}
+
+# Method groups routing policies can be configured for. Other RPC methods are `read`.
+METHOD_GROUPS = {
+    "submit_transaction": "submit",
+    "submit_serialized_transaction": "submit",
+    "submit_transaction_replacement": "submit",
+    "get_block_template": "mining",
+    "submit_block": "mining",
+    "add_peer": "admin",
+    "ban": "admin",
+    "unban": "admin",
+    "resolve_finality_conflict": "admin",
+    "shutdown": "admin",
+}
+
+
+def method_group(name: str) -> str:
+    """Routing group of an RpcClient method: `submit`, `mining`, `admin`, `subscriptions` or `read`."""
+    if name.startswith("subscribe_") or name.startswith("unsubscribe_"):
+        return "subscriptions"
+    return METHOD_GROUPS.get(name, "read")
+
+
+class Node:
+    """Health of one node of a FailoverRpcClient, updated by health checks."""
+
+    def __init__(self, client: RpcClient, url: str | None):
+        self.client = client
+        # The URL the node was configured with, or None for resolver nodes.
+        self.configured_url = url
+        self.healthy = False
+        self.synced = False
+        self.latency: float | None = None
+        self.daa_score: int | None = None
+        self.error: str | None = None
+        # Set when a health check reconnected the node, which drops its subscriptions.
+        self.reconnected = False
+
+    @property
+    def url(self) -> str | None:
+        return self.client.url or self.configured_url
+
+    def matches(self, targets: Sequence[str]) -> bool:
+        """Whether the node is among routing targets (URLs, or `resolver` for resolver nodes)."""
+        return (self.configured_url or "resolver") in targets
+
+    def to_dict(self) -> dict:
+        return {
+            "url": self.url,
+            "connected": self.client.is_connected,
+            "healthy": self.healthy,
+            "synced": self.synced,
+            "latency": self.latency,
+            "daa_score": self.daa_score,
+            "error": self.error,
+        }
+
+
+class FailoverRpcClient:
+    """RPC client spreading over several nodes, with automatic failover.
+
+    Nodes are health-checked every `health_interval` seconds: a node is healthy
+    when it answers `get_server_info()` within `health_timeout`, is synced, and
+    its virtual DAA score is within `max_daa_lag` of the most advanced node.
+    Requests go to the healthy node with the lowest latency. A request failing
+    because its node went down is retried once on another healthy node.
+
+    Subscriptions and event listeners are held on a primary node. When the
+    primary becomes unhealthy, another node is promoted, listeners are moved and
+    subscriptions are re-issued on it, and `failover` listeners are called with
+    an event dict holding `type`, `from` and `to` (the node URLs).
+
+    Routing policies restrict the nodes a method group may use, e.g. to submit
+    transactions only to a trusted local node and send reads to public nodes.
+    Groups are `submit`, `mining`, `admin`, `subscriptions` (the nodes eligible
+    as primary) and `read` (every other method); an RPC method name can also be
+    routed on its own. Routed requests fail rather than go to another node when
+    none of their nodes is healthy.
+
+    RPC methods, subscriptions and event listener methods mirror RpcClient.
+    Objects taking an RpcClient (e.g. UtxoProcessor) can be given `client`, the
+    current primary, but do not follow failovers.
+    """
+
+    def __init__(
+        self,
+        urls: Sequence[str] | None = None,
+        resolver: Resolver | None = None,
+        network_id: str | NetworkId | None = None,
+        encoding: str | Encoding | None = None,
+        nodes: int = 3,
+        health_interval: float = DEFAULT_HEALTH_INTERVAL,
+        health_timeout: float = DEFAULT_HEALTH_TIMEOUT,
+        max_daa_lag: int = DEFAULT_MAX_DAA_LAG,
+        routes: dict[str, Sequence[str]] | None = None,
+        config: Config | None = None,
+    ):
+        """Create a failover client.
+
+        Args:
+            urls: Node URLs.
+            resolver: Resolver to obtain `nodes` nodes from, in addition to `urls` (default: `Resolver()` when no URLs are given).
+            network_id: Network id (default: "mainnet").
+            encoding: RPC encoding, "borsh" or "json" (default: "borsh").
+            nodes: Number of resolver nodes (default: 3).
+            health_interval: Seconds between health checks (default: 10).
+            health_timeout: Seconds a health check may take (default: 5).
+            max_daa_lag: DAA score a node may lag behind the most advanced one (default: 600).
+            routes: Method group or RPC method name to the node URLs it may use, `resolver` standing for the resolver nodes (default: any node).
+            config: Settings (e.g. from `Config.load()`) for the node URL, resolver, network and encoding not given.
+
+        Raises:
+            ValueError: If `nodes`, `health_interval` or `health_timeout` is not positive, or a route targets no node.
+        """
+        if nodes <= 0 or health_interval <= 0 or health_timeout <= 0:
+            raise ValueError("nodes, health_interval and health_timeout must be positive")
+        if config is not None and urls is None and resolver is None:
+            urls = [config.url] if config.url is not None else None
+            resolver = config.make_resolver()
+        network_id = network_id or (config.network if config is not None else "mainnet")
+        network_id = network_id if isinstance(network_id, NetworkId) else NetworkId(network_id)
+        encoding = encoding or (config.encoding if config is not None else "borsh")
+
+        self._nodes = [
+            Node(RpcClient(url=url, encoding=encoding, network_id=network_id), url) for url in urls or []
+        ]
+        if resolver is not None or not urls:
+            resolver = resolver or Resolver()
+            self._nodes += [
+                Node(RpcClient(resolver=resolver, encoding=encoding, network_id=network_id), None)
+                for _ in range(nodes)
+            ]
+
+        self._routes = {key: list(targets) for key, targets in (routes or {}).items()}
+        for key, targets in self._routes.items():
+            if not any(node.matches(targets) for node in self._nodes):
+                raise ValueError(f"Route `{key}` targets no node: {targets}")
+
+        self._health_interval = health_interval
+        self._health_timeout = health_timeout
+        self._max_daa_lag = max_daa_lag
+        self._primary: Node | None = None
+        self._health_task: asyncio.Task | None = None
+        # Scope name (e.g. `block_added`) to subscription arguments.
+        self._subscriptions: dict[str, tuple[tuple, dict]] = {}
+        self._utxo_addresses: dict[str, Any] = {}
+        self._listeners: list[tuple[Any, Callable, tuple, dict]] = []
+        self._failover_listeners: list[tuple[Callable, tuple, dict]] = []
+
+    @property
+    def client(self) -> RpcClient | None:
+        """The primary RpcClient, holding subscriptions and listeners."""
+        return self._primary.client if self._primary else None
+
+    @property
+    def url(self) -> str | None:
+        """URL of the primary node."""
+        return self._primary.url if self._primary else None
+
+    @property
+    def is_connected(self) -> bool:
+        """Whether a primary node is connected."""
+        return self._primary is not None and self._primary.client.is_connected
+
+    @property
+    def nodes(self) -> list[dict]:
+        """Health of each node: `url`, `connected`, `healthy`, `synced`, `latency`, `daa_score` and `error`."""
+        return [node.to_dict() for node in self._nodes]
+
+    async def connect(self) -> None:
+        """Connect to the nodes and start health checks.
+
+        Raises:
+            Exception: If no node is healthy.
+        """
+        await self._check()
+        try:
+            await self._promote(self._select("subscriptions"))
+        except Exception:
+            await self.disconnect()
+            raise
+        self._health_task = asyncio.get_running_loop().create_task(self._health_loop())
+
+    async def disconnect(self) -> None:
+        """Stop health checks and disconnect from all nodes."""
+        if self._health_task is not None:
+            self._health_task.cancel()
+            self._health_task = None
+        self._primary = None
+        for node in self._nodes:
+            node.healthy = False
+        await asyncio.gather(
+            *(node.client.disconnect() for node in self._nodes if node.client.is_connected),
+            return_exceptions=True,
+        )
+
+    async def __aenter__(self):
+        await self.connect()
+        return self
+
+    async def __aexit__(self, *exc):
+        await self.disconnect()
+
+    def add_event_listener(self, event: Any, callback: Callable, *args: Any, **kwargs: Any) -> None:
+        """Register a callback for RPC events, or for `failover` events.
+
+        Listeners are held on the primary node and moved on failover.
+        """
+        if event == "failover":
+            self._failover_listeners.append((callback, args, kwargs))
+            return
+        self._listeners.append((event, callback, args, kwargs))
+        if self._primary is not None:
+            self._primary.client.add_event_listener(event, callback, *args, **kwargs)
+
+    def remove_event_listener(self, event: Any, callback: Callable | None = None) -> None:
+        """Remove an event listener, or all listeners of `event` when `callback` is None."""
+        if event == "failover":
+            self._failover_listeners = [
+                entry for entry in self._failover_listeners if callback is not None and entry[0] is not callback
+            ]
+            return
+        self._listeners = [
+            entry
+            for entry in self._listeners
+            if not (entry[0] == event and (callback is None or entry[1] is callback))
+        ]
+        if self._primary is not None:
+            self._primary.client.remove_event_listener(event, callback)
+
+    def __getattr__(self, name: str):
+        if name.startswith("_") or not callable(getattr(RpcClient, name, None)):
+            raise AttributeError(f"'FailoverRpcClient' object has no attribute '{name}'")
+        if name in CONFIGURATION_METHODS:
+            return lambda *args, **kwargs: self._configure(name, args, kwargs)
+        if name.startswith("subscribe_") or name.startswith("unsubscribe_"):
+            return lambda *args, **kwargs: self._subscription(name, args, kwargs)
+        return lambda *args, **kwargs: self._call(name, args, kwargs)
+
+    def _configure(self, name: str, args: tuple, kwargs: dict) -> None:
+        for node in self._nodes:
+            getattr(node.client, name)(*args, **kwargs)
+
+    def _call(self, name: str, args: tuple, kwargs: dict):
+        node = self._select(name)
+        result = getattr(node.client, name)(*args, **kwargs)
+        if not inspect.isawaitable(result):
+            return result
+        return self._complete(node, name, result, args, kwargs)
+
+    async def _complete(self, node: Node, name: str, pending, args: tuple, kwargs: dict):
+        try:
+            return await pending
+        except Exception:
+            was_connected = node.client.is_connected
+            if await self._probe(node) and was_connected:
+                # The node is fine, the request itself failed.
+                raise
+            if node is self._primary:
+                await self._recover()
+            return await getattr(self._select(name).client, name)(*args, **kwargs)
+
+    async def _subscription(self, name: str, args: tuple, kwargs: dict) -> None:
+        subscribe = name.startswith("subscribe_")
+        scope = name.removeprefix("subscribe_") if subscribe else name.removeprefix("unsubscribe_")
+        node = self._require_primary()
+        try:
+            await getattr(node.client, name)(*args, **kwargs)
+        except Exception:
+            was_connected = node.client.is_connected
+            if await self._probe(node) and was_connected:
+                raise
+            # Subscriptions recorded so far are re-issued on the recovered primary.
+            await self._recover()
+            await getattr(self._require_primary().client, name)(*args, **kwargs)
+
+        if scope == "utxos_changed":
+            addresses = {str(address): address for address in (args[0] if args else kwargs["addresses"])}
+            if subscribe:
+                self._utxo_addresses.update(addresses)
+            else:
+                for address in addresses:
+                    self._utxo_addresses.pop(address, None)
+        elif subscribe:
+            self._subscriptions[scope] = (args, kwargs)
+        else:
+            self._subscriptions.pop(scope, None)
+
+    def _require_primary(self) -> Node:
+        if self._primary is None:
+            raise Exception("FailoverRpcClient is not connected")
+        return self._primary
+
+    def _candidates(self, method: str) -> list[Node]:
+        """The nodes `method` (an RPC method name or a method group) may be routed to."""
+        targets = self._routes.get(method) or self._routes.get(method_group(method))
+        if targets is None:
+            return self._nodes
+        return [node for node in self._nodes if node.matches(targets)]
+
+    def _select(self, method: str) -> Node:
+        """The healthy node with the lowest latency among those `method` may use."""
+        candidates = self._candidates(method)
+        healthy = [node for node in candidates if node.healthy]
+        if not healthy:
+            errors = "; ".join(f"{node.url}: {node.error or 'unhealthy'}" for node in candidates)
+            raise Exception(f"No healthy node available for `{method}` ({errors})")
+        return min(healthy, key=lambda node: node.latency)
+
+    async def _probe(self, node: Node) -> bool:
+        client = node.client
+        try:
+            if not client.is_connected:
+                await client.connect(strategy="fallback", timeout_duration=int(self._health_timeout * 1000))
+                node.reconnected = True
+            started = time.monotonic()
+            info = await asyncio.wait_for(client.get_server_info(), self._health_timeout)
+        except Exception as err:
+            node.healthy, node.latency, node.error = False, None, str(err) or type(err).__name__
+            return False
+
+        node.latency = time.monotonic() - started
+        node.synced = info["isSynced"]
+        node.daa_score = info["virtualDaaScore"]
+        node.error = None if node.synced else "not synced"
+        node.healthy = node.synced
+        return node.healthy
+
+    async def _check(self) -> None:
+        await asyncio.gather(*(self._probe(node) for node in self._nodes))
+        scores = [node.daa_score for node in self._nodes if node.healthy]
+        if not scores:
+            return
+        for node in self._nodes:
+            if node.healthy and max(scores) - node.daa_score > self._max_daa_lag:
+                node.healthy, node.error = False, f"lagging {max(scores) - node.daa_score} DAA behind"
+
+    async def _health_loop(self) -> None:
+        while True:
+            await asyncio.sleep(self._health_interval)
+            await self._check()
+            if self._primary is None or not self._primary.healthy or self._primary.reconnected:
+                try:
+                    await self._recover()
+                except Exception:
+                    # Retried at the next health check.
+                    pass
+
+    async def _recover(self) -> None:
+        """Re-subscribe on a reconnected primary, or fail over from an unhealthy one."""
+        primary = self._primary
+        if primary is not None and primary.healthy and primary.reconnected:
+            await self._subscribe(primary)
+        else:
+            await self._promote(self._select("subscriptions"))
+
+    async def _subscribe(self, node: Node) -> None:
+        node.reconnected = False
+        for scope, (args, kwargs) in self._subscriptions.items():
+            await getattr(node.client, f"subscribe_{scope}")(*args, **kwargs)
+        if self._utxo_addresses:
+            await node.client.subscribe_utxos_changed(list(self._utxo_addresses.values()))
+
+    async def _promote(self, node: Node) -> None:
+        """Move listeners and subscriptions to `node` and make it the primary."""
+        previous, self._primary = self._primary, node
+        if previous is node:
+            return
+
+        for event, callback, args, kwargs in self._listeners:
+            if previous is not None:
+                previous.client.remove_event_listener(event, callback)
+            node.client.add_event_listener(event, callback, *args, **kwargs)
+        await self._subscribe(node)
+
+        if previous is None:
+            return
+        if previous.client.is_connected:
+            # Best effort: stop duplicate notifications from a node still up.
+            unsubscribe = [
+                getattr(previous.client, f"unsubscribe_{scope}")(*args, **kwargs)
+                for scope, (args, kwargs) in self._subscriptions.items()
+            ]
+            if self._utxo_addresses:
+                unsubscribe.append(previous.client.unsubscribe_utxos_changed(list(self._utxo_addresses.values())))
+            await asyncio.gather(*unsubscribe, return_exceptions=True)
+
+        event = {"type": "failover", "from": previous.url, "to": node.url}
+        for callback, args, kwargs in self._failover_listeners:
+            callback(*args, event, **kwargs)
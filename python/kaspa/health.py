@@ -0,0 +1,113 @@
+"""Health reports for services embedding the SDK, e.g. behind `/healthz`."""
+
+from typing import Any, Sequence
+
+
+def rpc_report(rpc: Any) -> dict:
+    # FailoverRpcClient (its primary, None when disconnected) and BlockingRpcClient wrap an RpcClient.
+    client = rpc.client if hasattr(rpc, "client") else rpc
+    if client is None:
+        return {
+            "connected": False,
+            "url": None,
+            "node_id": None,
+            "latency": None,
+            "last_notification_age": None,
+            "queued_requests": 0,
+            "abandoned_requests": 0,
+        }
+    return {
+        "connected": client.is_connected,
+        "url": client.url,
+        "node_id": client.node_id,
+        "latency": client.latency(),
+        "last_notification_age": client.last_notification_age,
+        "queued_requests": client.queued_requests,
+        "abandoned_requests": client.abandoned_requests,
+    }
+
+
+def processor_report(processor: Any) -> dict:
+    return {
+        "running": processor.is_active,
+        "synced": processor.is_synced,
+        "queue_depth": processor.queue_depth,
+        "dropped_events": processor.dropped_events,
+    }
+
+
+def context_report(context: Any) -> dict:
+    return {
+        "id": str(context.id),
+        "active": context.is_active,
+        "mature": context.mature_length,
+        "pending": len(context.pending()),
+        "in_flight": len(context.in_flight),
+    }
+
+
+def health(
+    rpc: Any = None,
+    processor: Any = None,
+    contexts: Sequence[Any] = (),
+    max_notification_age: float | None = None,
+    max_queue_depth: int | None = None,
+) -> dict:
+    """A health report of the SDK components a service runs.
+
+    Intended to back a `/healthz` endpoint: the report is built from local
+    state only, without RPC calls, so it can be polled often.
+
+        report = kaspa.health(client, processor, [context], max_notification_age=60)
+        return web.json_response(report, status=200 if report["healthy"] else 503)
+
+    Args:
+        rpc: The `RpcClient` (or `FailoverRpcClient`) (default: the processor's client).
+        processor: A `UtxoProcessor` whose sync state and event queue to report.
+        contexts: `UtxoContext`s whose UTXO counts to report.
+        max_notification_age: Seconds without a node notification after which
+            the service is unhealthy (default: not checked).
+        max_queue_depth: Processor event queue depth above which the service
+            is unhealthy (default: not checked).
+
+    Returns:
+        dict: `healthy` (bool), `problems` (list of str explaining an unhealthy
+        report), `rpc` (`connected`, `url`, `node_id`, `latency`,
+        `last_notification_age`, `queued_requests`, `abandoned_requests`),
+        `processor` (`running`, `synced`, `queue_depth`, `dropped_events`) and
+        `contexts` (`id`, `active`, `mature`, `pending`, `in_flight` each);
+        `rpc` and `processor` are None when not given.
+    """
+    if rpc is None and processor is not None:
+        rpc = processor.rpc
+    problems = []
+
+    rpc_status = rpc_report(rpc) if rpc is not None else None
+    if rpc_status is not None:
+        age = rpc_status["last_notification_age"]
+        if not rpc_status["connected"]:
+            problems.append("rpc not connected")
+        elif max_notification_age is not None and (age is None or age > max_notification_age):
+            problems.append("no notification received" if age is None else f"last notification {age:.0f}s ago")
+
+    processor_status = processor_report(processor) if processor is not None else None
+    if processor_status is not None:
+        if not processor_status["running"]:
+            problems.append("processor not running")
+        elif not processor_status["synced"]:
+            problems.append("node not synced")
+        if max_queue_depth is not None and processor_status["queue_depth"] > max_queue_depth:
+            problems.append(f"event queue depth {processor_status['queue_depth']} above {max_queue_depth}")
+
+    context_status = [context_report(context) for context in contexts]
+    for context in context_status:
+        if not context["active"]:
+            problems.append(f"context {context['id']} not active")
+
+    return {
+        "healthy": not problems,
+        "problems": problems,
+        "rpc": rpc_status,
+        "processor": processor_status,
+        "contexts": context_status,
+    }
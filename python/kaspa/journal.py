@@ -0,0 +1,142 @@
+"""Append-only journal of processor events with replay."""
+
+import json
+import os
+import sqlite3
+import threading
+import time
+from typing import Any, Iterator, Sequence
+
+FORMATS = ("jsonl", "sqlite")
+
+
+def read_lines(path: str) -> Iterator[dict]:
+    """Entries of a JSONL journal, skipping lines cut off by a crash."""
+    with open(path, encoding="utf-8") as file:
+        for line in file:
+            try:
+                yield json.loads(line)
+            except json.JSONDecodeError:
+                continue
+
+
+class EventJournal:
+    """Append-only journal of UtxoProcessor (or RpcClient) events.
+
+    Every event is stored with a cursor, a sequence number increasing by one per
+    event starting at 1. A consumer records the cursor of the last event it
+    processed and, after a crash or restart, calls `replay(cursor)` to receive
+    the events it missed:
+
+        journal = EventJournal("events.jsonl")
+        journal.attach(processor, ["pending", "maturity", "reorg"])
+
+        for entry in journal.replay(last_processed_cursor):
+            handle(entry["data"])
+
+    Events are stored as JSON lines (`format="jsonl"`) or in an SQLite database
+    (`format="sqlite"`, default for `.db`/`.sqlite`/`.sqlite3` paths). Values that
+    are not JSON serializable are stored as strings.
+    """
+
+    def __init__(self, path: str | os.PathLike, format: str | None = None, fsync: bool = False):
+        self.path = os.fspath(path)
+        if format is None:
+            format = "sqlite" if self.path.endswith((".db", ".sqlite", ".sqlite3")) else "jsonl"
+        if format not in FORMATS:
+            raise ValueError(f"format must be one of {', '.join(FORMATS)}, got {format!r}")
+        self.format = format
+        self.fsync = fsync
+        self._lock = threading.Lock()
+        if format == "sqlite":
+            self._db = sqlite3.connect(self.path, check_same_thread=False)
+            self._db.execute(
+                "CREATE TABLE IF NOT EXISTS events ("
+                "cursor INTEGER PRIMARY KEY, type TEXT, timestamp INTEGER, data TEXT)"
+            )
+            self._db.commit()
+            self._cursor = self._db.execute("SELECT COALESCE(MAX(cursor), 0) FROM events").fetchone()[0]
+        else:
+            self._cursor = 0
+            truncated = False
+            if os.path.exists(self.path):
+                for entry in read_lines(self.path):
+                    self._cursor = entry["cursor"]
+                if os.path.getsize(self.path) > 0:
+                    with open(self.path, "rb") as file:
+                        file.seek(-1, os.SEEK_END)
+                        truncated = file.read(1) != b"\n"
+            self._file = open(self.path, "a", encoding="utf-8")
+            if truncated:
+                # Terminate a line cut off by a crash; it is skipped when reading.
+                self._file.write("\n")
+
+    @property
+    def cursor(self) -> int:
+        """Cursor of the last event appended, 0 if the journal is empty."""
+        return self._cursor
+
+    def attach(self, source: Any, events: str | Sequence[str] = "all", **filters: Any) -> None:
+        """Record `events` of `source`, e.g. a UtxoProcessor.
+
+        Keyword arguments such as `addresses` or `contexts` are passed to
+        `add_event_listener()` to filter the recorded events.
+        """
+        source.add_event_listener(events, self.append, **filters)
+
+    def detach(self, source: Any, events: str | Sequence[str] = "all") -> None:
+        """Stop recording events of `source`."""
+        source.remove_event_listener(events, self.append)
+
+    def append(self, event: dict) -> int:
+        """Append an event (a dict with `type` and `data`) and return its cursor."""
+        with self._lock:
+            cursor = self._cursor + 1
+            timestamp = int(time.time() * 1000)
+            if self.format == "sqlite":
+                self._db.execute(
+                    "INSERT INTO events (cursor, type, timestamp, data) VALUES (?, ?, ?, ?)",
+                    (cursor, event.get("type"), timestamp, json.dumps(event.get("data"), default=str)),
+                )
+                self._db.commit()
+            else:
+                entry = {"cursor": cursor, "type": event.get("type"), "timestamp": timestamp, "data": event.get("data")}
+                self._file.write(json.dumps(entry, default=str) + "\n")
+                self._file.flush()
+                if self.fsync:
+                    os.fsync(self._file.fileno())
+            self._cursor = cursor
+            return cursor
+
+    def replay(self, from_cursor: int = 0) -> Iterator[dict]:
+        """Iterate over the events after `from_cursor`, oldest first.
+
+        Yields:
+            dict: Entries with `cursor`, `type`, `timestamp` (milliseconds) and `data`.
+        """
+        if self.format == "sqlite":
+            with self._lock:
+                rows = self._db.execute(
+                    "SELECT cursor, type, timestamp, data FROM events WHERE cursor > ? ORDER BY cursor",
+                    (from_cursor,),
+                ).fetchall()
+            for cursor, event_type, timestamp, data in rows:
+                yield {"cursor": cursor, "type": event_type, "timestamp": timestamp, "data": json.loads(data)}
+        else:
+            for entry in read_lines(self.path):
+                if entry["cursor"] > from_cursor:
+                    yield entry
+
+    def close(self) -> None:
+        """Close the journal file or database."""
+        with self._lock:
+            if self.format == "sqlite":
+                self._db.close()
+            else:
+                self._file.close()
+
+    def __enter__(self) -> "EventJournal":
+        return self
+
+    def __exit__(self, *exc: Any) -> None:
+        self.close()
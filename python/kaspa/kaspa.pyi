@@ -2,9 +2,13 @@
 # ruff: noqa: E501, F401
 
 import builtins
+import decimal
 import enum
+import os
 import typing
 
+from .config import Config
+
 @typing.final
 class AccountKind:
     r"""
@@ -97,7 +101,7 @@ class Address:
             Address: A new Address instance.
         
         Raises:
-            Exception: If the address string is invalid.
+            ValueError: If the address string is invalid.
         """
     @staticmethod
     def validate(address: builtins.str) -> builtins.bool:
@@ -110,6 +114,52 @@ class Address:
         Returns:
             bool: True if the address is valid, False otherwise.
         """
+    @staticmethod
+    def resolve(name: builtins.str) -> Address:
+        r"""
+        Resolve a name (e.g. "name.kas") to an Address with a registered resolver.
+        
+        Address strings are returned as is. Other names are passed to the
+        resolver registered for the longest matching suffix.
+        
+        Args:
+            name: The name or address string to resolve.
+        
+        Returns:
+            Address: The resolved address.
+        
+        Raises:
+            ValueError: If no resolver handles the name or it does not resolve to a valid address.
+            Exception: Any exception raised by the resolver.
+        """
+    @staticmethod
+    def register_resolver(suffix: builtins.str, resolver: typing.Callable[[str], Address | str | None]) -> None:
+        r"""
+        Register a name resolver for names ending in `suffix`.
+        
+        The resolver is called with the full name and returns an Address, an
+        address string, or None if the name is not registered. It replaces any
+        resolver previously registered for the same suffix. Resolvers are
+        called synchronously, so network lookups block the caller.
+        
+        Args:
+            suffix: The name suffix handled by the resolver (e.g. ".kas").
+            resolver: A callable taking the name.
+        
+        Raises:
+            ValueError: If the suffix is empty or the resolver is not callable.
+        """
+    @staticmethod
+    def unregister_resolver(suffix: builtins.str) -> builtins.bool:
+        r"""
+        Remove the name resolver registered for `suffix`.
+        
+        Args:
+            suffix: The name suffix of the resolver.
+        
+        Returns:
+            bool: True if a resolver was removed.
+        """
     def to_string(self) -> builtins.str:
         r"""
         The string representation of the Address.
@@ -117,6 +167,45 @@ class Address:
         Returns:
             str: A bech32 encoded Kaspa address string.
         """
+    def to_network(self, network_id: str | NetworkId, allow_mainnet: builtins.bool = False) -> Address:
+        r"""
+        Re-encode the address for another network.
+        
+        The result has the same payload (public key or script hash) under the
+        network's prefix, so it is controlled by the same key or script. This
+        is meant for moving test fixtures between networks: it does not derive
+        a new address, and reusing keys across networks links them. A
+        `UserWarning` is emitted when the prefix changes; testnet-10 and
+        testnet-11 share a prefix, so converting between them is a copy.
+        
+        Args:
+            network_id: The target network (e.g. "testnet-10").
+            allow_mainnet: Allow converting to or from mainnet (default False).
+        
+        Returns:
+            Address: The re-encoded address.
+        
+        Raises:
+            ValueError: If mainnet is involved and `allow_mainnet` is False.
+        """
+    @staticmethod
+    def to_network_many(addresses: typing.Sequence[Address | str], network_id: str | NetworkId, allow_mainnet: builtins.bool = False) -> builtins.list[Address]:
+        r"""
+        Re-encode many addresses for another network (see `to_network()`).
+        
+        A single `UserWarning` is emitted for the whole batch.
+        
+        Args:
+            addresses: Addresses or address strings.
+            network_id: The target network (e.g. "testnet-10").
+            allow_mainnet: Allow converting to or from mainnet (default False).
+        
+        Returns:
+            list[Address]: The re-encoded addresses, in order.
+        
+        Raises:
+            ValueError: If an address is invalid, or mainnet is involved and `allow_mainnet` is False.
+        """
     def short(self, n: builtins.int) -> builtins.str:
         r"""
         Get a shortened representation of the address.
@@ -135,6 +224,128 @@ class Address:
             str: The address as a string
         """
 
+@typing.final
+class AddressClusters:
+    r"""
+    Groups addresses by the common-input heuristic: addresses spent together
+    as inputs of one transaction are assumed to share an owner.
+    
+    Transactions are added in batches with `add_transactions()`; clusters
+    merge as later transactions link them. Each address gets the cluster id
+    of the earliest address of its cluster (the index of that address in the
+    order addresses were first seen), so ids are stable until clusters merge.
+    
+    The heuristic does not hold for CoinJoin-style transactions combining
+    inputs of several owners; filter those out before adding them.
+    """
+    @property
+    def cluster_count(self) -> builtins.int:
+        r"""
+        The number of clusters.
+        """
+    def __new__(cls, network_type: typing.Optional[str | NetworkType] = None) -> AddressClusters:
+        r"""
+        Create an empty clustering.
+        
+        Args:
+            network_type: Network used to derive the address of inputs whose UTXO entry has none (default: such inputs are skipped).
+        
+        Returns:
+            AddressClusters: A new, empty clustering.
+        """
+    def add_transactions(self, transactions: typing.Iterable[Transaction | typing.Sequence[Address | str]]) -> None:
+        r"""
+        Add a batch of transactions, merging the clusters of their input addresses.
+        
+        Args:
+            transactions: `Transaction` objects whose inputs carry UTXO entries, or sequences of the input addresses of each transaction.
+        
+        Raises:
+            Exception: If a transaction is neither a Transaction nor a sequence of addresses.
+        """
+    def cluster(self, address: Address | str) -> typing.Optional[builtins.int]:
+        r"""
+        The cluster id of an address.
+        
+        Args:
+            address: The address.
+        
+        Returns:
+            int | None: The cluster id, None if the address was never seen as an input.
+        """
+    def clusters(self, min_size: builtins.int = 1) -> builtins.list[builtins.list[Address]]:
+        r"""
+        All clusters, ordered by cluster id.
+        
+        Args:
+            min_size: Only return clusters of at least this many addresses (default: 1).
+        
+        Returns:
+            list[list[Address]]: The addresses of each cluster, in the order they were first seen.
+        """
+    def to_dict(self) -> builtins.dict[builtins.str, builtins.int]:
+        r"""
+        The cluster id of every address seen.
+        
+        Returns:
+            dict[str, int]: Cluster ids by address string.
+        """
+
+@typing.final
+class Amount:
+    r"""
+    Amount type for flexible input handling.
+    
+    Allows Rust functions to accept amounts in multiple convenient forms from
+    Python, converted to sompi (1 KAS = 100,000,000 sompi). `Amount(value)`
+    parses a value once, e.g. to display it with `format()`.
+    
+    Accepts:
+        - int: An amount in sompi.
+        - str: An amount with a unit, e.g. "1.5 KAS" or "1500 sompi" (case insensitive,
+          `TKAS`/`SKAS`/`DKAS` are accepted as `KAS`, `,` and `_` separators are ignored).
+        - decimal.Decimal: An amount in KAS.
+        - Amount: A parsed amount.
+    
+    `float` is rejected, as it cannot represent most KAS amounts exactly.
+    """
+    @property
+    def sompi(self) -> builtins.int:
+        r"""
+        The amount in sompi.
+        """
+    def __new__(cls, amount: int | str | decimal.Decimal | Amount) -> Amount:
+        r"""
+        Parse an amount.
+        
+        Args:
+            amount: An `int` in sompi, a string with a unit or a `decimal.Decimal` in KAS.
+        
+        Raises:
+            TypeError: If the amount is a `float` or of an unsupported type.
+            ValueError: If the amount is invalid.
+        """
+    def format(self, unit: builtins.str = "KAS", decimals: typing.Optional[builtins.int] = None, locale: typing.Optional[builtins.str] = None, thousands_separator: typing.Optional[builtins.str] = None, decimal_point: typing.Optional[builtins.str] = None, suffix: builtins.bool = True) -> builtins.str:
+        r"""
+        Format the amount for display.
+        
+        Args:
+            unit: `KAS` (or `TKAS`/`SKAS`/`DKAS`, the suffix shown) or `sompi` (default: KAS).
+            decimals: Fixed number of decimal places, rounded half up (default: as
+                many as needed, at most 8).
+            locale: Use the separators of a locale, e.g. `de_DE` or `fr`, or
+                `system` for the process locale (default: `,` and `.`).
+            thousands_separator: Separator between groups of 3 digits, overriding the locale.
+            decimal_point: Decimal point, overriding the locale.
+            suffix: Append the unit (default: True).
+        
+        Returns:
+            str: The formatted amount, e.g. "1,234.5 KAS" or "1.234,5 KAS".
+        
+        Raises:
+            ValueError: If the unit, decimals or locale are invalid.
+        """
+
 @typing.final
 class Balance:
     r"""
@@ -197,12 +408,115 @@ class Binary:
     data in multiple convenient forms from Python.
     
     Accepts:
-        - str: A hexadecimal string (e.g., "deadbeef").
         - bytes: Python bytes object.
+        - bytearray: Python bytearray object.
+        - memoryview: Any contiguous buffer exposed through a memoryview.
+        - str: A hexadecimal string (e.g., "deadbeef").
         - list[int]: A list of byte values (0-255).
     """
     ...
 
+@typing.final
+class BlockTemplate:
+    r"""
+    A block template returned by `RpcClient.block_templates()`.
+    """
+    @property
+    def block(self) -> dict:
+        r"""
+        The block template, as in the `get_block_template()` response.
+        """
+    @property
+    def is_synced(self) -> builtins.bool:
+        r"""
+        Whether the node was synced when the template was built.
+        """
+    @property
+    def age(self) -> builtins.float:
+        r"""
+        Seconds since the template was fetched.
+        """
+
+@typing.final
+class BlockTemplates:
+    r"""
+    Async iterator over the block templates of a node.
+    
+    Created by `RpcClient.block_templates()`. The first iteration returns the
+    current template; each following one waits for a `new-block-template`
+    notification (or `poll_interval` without one), fetches the template and
+    returns it if its parents or transactions changed.
+    
+    Iteration stops when the client disconnects.
+    """
+    def __aiter__(self) -> BlockTemplates: ...
+    def __anext__(self) -> typing.Awaitable[BlockTemplate]:
+        r"""
+        Wait for the next changed block template.
+        
+        Returns:
+            BlockTemplate: The template.
+        
+        Raises:
+            StopAsyncIteration: When the client is disconnected.
+            Exception: If fetching the template fails.
+        """
+
+@typing.final
+class CoinSupply:
+    r"""
+    The coin supply of the network.
+    
+    Returned by `RpcClient.coin_supply()`. Amounts are available in sompi
+    and as formatted KAS strings (e.g. "28,704,026,601.00000000").
+    """
+    @property
+    def max_supply(self) -> builtins.int:
+        r"""
+        The maximum supply in sompi.
+        """
+    @property
+    def circulating(self) -> builtins.int:
+        r"""
+        The circulating supply in sompi.
+        """
+    @property
+    def max_supply_kas(self) -> builtins.str:
+        r"""
+        The maximum supply as a formatted KAS string.
+        """
+    @property
+    def circulating_kas(self) -> builtins.str:
+        r"""
+        The circulating supply as a formatted KAS string.
+        """
+    @property
+    def circulating_ratio(self) -> builtins.float:
+        r"""
+        The share of the maximum supply in circulation, between 0 and 1.
+        """
+    @classmethod
+    def from_dict(cls, dict: dict) -> CoinSupply:
+        r"""
+        Create a CoinSupply from a `RpcClient.get_coin_supply()` response dict.
+        
+        Args:
+            dict: The response dict (with `maxSompi` and `circulatingSompi` keys).
+        
+        Returns:
+            CoinSupply: A new CoinSupply instance.
+        
+        Raises:
+            Exception: If the dict is not a coin supply response.
+        """
+    def to_dict(self) -> dict:
+        r"""
+        The response as a dict (`maxSompi` and `circulatingSompi`).
+        
+        Returns:
+            dict: Dictionary representation of the coin supply.
+        """
+
 @typing.final
 class DerivationPath:
     r"""
@@ -264,6 +578,171 @@ class DerivationPath:
             str: The path as a string (e.g., "m/44'/111111'/0'").
         """
 
+@typing.final
+class FeeEstimate:
+    r"""
+    Fee estimate of the node's mempool.
+    
+    Returned by `RpcClient.fee_estimate()`. Buckets are ordered from the highest
+    fee rate (priority) to the lowest.
+    """
+    @property
+    def priority_bucket(self) -> FeerateBucket:
+        r"""
+        The top-priority bucket, for inclusion in the next block.
+        """
+    @property
+    def normal_buckets(self) -> builtins.list[FeerateBucket]:
+        r"""
+        Buckets with normal priority, ordered by decreasing fee rate.
+        """
+    @property
+    def low_buckets(self) -> builtins.list[FeerateBucket]:
+        r"""
+        Buckets with low priority, ordered by decreasing fee rate.
+        """
+    @property
+    def buckets(self) -> builtins.list[FeerateBucket]:
+        r"""
+        All buckets (priority, normal and low), ordered by decreasing fee rate.
+        """
+    @property
+    def verbose(self) -> typing.Optional[dict]:
+        r"""
+        Verbose mempool data (requested with `verbose=True`), or None.
+        
+        Contains `mempoolReadyTransactionsCount`, `mempoolReadyTransactionsTotalMass`,
+        `networkMassPerSecond` and `nextBlockTemplateFeerateMin`/`Median`/`Max`.
+        """
+    @classmethod
+    def from_dict(cls, dict: dict) -> FeeEstimate:
+        r"""
+        Create a FeeEstimate from a `RpcClient.get_fee_estimate()` or
+        `RpcClient.get_fee_estimate_experimental()` response dict.
+        
+        Args:
+            dict: The response dict (with an `estimate` key and optional `verbose` key).
+        
+        Returns:
+            FeeEstimate: A new FeeEstimate instance.
+        
+        Raises:
+            Exception: If the dict is not a fee estimate response.
+        """
+    def bucket_for_deadline(self, seconds: builtins.float) -> FeerateBucket:
+        r"""
+        The cheapest bucket expected to be included within a deadline.
+        
+        Args:
+            seconds: The maximum acceptable time to inclusion in seconds.
+        
+        Returns:
+            FeerateBucket: The lowest fee rate bucket with `estimated_seconds <= seconds`,
+                or the priority bucket if no bucket meets the deadline.
+        """
+    def to_dict(self) -> dict:
+        r"""
+        The response as a dict (`estimate` and `verbose`).
+        
+        Returns:
+            dict: Dictionary representation of the fee estimate.
+        """
+
+@typing.final
+class FeerateBucket:
+    r"""
+    A fee estimate bucket: a fee rate and the expected time to inclusion.
+    
+    This type is not intended to be instantiated directly from Python.
+    It is returned by `FeeEstimate` and can be passed to `Fees.from_bucket()`.
+    """
+    @property
+    def feerate(self) -> builtins.float:
+        r"""
+        The fee rate in sompi per gram of transaction mass.
+        """
+    @property
+    def estimated_seconds(self) -> builtins.float:
+        r"""
+        The estimated time to inclusion in seconds.
+        """
+    def to_dict(self) -> dict:
+        r"""
+        The bucket as a dict (`feerate`, `estimatedSeconds`).
+        
+        Returns:
+            dict: Dictionary representation of the bucket.
+        """
+
+@typing.final
+class Fees:
+    r"""
+    Transaction fee specification.
+    
+    A fee is either an absolute priority fee in sompi (paid by the sender, or
+    deducted from the outputs with `receiver_pays`), or a fee rate in sompi per
+    gram of transaction mass. Functions accepting a `Fees` also accept a plain
+    amount (`int` sompi, or a string such as "0.01 KAS", see `Amount`), which is
+    an absolute priority fee paid by the sender.
+    
+    Precedence: an absolute fee is added on top of the network fee computed from
+    `fee_rate`. A rate `Fees` replaces `fee_rate`, so passing both raises.
+    """
+    @property
+    def amount(self) -> typing.Optional[builtins.int]:
+        r"""
+        The absolute priority fee in sompi, or None for a fee rate.
+        """
+    @property
+    def fee_rate(self) -> typing.Optional[builtins.float]:
+        r"""
+        The fee rate in sompi per gram, or None for an absolute fee.
+        """
+    @property
+    def receiver_pays(self) -> builtins.bool:
+        r"""
+        Whether the absolute fee is deducted from the payment outputs.
+        """
+    def __new__(cls, amount: Amount, receiver_pays: builtins.bool = False) -> Fees:
+        r"""
+        Create an absolute priority fee.
+        
+        Args:
+            amount: The priority fee in sompi, or an amount string such as "0.01 KAS".
+            receiver_pays: Deduct the fee from the payment outputs instead of the change (default: False).
+        
+        Returns:
+            Fees: A new absolute Fees instance.
+        """
+    @staticmethod
+    def rate(sompi_per_gram: builtins.float) -> Fees:
+        r"""
+        Create a fee rate in sompi per gram of transaction mass.
+        
+        Args:
+            sompi_per_gram: The fee rate (must be a positive finite number).
+        
+        Returns:
+            Fees: A new rate Fees instance.
+        
+        Raises:
+            Exception: If the fee rate is not positive.
+        """
+    @staticmethod
+    def from_bucket(bucket: typing.Any) -> Fees:
+        r"""
+        Create a fee rate from a fee estimate bucket.
+        
+        Args:
+            bucket: A bucket of `RpcClient.get_fee_estimate()` (dict with a `feerate` key) or any object with a `feerate` attribute.
+        
+        Returns:
+            Fees: A new rate Fees instance using the bucket feerate.
+        
+        Raises:
+            Exception: If the bucket has no valid `feerate`.
+        """
+
 @typing.final
 class Generator:
     r"""
@@ -271,38 +750,53 @@ class Generator:
     
     Handles UTXO selection, fee calculation, change outputs, and transaction
     splitting for large transfers.
+    
+    When created from a UtxoContext, the UTXOs it selects are locked on the
+    context (see `UtxoContext.lock()`) so concurrent Generators do not spend them.
     """
-    def __new__(cls, entries: UtxoEntries | UtxoContext, change_address: Address, network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> Generator:
+    def __new__(cls, entries: UtxoEntries | UtxoContext, change_address: Address | builtins.str | typing.Callable[[], Address | builtins.str], network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[Amount | Fees] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, min_change: typing.Optional[Amount] = None, split_outputs: typing.Optional[builtins.int] = None, percentage_outputs: typing.Optional[typing.Sequence[dict]] = None) -> Generator:
         r"""
         Create a new transaction generator.
         
         Args:
             network_id: The network to build transactions for (required for UTXO entries).
             entries: UtxoContext or list of UTXO entries to spend from.
-            change_address: Address to send change to.
+            change_address: Address to send change to, or a callable returning a fresh change address (invoked once per Generator, and again by `TransactionPlan.rebuild()`).
             outputs: Optional list of payment outputs.
             payload: Optional transaction payload (OP_RETURN data).
-            fee_rate: Optional fee rate multiplier.
-            priority_fee: Additional fee in sompi.
+            fee_rate: Optional fee rate in sompi per gram of mass.
+            priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
             priority_entries: UTXOs to use first.
             sig_op_count: Signature operations per input (default: 1).
             minimum_signatures: For multisig fee estimation.
+            min_change: Optional minimum change amount in sompi. Change of the final transaction below this amount is added to the fee instead of creating a change output (best effort). With receiver-paid fees, the change covers the fees deducted from the first payment output instead.
+            split_outputs: Optional number of equal outputs each payment output is split into (the remainder goes to the first one). Useful to pre-fragment UTXOs.
+            percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` (`"down"`, the default, `"up"` or `"nearest"`). Each routes `percent` of the payment amount to `address`, taken out of the payment outputs in proportion to their amounts (e.g. a platform fee). With receiver-paid fees, percentages apply to the amount after fees.
         
         Returns:
             Generator: A new Generator instance.
         
         Raises:
+            ValueError: If an address does not belong to `network_id`.
             Exception: If generator creation fails.
         """
-    def estimate(self) -> GeneratorSummary:
+    def estimate(self) -> TransactionPlan:
         r"""
         Estimate the transaction without generating.
         
+        The returned plan lists the inputs, outputs and fees of every
+        transaction. No UTXOs stay locked until `TransactionPlan.materialize()`
+        is called to get the transactions to sign.
+        
+        Pending signals are checked between transactions, so a long estimate
+        can be interrupted with Ctrl-C.
+        
         Returns:
-            GeneratorSummary: A summary with fee, transaction count, and other details.
+            TransactionPlan: A GeneratorSummary with the planned transactions.
         
         Raises:
             Exception: If estimation fails.
+            KeyboardInterrupt: If interrupted.
         """
     def summary(self) -> GeneratorSummary:
         r"""
@@ -326,7 +820,6 @@ class Generator:
             Exception: If transaction generation fails.
         """
 
-@typing.final
 class GeneratorSummary:
     r"""
     A class containing a summary produced by transaction Generator.
@@ -385,51 +878,185 @@ class GeneratorSummary:
         """
     def to_dict(self) -> dict:
         r"""
-        Get a dictionary representation of the GeneratorSummary.
+        Get a dictionary representation of the GeneratorSummary.
+        
+        Returns:
+            dict: the GeneratorSummary in dictionary form.
+        """
+    def __eq__(self, other: GeneratorSummary) -> builtins.bool: ...
+
+@typing.final
+class Hash:
+    r"""
+    A 32-byte hash value.
+    
+    Used for transaction IDs, block hashes, and other cryptographic purposes.
+    """
+    def __eq__(self, other: builtins.object) -> builtins.bool: ...
+    def __new__(cls, hex_str: builtins.str) -> Hash:
+        r"""
+        Create a new Hash from a hex string.
+        
+        Args:
+            hex_str: A 64-character hex string representing the hash.
+        
+        Returns:
+            Hash: A new Hash instance.
+        
+        Raises:
+            ValueError: If the hex string is invalid.
+        """
+    @staticmethod
+    def from_bytes(data: Binary) -> Hash:
+        r"""
+        Create a Hash from its 32-byte binary form.
+        
+        Args:
+            data: The hash as bytes, bytearray, memoryview, or hex string.
+        
+        Returns:
+            Hash: A new Hash instance.
+        
+        Raises:
+            Exception: If the data is not exactly 32 bytes.
+        """
+    def to_bytes(self) -> bytes:
+        r"""
+        Convert the hash to bytes.
+        
+        Returns:
+            bytes: The 32-byte hash.
+        """
+    def to_string(self) -> builtins.str:
+        r"""
+        Convert the hash to a hex string.
+        
+        Returns:
+            str: A 64-character hex string.
+        """
+    def __str__(self) -> builtins.str:
+        r"""
+        The string representation.
+        
+        Returns:
+            str: The Hash as a hex string
+        """
+    def __bytes__(self) -> bytes:
+        r"""
+        The byte representation
+        """
+
+@typing.final
+class HeaderWalk:
+    r"""
+    Async iterator over block headers, walking the DAG towards genesis.
+    
+    Created by `RpcClient.walk_selected_parent_chain()` and
+    `RpcClient.get_block_ancestors()`. Each block is fetched with
+    `get_block()` (without transactions) only when the next header is
+    requested, so a walk can be abandoned at any point.
+    
+    Headers are dicts as in the `get_block()` response.
+    """
+    def __aiter__(self) -> HeaderWalk: ...
+    def __anext__(self) -> typing.Awaitable[dict]:
+        r"""
+        Fetch the next header of the walk.
         
         Returns:
-            dict: the GeneratorSummary in dictionary form.
+            dict: The header.
+        
+        Raises:
+            StopAsyncIteration: When the walk reached its limit or genesis.
+            Exception: If fetching a block fails.
         """
-    def __eq__(self, other: GeneratorSummary) -> builtins.bool: ...
 
 @typing.final
-class Hash:
+class KeyHandle:
     r"""
-    A 32-byte hash value.
+    A reference to a key stored in a `KeyVault`.
+    """
+    @property
+    def id(self) -> builtins.str:
+        r"""
+        The key id, its x-only public key as a hex string.
+        """
+
+@typing.final
+class KeyVault:
+    r"""
+    An in-memory store of private keys encrypted at rest.
     
-    Used for transaction IDs, block hashes, and other cryptographic purposes.
+    Imported keys are encrypted with a secret (XChaCha20-Poly1305, key derived
+    with Argon2) and only decrypted for a signing operation, or while unlocked
+    with `unlock()` for at most `ttl` seconds. Signing APIs accept `KeyHandle`s
+    from `key()` in place of `PrivateKey`s, so long-running processes do not keep
+    raw keys around.
     """
-    def __eq__(self, other: builtins.object) -> builtins.bool: ...
-    def __new__(cls, hex_str: builtins.str) -> Hash:
+    @property
+    def ids(self) -> builtins.list[builtins.str]:
         r"""
-        Create a new Hash from a hex string.
+        The ids of the stored keys.
+        """
+    def __new__(cls) -> KeyVault:
+        r"""
+        Create an empty vault.
+        """
+    def import_key(self, private_key: PrivateKey, secret: builtins.str) -> builtins.str:
+        r"""
+        Encrypt and store a private key.
         
         Args:
-            hex_str: A 64-character hex string representing the hash.
+            private_key: The key to store.
+            secret: The secret encrypting the key.
         
         Returns:
-            Hash: A new Hash instance.
+            str: The key id, its x-only public key as a hex string.
         
         Raises:
-            Exception: If the hex string is invalid.
+            ValueError: If `secret` is empty.
         """
-    def to_string(self) -> builtins.str:
+    def remove(self, id: builtins.str) -> builtins.bool:
         r"""
-        Convert the hash to a hex string.
+        Remove a key from the vault.
         
         Returns:
-            str: A 64-character hex string.
+            bool: True if the key was stored.
         """
-    def __str__(self) -> builtins.str:
+    def unlock(self, id: builtins.str, secret: builtins.str, ttl: typing.Optional[builtins.float] = None) -> None:
         r"""
-        The string representation.
+        Keep a key decrypted so handles without a secret can sign with it.
         
-        Returns:
-            str: The Hash as a hex string
+        Args:
+            id: The key id.
+            secret: The secret the key was imported with.
+            ttl: Seconds until the key locks again (default: until `lock()`).
+        
+        Raises:
+            ValueError: If the id is unknown, the secret is wrong or `ttl` is not positive.
         """
-    def __bytes__(self) -> bytes:
+    def lock(self, id: typing.Optional[builtins.str] = None) -> None:
         r"""
-        The byte representation
+        Lock a key, or all keys, erasing their decrypted copies.
+        """
+    def is_unlocked(self, id: builtins.str) -> builtins.bool:
+        r"""
+        Whether a key is unlocked.
+        """
+    def key(self, id: builtins.str, secret: typing.Optional[builtins.str] = None) -> KeyHandle:
+        r"""
+        A handle signing with a stored key, accepted by signing APIs in place of a `PrivateKey`.
+        
+        Args:
+            id: The key id.
+            secret: Decrypt the key with this secret for each operation. Without
+                it, the key must be unlocked when signing.
+        
+        Returns:
+            KeyHandle: The handle.
+        
+        Raises:
+            ValueError: If the id is unknown.
         """
 
 @typing.final
@@ -468,7 +1095,7 @@ class Keypair:
             Keypair: A new Keypair instance.
         
         Raises:
-            Exception: If any key format is invalid.
+            ValueError: If any key format is invalid.
         """
     def to_address(self, network: str | NetworkType) -> Address:
         r"""
@@ -513,6 +1140,85 @@ class Keypair:
             Exception: If derivation fails.
         """
 
+@typing.final
+class MiningJob:
+    r"""
+    A mining job built from a block template, e.g. for a stratum bridge.
+    
+    Holds the header pre-PoW hash and target miners work on, and validates
+    nonces (kHeavyHash) locally, so shares can be checked against a pool
+    target and blocks against the network target before `submit_block()`.
+    """
+    @property
+    def pre_pow_hash(self) -> builtins.str:
+        r"""
+        The header hash with zero nonce and timestamp, as hex string.
+        """
+    @property
+    def pre_pow_hash_words(self) -> builtins.list[builtins.int]:
+        r"""
+        The pre-PoW hash as four little-endian 64-bit words, as sent in stratum `mining.notify` jobs.
+        """
+    @property
+    def timestamp(self) -> builtins.int:
+        r"""
+        The header timestamp in milliseconds.
+        """
+    @property
+    def bits(self) -> builtins.int:
+        r"""
+        The compact network target (difficulty bits).
+        """
+    @property
+    def target(self) -> builtins.int:
+        r"""
+        The network target, as integer.
+        """
+    def __new__(cls, template: BlockTemplate | dict) -> MiningJob:
+        r"""
+        Create a mining job from a block template.
+        
+        Args:
+            template: A `BlockTemplate`, a `get_block_template()` response or its `block` dict.
+        
+        Returns:
+            MiningJob: A new MiningJob instance.
+        
+        Raises:
+            Exception: If the template cannot be decoded.
+        """
+    def pow_value(self, nonce: builtins.int) -> builtins.int:
+        r"""
+        Compute the proof-of-work value of a nonce.
+        
+        Args:
+            nonce: The nonce.
+        
+        Returns:
+            int: The kHeavyHash PoW value; the nonce is valid for targets greater than or equal to it.
+        """
+    def check_nonce(self, nonce: builtins.int, target: typing.Optional[builtins.int] = None) -> builtins.bool:
+        r"""
+        Check a nonce against a target.
+        
+        Args:
+            nonce: The nonce submitted by a miner.
+            target: The target to check against, e.g. a pool share target (default: the network target).
+        
+        Returns:
+            bool: True if the PoW value of the nonce does not exceed the target.
+        """
+    def block(self, nonce: builtins.int) -> dict:
+        r"""
+        The template block with `nonce` set, for `submit_block()`.
+        
+        Args:
+            nonce: The nonce found by a miner.
+        
+        Returns:
+            dict: The block, e.g. `await client.submit_block({"block": job.block(nonce), "allowNonDaaBlocks": False})`.
+        """
+
 @typing.final
 class Mnemonic:
     r"""
@@ -722,7 +1428,7 @@ class Outputs:
     
     Accepts:
         list[PaymentOutput]: A list of PaymentOutput objects.
-        list[dict]: A list of dicts with `address` and `amount` keys.
+        list[dict]: A list of dicts with `address` and `amount` (an `Amount`) keys.
     """
     ...
 
@@ -790,6 +1496,14 @@ class PendingTransaction:
         The transaction type: "batch" for intermediate or "final" for last.
         """
     @property
+    def change_entry(self) -> typing.Optional[UtxoEntryReference]:
+        r"""
+        The change output as an unconfirmed UTXO entry, or None if the transaction has no change.
+        
+        The entry can be spent before the transaction is confirmed (e.g. passed in
+        `priority_entries`). Its `block_daa_score` is set to the maximum value.
+        """
+    @property
     def transaction(self) -> Transaction:
         r"""
         The underlying transaction object for manual inspection or modification.
@@ -808,21 +1522,42 @@ class PendingTransaction:
         Returns:
             list[UtxoEntryReference]: List of UTXO entries.
         """
-    def create_input_signature(self, input_index: builtins.int, private_key: PrivateKey, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
+    def create_input_signature(self, input_index: builtins.int, private_key: PrivateKey | KeyHandle, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
         r"""
         Create a signature for a specific input.
         
         Args:
             input_index: The index of the input to sign.
-            private_key: The private key for signing.
+            private_key: The private key, or a `KeyHandle` from a `KeyVault`.
             sighash_type: The signature hash type (default: All).
         
         Returns:
             str: The signature as a hex string.
         
         Raises:
+            PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
             Exception: If signing fails.
         """
+    def signature_hash(self, input_index: builtins.int, sighash_type: str | SighashType | None = SighashType.All, ecdsa: typing.Optional[builtins.bool] = None) -> builtins.str:
+        r"""
+        The signature hash of an input, the message a signer signs for it.
+        
+        Lets keys live outside the process (e.g. a signing service or HSM): the
+        external signer signs the hash, and the signature is set with `fill_input()`.
+        Signing policies are consulted first (see `add_signing_policy()`).
+        
+        Args:
+            input_index: The index of the input.
+            sighash_type: The signature hash type (default: All).
+            ecdsa: Compute the ECDSA instead of the Schnorr hash (default: ECDSA for P2PK ECDSA inputs).
+        
+        Returns:
+            str: The 32-byte hash as a hex string.
+        
+        Raises:
+            PolicyViolationError: If a signing policy vetoes the transaction.
+            ValueError: If `input_index` is out of range.
+        """
     def fill_input(self, input_index: builtins.int, signature_script: Binary) -> None:
         r"""
         Fill an input's signature script with a pre-computed signature.
@@ -834,33 +1569,100 @@ class PendingTransaction:
         Raises:
             Exception: If filling fails.
         """
-    def sign_input(self, input_index: builtins.int, private_key: PrivateKey, sighash_type: str | SighashType | None = SighashType.All) -> None:
+    def sign_input(self, input_index: builtins.int, private_key: PrivateKey | KeyHandle, sighash_type: str | SighashType | None = SighashType.All) -> None:
         r"""
         Sign a specific input with a private key.
         
         Args:
             input_index: The index of the input to sign.
-            private_key: The private key for signing.
+            private_key: The private key, or a `KeyHandle` from a `KeyVault`.
             sighash_type: The signature hash type (default: All).
         
         Raises:
+            PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
             Exception: If signing fails.
         """
     def sign(self, private_keys: list, check_fully_signed: typing.Optional[builtins.bool] = None) -> None:
         r"""
         Sign all inputs with the provided private keys.
         
+        Inputs spending a P2PK script of one of the keys are signed in parallel
+        with the GIL released. Other inputs keep their signature script.
+        
         Args:
-            private_keys: List of PrivateKey objects for signing.
-            check_fully_signed: Verify all inputs are signed (default: None).
+            private_keys: List of PrivateKey objects or `KeyHandle`s for signing.
+            check_fully_signed: Raise if an input is left without a signature (default: None, treated as True).
         
         Raises:
+            PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
             Exception: If signing fails or transaction is not fully signed.
         """
+    def signatures(self) -> builtins.list[dict]:
+        r"""
+        Report the signatures of each input, e.g. to find which input fails verification.
+        
+        Signatures of P2PK inputs are checked against the key of the spent
+        script, signatures of P2SH inputs (e.g. multisig) against the keys of
+        the redeem script.
+        
+        Returns:
+            list[dict]: One dict per input with `index`, `address`, `signed` (the
+                input has signatures), `valid` (all its signatures verify) and
+                `signatures`, a list of dicts with `public_key` (hex, None if the
+                signature matches no key), `sighash_type` (e.g. "All", None if
+                invalid) and `valid`.
+        
+        Raises:
+            Exception: If a UTXO entry of an input is missing.
+        """
+    def finalize_multisig(self, input_index: builtins.int, redeem_script: Binary, signatures: typing.Sequence[Binary]) -> dict:
+        r"""
+        Finalize a multisig input from the signatures collected from its cosigners.
+        
+        Each signature is matched against the keys of the redeem script. When
+        distinct cosigners reach the threshold, the input's signature script is
+        set with their signatures in key order; otherwise the input is left
+        unchanged and the report lists the missing cosigners. Signatures
+        repeating a cosigner or matching no key are reported and left out.
+        
+        Args:
+            input_index: The index of the multisig input.
+            redeem_script: The multisig redeem script of the spent P2SH output.
+            signatures: The cosigner signatures, as returned by `create_input_signature()` (or 64 signature bytes followed by the sighash type).
+        
+        Returns:
+            dict: `index`, `required` (the threshold), `signed` (distinct
+                cosigners with a valid signature), `complete` (the input was
+                finalized), `cosigners` (hex keys that signed), `missing` (hex
+                keys that did not), `duplicates` (hex keys of repeated
+                signatures) and `unmatched` (number of signatures matching no key).
+        
+        Raises:
+            ValueError: If `input_index` is out of range, the redeem script is not a multisig script or not the one of the spent output, or a signature is malformed.
+            Exception: If a UTXO entry of an input is missing.
+        """
+    def validate(self) -> None:
+        r"""
+        Verify the transaction locally by executing the script of each input
+        with the consensus script engine.
+        
+        Catches invalid or missing signatures before submission, with the
+        failing input and script error instead of a node rejection.
+        Verification runs with the GIL released.
+        
+        Raises:
+            ScriptVerificationError: If an input fails script execution (see `kaspa.exceptions`).
+            Exception: If a UTXO entry of an input is missing.
+        """
     def submit(self, rpc_client: RpcClient) -> str:
         r"""
         Submit the signed transaction to the network.
         
+        When generated from a UtxoContext, the change of the submitted transaction
+        is tracked as in-flight and spent first by the next Generator of the context,
+        and the UTXOs locked for this transaction are released. If the node rejects
+        it, the in-flight change it spends is dropped.
+        
         Args:
             rpc_client: The RPC client for submission.
         
@@ -868,6 +1670,7 @@ class PendingTransaction:
             str: The transaction ID on success (async).
         
         Raises:
+            TransactionRejectedError: If the node rejects the transaction (see `kaspa.exceptions`).
             Exception: If submission fails.
         """
 
@@ -889,7 +1692,7 @@ class PrivateKey:
             PrivateKey: A new PrivateKey instance.
         
         Raises:
-            Exception: If the hex string is invalid.
+            ValueError: If the hex string is invalid.
         """
     def to_string(self) -> builtins.str:
         r"""
@@ -1013,7 +1816,7 @@ class PublicKey:
             PublicKey: A new PublicKey instance.
         
         Raises:
-            Exception: If the hex string is invalid.
+            ValueError: If the hex string is invalid.
         """
     def to_string(self) -> builtins.str:
         r"""
@@ -1352,10 +2155,13 @@ class Resolver:
         
         Args:
             urls: Optional list of resolver URLs. Uses defaults if not provided.
-            tls: Use TLS connections (default: False).
+            tls: Only resolve nodes accepting TLS (`wss://`) connections (default: False).
         
         Returns:
             Resolver: A new Resolver instance.
+        
+        Raises:
+            ValueError: If `urls` is empty.
         """
     def urls(self) -> builtins.list[builtins.str]:
         r"""
@@ -1427,7 +2233,33 @@ class RpcClient:
         r"""
         The unique identifier of the connected node, or None if not connected via resolver.
         """
-    def __new__(cls, resolver: typing.Optional[Resolver] = None, url: typing.Optional[builtins.str] = None, encoding: str | Encoding | None = Encoding.Borsh, network_id: typing.Optional[NetworkId] = None) -> RpcClient:
+    @property
+    def cache_size(self) -> typing.Optional[builtins.int]:
+        r"""
+        Number of cached responses, or None if the cache is disabled.
+        """
+    @property
+    def queued_requests(self) -> builtins.int:
+        r"""
+        Number of RPC method calls waiting for the rate limit (see `set_rate_limit()`).
+        """
+    @property
+    def abandoned_requests(self) -> builtins.int:
+        r"""
+        Number of RPC requests cancelled (or timed out) after they were sent,
+        before the node answered.
+        
+        A cancelled request stops waiting for its response and frees its
+        `max_in_flight` slot (see `set_rate_limit()`) at once; the response, if
+        the node still sends one, is discarded.
+        """
+    @property
+    def last_notification_age(self) -> typing.Optional[builtins.float]:
+        r"""
+        Seconds since the last node notification was received, or None if none
+        was received yet.
+        """
+    def __new__(cls, resolver: typing.Optional[Resolver] = None, url: typing.Optional[builtins.str] = None, encoding: str | Encoding | None = Encoding.Borsh, network_id: typing.Optional[NetworkId] = None, config: typing.Optional[Config] = None) -> RpcClient:
         r"""
         Create a new RPC client.
         
@@ -1436,6 +2268,7 @@ class RpcClient:
             url: Optional direct node URL.
             encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant (default: "borsh").
             network_id: Network identifier (default: "mainnet").
+            config: Settings (e.g. from `Config.load()`) for the arguments not given.
         
         Returns:
             RpcClient: A new RpcClient instance.
@@ -1467,6 +2300,9 @@ class RpcClient:
         r"""
         Connect to a Kaspa node (async).
         
+        A blocking connect can be cancelled (e.g. with `asyncio.wait_for()` or
+        Ctrl-C); cancellation disconnects the client and stops retrying.
+        
         Args:
             block_async_connect: Block until connected (default: True).
             strategy: Connection strategy ("retry" or "fallback", default: "retry").
@@ -1489,58 +2325,298 @@ class RpcClient:
         Start the RPC client (async).
         
         Raises:
-            Exception: If starting fails.
-        """
-    def stop(self) -> None:
-        r"""
-        Stop background RPC services (automatically stopped when invoking RpcClient.disconnect).
+            Exception: If starting fails.
+        """
+    def stop(self) -> None:
+        r"""
+        Stop background RPC services (automatically stopped when invoking RpcClient.disconnect).
+        """
+    def trigger_abort(self) -> None:
+        r"""
+        Triggers a disconnection on the underlying WebSocket
+        if the WebSocket is in connected state.
+        This is intended for debug purposes only.
+        Can be used to test application reconnection logic.
+        """
+    def add_event_listener(self, event: RpcEventName | NotificationEvent, callback: typing.Callable[..., None], *args: typing.Any, **kwargs: typing.Any) -> None:
+        r"""
+        Register a callback for RPC events.
+        
+        Args:
+            event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
+            callback: Function to call when event occurs. An `async def` callback is scheduled on the event loop running when it is registered.
+            *args: Additional arguments to pass to callback.
+            **kwargs: Additional keyword arguments to pass to callback.
+        
+        Raises:
+            Exception: If the event type is invalid.
+        """
+    def remove_event_listener(self, event: RpcEventName | NotificationEvent, callback: None | typing.Callable[..., None] = None) -> None:
+        r"""
+        Remove an event listener.
+        
+        Args:
+            event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
+            callback: Specific callback to remove, or None to remove all.
+        
+        Raises:
+            Exception: If the event type is invalid.
+        """
+    @staticmethod
+    def default_port(encoding: Encoding, network: NetworkType) -> builtins.int:
+        r"""
+        Get the default RPC port for a given encoding and network type.
+        
+        Args:
+            encoding: RPC encoding format ("borsh" or "json").
+            network: Network type (e.g., "mainnet", "testnet-10", "testnet-11").
+        
+        Returns:
+            int: The default port number for the specified configuration.
+        """
+    def remove_all_event_listeners(self) -> None:
+        r"""
+        Remove all registered event listeners.
+        """
+    def add_middleware(self, middleware: typing.Any) -> None:
+        r"""
+        Register a middleware invoked around each RPC method call.
+        
+        A middleware is any object implementing some of these methods:
+            - on_request(method, request): Called before the request is sent. Return a dict to replace the request, or None to keep it.
+            - on_response(method, response, latency): Called with the response and the latency in seconds. Return a value to replace the response, or None to keep it.
+            - on_error(method, error, latency): Called when the call fails.
+        
+        Request hooks run in registration order, response and error hooks in reverse
+        order. An exception raised by a hook fails the call. Middleware applies to the
+        request/response RPC methods (e.g. `get_block`, `submit_transaction`).
+        
+        Args:
+            middleware: The middleware object.
+        """
+    def remove_middleware(self, middleware: typing.Optional[typing.Any] = None) -> None:
+        r"""
+        Remove a registered middleware.
+        
+        Args:
+            middleware: The middleware to remove, or None to remove all.
+        """
+    def enable_cache(self, size: builtins.int = 1024, ttl: typing.Optional[builtins.float] = None) -> None:
+        r"""
+        Enable the response cache for immutable queries.
+        
+        Responses of `get_block` and `get_utxo_return_address` are kept in an LRU
+        cache keyed by the request, so repeated lookups of the same block or
+        transaction are served without a round trip. Failed calls are not cached.
+        While the cache is enabled, `get_block` responses omit the block's
+        `verboseData` (children, chain membership and merge sets change as the
+        DAG grows). Enabling the cache again replaces (and empties) the existing
+        one.
+        
+        Args:
+            size: Maximum number of cached responses (default: 1024).
+            ttl: Optional time to live of cached responses in seconds.
+        
+        Raises:
+            Exception: If `ttl` is negative.
+        """
+    def disable_cache(self) -> None:
+        r"""
+        Disable and drop the response cache.
+        """
+    def clear_cache(self) -> None:
+        r"""
+        Remove all cached responses.
+        """
+    def set_keep_alive(self, interval: typing.Optional[builtins.float] = 15.0, timeout: builtins.float = 5.0) -> None:
+        r"""
+        Configure keep-alive pings on the connection.
+        
+        While connected, the node is pinged every `interval` seconds and the round
+        trip is recorded (see `latency()`). When a ping fails or takes longer than
+        `timeout`, a `stale-connection` event is emitted to listeners with the `rpc`
+        URL and the `error`, so the application can reconnect or fail over before
+        requests time out. The event is emitted once per stale period, and again
+        only after a successful ping. Keep-alive is disabled by default.
+        
+        Args:
+            interval: Seconds between pings, or None to disable keep-alive (default: 15).
+            timeout: Seconds a ping may take before the connection is stale (default: 5).
+        
+        Raises:
+            Exception: If `interval` or `timeout` is not positive.
+        """
+    def set_rate_limit(self, rate: typing.Optional[builtins.float] = None, burst: builtins.int = 1, max_in_flight: typing.Optional[builtins.int] = None) -> None:
+        r"""
+        Limit the rate and concurrency of RPC method calls.
+        
+        Calls exceeding `rate` requests per second (after an initial `burst`) or
+        `max_in_flight` concurrent requests are queued and sent first come, first
+        served, keeping scripts within the limits of public nodes. Limits apply to
+        the request/response RPC methods; cached responses are not limited.
+        Setting limits again replaces the previous ones, calling without limits
+        disables limiting.
+        
+        Args:
+            rate: Maximum requests per second, or None for no rate limit.
+            burst: Requests that may be sent at once before `rate` applies (default: 1).
+            max_in_flight: Maximum concurrent requests, or None for no limit.
+        
+        Raises:
+            Exception: If `rate`, `burst` or `max_in_flight` is not positive.
+        """
+    def set_retry_policy(self, attempts: typing.Optional[builtins.int] = 3, base_delay: builtins.float = 0.1, max_delay: builtins.float = 5.0, methods: typing.Optional[dict[str, dict | None]] = None) -> None:
+        r"""
+        Retry RPC method calls failing with transient errors.
+        
+        Calls failing because the client is disconnected, the request timed out
+        or the node is busy are retried up to `attempts` times in total, waiting
+        `base_delay` seconds after the first failure and doubling up to
+        `max_delay`. Half of each wait is random (jitter), so clients do not retry
+        in lockstep. Other errors are raised immediately, after the middleware
+        `on_error` hook sees the final failure only.
+        
+        Methods with side effects (`submit_transaction()`, `submit_block()`,
+        `ban()`, ...) are not retried unless configured in `methods`. Retrying is
+        disabled by default; calling with `attempts=None` disables it again.
+        
+        Args:
+            attempts: Total attempts per call (default: 3), or None to disable retrying.
+            base_delay: Seconds to wait after the first failure (default: 0.1).
+            max_delay: Maximum seconds between attempts (default: 5).
+            methods: Per-method overrides, e.g. `{"submit_transaction": {"attempts": 2},
+                "get_block_template": None}`: a dict with `attempts`, `base_delay`
+                and/or `max_delay` (others inherited), or None to never retry the method.
+        
+        Raises:
+            Exception: If `attempts` or the delays are not positive, or an override is invalid.
+        """
+    def latency(self) -> typing.Optional[builtins.float]:
+        r"""
+        Round trip of the last successful ping in seconds.
+        
+        Updated by keep-alive pings and by `ping()` calls.
+        
+        Returns:
+            float | None: The latency, or None if no ping succeeded yet.
+        """
+    def call(self, method: builtins.str, request: typing.Optional[dict] = None) -> typing.Any:
+        r"""
+        Call an RPC method by name (async).
+        
+        Generic entry point sending the `request` dict to the RPC method `method`,
+        e.g. for method names read from configuration or received over an API.
+        The method name may be given in snake case (`get_block_count`) or as in the
+        node's RPC API (`getBlockCount`, `GetBlockCount`). The request is converted
+        as in the wRPC protocol (camelCase keys, addresses, hashes and transactions
+        as strings and dicts), without the Python objects the RpcClient methods
+        accept. Middleware, caching and rate limiting apply as for direct calls.
+        
+        Args:
+            method: The RPC method name.
+            request: The request parameters (default: empty).
+        
+        Returns:
+            dict: The response, as returned by the RPC method.
+        
+        Raises:
+            Exception: If `method` is not an RPC method of the node, the request does not convert, or the call fails.
         """
-    def trigger_abort(self) -> None:
+    def block_templates(self, pay_address: Address | str, extra_data: typing.Optional[Binary] = None, poll_interval: builtins.float = 1.0) -> BlockTemplates:
         r"""
-        Triggers a disconnection on the underlying WebSocket
-        if the WebSocket is in connected state.
-        This is intended for debug purposes only.
-        Can be used to test application reconnection logic.
+        Iterate over the block templates of the node, e.g. for pool software.
+        
+        Subscribes to `new-block-template` notifications and fetches the template
+        after each one (or every `poll_interval` seconds without one), yielding
+        only templates whose parents or transactions changed:
+        `async for template in client.block_templates(pay_address):`.
+        
+        Args:
+            pay_address: The address coinbase rewards are paid to.
+            extra_data: Extra data included in the coinbase transaction (default: empty).
+            poll_interval: Seconds after which the template is refetched without a notification (default: 1.0).
+        
+        Returns:
+            BlockTemplates: An async iterator of `BlockTemplate`.
+        
+        Raises:
+            Exception: If `pay_address` is invalid or `poll_interval` is not positive.
         """
-    def add_event_listener(self, event: NotificationEvent, callback: typing.Callable[..., None], *args: typing.Any, **kwargs: typing.Any) -> None:
+    def walk_selected_parent_chain(self, from_hash: Hash | str, limit: builtins.int) -> HeaderWalk:
         r"""
-        Register a callback for RPC events.
+        Walk the selected parent chain from a block towards genesis.
+        
+        Yields the header of `from_hash` first, then the header of its selected
+        parent and so on. Headers are fetched lazily, one `get_block()` call per
+        iteration: `async for header in client.walk_selected_parent_chain(sink, 100):`.
         
         Args:
-            event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
-            callback: Function to call when event occurs.
-            *args: Additional arguments to pass to callback.
-            **kwargs: Additional keyword arguments to pass to callback.
+            from_hash: The block to start from, e.g. the sink.
+            limit: The maximum number of headers to yield.
+        
+        Returns:
+            HeaderWalk: An async iterator of header dicts.
         
         Raises:
-            Exception: If the event type is invalid.
+            Exception: If `from_hash` is not a valid hash.
         """
-    def remove_event_listener(self, event: NotificationEvent, callback: None | typing.Callable[..., None] = None) -> None:
+    def get_block_ancestors(self, hash: Hash | str, depth: builtins.int) -> HeaderWalk:
         r"""
-        Remove an event listener.
+        Walk the ancestors of a block breadth-first.
+        
+        Yields the header of `hash` first, then the headers of its direct
+        parents, their parents and so on, each block once. Headers are fetched
+        lazily, one `get_block()` call per iteration.
         
         Args:
-            event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
-            callback: Specific callback to remove, or None to remove all.
+            hash: The block to start from.
+            depth: How many parent levels to walk (0 yields `hash` only).
+        
+        Returns:
+            HeaderWalk: An async iterator of header dicts.
         
         Raises:
-            Exception: If the event type is invalid.
+            Exception: If `hash` is not a valid hash.
         """
-    @staticmethod
-    def default_port(encoding: Encoding, network: NetworkType) -> builtins.int:
+    def stream_utxos_by_addresses(self, addresses: typing.Sequence[Address], batch_size: builtins.int = 1000) -> UtxoStream:
         r"""
-        Get the default RPC port for a given encoding and network type.
+        Stream the UTXOs of many addresses as JSON Lines chunks.
+        
+        Queries `batch_size` addresses per `get_utxos_by_addresses()` call and
+        yields each response as `bytes`, without building a Python dict per
+        entry: `async for chunk in client.stream_utxos_by_addresses(addresses):`.
         
         Args:
-            encoding: RPC encoding format ("borsh" or "json").
-            network: Network type (e.g., "mainnet", "testnet-10", "testnet-11").
+            addresses: The addresses to snapshot.
+            batch_size: Addresses queried per call (default: 1000).
         
         Returns:
-            int: The default port number for the specified configuration.
+            UtxoStream: An async iterator of JSON Lines chunks.
+        
+        Raises:
+            ValueError: If `batch_size` is 0.
         """
-    def remove_all_event_listeners(self) -> None:
+    def dump_utxos_by_addresses(self, addresses: typing.Sequence[Address], path: builtins.str | os.PathLike, batch_size: builtins.int = 1000) -> dict:
         r"""
-        Remove all registered event listeners.
+        Write the UTXOs of many addresses to a file as JSON Lines (async).
+        
+        Like `stream_utxos_by_addresses()`, but the chunks are written to
+        `path` directly, one entry per line in the `get_utxos_by_addresses()`
+        entry form. Suited to snapshotting large address sets for research:
+        memory use is bounded by one batch.
+        
+        Args:
+            addresses: The addresses to snapshot.
+            path: The file to write (replaced if it exists).
+            batch_size: Addresses queried per call (default: 1000).
+        
+        Returns:
+            dict: `entries` (the number of UTXOs written) and `amount` (their total in sompi).
+        
+        Raises:
+            ValueError: If `batch_size` is 0.
+            OSError: If the file cannot be written.
+            Exception: If an RPC call fails.
         """
     def subscribe_utxos_changed(self, addresses: typing.Sequence[Address]) -> None:
         r"""
@@ -1582,6 +2658,52 @@ class RpcClient:
         Raises:
             Exception: If not connected or unsubscription fails.
         """
+    def fee_estimate(self, verbose: builtins.bool = False) -> FeeEstimate:
+        r"""
+        Get the node's fee estimate as a structured FeeEstimate (async).
+        
+        Uses the experimental fee estimator endpoint, which can also return
+        verbose mempool data.
+        
+        Args:
+            verbose: Include verbose mempool data (default: False).
+        
+        Returns:
+            FeeEstimate: The fee estimate buckets and optional verbose data.
+        
+        Raises:
+            Exception: If the RPC call fails.
+        """
+    def coin_supply(self) -> CoinSupply:
+        r"""
+        Get the network's coin supply as a structured CoinSupply (async).
+        
+        Returns:
+            CoinSupply: The maximum and circulating supply.
+        
+        Raises:
+            Exception: If the RPC call fails.
+        """
+    def submit_serialized_transaction(self, data: Binary, allow_orphan: builtins.bool = False) -> str:
+        r"""
+        Submit an externally built, signed transaction given in serialized form (async).
+        
+        The transaction is the Borsh encoding of a consensus transaction, as
+        returned by `Transaction.to_bytes()` and produced by rusty-kaspa based
+        tooling and signers. This is not the hex-encoded wire format of other
+        SDKs. Its id is recomputed locally before submission.
+        
+        Args:
+            data: The Borsh-encoded transaction as bytes or a hex string.
+            allow_orphan: Allow orphan transactions (default: False).
+        
+        Returns:
+            str: The transaction ID on success.
+        
+        Raises:
+            TransactionRejectedError: If the node rejects the transaction (see `kaspa.exceptions`).
+            Exception: If the transaction cannot be deserialized or the call fails.
+        """
     def subscribe_block_added(self) -> None: ...
     def unsubscribe_block_added(self) -> None: ...
     def subscribe_finality_conflict(self) -> None: ...
@@ -1809,6 +2931,11 @@ class ScriptPublicKey:
         r"""
         The script bytes as a hex string.
         """
+    @property
+    def script_bytes(self) -> bytes:
+        r"""
+        The script as bytes.
+        """
     def __eq__(self, other: builtins.object) -> builtins.bool: ...
     def __new__(cls, version: builtins.int, script: Binary) -> ScriptPublicKey:
         r"""
@@ -1930,6 +3057,11 @@ class Transaction:
             value: The payload as bytes or hex string.
         """
     @property
+    def payload_bytes(self) -> bytes:
+        r"""
+        The transaction payload data as bytes.
+        """
+    @property
     def mass(self) -> builtins.int:
         r"""
         The transaction mass used for fee calculation.
@@ -1943,15 +3075,15 @@ class Transaction:
             value: The transaction mass value.
         """
     @subnetwork_id.setter
-    def subnetwork_id(self, value: builtins.str) -> None:
+    def subnetwork_id(self, value: Binary) -> None:
         r"""
         Set the subnetwork identifier.
         
         Args:
-            value: The subnetwork ID as a hex string.
+            value: The subnetwork ID as bytes or hex string.
         
         Raises:
-            Exception: If the hex string is invalid or has incorrect length.
+            Exception: If the value is invalid or has incorrect length.
         """
     def is_coinbase(self) -> builtins.bool:
         r"""
@@ -2029,6 +3161,33 @@ class Transaction:
             KeyError: If required keys are missing.
             ValueError: If values are invalid.
         """
+    def to_bytes(self) -> bytes:
+        r"""
+        Serialize the transaction to bytes.
+        
+        The encoding is the Borsh serialization of the consensus transaction, as
+        read by `Transaction.from_bytes()` and `RpcClient.submit_serialized_transaction()`.
+        
+        Returns:
+            bytes: The serialized transaction.
+        
+        Raises:
+            Exception: If serialization fails.
+        """
+    @classmethod
+    def from_bytes(cls, data: Binary) -> Transaction:
+        r"""
+        Create a Transaction from bytes written by `to_bytes()`.
+        
+        Args:
+            data: The Borsh-serialized consensus transaction, as bytes or a hex string.
+        
+        Returns:
+            Transaction: A new Transaction instance, with its id computed.
+        
+        Raises:
+            ValueError: If the data is not a serialized transaction.
+        """
     def __eq__(self, other: Transaction) -> builtins.bool: ...
 
 @typing.final
@@ -2057,6 +3216,11 @@ class TransactionInput:
         The unlocking script (signature) as a hex string, or None if not set.
         """
     @property
+    def signature_script_bytes(self) -> typing.Optional[bytes]:
+        r"""
+        The unlocking script (signature) as bytes, or None if not set.
+        """
+    @property
     def sequence(self) -> builtins.int:
         r"""
         The sequence number used for relative time locks.
@@ -2273,12 +3437,122 @@ class TransactionOutput:
         """
     def __eq__(self, other: TransactionOutput) -> builtins.bool: ...
 
+@typing.final
+class TransactionPlan(GeneratorSummary):
+    r"""
+    A transaction plan produced by `Generator.estimate()`.
+    
+    Extends GeneratorSummary with the inputs selected, the outputs and the
+    fee of every transaction, without holding any UTXO locks. Show it for
+    confirmation, then call `materialize()` to get signable transactions.
+    
+    See Also:
+        Generator, GeneratorSummary
+    """
+    @property
+    def inputs(self) -> builtins.list[UtxoEntryReference]:
+        r"""
+        The UTXOs the plan spends from the Generator source.
+        
+        Change outputs of batch transactions spent by later stages are not included.
+        """
+    @property
+    def steps(self) -> builtins.list[dict]:
+        r"""
+        The planned transactions in submission order.
+        
+        Each step is a dict with `id`, `type` (`batch` or `final`), `inputs`
+        (`transaction_id`, `index`, `amount`, `address`), `outputs` (`address`,
+        `amount`), `fee`, `mass` and `change` (the change amount in sompi).
+        """
+    def materialize(self) -> builtins.list[PendingTransaction]:
+        r"""
+        Reserve the planned inputs and return the transactions to sign.
+        
+        For a plan estimated from a UtxoContext, the inputs are locked on the
+        context again, so this fails if another Generator took one in the meantime.
+        
+        Returns:
+            list[PendingTransaction]: The transactions to sign and submit, in order.
+        
+        Raises:
+            Exception: If a planned input is locked by another Generator.
+        """
+    def rebuild(self, priority_fee: Amount | Fees) -> dict:
+        r"""
+        Re-run UTXO selection with a different priority fee.
+        
+        Selection runs against a snapshot of the source UTXOs taken by the first
+        rebuild rather than the current state of the source, so a fee slider can
+        call this on every change. Only the final transaction is generated again,
+        unless a fee rate is given, which changes the fees of every transaction.
+        Nothing is locked until the new plan is materialized. A callable
+        `change_address` given to the Generator is invoked for the new plan.
+        
+        Args:
+            priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing the plan's fee rate).
+        
+        Returns:
+            dict: `plan` (the new TransactionPlan), `added` and `removed` (lists of
+                UtxoEntryReference selected or no longer selected), and the `fees`,
+                `mass` and `transactions` differences to this plan.
+        
+        Raises:
+            Exception: If the UTXOs do not cover the outputs and fees.
+            KeyboardInterrupt: If interrupted.
+        """
+    def to_dict(self) -> dict:
+        r"""
+        Get a dictionary representation of the TransactionPlan.
+        
+        Returns:
+            dict: The GeneratorSummary fields along with `steps`.
+        """
+
+@typing.final
+class UtxoChanges:
+    r"""
+    Async iterator over the UTXO changes of a UtxoContext.
+    
+    Created by `UtxoContext.changes()`. The first iteration takes the
+    context's (mature and pending) UTXO set, then each iteration waits for
+    transaction events of the context changing it and returns the difference,
+    so an external store can be kept in sync without diffing full snapshots.
+    
+    Iteration stops when the UtxoProcessor shuts down.
+    """
+    def __aiter__(self) -> UtxoChanges: ...
+    def __anext__(self) -> typing.Awaitable[tuple[builtins.list[UtxoEntryReference], builtins.list[UtxoEntryReference]]]:
+        r"""
+        Wait for the next batch of changes.
+        
+        Returns:
+            tuple[list[UtxoEntryReference], list[UtxoEntryReference]]: The
+                entries added and removed since the previous batch.
+        
+        Raises:
+            StopAsyncIteration: When the UtxoProcessor shuts down.
+        """
+
 @typing.final
 class UtxoContext:
     r"""
     UTXO context for tracking addresses and balances.
     """
     @property
+    def id(self) -> Hash:
+        r"""
+        The context id.
+        
+        Stable across restarts when the context is created with an explicit `id`
+        (kaspa-ng binds account contexts to the account id).
+        """
+    @property
+    def processor(self) -> UtxoProcessor:
+        r"""
+        The UtxoProcessor this context is bound to.
+        """
+    @property
     def is_active(self) -> builtins.bool:
         r"""
         Whether the underlying processor is connected and running.
@@ -2298,6 +3572,22 @@ class UtxoContext:
         r"""
         Current balance formatted as strings (if available).
         """
+    @property
+    def in_flight(self) -> builtins.list[UtxoEntryReference]:
+        r"""
+        Unconfirmed change of transactions submitted from this context.
+        
+        Generators created from this context spend these entries first, so
+        payouts can be chained without waiting for confirmation. Entries are
+        dropped once the node reports them, a submitted transaction spends them or
+        is rejected while spending them, or their transaction would have matured.
+        """
+    @property
+    def locked(self) -> builtins.list[TransactionOutpoint]:
+        r"""
+        Outpoints currently locked, either explicitly via `lock()` or by Generators
+        created from this context that have not yet submitted or been dropped.
+        """
     def __new__(cls, processor: UtxoProcessor, id: str | Hash = None) -> UtxoContext:
         r"""
         Create a new UtxoContext.
@@ -2305,14 +3595,32 @@ class UtxoContext:
         Args:
             processor: The UtxoProcessor to bind to.
             id: Optional 32-byte hex id (string) or Hash.
+        
+        Raises:
+            ValueError: If a live UtxoContext of the processor already has this id.
         """
-    def track_addresses(self, addresses: Sequence[Address] | Sequence[str], current_daa_score: typing.Optional[builtins.int] = None) -> None:
+    def track_addresses(self, addresses: Sequence[Address] | Sequence[str], current_daa_score: typing.Optional[builtins.int] = None, progress: typing.Optional[typing.Callable[[dict], None]] = None, batch_size: builtins.int = 256, concurrency: builtins.int = 4) -> None:
         r"""
         Track and scan a list of addresses (async).
         
+        Addresses are subscribed to and scanned in batches of `batch_size`, with
+        up to `concurrency` batches in flight, so tracking tens of thousands of
+        addresses does not wait on one request per address or one huge request.
+        
+        With `progress`, the callback is invoked after each batch with a dict:
+        `scanned` (addresses scanned so far), `total` (addresses to scan) and
+        `utxos` (UTXO entries known to the context). An exception raised by the
+        callback aborts the scan.
+        
         Args:
             addresses: List of Address objects or address strings.
             current_daa_score: Optional current DAA score for scan context.
+            progress: Optional callback receiving scan progress dicts.
+            batch_size: Number of addresses registered and scanned per request (default: 256).
+            concurrency: Maximum number of batches processed concurrently (default: 4).
+        
+        Raises:
+            Exception: If `batch_size` or `concurrency` is 0, or the scan fails.
         """
     def unregister_addresses(self, addresses: Sequence[Address] | Sequence[str]) -> None:
         r"""
@@ -2322,10 +3630,132 @@ class UtxoContext:
         r"""
         Clear all tracked addresses and UTXOs (async).
         """
+    def rescan(self) -> dict:
+        r"""
+        Re-fetch the UTXO set of all tracked addresses from the node (async).
+        
+        Use this after suspected missed notifications (e.g. a dropped connection).
+        The context is cleared and rescanned, and the difference against the
+        previously known (mature and pending) UTXO set is returned so the
+        application can reconcile its own state.
+        
+        Returns:
+            dict: Dictionary with "added" and "removed" lists of UtxoEntryReference.
+        
+        Raises:
+            Exception: If the processor is not running or the rescan fails.
+        """
+    def changes(self) -> UtxoChanges:
+        r"""
+        Iterate over changes of the UTXO set.
+        
+        Each batch holds the entries added and removed since the previous
+        batch (or since the first iteration started), e.g. to apply them to
+        a database with `async for added, removed in context.changes()`.
+        
+        Returns:
+            UtxoChanges: An async iterator of `(added, removed)` tuples.
+        """
+    def snapshot(self) -> builtins.str:
+        r"""
+        Capture the context state as a JSON snapshot.
+        
+        The snapshot contains the tracked addresses, the known (mature and pending)
+        UTXO set, the balance, the current DAA score and a SHA-256 integrity
+        checksum. It does not change with the context, so it can be archived
+        and compared with a later snapshot using `compare_snapshots()`.
+        
+        Returns:
+            str: The snapshot as a JSON string.
+        """
+    def save(self, path: str) -> None:
+        r"""
+        Write a snapshot of the context state to a file.
+        
+        The file is written to a temporary path first and then renamed, so an
+        interrupted write never leaves a truncated snapshot behind.
+        
+        Args:
+            path: Destination file path.
+        
+        Raises:
+            Exception: If the snapshot cannot be written.
+        """
+    def resume(self, path: typing.Optional[str] = None, *, snapshot: typing.Optional[builtins.str] = None) -> None:
+        r"""
+        Resume monitoring from a snapshot without a full rescan (async).
+        
+        Registers the snapshot addresses for UTXO change notifications and
+        restores the known UTXO set. The processor must be started. UTXOs spent
+        while the service was offline are not detected; use `track_addresses()`
+        when an authoritative rescan is required.
+        
+        Args:
+            path: Snapshot file written by `save()`.
+            snapshot: Snapshot JSON string returned by `snapshot()` (alternative to `path`).
+        
+        Raises:
+            Exception: If the snapshot is corrupted, or belongs to another network or context id.
+        """
+    @staticmethod
+    def compare_snapshots(before: builtins.str, after: builtins.str) -> dict:
+        r"""
+        Compare two snapshots of the same context, e.g. for audits and reconciliation.
+        
+        Args:
+            before: The earlier snapshot JSON string returned by `snapshot()`.
+            after: The later snapshot JSON string.
+        
+        Returns:
+            dict: `added`/`removed` UTXO entries (list of UtxoEntryReference),
+                `addedAddresses`/`removedAddresses` (list of str), `daaScore` (tuple of
+                the before and after DAA scores) and `balance` (tuple of the before and
+                after balances as dicts with `mature`, `pending` and `outgoing` keys,
+                None if not recorded).
+        
+        Raises:
+            Exception: If a snapshot is corrupted, or the snapshots belong to different networks or contexts.
+        """
+    def descriptor(self) -> builtins.str:
+        r"""
+        Export the context identity as JSON.
+        
+        The descriptor contains the context id, the network id and the tracked
+        addresses, and can be used to key external records against the context.
+        
+        Returns:
+            str: The descriptor as a JSON string.
+        """
     def mature_range(self, from_: builtins.int, to: builtins.int) -> builtins.list[UtxoEntryReference]:
         r"""
         Return a range of mature UTXO entries.
         """
+    def clear_in_flight(self) -> None:
+        r"""
+        Forget all in-flight change entries (e.g. after a submitted transaction was rejected).
+        """
+    def lock(self, outpoints: Sequence[TransactionOutpoint] | Sequence[UtxoEntryReference]) -> None:
+        r"""
+        Lock outpoints so Generators created from this context do not spend them.
+        
+        Generators lock the UTXOs they select automatically and release them once
+        the transaction is submitted, when generation fails, or when the Generator
+        and its transactions are dropped. Use this to reserve UTXOs manually.
+        
+        Args:
+            outpoints: Outpoints (or UTXO entries) to lock.
+        
+        Raises:
+            Exception: If any of the outpoints is already locked (nothing is locked then).
+        """
+    def unlock(self, outpoints: typing.Optional[Sequence[TransactionOutpoint] | Sequence[UtxoEntryReference]] = None) -> None:
+        r"""
+        Release locked outpoints.
+        
+        Args:
+            outpoints: Outpoints (or UTXO entries) to unlock. Releases all locks if omitted,
+                including those held by Generators.
+        """
     def pending(self) -> builtins.list[UtxoEntryReference]:
         r"""
         Return pending UTXO entries.
@@ -2498,6 +3928,27 @@ class UtxoEntryReference:
         r"""
         The locking script for this UTXO.
         """
+    def maturity_daa_score(self, network_id: NetworkId) -> builtins.int:
+        r"""
+        The DAA score at which this UTXO becomes mature (spendable).
+        
+        Args:
+            network_id: The network, whose coinbase or user transaction maturity period applies.
+        
+        Returns:
+            int: The block DAA score plus the maturity period.
+        """
+    def daa_until_mature(self, network_id: NetworkId, current_daa_score: builtins.int) -> builtins.int:
+        r"""
+        The number of DAA score units until this UTXO becomes mature.
+        
+        Args:
+            network_id: The network, whose coinbase or user transaction maturity period applies.
+            current_daa_score: The current virtual DAA score, e.g. from `get_block_dag_info()`.
+        
+        Returns:
+            int: The remaining DAA score units (about 10 per second), 0 if mature.
+        """
     def __eq__(self, other: builtins.object) -> builtins.bool: ...
     def to_dict(self) -> dict:
         r"""
@@ -2555,13 +4006,40 @@ class UtxoProcessor:
         r"""
         Whether the processor is connected and running.
         """
-    def __new__(cls, rpc: RpcClient, network_id: NetworkId) -> UtxoProcessor:
+    @property
+    def is_synced(self) -> builtins.bool:
+        r"""
+        Whether the connected node reports being synced (see the `sync-state` event).
+        """
+    @property
+    def queue_depth(self) -> builtins.int:
+        r"""
+        Number of events currently queued for delivery to Python callbacks.
+        """
+    @property
+    def dropped_events(self) -> builtins.int:
+        r"""
+        Number of events dropped or coalesced by the event queue policy.
+        """
+    @property
+    def duplicate_events(self) -> builtins.int:
+        r"""
+        Number of repeated transaction events suppressed by deduplication.
+        """
+    def __new__(cls, rpc: RpcClient, network_id: NetworkId, queue_capacity: typing.Optional[builtins.int] = None, queue_policy: typing.Optional[builtins.str | EventQueuePolicy] = None, dedup: builtins.bool = True, dedup_path: typing.Optional[str] = None) -> UtxoProcessor:
         r"""
         Create a new UtxoProcessor.
         
         Args:
             rpc: The RPC client to use for network communication.
             network_id: Network identifier for UTXO processing.
+            queue_capacity: Maximum number of events buffered for Python callbacks (default 1024).
+            queue_policy: Policy applied when the event queue is full (default `EventQueuePolicy.Block`).
+            dedup: Emit each transaction event (`pending`, `maturity`, `discovery`, `reorg`, `stasis`) at most once per context and transaction, suppressing repeats caused by reconnects and rescans (default True).
+            dedup_path: Optional file persisting the seen transaction events, so repeats are also suppressed across restarts (use with UtxoContexts created with a fixed `id`).
+        
+        Raises:
+            Exception: If `queue_capacity` is 0, `dedup_path` is given with `dedup=False`, or `dedup_path` cannot be read or written.
         """
     def start(self) -> None:
         r"""
@@ -2571,6 +4049,23 @@ class UtxoProcessor:
         r"""
         Stop UTXO processing (async).
         """
+    def refresh(self, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None) -> dict:
+        r"""
+        Re-fetch the UTXO set from the node for the contexts tracking the given addresses (async).
+        
+        Use this after suspected missed notifications (e.g. a dropped connection).
+        Every affected context is fully rescanned: its UTXO set is cleared and
+        reloaded for all of its tracked addresses.
+        
+        Args:
+            addresses: Optional list of Address objects or address strings. Defaults to all tracked addresses.
+        
+        Returns:
+            dict: Dictionary with "added" and "removed" lists of UtxoEntryReference.
+        
+        Raises:
+            Exception: If the processor is not running or a rescan fails.
+        """
     def set_network_id(self, network_id: NetworkId) -> None:
         r"""
         Set the network id for the processor.
@@ -2585,18 +4080,30 @@ class UtxoProcessor:
         r"""
         Set the user transaction maturity period DAA for a network.
         """
+    @staticmethod
+    def get_coinbase_transaction_maturity_daa(network_id: NetworkId) -> builtins.int:
+        r"""
+        Get the coinbase transaction maturity period DAA for a network.
+        """
+    @staticmethod
+    def get_user_transaction_maturity_daa(network_id: NetworkId) -> builtins.int:
+        r"""
+        Get the user transaction maturity period DAA for a network.
+        """
     @typing.overload
-    def add_event_listener(self, callback: typing.Callable[..., typing.Any], *args: typing.Any, **kwargs: typing.Any) -> None: ...
+    def add_event_listener(self, callback: typing.Callable[..., typing.Any], *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None: ...
     @typing.overload
-    def add_event_listener(self, event_or_callback: builtins.str | UtxoProcessorEvent | typing.Sequence[builtins.str | UtxoProcessorEvent], callback: typing.Callable[..., typing.Any], *args: typing.Any, **kwargs: typing.Any) -> None: ...
-    def add_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None, *args: typing.Any, **kwargs: typing.Any) -> None:
+    def add_event_listener(self, event_or_callback: UtxoProcessorEventName | UtxoProcessorEvent | typing.Sequence[UtxoProcessorEventName | UtxoProcessorEvent], callback: typing.Callable[..., typing.Any], *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None: ...
+    def add_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None, *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None:
         r"""
         Register a callback for UtxoProcessor events.
         
         Args:
             event_or_callback: Event target as string (kebab-case), `UtxoProcessorEvent` variant, a list of those, "*" / "all", or a callback (listen to all events).
-            callback: Function to call when event occurs (required when event_or_callback is an event target).
+            callback: Function to call when event occurs (required when event_or_callback is an event target). An `async def` callback is scheduled on the event loop running when it is registered.
             *args: Additional arguments to pass to callback.
+            addresses: Optional keyword-only list of addresses. Events referencing other addresses are not delivered.
+            contexts: Optional keyword-only list of UtxoContext objects (or context ids). Events bound to other contexts are not delivered.
             **kwargs: Additional keyword arguments to pass to callback.
         
         Returns:
@@ -2605,11 +4112,21 @@ class UtxoProcessor:
         Notes:
             Callback will be invoked as: callback(*args, event, **kwargs)
             Where event is a dict like: {"type": str, "data": ...}
+        
+            Filters are evaluated in Rust before the GIL is acquired. Events that
+            carry no address (or context) information, such as `connect` or
+            `daa-score-change`, are not filtered on that criterion.
+        
+            `reorg` events additionally carry an "affected" list with the last event
+            previously delivered (via `pending`, `maturity`, `discovery` or `stasis`)
+            for the invalidated transaction and context, as a dict with `id`, `type`
+            and `blockDaaScore`, so credited deposits can be reverted. The list is
+            empty if the transaction was never delivered or has aged out of the tracker.
         """
     @typing.overload
     def remove_event_listener(self, event_or_callback: typing.Callable[..., typing.Any]) -> None: ...
     @typing.overload
-    def remove_event_listener(self, event_or_callback: builtins.str | UtxoProcessorEvent | typing.Sequence[builtins.str | UtxoProcessorEvent], callback: typing.Optional[typing.Callable[..., typing.Any]] = None) -> None: ...
+    def remove_event_listener(self, event_or_callback: UtxoProcessorEventName | UtxoProcessorEvent | typing.Sequence[UtxoProcessorEventName | UtxoProcessorEvent], callback: typing.Optional[typing.Callable[..., typing.Any]] = None) -> None: ...
     def remove_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None) -> None:
         r"""
         Remove an event listener.
@@ -2629,6 +4146,30 @@ class UtxoProcessor:
             None
         """
 
+@typing.final
+class UtxoStream:
+    r"""
+    Async iterator over the UTXOs of many addresses, as JSON Lines chunks.
+    
+    Created by `RpcClient.stream_utxos_by_addresses()`. Each iteration queries
+    the next batch of addresses with `get_utxos_by_addresses()` and yields its
+    entries as `bytes`: one JSON object per line, in the form of the
+    `get_utxos_by_addresses()` entries. No Python objects are built per entry,
+    and only one batch is held in memory at a time.
+    """
+    def __aiter__(self) -> UtxoStream: ...
+    def __anext__(self) -> typing.Awaitable[bytes]:
+        r"""
+        Fetch the UTXOs of the next batch of addresses.
+        
+        Returns:
+            bytes: The entries as JSON Lines (empty if the batch holds no UTXOs).
+        
+        Raises:
+            StopAsyncIteration: When all addresses were queried.
+            Exception: If the RPC call fails.
+        """
+
 @typing.final
 class XOnlyPublicKey:
     r"""
@@ -2647,7 +4188,7 @@ class XOnlyPublicKey:
             XOnlyPublicKey: A new XOnlyPublicKey instance.
         
         Raises:
-            Exception: If the hex string is invalid.
+            ValueError: If the hex string is invalid.
         """
     def to_string(self) -> builtins.str:
         r"""
@@ -2938,6 +4479,7 @@ class NotificationEvent(enum.Enum):
         - NewBlockTemplate: Triggered when a new block template is available for mining.
         - Connect: Triggered when the RPC client connects to a node.
         - Disconnect: Triggered when the RPC client disconnects from a node.
+        - StaleConnection: Triggered when a keep-alive ping fails or times out (see `RpcClient.set_keep_alive()`).
     """
     All = ...
     BlockAdded = ...
@@ -2951,6 +4493,7 @@ class NotificationEvent(enum.Enum):
     NewBlockTemplate = ...
     Connect = ...
     Disconnect = ...
+    StaleConnection = ...
 
 @typing.final
 class AddressVersion(enum.Enum):
@@ -3276,6 +4819,20 @@ class SighashType(enum.Enum):
     NoneAnyOneCanPay = ...
     SingleAnyOneCanPay = ...
 
+@typing.final
+class EventQueuePolicy(enum.Enum):
+    r"""
+    Policy applied when the `UtxoProcessor` event queue is full.
+    
+    Variants:
+        - Block: Wait for Python callbacks to catch up. No events are dropped.
+        - DropOldest: Drop the oldest queued event to make room for the new one.
+        - CoalesceBalance: Drop the queued `balance` event of the same context, the newer one being queued last, otherwise wait.
+    """
+    Block = ...
+    DropOldest = ...
+    CoalesceBalance = ...
+
 @typing.final
 class UtxoProcessorEvent(enum.Enum):
     r"""
@@ -3299,6 +4856,29 @@ class UtxoProcessorEvent(enum.Enum):
     Balance = ...
     Error = ...
 
+def add_signing_policy(policy: typing.Callable[[dict], bool | str | None]) -> None:
+    r"""
+    Register a signing policy, consulted before any transaction is signed.
+    
+    The policy is called with a dict describing the transaction about to be
+    signed: `operation` (`sign`, `sign_input`, `create_input_signature`,
+    `signature_hash`, `sign_transaction`, `send` or `sweep`), `transaction_id`, `amount` (the
+    sompi leaving the wallet: the outputs other than change), `fee` (None when
+    unknown) and `outputs`, a list of dicts with `address` (None for
+    non-standard scripts), `amount` and `change`.
+    
+    Returning None or True allows signing. Returning False or a reason string,
+    or raising `PolicyViolationError`, vetoes it: the signing call raises
+    `PolicyViolationError` and nothing is signed. Policies run in registration
+    order, on every signing call (`sign_input()` calls it once per input).
+    
+    Args:
+        policy: A callable taking the request dict.
+    
+    Raises:
+        ValueError: If `policy` is not callable.
+    """
+
 def address_from_script_public_key(script_public_key: ScriptPublicKey, network: str | NetworkType) -> Address:
     r"""
     Extract the address from a script public key.
@@ -3314,6 +4894,51 @@ def address_from_script_public_key(script_public_key: ScriptPublicKey, network:
         Exception: If address extraction fails.
     """
 
+def analyze_utxos(entries: UtxoContext | typing.Sequence[UtxoEntryReference | dict], daa_score: typing.Optional[builtins.int] = None, dust_threshold: builtins.int = 10000000, age_buckets: typing.Optional[typing.Sequence[builtins.int]] = None) -> dict:
+    r"""
+    Summarize a set of UTXOs for dashboards and reports.
+    
+    The aggregation runs in Rust, without iterating over the entries in
+    Python. Entry ages are measured in DAA score units from `daa_score`.
+    
+    Args:
+        entries: A UtxoContext (mature and pending entries) or a list of UTXO entries.
+        daa_score: The DAA score ages are measured from, e.g. the virtual DAA score (default: the highest block DAA score of the entries).
+        dust_threshold: Amounts below this many sompi are counted as dust (default: 0.1 KAS).
+        age_buckets: Ascending upper bounds of the age buckets, in DAA score units (default: 100, 1,000, ... 10,000,000).
+    
+    Returns:
+        dict: `count`, `total`, `min`, `max`, `mean` and `median` (amounts in
+        sompi; None without entries), `dust` and `coinbase` (entry counts) and
+        `age_buckets`, a list of dicts with `max_age` (exclusive; None for the
+        last bucket), `count` and `amount`.
+    
+    Raises:
+        ValueError: If `age_buckets` is not strictly ascending.
+    """
+
+def bits_to_difficulty(bits: builtins.int) -> builtins.float:
+    r"""
+    Convert compact difficulty bits to a difficulty.
+    
+    Args:
+        bits: The compact target, e.g. a header's `bits`.
+    
+    Returns:
+        float: The difficulty, as reported by `get_block_dag_info()`.
+    """
+
+def bits_to_target(bits: builtins.int) -> builtins.int:
+    r"""
+    Convert compact difficulty bits to a target.
+    
+    Args:
+        bits: The compact target, e.g. a header's `bits`.
+    
+    Returns:
+        int: The target.
+    """
+
 def calculate_storage_mass(network_id: NetworkId, input_values: typing.Sequence[builtins.int], output_values: typing.Sequence[builtins.int]) -> typing.Optional[builtins.int]:
     r"""
     Calculate the storage mass for a transaction.
@@ -3362,20 +4987,35 @@ def calculate_transaction_mass(network_id: NetworkId, tx: Transaction, minimum_s
         Exception: If mass calculation fails.
     """
 
-def create_input_signature(tx: Transaction, input_index: builtins.int, private_key: PrivateKey, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
+def combine_shares(shares: typing.Sequence[builtins.str]) -> Mnemonic | bytes:
+    r"""
+    Restore a secret from shares created by `split_secret()`.
+    
+    Args:
+        shares: At least `threshold` distinct shares of the same set, in any order.
+    
+    Returns:
+        Mnemonic | bytes: The Mnemonic when a mnemonic was split, else the secret bytes.
+    
+    Raises:
+        ValueError: If a share is malformed, the shares belong to different sets, are too few, or do not restore the secret.
+    """
+
+def create_input_signature(tx: Transaction, input_index: builtins.int, private_key: PrivateKey | KeyHandle, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
     r"""
     Create a signature for a specific transaction input.
     
     Args:
         tx: The transaction containing the input to sign.
         input_index: The index of the input to sign.
-        private_key: The private key for signing.
+        private_key: The private key, or a `KeyHandle` from a `KeyVault`.
         sighash_type: The signature hash type (default: All).
     
     Returns:
         str: The signature as a hex string.
     
     Raises:
+        PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
         Exception: If signing fails.
     """
 
@@ -3397,14 +5037,14 @@ def create_multisig_address(minimum_signatures: builtins.int, keys: typing.Seque
         Exception: If address creation fails.
     """
 
-def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priority_fee: builtins.int, payload: typing.Optional[Binary] = None, sig_op_count: typing.Optional[builtins.int] = None) -> Transaction:
+def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priority_fee: Amount, payload: typing.Optional[Binary] = None, sig_op_count: typing.Optional[builtins.int] = None) -> Transaction:
     r"""
     Create a single transaction from UTXOs.
     
     Args:
         utxo_entry_source: List of UTXO entries to spend.
         outputs: List of payment outputs.
-        priority_fee: Priority fee in sompi.
+        priority_fee: Priority fee in sompi, or an amount string such as "0.001 KAS".
         payload: Optional transaction payload data.
         sig_op_count: Signature operations per input (default: 1).
     
@@ -3415,7 +5055,7 @@ def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priorit
         Exception: If transaction creation fails or fee exceeds input amount.
     """
 
-def create_transactions(entries: UtxoEntries | UtxoContext, change_address: Address, network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> dict:
+def create_transactions(entries: UtxoEntries | UtxoContext, change_address: Address | builtins.str | typing.Callable[[], Address | builtins.str], network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[Amount | Fees] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, min_change: typing.Optional[Amount] = None, split_outputs: typing.Optional[builtins.int] = None, percentage_outputs: typing.Optional[typing.Sequence[dict]] = None) -> dict:
     r"""
     Create one or more transactions with automatic UTXO selection and change handling.
     
@@ -3424,14 +5064,17 @@ def create_transactions(entries: UtxoEntries | UtxoContext, change_address: Addr
     Args:
         network_id: The network to build transactions for (required for UTXO entries).
         entries: UtxoContext or list of UTXO entries to spend from.
-        change_address: Address to send change to.
+        change_address: Address to send change to, or a callable returning a fresh change address.
         outputs: Optional list of payment outputs.
         payload: Optional transaction payload data.
-        fee_rate: Optional fee rate multiplier.
-        priority_fee: Additional fee in sompi.
+        fee_rate: Optional fee rate in sompi per gram of mass.
+        priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
         priority_entries: UTXOs to use first.
         sig_op_count: Signature operations per input (default: 1).
         minimum_signatures: For multisig fee estimation.
+        min_change: Optional minimum change amount in sompi; smaller change is added to the fee.
+        split_outputs: Optional number of equal outputs each payment output is split into.
+        percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` routing a percentage of the payment amount to additional outputs (see `Generator`).
     
     Returns:
         dict: Dictionary with "transactions" (list) and "summary" keys.
@@ -3440,21 +5083,56 @@ def create_transactions(entries: UtxoEntries | UtxoContext, change_address: Addr
         Exception: If transaction creation fails.
     """
 
-def estimate_transactions(entries: UtxoEntries | UtxoContext, change_address: Address, network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> GeneratorSummary:
+def difficulty_to_target(difficulty: builtins.float) -> builtins.int:
+    r"""
+    Convert a difficulty to a target, e.g. a pool share target.
+    
+    Args:
+        difficulty: The difficulty (at least 1).
+    
+    Returns:
+        int: The target.
+    
+    Raises:
+        ValueError: If the difficulty is below 1 or not finite.
+    """
+
+def estimate_hashrate(headers: typing.Sequence[dict]) -> builtins.float:
+    r"""
+    Estimate the network hashrate from block headers.
+    
+    Local counterpart of `RpcClient.estimate_network_hashes_per_second()`: the
+    blue work accumulated between the headers with the lowest and highest blue
+    work, divided by the time between them.
+    
+    Args:
+        headers: Headers (dicts with `blueWork` and `timestamp` keys), e.g. from `get_headers()` or `get_block()`.
+    
+    Returns:
+        float: The estimated hashes per second.
+    
+    Raises:
+        ValueError: If fewer than two headers are given or they span no time.
+    """
+
+def estimate_transactions(entries: UtxoEntries | UtxoContext, change_address: Address | builtins.str | typing.Callable[[], Address | builtins.str], network_id: typing.Optional[NetworkId] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[Amount | Fees] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, min_change: typing.Optional[Amount] = None, split_outputs: typing.Optional[builtins.int] = None, percentage_outputs: typing.Optional[typing.Sequence[dict]] = None) -> GeneratorSummary:
     r"""
     Estimate transaction fees and count without creating transactions.
     
     Args:
         network_id: The network to estimate for (required for UTXO entries).
         entries: UtxoContext or list of UTXO entries to spend from.
-        change_address: Address to send change to.
+        change_address: Address to send change to, or a callable returning a fresh change address.
         outputs: Optional list of payment outputs.
         payload: Optional transaction payload data.
-        fee_rate: Optional fee rate multiplier.
-        priority_fee: Additional fee in sompi.
+        fee_rate: Optional fee rate in sompi per gram of mass.
+        priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
         priority_entries: UTXOs to use first.
         sig_op_count: Signature operations per input (default: 1).
         minimum_signatures: For multisig fee estimation.
+        min_change: Optional minimum change amount in sompi; smaller change is added to the fee.
+        split_outputs: Optional number of equal outputs each payment output is split into.
+        percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` routing a percentage of the payment amount to additional outputs (see `Generator`).
     
     Returns:
         GeneratorSummary: Summary with fee, transaction count, and other details.
@@ -3463,6 +5141,60 @@ def estimate_transactions(entries: UtxoEntries | UtxoContext, change_address: Ad
         Exception: If estimation fails.
     """
 
+def format_amount(amount: Amount, unit: builtins.str = "KAS", decimals: typing.Optional[builtins.int] = None, locale: typing.Optional[builtins.str] = None, thousands_separator: typing.Optional[builtins.str] = None, decimal_point: typing.Optional[builtins.str] = None, suffix: builtins.bool = True) -> builtins.str:
+    r"""
+    Format an amount for display, with locale-aware separators.
+    
+    Args:
+        amount: The amount (an `int` in sompi, a string with a unit, a `decimal.Decimal` in KAS or an `Amount`).
+        unit: `KAS` (or `TKAS`/`SKAS`/`DKAS`, the suffix shown) or `sompi` (default: KAS).
+        decimals: Fixed number of decimal places, rounded half up (default: as many as needed).
+        locale: Use the separators of a locale, e.g. `de_DE` or `fr`, or `system` for the process locale.
+        thousands_separator: Separator between groups of 3 digits, overriding the locale.
+        decimal_point: Decimal point, overriding the locale.
+        suffix: Append the unit (default: True).
+    
+    Returns:
+        str: The formatted amount, e.g. "1,234.5 KAS" or "1.234,5 KAS".
+    
+    Raises:
+        ValueError: If the amount, unit, decimals or locale are invalid.
+    """
+
+def generate_vanity_address(prefix_pattern: builtins.str, network: typing.Optional[str | NetworkType] = None, threads: typing.Optional[builtins.int] = None, progress: typing.Optional[typing.Callable[[dict], bool | None]] = None, progress_interval: builtins.float = 1.0, timeout: typing.Optional[builtins.float] = None, ecdsa: builtins.bool = False) -> typing.Optional[Keypair]:
+    r"""
+    Generate a keypair whose address starts with a pattern (a vanity address).
+    
+    Keys are searched on `threads` threads with the GIL released, so other
+    Python threads keep running. Each character after the fixed leading ones
+    multiplies the expected number of attempts by 32: the address payload
+    always starts with `q`, followed by one of `qpzr` (Schnorr) or `y9x8`
+    (ECDSA), and a pattern of n characters takes about 2^(5n-8) attempts.
+    
+    With `progress`, the callback is invoked every `progress_interval` seconds
+    with a dict: `attempts` (keys tried so far), `elapsed` (seconds), `rate`
+    (keys per second) and `expected` (expected total attempts). Returning
+    False from the callback cancels the search; an exception raised by it
+    aborts the search and is re-raised. Ctrl-C (KeyboardInterrupt) also stops
+    the workers.
+    
+    Args:
+        prefix_pattern: The leading address characters, with or without the network prefix (e.g. "qqkas" or "kaspa:qqkas").
+        network: The network type for address encoding (default: "mainnet").
+        threads: Number of worker threads (default: the number of CPUs).
+        progress: Optional callback receiving progress dicts.
+        progress_interval: Seconds between progress callbacks (default: 1).
+        timeout: Seconds after which the search gives up (default: none).
+        ecdsa: Search ECDSA instead of Schnorr addresses (default: False).
+    
+    Returns:
+        Keypair | None: The keypair (its address is `to_address(network)`, or `to_address_ecdsa(network)` with `ecdsa`), None if cancelled or timed out.
+    
+    Raises:
+        ValueError: If the pattern cannot appear in an address, `threads` or `progress_interval` is not positive, or `timeout` is negative.
+        KeyboardInterrupt: If interrupted.
+    """
+
 def is_script_pay_to_pubkey(script: Binary) -> builtins.bool:
     r"""
     Check if a script is a pay-to-pubkey (P2PK) script.
@@ -3505,6 +5237,26 @@ def kaspa_to_sompi(kaspa: builtins.float) -> builtins.int:
     
     Returns:
         int: The amount in sompi.
+    
+    Raises:
+        ValueError: If the amount is negative or not finite.
+    """
+
+def max_payload_size_for(inputs: UtxoEntries, outputs: Outputs, network_id: NetworkId, minimum_signatures: typing.Optional[builtins.int] = None) -> builtins.int:
+    r"""
+    Get the largest payload that fits a standard transaction with the given inputs and outputs.
+    
+    Args:
+        inputs: The UTXO entries the transaction spends.
+        outputs: The payment outputs of the transaction (including change, if any).
+        network_id: The network identifier.
+        minimum_signatures: Minimum signatures per input (default: 1).
+    
+    Returns:
+        int: The maximum payload size in bytes.
+    
+    Raises:
+        Exception: If the transaction exceeds the standard mass without a payload, or mass calculation fails.
     """
 
 def maximum_standard_transaction_mass() -> builtins.int:
@@ -3515,6 +5267,26 @@ def maximum_standard_transaction_mass() -> builtins.int:
         int: The maximum standard transaction mass.
     """
 
+def parse_amount(amount: Amount) -> builtins.int:
+    r"""
+    Parse an amount into sompi.
+    
+    Accepts the same forms as all amount parameters: an `int` in sompi, a
+    string with a unit such as "1.5 KAS" or "1500 sompi", or a
+    `decimal.Decimal` in KAS. Unlike `kaspa_to_sompi()`, no float is involved,
+    so the conversion is exact.
+    
+    Args:
+        amount: The amount to parse.
+    
+    Returns:
+        int: The amount in sompi.
+    
+    Raises:
+        TypeError: If the amount is a `float` or of an unsupported type.
+        ValueError: If the amount is negative, out of range, has more than 8 decimal places or no unit.
+    """
+
 def pay_to_address_script(address: Address) -> ScriptPublicKey:
     r"""
     Create a pay-to-address locking script.
@@ -3552,6 +5324,69 @@ def pay_to_script_hash_signature_script(redeem_script: Binary, signature: Binary
         Exception: If script creation fails.
     """
 
+def plan_payload(payload_size: builtins.int, inputs: UtxoEntries, outputs: Outputs, network_id: NetworkId, minimum_signatures: typing.Optional[builtins.int] = None, fee_rate: typing.Optional[builtins.float] = None) -> dict:
+    r"""
+    Plan a transaction carrying a payload of a given size.
+    
+    Tells whether the transaction fits the standard mass limit and the fee it
+    needs, e.g. to size data inscriptions before building them.
+    
+    Args:
+        payload_size: The desired payload size in bytes.
+        inputs: The UTXO entries the transaction spends.
+        outputs: The payment outputs of the transaction (including change, if any).
+        network_id: The network identifier.
+        minimum_signatures: Minimum signatures per input (default: 1).
+        fee_rate: Fee rate in sompi per gram of mass (default: the minimum relay fee).
+    
+    Returns:
+        dict: `payload_size`, `mass`, `max_mass`, `fits` (the mass is within
+            `max_mass`), `max_payload_size` (None if nothing fits) and `fee` (in
+            sompi, None if the transaction does not fit).
+    
+    Raises:
+        Exception: If mass calculation fails.
+    """
+
+def remove_signing_policy(policy: typing.Callable[[dict], bool | str | None]) -> builtins.bool:
+    r"""
+    Remove a signing policy registered with `add_signing_policy()`.
+    
+    Args:
+        policy: The registered callable.
+    
+    Returns:
+        bool: True if the policy was removed.
+    """
+
+def send(rpc: RpcClient | str, private_key: PrivateKey | KeyHandle | Mnemonic | str, to_address: Address | str, amount: Amount, priority_fee: typing.Optional[Amount] = None, network_id: typing.Optional[NetworkId] = None, resolve_names: builtins.bool = False) -> str:
+    r"""
+    Send KAS to an address in one call (async).
+    
+    Connects (when given a URL), fetches the UTXOs of the sender address,
+    generates, signs and submits the transaction(s) and returns the id of the
+    final transaction. Change is returned to the sender address. Large
+    transfers may be split into several transactions by the Generator; they
+    are all submitted in order.
+    
+    Args:
+        rpc: A connected RpcClient or a node URL (e.g. "ws://127.0.0.1:17110").
+        private_key: A PrivateKey, a KeyHandle, a hex private key string, a Mnemonic or a mnemonic phrase. Mnemonics use the first receive address of account 0 (m/44'/111111'/0'/0/0).
+        to_address: Destination Address or address string.
+        amount: Amount to send in sompi, or an amount string such as "1.5 KAS" (see `Amount`).
+        priority_fee: Additional fee, as `amount` (default: no priority fee).
+        network_id: Network to use (default: derived from the destination address prefix; "testnet-10" for testnet).
+        resolve_names: Accept a name (e.g. "name.kas") as `to_address`, resolved with `Address.resolve()` (default: False).
+    
+    Returns:
+        str: The id of the final transaction.
+    
+    Raises:
+        TransactionRejectedError: If the node rejects a transaction (see `kaspa.exceptions`).
+        PolicyViolationError: If a signing policy vetoes a transaction (see `add_signing_policy()`).
+        Exception: If the key or address is invalid, the client is not connected, or generation, signing or submission fails.
+    """
+
 def sign_message(message: builtins.str, private_key: PrivateKey, no_aux_rand: builtins.bool = False) -> builtins.str:
     r"""
     Sign an arbitrary message with a private key.
@@ -3568,13 +5403,13 @@ def sign_message(message: builtins.str, private_key: PrivateKey, no_aux_rand: bu
         Exception: If signing fails.
     """
 
-def sign_script_hash(script_hash: builtins.str, privkey: PrivateKey) -> builtins.str:
+def sign_script_hash(script_hash: builtins.str, privkey: PrivateKey | KeyHandle) -> builtins.str:
     r"""
     Sign a script hash with a private key.
     
     Args:
         script_hash: The script hash to sign as a hex string.
-        privkey: The private key for signing.
+        privkey: The private key, or a `KeyHandle` from a `KeyVault`.
     
     Returns:
         str: The signature as a hex string.
@@ -3589,13 +5424,14 @@ def sign_transaction(tx: Transaction, signer: list, verify_sig: builtins.bool) -
     
     Args:
         tx: The transaction to sign.
-        signer: List of PrivateKey objects for signing.
+        signer: List of PrivateKey objects or `KeyHandle`s for signing.
         verify_sig: Whether to verify signatures after signing.
     
     Returns:
         Transaction: The signed transaction.
     
     Raises:
+        PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
         Exception: If signing or verification fails.
     """
 
@@ -3622,6 +5458,87 @@ def sompi_to_kaspa_string_with_suffix(sompi: builtins.int, network: str | Networ
         str: Formatted string like "1.5 KAS" or "1.5 TKAS".
     """
 
+def split_secret(secret: Mnemonic | str | bytes, threshold: builtins.int, shares: builtins.int) -> builtins.list[builtins.str]:
+    r"""
+    Split a mnemonic or secret into shares, any `threshold` of which restore it.
+    
+    Shamir's secret sharing over GF(256): each share is a point of a random
+    polynomial of degree `threshold - 1` whose constant term is the secret, so
+    fewer than `threshold` shares reveal nothing about it. For a mnemonic, its
+    entropy is split, so the shares are as long as the entropy (16 to 32
+    bytes) plus 14 bytes of metadata.
+    
+    Shares are hex strings carrying a set identifier, the threshold, their
+    index and a checksum (to catch typos). A digest of the secret is split
+    along with it, to verify its reconstruction without revealing anything to
+    holders of fewer than `threshold` shares. They are not compatible with
+    SLIP-0039. The secret must be restored with `combine_shares()`; the
+    mnemonic passphrase, if any, is not part of the shares.
+    
+    Args:
+        secret: A Mnemonic, an (English) mnemonic phrase, or the bytes of a secret (e.g. a seed) as bytes or a hex string.
+        threshold: Number of shares needed to restore the secret (1 to `shares`).
+        shares: Number of shares to create (at most 255).
+    
+    Returns:
+        list[str]: The shares, as hex strings.
+    
+    Raises:
+        ValueError: If the secret is invalid or empty, or the threshold or share count is out of range.
+    """
+
+def sweep_private_key(rpc: RpcClient | str, private_key: PrivateKey | KeyHandle | Mnemonic | str, destination: Address | str, fee: typing.Optional[Amount] = None, network_id: typing.Optional[NetworkId] = None, resolve_names: builtins.bool = False) -> list[str]:
+    r"""
+    Sweep all funds of a private key (e.g. a paper wallet) to an address (async).
+    
+    Fetches the UTXOs of both the Schnorr (`Version.PubKey`) and the ECDSA
+    (`Version.PubKeyECDSA`) address of the key and drains them into
+    `destination`. UTXO sets too large for one transaction are compounded
+    by the Generator into as many chained transactions as needed; they are
+    all submitted in order.
+    
+    Args:
+        rpc: A connected RpcClient or a node URL (e.g. "ws://127.0.0.1:17110").
+        private_key: A PrivateKey, a KeyHandle, a hex private key string, a Mnemonic or a mnemonic phrase. Mnemonics use the first receive address of account 0 (m/44'/111111'/0'/0/0).
+        destination: Address or address string receiving the funds.
+        fee: Priority fee in sompi, or an amount string such as "0.001 KAS" (default: no priority fee).
+        network_id: Network to use (default: derived from the destination address prefix; "testnet-10" for testnet).
+        resolve_names: Accept a name (e.g. "name.kas") as `destination`, resolved with `Address.resolve()` (default: False).
+    
+    Returns:
+        list[str]: The ids of the submitted transactions, the final sweep transaction last.
+    
+    Raises:
+        TransactionRejectedError: If the node rejects a transaction (see `kaspa.exceptions`).
+        PolicyViolationError: If a signing policy vetoes a transaction (see `add_signing_policy()`).
+        Exception: If the key or address is invalid, the key holds no UTXOs, the client is not connected, or generation, signing or submission fails.
+    """
+
+def target_to_bits(target: builtins.int) -> builtins.int:
+    r"""
+    Convert a target to compact difficulty bits.
+    
+    Args:
+        target: The target.
+    
+    Returns:
+        int: The compact target (precision beyond its 3-byte mantissa is lost).
+    """
+
+def target_to_difficulty(target: builtins.int) -> builtins.float:
+    r"""
+    Convert a target to a difficulty.
+    
+    Args:
+        target: The target.
+    
+    Returns:
+        float: The difficulty (the maximum target divided by `target`).
+    
+    Raises:
+        ValueError: If the target is zero.
+    """
+
 def update_transaction_mass(network_id: NetworkId, tx: Transaction, minimum_signatures: typing.Optional[builtins.int] = None) -> builtins.bool:
     r"""
     Calculate and update the mass field of an unsigned transaction.
@@ -3659,7 +5576,7 @@ def verify_message(message: builtins.str, signature: builtins.str, public_key: P
 # =============================================================================
 
 """
-TypedDict definitions for Kaspa RPC request/response messages & contained types.
+TypedDict definitions for Kaspa RPC request/response messages, event payloads & contained types.
 
 This file is maunally maintained and appended to kaspa.pyi file via stub gen process.
 
@@ -3667,7 +5584,7 @@ Long term, attempts should be made to auto generate.
 """
 
 from enum import Enum
-from typing import TypedDict
+from typing import Literal, TypedDict, Union
 
 
 # =============================================================================
@@ -4620,4 +6537,257 @@ class SubmitTransactionResponse(TypedDict):
 class SubmitTransactionReplacementResponse(TypedDict):
     """Response from submit_transaction_replacement."""
     transactionId: str
-    replacedTransaction: RpcTransaction
\ No newline at end of file
+    replacedTransaction: RpcTransaction
+
+
+# =============================================================================
+# Event Types (passed to event listener callbacks)
+# =============================================================================
+
+RpcEventName = Literal[
+    "all",
+    "connect",
+    "disconnect",
+    "stale-connection",
+    "block-added",
+    "virtual-chain-changed",
+    "finality-conflict",
+    "finality-conflict-resolved",
+    "utxos-changed",
+    "sink-blue-score-changed",
+    "virtual-daa-score-changed",
+    "pruning-point-utxo-set-override",
+    "new-block-template",
+]
+"""Event names accepted by `RpcClient.add_event_listener()`."""
+
+
+class RpcConnectionEvent(TypedDict):
+    """`connect` / `disconnect` event."""
+    type: str
+    rpc: str | None
+
+
+class StaleConnectionEvent(TypedDict):
+    """`stale-connection` event (see `RpcClient.set_keep_alive()`)."""
+    type: Literal["stale-connection"]
+    rpc: str | None
+    error: str
+
+
+class UtxosChangedEvent(TypedDict):
+    """`utxos-changed` event."""
+    type: str
+    added: list[RpcUtxosByAddressesEntry]
+    removed: list[RpcUtxosByAddressesEntry]
+
+
+class BlockAddedNotification(TypedDict):
+    """Data of a `block-added` event."""
+    block: RpcBlock
+
+
+class VirtualChainChangedNotification(TypedDict):
+    """Data of a `virtual-chain-changed` event."""
+    removedChainBlockHashes: list[str]
+    addedChainBlockHashes: list[str]
+    acceptedTransactionIds: list[RpcAcceptedTransactionIds]
+
+
+class FinalityConflictNotification(TypedDict):
+    """Data of a `finality-conflict` event."""
+    violatingBlockHash: str
+
+
+class FinalityConflictResolvedNotification(TypedDict):
+    """Data of a `finality-conflict-resolved` event."""
+    finalityBlockHash: str
+
+
+class SinkBlueScoreChangedNotification(TypedDict):
+    """Data of a `sink-blue-score-changed` event."""
+    sinkBlueScore: int
+
+
+class VirtualDaaScoreChangedNotification(TypedDict):
+    """Data of a `virtual-daa-score-changed` event."""
+    virtualDaaScore: int
+
+
+class PruningPointUtxoSetOverrideNotification(TypedDict):
+    """Data of a `pruning-point-utxo-set-override` event."""
+    pass
+
+
+class NewBlockTemplateNotification(TypedDict):
+    """Data of a `new-block-template` event."""
+    pass
+
+
+class BlockAddedEvent(TypedDict):
+    """`block-added` event."""
+    type: str
+    data: BlockAddedNotification
+
+
+class VirtualChainChangedEvent(TypedDict):
+    """`virtual-chain-changed` event."""
+    type: str
+    data: VirtualChainChangedNotification
+
+
+class FinalityConflictEvent(TypedDict):
+    """`finality-conflict` event."""
+    type: str
+    data: FinalityConflictNotification
+
+
+class FinalityConflictResolvedEvent(TypedDict):
+    """`finality-conflict-resolved` event."""
+    type: str
+    data: FinalityConflictResolvedNotification
+
+
+class SinkBlueScoreChangedEvent(TypedDict):
+    """`sink-blue-score-changed` event."""
+    type: str
+    data: SinkBlueScoreChangedNotification
+
+
+class VirtualDaaScoreChangedEvent(TypedDict):
+    """`virtual-daa-score-changed` event."""
+    type: str
+    data: VirtualDaaScoreChangedNotification
+
+
+class PruningPointUtxoSetOverrideEvent(TypedDict):
+    """`pruning-point-utxo-set-override` event."""
+    type: str
+    data: PruningPointUtxoSetOverrideNotification
+
+
+class NewBlockTemplateEvent(TypedDict):
+    """`new-block-template` event."""
+    type: str
+    data: NewBlockTemplateNotification
+
+
+RpcEventPayload = Union[
+    RpcConnectionEvent,
+    StaleConnectionEvent,
+    UtxosChangedEvent,
+    BlockAddedEvent,
+    VirtualChainChangedEvent,
+    FinalityConflictEvent,
+    FinalityConflictResolvedEvent,
+    SinkBlueScoreChangedEvent,
+    VirtualDaaScoreChangedEvent,
+    PruningPointUtxoSetOverrideEvent,
+    NewBlockTemplateEvent,
+]
+"""Any event passed to `RpcClient` event listeners."""
+
+
+UtxoProcessorEventName = Literal[
+    "*",
+    "all",
+    "connect",
+    "disconnect",
+    "utxo-index-not-enabled",
+    "sync-state",
+    "server-status",
+    "utxo-proc-start",
+    "utxo-proc-stop",
+    "utxo-proc-error",
+    "daa-score-change",
+    "pending",
+    "reorg",
+    "stasis",
+    "maturity",
+    "discovery",
+    "balance",
+    "error",
+]
+"""Event names accepted by `UtxoProcessor.add_event_listener()`."""
+
+
+class UtxoContextBalance(TypedDict):
+    """Balance of a `UtxoContext`, in sompi."""
+    mature: int
+    pending: int
+    outgoing: int
+    matureUtxoCount: int
+    pendingUtxoCount: int
+    stasisUtxoCount: int
+
+
+class BalanceEventData(TypedDict):
+    """Data of a `balance` event."""
+    balance: UtxoContextBalance | None
+    id: str
+
+
+class BalanceEvent(TypedDict):
+    """`balance` event."""
+    type: Literal["balance"]
+    data: BalanceEventData
+
+
+class DaaScoreChangeEventData(TypedDict):
+    """Data of a `daa-score-change` event."""
+    currentDaaScore: int
+
+
+class DaaScoreChangeEvent(TypedDict):
+    """`daa-score-change` event."""
+    type: Literal["daa-score-change"]
+    data: DaaScoreChangeEventData
+
+
+class TransactionRecordData(TypedDict, total=False):
+    """A transaction record delivered by `pending`, `reorg`, `stasis`, `maturity` and `discovery` events."""
+    id: str
+    unixtimeMsec: int | None
+    value: int
+    binding: dict
+    blockDaaScore: int
+    network: str
+    data: dict
+    note: str | None
+    metadata: str | None
+
+
+class TransactionRecordEvent(TypedDict):
+    """`pending`, `stasis`, `maturity` and `discovery` event."""
+    type: Literal["pending", "stasis", "maturity", "discovery"]
+    data: TransactionRecordData
+
+
+class AffectedTransaction(TypedDict):
+    """The last event delivered for a transaction invalidated by a `reorg`."""
+    id: str
+    type: Literal["pending", "stasis", "maturity", "discovery"]
+    blockDaaScore: int
+
+
+class ReorgEvent(TypedDict, total=False):
+    """`reorg` event. `affected` holds the last event previously delivered for the invalidated transaction."""
+    type: Literal["reorg"]
+    data: TransactionRecordData
+    affected: list[AffectedTransaction]
+
+
+class UtxoProcessorGenericEvent(TypedDict):
+    """Other `UtxoProcessor` events (`connect`, `sync-state`, `utxo-proc-start`, ...)."""
+    type: str
+    data: dict | None
+
+
+UtxoProcessorEventPayload = Union[
+    BalanceEvent,
+    DaaScoreChangeEvent,
+    TransactionRecordEvent,
+    ReorgEvent,
+    UtxoProcessorGenericEvent,
+]
+"""Any event passed to `UtxoProcessor` event listeners."""
\ No newline at end of file
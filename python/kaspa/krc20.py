@@ -0,0 +1,165 @@
+"""KRC-20 token state from a Kasplex indexer."""
+
+import json
+import urllib.error
+import urllib.parse
+import urllib.request
+from decimal import Decimal
+from typing import Any, Iterator
+
+NETWORK_URLS = {
+    "mainnet": "https://api.kasplex.org/v1",
+    "testnet-10": "https://tn10api.kasplex.org/v1",
+}
+DEFAULT_TIMEOUT = 10.0
+
+# Fields returned as decimal strings by the indexer, converted to int.
+NUMERIC_FIELDS = {
+    "max",
+    "lim",
+    "pre",
+    "dec",
+    "minted",
+    "burned",
+    "balance",
+    "locked",
+    "amt",
+    "holderTotal",
+    "transferTotal",
+    "mintTotal",
+    "opScore",
+    "opScoreAdd",
+    "opScoreMod",
+    "opAccept",
+    "daaScore",
+    "mtsAdd",
+    "mtsMod",
+    "feeRev",
+}
+
+
+class IndexerError(Exception):
+    """An indexer request failed or returned an error message.
+
+    Attributes:
+        status: The HTTP status, None for connection errors.
+    """
+
+    def __init__(self, message: str, status: int | None = None):
+        super().__init__(message)
+        self.status = status
+
+
+def to_decimal(amount: int, dec: int) -> Decimal:
+    """Convert an integer token amount to tokens using the token's `dec` decimals."""
+    return Decimal(amount).scaleb(-dec)
+
+
+def convert(value: Any) -> Any:
+    """Convert the numeric string fields of an indexer response to int."""
+    if isinstance(value, list):
+        return [convert(item) for item in value]
+    if isinstance(value, dict):
+        return {
+            key: int(item) if key in NUMERIC_FIELDS and isinstance(item, str) and item.isdigit() else convert(item)
+            for key, item in value.items()
+        }
+    return value
+
+
+class KasplexClient:
+    """Read KRC-20 token state (token info, balances, operations) from a Kasplex indexer.
+
+    KRC-20 state is kept by indexers, not by the node, so reading it needs an
+    HTTP API next to the `RpcClient`. Amounts are returned as int in the
+    token's smallest unit; `to_decimal(amount, token["dec"])` converts them:
+
+        kasplex = KasplexClient("testnet-10")
+        token = kasplex.token("KASP")
+        for balance in kasplex.balances(address):
+            print(balance["tick"], to_decimal(balance["balance"], balance["dec"]))
+
+    Requests are blocking; use `asyncio.to_thread()` from async code.
+    """
+
+    def __init__(self, network: str = "mainnet", url: str | None = None, timeout: float = DEFAULT_TIMEOUT):
+        if url is None:
+            if network not in NETWORK_URLS:
+                raise ValueError(f"No default indexer for {network!r}, pass `url`")
+            url = NETWORK_URLS[network]
+        if timeout <= 0:
+            raise ValueError("timeout must be positive")
+        self.network = network
+        self.url = url.rstrip("/")
+        self.timeout = timeout
+
+    def info(self) -> dict:
+        """Indexer status (e.g. the DAA score it has indexed up to)."""
+        return self._get("/info")["result"]
+
+    def token(self, tick: str) -> dict:
+        """Token info: `tick`, `max`, `lim`, `pre`, `dec`, `minted`, `state`, holders, ..."""
+        result = self._get(f"/krc20/token/{urllib.parse.quote(tick.upper())}")["result"]
+        if not result:
+            raise IndexerError(f"Token {tick!r} not found", 404)
+        return result[0]
+
+    def tokens(self) -> Iterator[dict]:
+        """Iterate over all deployed tokens."""
+        yield from self._pages("/krc20/tokenlist")
+
+    def balances(self, address: Any) -> list[dict]:
+        """The token balances of an address: dicts with `tick`, `balance`, `locked` and `dec`."""
+        return list(self._pages(f"/krc20/address/{address}/tokenlist"))
+
+    def balance(self, address: Any, tick: str) -> dict:
+        """The balance of one token held by an address."""
+        path = f"/krc20/address/{address}/token/{urllib.parse.quote(tick.upper())}"
+        result = self._get(path)["result"]
+        if not result:
+            return {"tick": tick.upper(), "balance": 0, "locked": 0}
+        return result[0]
+
+    def operations(self, address: Any = None, tick: str | None = None) -> Iterator[dict]:
+        """Iterate over operations (deploy, mint, transfer, ...), newest first, of an address and/or token."""
+        if address is None and tick is None:
+            raise ValueError("address or tick is required")
+        params = {}
+        if address is not None:
+            params["address"] = str(address)
+        if tick is not None:
+            params["tick"] = tick.upper()
+        yield from self._pages("/krc20/oplist", params)
+
+    def operation(self, id: str) -> dict:
+        """One operation by the id (hash) of its reveal transaction."""
+        result = self._get(f"/krc20/op/{id}")["result"]
+        if not result:
+            raise IndexerError(f"Operation {id!r} not found", 404)
+        return result[0]
+
+    def _pages(self, path: str, params: dict | None = None) -> Iterator[dict]:
+        params = dict(params or {})
+        while True:
+            response = self._get(path, params)
+            yield from response.get("result") or []
+            if not response.get("next") or not response.get("result"):
+                return
+            params["next"] = response["next"]
+
+    def _get(self, path: str, params: dict | None = None) -> dict:
+        url = self.url + path
+        if params:
+            url += "?" + urllib.parse.urlencode(params)
+        request = urllib.request.Request(url, headers={"Accept": "application/json"})
+        try:
+            with urllib.request.urlopen(request, timeout=self.timeout) as response:
+                body = json.loads(response.read())
+        except urllib.error.HTTPError as err:
+            raise IndexerError(f"Indexer request {path} failed: HTTP {err.code}", err.code) from err
+        except (urllib.error.URLError, OSError, ValueError) as err:
+            raise IndexerError(f"Indexer request {path} failed: {err}") from err
+        message = body.get("message")
+        if message not in (None, "successful"):
+            raise IndexerError(f"Indexer request {path} failed: {message}")
+        return convert(body)
@@ -0,0 +1,167 @@
+"""Maturity progress callbacks for pending transactions and UTXOs."""
+
+import threading
+from typing import Any, Callable, Sequence
+
+from .kaspa import NetworkId, UtxoProcessor
+
+DEFAULT_THRESHOLDS = (0.25, 0.5, 0.75, 1.0)
+# DAA score units per second since the 10 BPS (Crescendo) hardfork.
+DAA_SCORE_PER_SECOND = 10
+
+
+class Watch:
+    """A pending maturity: the DAA score range it spans and the thresholds left to report."""
+
+    def __init__(self, id: str, start: int, maturity: int, thresholds: Sequence[float], callback: Callable | None):
+        self.id = id
+        self.start = start
+        self.maturity = maturity
+        self.thresholds = sorted(thresholds)
+        self.callback = callback
+
+    def progress(self, daa_score: int) -> float:
+        if self.maturity <= self.start:
+            return 1.0
+        return min(max((daa_score - self.start) / (self.maturity - self.start), 0.0), 1.0)
+
+
+class MaturityWatcher:
+    """Report the maturity progress of pending transactions as DAA score advances.
+
+    Attached to a `UtxoProcessor`, the watcher follows every `pending`
+    transaction record and calls `on_progress` each time its progress crosses
+    one of `thresholds` (fractions of the maturity period, 1.0 meaning mature).
+    Single UTXOs or records can also be watched with their own callback and
+    thresholds through `watch()`:
+
+        watcher = MaturityWatcher(processor, on_progress=lambda event: print(event))
+        watcher.watch(entry, on_mature, thresholds=[1.0])
+
+    Callbacks receive a dict with `id`, `threshold`, `progress`, `daaScore`,
+    `maturityDaaScore`, `remainingDaa` and `estimatedSeconds`. They are called
+    from the processor's event thread and should return quickly.
+    """
+
+    def __init__(
+        self,
+        processor: Any,
+        on_progress: Callable[[dict], Any] | None = None,
+        thresholds: Sequence[float] = DEFAULT_THRESHOLDS,
+        network_id: NetworkId | str | None = None,
+    ):
+        check_thresholds(thresholds)
+        self.processor = processor
+        self.on_progress = on_progress
+        self.thresholds = list(thresholds)
+        self.network_id = NetworkId(network_id) if isinstance(network_id, str) else network_id
+        self.daa_score: int | None = None
+        self._watches: dict[str, Watch] = {}
+        self._lock = threading.Lock()
+        processor.add_event_listener(["pending", "maturity", "reorg", "daa-score-change"], self._on_event)
+
+    def close(self) -> None:
+        """Stop following the processor."""
+        self.processor.remove_event_listener(["pending", "maturity", "reorg", "daa-score-change"], self._on_event)
+
+    @property
+    def watching(self) -> list[str]:
+        """Ids of the records and UTXOs not yet mature."""
+        with self._lock:
+            return list(self._watches)
+
+    def watch(
+        self,
+        target: Any,
+        callback: Callable[[dict], Any] | None = None,
+        thresholds: Sequence[float] | None = None,
+    ) -> str:
+        """Watch a `UtxoEntryReference` or a transaction record dict.
+
+        Args:
+            target: The UTXO or record (e.g. the `data` of a `pending` event).
+            callback: Called instead of `on_progress` for this target.
+            thresholds: Fractions of the maturity period to report (default: the watcher's).
+
+        Returns:
+            str: The watch id: the record id, or `<transaction id>:<index>` for UTXOs.
+        """
+        thresholds = self.thresholds if thresholds is None else list(thresholds)
+        check_thresholds(thresholds)
+        if isinstance(target, dict):
+            id = target["id"]
+            start = target["blockDaaScore"]
+            maturity = start + UtxoProcessor.get_user_transaction_maturity_daa(self._network_id())
+        else:
+            id = f"{target.outpoint.transaction_id}:{target.outpoint.index}"
+            start = target.block_daa_score
+            maturity = target.maturity_daa_score(self._network_id())
+        watch = Watch(id, start, maturity, thresholds, callback)
+        with self._lock:
+            self._watches[id] = watch
+        if self.daa_score is not None:
+            self._advance(self.daa_score)
+        return id
+
+    def unwatch(self, id: str) -> None:
+        """Stop watching a record or UTXO."""
+        with self._lock:
+            self._watches.pop(id, None)
+
+    def _network_id(self) -> NetworkId:
+        network_id = self.network_id or self.processor.network_id
+        if network_id is None:
+            raise ValueError("network_id is required when the processor has no network id")
+        return network_id
+
+    def _on_event(self, event: dict) -> None:
+        data = event.get("data") or {}
+        kind = event.get("type")
+        if kind == "daa-score-change":
+            self.daa_score = data["currentDaaScore"]
+            self._advance(self.daa_score)
+        elif kind == "pending" and self.on_progress is not None:
+            self.watch(data)
+        elif kind == "reorg":
+            self.unwatch(data.get("id"))
+        elif kind == "maturity":
+            # The processor considers the record mature; report what is left.
+            with self._lock:
+                watch = self._watches.get(data.get("id"))
+            if watch is not None:
+                self._report(watch, max(watch.maturity, self.daa_score or 0))
+
+    def _advance(self, daa_score: int) -> None:
+        with self._lock:
+            watches = list(self._watches.values())
+        for watch in watches:
+            self._report(watch, daa_score)
+
+    def _report(self, watch: Watch, daa_score: int) -> None:
+        progress = watch.progress(daa_score)
+        remaining = max(watch.maturity - daa_score, 0)
+        with self._lock:
+            crossed = [threshold for threshold in watch.thresholds if threshold <= progress]
+            watch.thresholds = [threshold for threshold in watch.thresholds if threshold > progress]
+            if not watch.thresholds:
+                self._watches.pop(watch.id, None)
+        callback = watch.callback or self.on_progress
+        if callback is None:
+            return
+        for threshold in crossed:
+            callback(
+                {
+                    "id": watch.id,
+                    "threshold": threshold,
+                    "progress": progress,
+                    "daaScore": daa_score,
+                    "maturityDaaScore": watch.maturity,
+                    "remainingDaa": remaining,
+                    "estimatedSeconds": remaining / DAA_SCORE_PER_SECOND,
+                }
+            )
+
+
+def check_thresholds(thresholds: Sequence[float]) -> None:
+    if not thresholds or any(not 0 < threshold <= 1 for threshold in thresholds):
+        raise ValueError("thresholds must be fractions in (0, 1]")
@@ -0,0 +1,169 @@
+"""Periodic sampling of node metrics into a time series."""
+
+import asyncio
+import collections
+import time
+from typing import Any, AsyncIterator, Sequence
+
+DEFAULT_INTERVAL = 1.0
+DEFAULT_CAPACITY = 3600
+
+# Sample fields, by the `get_metrics()` category and key they are read from.
+FIELDS = {
+    "daa_score": ("consensusMetrics", "networkVirtualDaaScore"),
+    "mempool_size": ("consensusMetrics", "networkMempoolSize"),
+    "difficulty": ("consensusMetrics", "networkDifficulty"),
+    "tip_hashes": ("consensusMetrics", "networkTipHashesCount"),
+    "cpu_usage": ("processMetrics", "cpuUsage"),
+    "resident_set_size": ("processMetrics", "residentSetSize"),
+    "active_peers": ("connectionMetrics", "activePeers"),
+}
+
+
+def sample_from_metrics(timestamp: float, metrics: dict) -> dict:
+    """A flat sample of a `get_metrics()` response; missing metrics are None."""
+    sample: dict[str, Any] = {"timestamp": timestamp}
+    for field, (category, key) in FIELDS.items():
+        sample[field] = (metrics.get(category) or {}).get(key)
+    return sample
+
+
+class MetricsSampler:
+    """Poll node metrics at a fixed interval and buffer them as a time series.
+
+    Each sample is one `get_metrics()` call, flattened into a dict with
+    `timestamp` (Unix seconds), `daa_score`, `mempool_size`, `difficulty`,
+    `tip_hashes`, `cpu_usage`, `resident_set_size` and `active_peers`. Polls
+    are scheduled on a fixed grid from `start()`, so the sampling rate does
+    not drift with call latency; a poll still running at the next tick skips
+    that tick. Failed polls are counted in `errors` and skipped.
+
+    The last `capacity` samples are kept for `samples` and `arrays()`, and
+    `stream()` yields new samples as they are taken:
+
+        async with MetricsSampler(client, interval=5) as sampler:
+            async for sample in sampler.stream():
+                print(sample["daa_score"], sample["mempool_size"])
+    """
+
+    def __init__(self, rpc: Any, interval: float = DEFAULT_INTERVAL, capacity: int = DEFAULT_CAPACITY):
+        """Create a sampler; sampling begins with `start()`.
+
+        Args:
+            rpc: A connected `RpcClient`.
+            interval: Seconds between samples (default: 1).
+            capacity: Number of samples kept (default: 3600).
+
+        Raises:
+            ValueError: If `interval` or `capacity` is not positive.
+        """
+        if interval <= 0 or capacity <= 0:
+            raise ValueError("interval and capacity must be positive")
+        self.rpc = rpc
+        self.interval = interval
+        self.errors = 0
+        self.last_error: Exception | None = None
+        self._samples: collections.deque[dict] = collections.deque(maxlen=capacity)
+        self._subscribers: list[asyncio.Queue] = []
+        self._task: asyncio.Task | None = None
+
+    @property
+    def samples(self) -> list[dict]:
+        """The buffered samples, oldest first."""
+        return list(self._samples)
+
+    @property
+    def is_running(self) -> bool:
+        """Whether the sampler is polling."""
+        return self._task is not None and not self._task.done()
+
+    def start(self) -> None:
+        """Start sampling on the running event loop; the first sample is taken immediately."""
+        if not self.is_running:
+            self._task = asyncio.get_running_loop().create_task(self._run())
+
+    async def stop(self) -> None:
+        """Stop sampling and end all streams. Buffered samples are kept."""
+        if self._task is not None:
+            self._task.cancel()
+            await asyncio.gather(self._task, return_exceptions=True)
+            self._task = None
+        for queue in self._subscribers:
+            queue.put_nowait(None)
+
+    async def __aenter__(self) -> "MetricsSampler":
+        self.start()
+        return self
+
+    async def __aexit__(self, *exc: Any) -> None:
+        await self.stop()
+
+    def arrays(self, fields: Sequence[str] | None = None) -> dict:
+        """The buffered samples as NumPy arrays, one per field.
+
+        Args:
+            fields: Fields to export (default: `timestamp` and all metric fields).
+
+        Returns:
+            dict: Field name to array; missing metrics are NaN.
+
+        Raises:
+            ImportError: If numpy is not installed.
+            ValueError: If a field is unknown.
+        """
+        try:
+            import numpy as np
+        except ImportError:
+            raise ImportError("numpy is required for array exports: pip install kaspa[dataframe]") from None
+        fields = list(fields) if fields is not None else ["timestamp", *FIELDS]
+        unknown = [field for field in fields if field != "timestamp" and field not in FIELDS]
+        if unknown:
+            raise ValueError(f"Unknown fields: {', '.join(unknown)}")
+        return {
+            field: np.array(
+                [np.nan if sample[field] is None else sample[field] for sample in self._samples], dtype=np.float64
+            )
+            for field in fields
+        }
+
+    async def stream(self) -> AsyncIterator[dict]:
+        """Yield each new sample until the sampler is stopped.
+
+        Samples taken while the consumer is busy are queued, so none are missed.
+        """
+        queue: asyncio.Queue = asyncio.Queue()
+        self._subscribers.append(queue)
+        try:
+            while (sample := await queue.get()) is not None:
+                yield sample
+        finally:
+            self._subscribers.remove(queue)
+
+    async def sample(self) -> dict:
+        """Take one sample now, buffer and publish it.
+
+        Raises:
+            Exception: If the `get_metrics()` call fails.
+        """
+        metrics = await self.rpc.get_metrics(
+            {"processMetrics": True, "connectionMetrics": True, "consensusMetrics": True}
+        )
+        sample = sample_from_metrics(time.time(), metrics)
+        self._samples.append(sample)
+        for queue in self._subscribers:
+            queue.put_nowait(sample)
+        return sample
+
+    async def _run(self) -> None:
+        loop = asyncio.get_running_loop()
+        started = loop.time()
+        tick = 0
+        while True:
+            try:
+                await self.sample()
+            except Exception as err:
+                self.errors += 1
+                self.last_error = err
+            # Next tick on the grid from `started`, skipping ticks a slow poll overran.
+            tick = max(tick + 1, int((loop.time() - started) / self.interval) + 1)
+            await asyncio.sleep(max(started + tick * self.interval - loop.time(), 0))
@@ -0,0 +1,382 @@
+"""Bulk payouts: many (address, amount) rows paid in as few transactions as fit."""
+
+import asyncio
+import os
+import sqlite3
+import threading
+import time
+from typing import Any, Iterable, Sequence
+
+from .kaspa import Address, Generator, NetworkId, PaymentOutput, exceptions, maximum_standard_transaction_mass, parse_amount
+
+DEFAULT_MAX_OUTPUTS = 100
+DEFAULT_RETRIES = 3
+DEFAULT_RETRY_DELAY = 1.0
+
+# Rejections worth resubmitting; the others fail the same way every time.
+RETRY_REASONS = {"orphan", "unknown"}
+# Rejections meaning the node already has the transaction.
+ACCEPTED_REASONS = {"already_accepted", "duplicate"}
+# Rejections meaning the transaction was not and will not be accepted.
+REJECTED_REASONS = {"double_spend", "insufficient_fee", "mass_exceeded", "bad_signature", "non_standard"}
+
+
+class PayoutStore:
+    """SQLite record of the rows paid by idempotency key.
+
+    A row is recorded as `pending`, with the id of its transaction, before the
+    transaction is submitted, and marked `submitted` once the node accepts it.
+    A `pending` record left by a crash may or may not have been accepted; it
+    blocks the key until `forget()` is called after checking the transaction
+    (e.g. with `get_mempool_entry` or the recipient's UTXOs).
+    """
+
+    def __init__(self, path: str | os.PathLike):
+        self.path = os.fspath(path)
+        self._lock = threading.Lock()
+        self._db = sqlite3.connect(self.path, check_same_thread=False)
+        self._db.execute(
+            "CREATE TABLE IF NOT EXISTS payouts ("
+            "key TEXT PRIMARY KEY, status TEXT, address TEXT, amount INTEGER, "
+            "transaction_id TEXT, output_index INTEGER, timestamp INTEGER)"
+        )
+        self._db.commit()
+
+    def get(self, key: str) -> dict | None:
+        """The record of `key`: a dict with `key`, `status`, `address`, `amount`,
+        `transaction_id`, `output_index` and `timestamp` (milliseconds), or None."""
+        with self._lock:
+            row = self._db.execute(
+                "SELECT key, status, address, amount, transaction_id, output_index, timestamp FROM payouts WHERE key = ?",
+                (key,),
+            ).fetchone()
+        if row is None:
+            return None
+        return dict(zip(("key", "status", "address", "amount", "transaction_id", "output_index", "timestamp"), row))
+
+    def reserve(self, records: Sequence[tuple[str, str, int, str, int]]) -> None:
+        """Record `(key, address, amount, transaction_id, output_index)` rows as pending, atomically.
+
+        Raises:
+            ValueError: If a key is already recorded.
+        """
+        timestamp = int(time.time() * 1000)
+        with self._lock:
+            try:
+                with self._db:
+                    self._db.executemany(
+                        "INSERT INTO payouts (key, status, address, amount, transaction_id, output_index, timestamp) "
+                        "VALUES (?, 'pending', ?, ?, ?, ?, ?)",
+                        [(*record, timestamp) for record in records],
+                    )
+            except sqlite3.IntegrityError as err:
+                raise ValueError(f"Idempotency key already recorded: {err}") from err
+
+    def complete(self, keys: Sequence[str]) -> None:
+        """Mark the records of `keys` submitted."""
+        with self._lock, self._db:
+            self._db.executemany("UPDATE payouts SET status = 'submitted' WHERE key = ?", [(key,) for key in keys])
+
+    def forget(self, keys: Sequence[str]) -> None:
+        """Delete the records of `keys`, so they are paid again by the next batch."""
+        with self._lock, self._db:
+            self._db.executemany("DELETE FROM payouts WHERE key = ?", [(key,) for key in keys])
+
+    def close(self) -> None:
+        """Close the database."""
+        with self._lock:
+            self._db.close()
+
+
+class Row:
+    """A validated payout row."""
+
+    def __init__(self, index: int, address: Address, amount: int, reference: Any, key: str | None):
+        self.index = index
+        self.address = address
+        self.amount = amount
+        self.reference = reference
+        self.key = key
+
+    def report(self, status: str, transaction_id: str | None = None, output_index: int | None = None, error: str | None = None) -> dict:
+        return {
+            "row": self.index,
+            "address": self.address.to_string(),
+            "amount": self.amount,
+            "reference": self.reference,
+            "key": self.key,
+            "status": status,
+            "transaction_id": transaction_id,
+            "output_index": output_index,
+            "error": error,
+        }
+
+
+class PayoutBatch:
+    """Pay a large number of (address, amount) rows, e.g. exchange withdrawals.
+
+    Rows are validated as they are added. `submit()` packs consecutive rows
+    into transactions of at most `max_outputs` outputs, shrinking a chunk until
+    its transaction fits the standard mass limit (storage mass grows quickly
+    with small outputs). Each transaction is signed, submitted with retries, and
+    its change spent by the next one:
+
+        batch = PayoutBatch("mainnet", hot_wallet_address)
+        batch.extend(csv.reader(open("withdrawals.csv")))
+        report = await batch.submit(client, utxo_entries, [private_key])
+        for row in report:
+            print(row["row"], row["status"], row["transaction_id"], row["output_index"])
+
+    The report has one dict per row with `row`, `address`, `amount`,
+    `reference`, `status`, `transaction_id`, `output_index` and `error`.
+    `status` is `submitted`, `failed` (the row cannot be paid, e.g. its output
+    alone exceeds the mass limit, or its transaction was not accepted after
+    `retries` attempts) or `skipped` (not attempted because an earlier
+    submission failed; its change may still be accepted, so the batch stops
+    rather than risk paying twice). `plan()` runs the same packing without
+    signing or submitting.
+
+    With a `store` (a `PayoutStore` or the path of its SQLite database), rows
+    added with an idempotency `key` are recorded before their transaction is
+    submitted, and a key found in the store is never paid again: running a
+    crashed batch again reports those rows from the store instead. Rows whose
+    transaction may have reached the node without confirmation of acceptance
+    have status `unconfirmed`; see `PayoutStore.forget()`.
+    """
+
+    def __init__(
+        self,
+        network_id: NetworkId | str,
+        change_address: Address | str,
+        max_outputs: int = DEFAULT_MAX_OUTPUTS,
+        fee_rate: float | None = None,
+        priority_fee: Any = None,
+        retries: int = DEFAULT_RETRIES,
+        retry_delay: float = DEFAULT_RETRY_DELAY,
+        store: PayoutStore | str | os.PathLike | None = None,
+    ):
+        if max_outputs < 1:
+            raise ValueError("max_outputs must be at least 1")
+        if retries < 0 or retry_delay < 0:
+            raise ValueError("retries and retry_delay must not be negative")
+        self.network_id = NetworkId(network_id) if isinstance(network_id, str) else network_id
+        self.change_address = Address(change_address) if isinstance(change_address, str) else change_address
+        self.max_outputs = max_outputs
+        self.fee_rate = fee_rate
+        self.priority_fee = priority_fee
+        self.retries = retries
+        self.retry_delay = retry_delay
+        self.store = store if store is None or isinstance(store, PayoutStore) else PayoutStore(store)
+        self._rows: list[Row] = []
+        self._keys: set[str] = set()
+
+    def __len__(self) -> int:
+        return len(self._rows)
+
+    @property
+    def total(self) -> int:
+        """The sum of all row amounts in sompi."""
+        return sum(row.amount for row in self._rows)
+
+    def add(self, address: Address | str, amount: Any, reference: Any = None, key: str | None = None) -> int:
+        """Validate and add a row.
+
+        Args:
+            address: The recipient, on the batch's network.
+            amount: The amount in sompi, or an amount string such as "1.5 KAS".
+            reference: Any value identifying the row in the report (e.g. a withdrawal id).
+            key: The idempotency key of the row, unique across batches sharing a store.
+
+        Returns:
+            int: The row index.
+
+        Raises:
+            ValueError: If the address or amount is invalid.
+        """
+        if isinstance(address, str):
+            if not Address.validate(address):
+                raise ValueError(f"Invalid address {address!r}")
+            address = Address(address)
+        if address.prefix != self.change_address.prefix:
+            raise ValueError(f"Address {address.to_string()} is not on the {self.change_address.prefix} network")
+        amount = parse_amount(amount)
+        if amount <= 0:
+            raise ValueError(f"Amount must be positive, got {amount}")
+        if key is not None:
+            if key in self._keys:
+                raise ValueError(f"Duplicate idempotency key {key!r}")
+            self._keys.add(key)
+        self._rows.append(Row(len(self._rows), address, amount, reference, key))
+        return len(self._rows) - 1
+
+    def extend(self, rows: Iterable[Sequence | dict], skip_invalid: bool = False) -> list[dict]:
+        """Add rows given as `(address, amount[, reference[, key]])` sequences or dicts with these keys.
+
+        Args:
+            rows: The rows to add.
+            skip_invalid: Collect invalid rows instead of raising on the first one.
+
+        Returns:
+            list[dict]: The rejected rows with `row` (position in `rows`), `data` and `error`.
+
+        Raises:
+            ValueError: If a row is invalid and `skip_invalid` is False.
+        """
+        rejected = []
+        for position, data in enumerate(rows):
+            try:
+                if isinstance(data, dict):
+                    self.add(data["address"], data["amount"], data.get("reference"), data.get("key"))
+                else:
+                    self.add(*data)
+            except (ValueError, KeyError, TypeError) as err:
+                if not skip_invalid:
+                    raise ValueError(f"Invalid row {position}: {err}") from err
+                rejected.append({"row": position, "data": data, "error": str(err)})
+        return rejected
+
+    def plan(self, entries: Sequence) -> list[dict]:
+        """Pack the rows into transactions without signing or submitting.
+
+        Args:
+            entries: The UTXOs to pay from.
+
+        Returns:
+            list[dict]: The report rows, with status `planned` or `failed`.
+        """
+        report: dict[int, dict] = {}
+        for rows, pending in self._chunks(self._rows, list(entries), report):
+            for position, row in enumerate(rows):
+                report[row.index] = row.report("planned", pending[-1].id, position)
+        return [report[row.index] for row in self._rows]
+
+    async def submit(self, rpc: Any, entries: Sequence, signer: Any) -> list[dict]:
+        """Pay all rows.
+
+        Args:
+            rpc: A connected RpcClient.
+            entries: The UTXOs to pay from, spendable by `signer`.
+            signer: A list of PrivateKey signing the transactions, or a signer with
+                a `sign(pending)` method (e.g. `RemoteSigner`).
+
+        Returns:
+            list[dict]: The reconciliation report, one dict per row.
+        """
+        report: dict[int, dict] = {}
+        unpaid = [row for row in self._rows if not self._recorded(row, report)]
+        for rows, pending in self._chunks(unpaid, list(entries), report):
+            keys = [row.key for row in rows if row.key is not None]
+            if self.store is not None and keys:
+                self.store.reserve(
+                    [
+                        (row.key, row.address.to_string(), row.amount, pending[-1].id, position)
+                        for position, row in enumerate(rows)
+                        if row.key is not None
+                    ]
+                )
+            try:
+                for transaction in pending:
+                    if isinstance(signer, list):
+                        transaction.sign(signer)
+                    else:
+                        signer.sign(transaction)
+                    transaction_id = await self._submit(rpc, transaction)
+            except Exception as err:
+                rejected = isinstance(err, exceptions.TransactionRejectedError) and err.reason in REJECTED_REASONS
+                if self.store is not None and rejected:
+                    self.store.forget(keys)
+                for position, row in enumerate(rows):
+                    kept = self.store is not None and row.key is not None and not rejected
+                    status = "unconfirmed" if kept else "failed"
+                    report[row.index] = row.report(status, pending[-1].id, position if kept else None, str(err))
+                break
+            if self.store is not None and keys:
+                self.store.complete(keys)
+            for position, row in enumerate(rows):
+                report[row.index] = row.report("submitted", transaction_id, position)
+        for row in self._rows:
+            report.setdefault(row.index, row.report("skipped"))
+        return [report[row.index] for row in self._rows]
+
+    def _recorded(self, row: Row, report: dict[int, dict]) -> bool:
+        """Report a row from the store if its key is recorded there."""
+        if self.store is None or row.key is None:
+            return False
+        record = self.store.get(row.key)
+        if record is None:
+            return False
+        if record["address"] != row.address.to_string() or record["amount"] != row.amount:
+            report[row.index] = row.report("failed", error=f"Idempotency key {row.key!r} was used for another payout")
+        else:
+            status = "submitted" if record["status"] == "submitted" else "unconfirmed"
+            report[row.index] = row.report(status, record["transaction_id"], record["output_index"])
+        return True
+
+    def _chunks(self, rows: list[Row], entries: list, report: dict[int, dict]):
+        """Yield (rows, pending transactions) chunks, spending the change of the previous chunk.
+
+        Rows that cannot be paid even alone are marked failed in `report`.
+        """
+        start = 0
+        while start < len(rows):
+            count = min(self.max_outputs, len(rows) - start)
+            pending, error = self._generate(entries, rows[start : start + count])
+            if pending is None:
+                # Binary search for the largest chunk whose transaction fits.
+                low, high = 1, count - 1
+                while low <= high:
+                    middle = (low + high) // 2
+                    fitted, chunk_error = self._generate(entries, rows[start : start + middle])
+                    if fitted is not None:
+                        count, pending = middle, fitted
+                        low = middle + 1
+                    else:
+                        error = chunk_error if middle == 1 else error
+                        high = middle - 1
+            if pending is None:
+                row = rows[start]
+                report[row.index] = row.report("failed", error=error)
+                start += 1
+                continue
+            yield rows[start : start + count], pending
+            spent = {outpoint(entry) for transaction in pending for entry in transaction.get_utxo_entries()}
+            change = [transaction.change_entry for transaction in pending if transaction.change_entry is not None]
+            entries = [entry for entry in entries + change if outpoint(entry) not in spent]
+            start += count
+
+    def _generate(self, entries: list, rows: list[Row]) -> tuple[list | None, str | None]:
+        try:
+            pending = list(
+                Generator(
+                    entries,
+                    self.change_address,
+                    network_id=self.network_id,
+                    outputs=[PaymentOutput(row.address, row.amount) for row in rows],
+                    fee_rate=self.fee_rate,
+                    priority_fee=self.priority_fee,
+                )
+            )
+        except Exception as err:
+            return None, str(err)
+        if not pending or pending[-1].mass > maximum_standard_transaction_mass():
+            return None, "transaction exceeds the standard mass limit"
+        return pending, None
+
+    async def _submit(self, rpc: Any, transaction: Any) -> str:
+        for attempt in range(self.retries + 1):
+            try:
+                return await transaction.submit(rpc)
+            except exceptions.TransactionRejectedError as err:
+                if err.reason in ACCEPTED_REASONS:
+                    return transaction.id
+                if err.reason not in RETRY_REASONS or attempt == self.retries:
+                    raise
+            except Exception:
+                if attempt == self.retries:
+                    raise
+            await asyncio.sleep(self.retry_delay * 2**attempt)
+        raise AssertionError("unreachable")
+
+
+def outpoint(entry: Any) -> tuple[str, int]:
+    return (entry.outpoint.transaction_id, entry.outpoint.index)
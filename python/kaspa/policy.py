@@ -0,0 +1,81 @@
+"""Spend limits enforced before signing, through `add_signing_policy()`."""
+
+import threading
+import time
+from collections import deque
+from typing import Any, Callable, Iterable
+
+DEFAULT_WINDOW = 3600.0
+
+
+class SpendLimitPolicy:
+    """A signing policy enforcing withdrawal limits.
+
+    Vetoes signing a transaction sending more than `max_amount` sompi, paying
+    an address outside `allowed_destinations` or inside `denied_destinations`,
+    or bringing the amount signed within the last `window` seconds (an hour by
+    default) above `max_per_window`:
+
+        policy = SpendLimitPolicy(max_amount=kaspa_to_sompi(1000), max_per_window=kaspa_to_sompi(10_000))
+        add_signing_policy(policy)
+
+    Amounts are the sompi leaving the wallet (change excluded). A transaction
+    counts once towards the window, however many signing calls it takes (e.g.
+    one `sign_input()` per input), from the first call the policy allows.
+    """
+
+    def __init__(
+        self,
+        max_amount: int | None = None,
+        max_per_window: int | None = None,
+        window: float = DEFAULT_WINDOW,
+        allowed_destinations: Iterable[Any] | None = None,
+        denied_destinations: Iterable[Any] | None = None,
+        clock: Callable[[], float] = time.monotonic,
+    ):
+        if window <= 0:
+            raise ValueError("window must be positive")
+        self.max_amount = max_amount
+        self.max_per_window = max_per_window
+        self.window = window
+        self.allowed_destinations = None if allowed_destinations is None else {str(address) for address in allowed_destinations}
+        self.denied_destinations = {str(address) for address in denied_destinations or ()}
+        self.clock = clock
+        self._signed: deque[tuple[float, str, int]] = deque()
+        self._lock = threading.Lock()
+
+    @property
+    def spent(self) -> int:
+        """The amount signed within the window, in sompi."""
+        with self._lock:
+            self._expire(self.clock())
+            return sum(amount for _, _, amount in self._signed)
+
+    def __call__(self, request: dict) -> str | None:
+        """Check a signing request; returns the reason of a veto, None to allow."""
+        amount = request["amount"]
+        for output in request["outputs"]:
+            if output["change"]:
+                continue
+            address = output["address"]
+            if address in self.denied_destinations:
+                return f"destination {address} is denied"
+            if self.allowed_destinations is not None and address not in self.allowed_destinations:
+                return f"destination {address} is not allowed"
+        if self.max_amount is not None and amount > self.max_amount:
+            return f"amount {amount} exceeds the limit of {self.max_amount} per transaction"
+
+        with self._lock:
+            now = self.clock()
+            self._expire(now)
+            if any(transaction_id == request["transaction_id"] for _, transaction_id, _ in self._signed):
+                return None
+            spent = sum(amount for _, _, amount in self._signed)
+            if self.max_per_window is not None and spent + amount > self.max_per_window:
+                return f"amount {amount} exceeds the remaining {max(self.max_per_window - spent, 0)} of the limit per {self.window:g}s"
+            self._signed.append((now, request["transaction_id"], amount))
+        return None
+
+    def _expire(self, now: float) -> None:
+        while self._signed and self._signed[0][0] <= now - self.window:
+            self._signed.popleft()
@@ -0,0 +1,33 @@
+"""Event loop detection.
+
+The SDK's awaitables and async event listeners resolve on the loop they are
+created from, whatever its implementation: asyncio's default loop, uvloop or a
+loop from a custom event loop policy.
+"""
+
+import asyncio
+
+
+def event_loop_backend(loop: asyncio.AbstractEventLoop | None = None) -> str:
+    """The implementation of an event loop: "asyncio", "uvloop", ...
+
+    Args:
+        loop: The loop to inspect (default: the running loop).
+
+    Returns:
+        The top-level package of the loop's class.
+
+    Raises:
+        RuntimeError: If `loop` is not given and no event loop is running.
+    """
+    if loop is None:
+        loop = asyncio.get_running_loop()
+    return type(loop).__module__.split(".")[0]
+
+
+def uses_uvloop() -> bool:
+    """Whether the running event loop is uvloop's (False if no loop is running)."""
+    try:
+        return event_loop_backend() == "uvloop"
+    except RuntimeError:
+        return False
@@ -0,0 +1,154 @@
+"""Signing with keys held by an external signing service."""
+
+import json
+import ssl
+import urllib.error
+import urllib.request
+from typing import Any
+
+from .kaspa import is_script_pay_to_pubkey_ecdsa
+
+DEFAULT_TIMEOUT = 10.0
+# SIG_HASH_ALL, appended to P2PK signatures.
+SIGHASH_ALL = "01"
+
+
+class RemoteSignerError(Exception):
+    """A signing service request failed or returned an invalid response.
+
+    Attributes:
+        status: The HTTP status, None for connection and response errors.
+    """
+
+    def __init__(self, message: str, status: int | None = None):
+        super().__init__(message)
+        self.status = status
+
+
+class RemoteSigner:
+    """Sign pending transactions with a signing service over HTTPS (optionally mTLS).
+
+    Private keys stay in the service (e.g. an HSM or an isolated enclave). For
+    each transaction the signer computes the signature hash of every input with
+    `PendingTransaction.signature_hash()` and POSTs them as JSON to `url`:
+
+        {"transaction_id": "...", "key_id": "...", "transaction": {...},
+         "inputs": [{"index": 0, "sighash": "<hex>", "ecdsa": false,
+                     "address": "kaspa:...", "script_public_key": "<hex>"}]}
+
+    The full transaction (`Transaction.to_dict()`) lets the service apply its
+    own checks before signing. It answers with one entry per signed input:
+
+        {"signatures": [{"index": 0, "signature": "<64-byte hex>"}]}
+
+    `signature` is wrapped into a P2PK signature script (SIG_HASH_ALL); inputs
+    needing other scripts (e.g. multisig) return a full `signature_script` hex
+    instead. With `verify`, the transaction is then checked with `validate()`.
+
+    A signer is any object with a `sign(pending)` method; `PayoutBatch.submit()`
+    accepts one in place of a list of private keys:
+
+        signer = RemoteSigner("https://signer.internal/sign", cert="client.pem", key="client.key", ca="ca.pem")
+        for pending in generator:
+            signer.sign(pending)
+            await pending.submit(client)
+
+    Requests are blocking; use `asyncio.to_thread()` from async code.
+    """
+
+    def __init__(
+        self,
+        url: str,
+        cert: str | None = None,
+        key: str | None = None,
+        ca: str | None = None,
+        key_id: str | None = None,
+        headers: dict[str, str] | None = None,
+        timeout: float = DEFAULT_TIMEOUT,
+        verify: bool = True,
+    ):
+        if timeout <= 0:
+            raise ValueError("timeout must be positive")
+        if key is not None and cert is None:
+            raise ValueError("key requires cert")
+        self.url = url
+        self.key_id = key_id
+        self.headers = dict(headers or {})
+        self.timeout = timeout
+        self.verify = verify
+        self._context = None
+        if url.startswith("https:"):
+            self._context = ssl.create_default_context(cafile=ca)
+            if cert is not None:
+                self._context.load_cert_chain(cert, key)
+
+    def sign(self, pending: Any) -> None:
+        """Sign all inputs of a `PendingTransaction` with the service.
+
+        Raises:
+            RemoteSignerError: If the request fails or an input is left unsigned.
+            PolicyViolationError: If a signing policy vetoes the transaction.
+            ScriptVerificationError: If `verify` and a returned signature is invalid.
+        """
+        transaction = pending.transaction
+        inputs = []
+        for index, input in enumerate(transaction.inputs):
+            entry = input.utxo
+            script = entry.script_public_key.script if entry is not None else None
+            ecdsa = script is not None and is_script_pay_to_pubkey_ecdsa(script)
+            inputs.append(
+                {
+                    "index": index,
+                    "sighash": pending.signature_hash(index, ecdsa=ecdsa),
+                    "ecdsa": ecdsa,
+                    "address": entry.address.to_string() if entry is not None and entry.address is not None else None,
+                    "script_public_key": script,
+                }
+            )
+
+        response = self._post(
+            {
+                "transaction_id": pending.id,
+                "key_id": self.key_id,
+                "transaction": transaction.to_dict(),
+                "inputs": inputs,
+            }
+        )
+
+        signed = set()
+        for signature in response.get("signatures") or []:
+            index = signature.get("index")
+            if not isinstance(index, int) or not 0 <= index < len(inputs):
+                raise RemoteSignerError(f"Invalid input index in signing response: {index!r}")
+            if "signature_script" in signature:
+                pending.fill_input(index, signature["signature_script"])
+            elif len(signature.get("signature") or "") == 128:
+                pending.fill_input(index, "41" + signature["signature"] + SIGHASH_ALL)
+            else:
+                raise RemoteSignerError(f"Invalid signature for input {index} in signing response")
+            signed.add(index)
+        unsigned = sorted(set(range(len(inputs))) - signed)
+        if unsigned:
+            raise RemoteSignerError(f"Signing service left inputs {unsigned} of {pending.id} unsigned")
+        if self.verify:
+            pending.validate()
+
+    def _post(self, body: dict) -> dict:
+        request = urllib.request.Request(
+            self.url,
+            data=json.dumps(body, default=str).encode(),
+            headers={"Content-Type": "application/json", "Accept": "application/json", **self.headers},
+            method="POST",
+        )
+        try:
+            with urllib.request.urlopen(request, timeout=self.timeout, context=self._context) as response:
+                result = json.loads(response.read())
+        except urllib.error.HTTPError as err:
+            raise RemoteSignerError(f"Signing request failed: HTTP {err.code}", err.code) from err
+        except (urllib.error.URLError, OSError, ValueError) as err:
+            raise RemoteSignerError(f"Signing request failed: {err}") from err
+        if not isinstance(result, dict):
+            raise RemoteSignerError("Invalid signing response")
+        if result.get("error"):
+            raise RemoteSignerError(f"Signing service refused: {result['error']}")
+        return result
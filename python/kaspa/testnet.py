@@ -0,0 +1,163 @@
+"""Testnet helpers: requesting faucet funds and waiting for them to mature."""
+
+import asyncio
+import json
+import os
+import time
+import urllib.error
+import urllib.request
+from typing import Any
+
+DEFAULT_NETWORK = "testnet-10"
+DEFAULT_TIMEOUT = 30.0
+DEFAULT_WAIT = 600.0
+# DAA score a UTXO must age before wallets treat it as mature (10 seconds at 10 BPS).
+DEFAULT_MATURITY = 100
+
+# Public faucets accepting `POST {"address": ..., "amount": ...}`. A faucet
+# URL in the KASPA_FAUCET_URL environment variable takes precedence.
+FAUCETS = {
+    "testnet-10": "https://faucet-tn10.kaspanet.io/api/faucet",
+    "testnet-11": "https://faucet-tn11.kaspanet.io/api/faucet",
+}
+
+
+class FaucetError(Exception):
+    """A faucet request failed or was refused.
+
+    Attributes:
+        status: The HTTP status, None for connection and response errors.
+    """
+
+    def __init__(self, message: str, status: int | None = None):
+        super().__init__(message)
+        self.status = status
+
+
+def faucet_url(network_id: Any = DEFAULT_NETWORK) -> str:
+    """The faucet URL for a testnet: KASPA_FAUCET_URL, else the known public faucet.
+
+    Raises:
+        ValueError: If no faucet is known for the network.
+    """
+    url = os.environ.get("KASPA_FAUCET_URL")
+    if url:
+        return url
+    network = str(network_id)
+    if network not in FAUCETS:
+        raise ValueError(f"No known faucet for {network}; set KASPA_FAUCET_URL or pass url")
+    return FAUCETS[network]
+
+
+def request_funds(
+    address: Any,
+    amount: int | None = None,
+    network_id: Any = DEFAULT_NETWORK,
+    url: str | None = None,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> str | None:
+    """Request testnet funds for `address` from a faucet.
+
+    Args:
+        address: The address to fund.
+        amount: Sompi to request (default: the faucet's amount).
+        network_id: The testnet, selecting the faucet (default: testnet-10).
+        url: The faucet URL, overriding the network's faucet.
+        timeout: Seconds to wait for the faucet.
+
+    Returns:
+        The id of the funding transaction, None if the faucet does not report it.
+
+    Raises:
+        FaucetError: If the request fails or the faucet refuses it.
+    """
+    body: dict[str, Any] = {"address": str(address)}
+    if amount is not None:
+        body["amount"] = amount
+    request = urllib.request.Request(
+        url or faucet_url(network_id),
+        data=json.dumps(body).encode(),
+        headers={"Content-Type": "application/json", "Accept": "application/json"},
+        method="POST",
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=timeout) as response:
+            content = response.read()
+    except urllib.error.HTTPError as err:
+        raise FaucetError(f"Faucet request failed: HTTP {err.code}", err.code) from err
+    except (urllib.error.URLError, OSError) as err:
+        raise FaucetError(f"Faucet request failed: {err}") from err
+    try:
+        result = json.loads(content) if content.strip() else {}
+    except ValueError as err:
+        raise FaucetError("Invalid faucet response") from err
+    if not isinstance(result, dict):
+        return None
+    if result.get("error"):
+        raise FaucetError(f"Faucet refused: {result['error']}")
+    return result.get("transactionId") or result.get("txid") or result.get("transaction_id")
+
+
+async def wait_for_funds(
+    rpc: Any,
+    address: Any,
+    amount: int = 1,
+    maturity: int = DEFAULT_MATURITY,
+    timeout: float = DEFAULT_WAIT,
+    poll_interval: float = 1.0,
+) -> list[dict]:
+    """Wait until `address` holds at least `amount` sompi in mature UTXOs.
+
+    A UTXO is mature once the virtual DAA score is `maturity` past its block's.
+
+    Returns:
+        The mature UTXO entries (`get_utxos_by_addresses()` form), usable as Generator entries.
+
+    Raises:
+        TimeoutError: If the funds are not mature within `timeout` seconds.
+    """
+    deadline = time.monotonic() + timeout
+    while True:
+        response = await rpc.get_utxos_by_addresses({"addresses": [str(address)]})
+        info = await rpc.get_server_info()
+        daa_score = info["virtualDaaScore"]
+        entries = [
+            entry
+            for entry in response.get("entries", [])
+            if entry["utxoEntry"]["blockDaaScore"] + maturity <= daa_score
+        ]
+        if sum(entry["utxoEntry"]["amount"] for entry in entries) >= amount:
+            return entries
+        if time.monotonic() + poll_interval > deadline:
+            raise TimeoutError(f"{address} has no {amount} sompi of mature funds after {timeout:g}s")
+        await asyncio.sleep(poll_interval)
+
+
+async def fund(
+    rpc: Any,
+    address: Any,
+    amount: int | None = None,
+    network_id: Any = DEFAULT_NETWORK,
+    url: str | None = None,
+    maturity: int = DEFAULT_MATURITY,
+    timeout: float = DEFAULT_WAIT,
+) -> list[dict]:
+    """Request faucet funds for `address` and wait for them to mature.
+
+    Intended for example scripts and integration tests:
+
+        from kaspa import testnet
+
+        entries = await testnet.fund(client, address, kaspa_to_sompi(10))
+
+    Returns:
+        The mature UTXO entries of `address`.
+
+    Raises:
+        FaucetError: If the faucet request fails.
+        TimeoutError: If the funds are not mature within `timeout` seconds.
+    """
+    response = await rpc.get_balance_by_address({"address": str(address)})
+    target = response["balance"] + (amount or 1)
+    await asyncio.to_thread(request_funds, address, amount, network_id, url)
+    return await wait_for_funds(rpc, address, target, maturity, timeout)
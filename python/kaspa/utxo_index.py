@@ -0,0 +1,241 @@
+"""SQLite mirror of tracked UTXOs and transaction records."""
+
+import json
+import os
+import sqlite3
+import threading
+from typing import Any
+
+# UtxoProcessor events carrying transaction records.
+RECORD_EVENTS = ["pending", "maturity", "discovery", "reorg", "stasis"]
+
+SCHEMA = """
+CREATE TABLE IF NOT EXISTS utxos (
+    transaction_id TEXT NOT NULL,
+    output_index INTEGER NOT NULL,
+    context TEXT NOT NULL,
+    address TEXT,
+    amount INTEGER NOT NULL,
+    script_public_key TEXT,
+    block_daa_score INTEGER NOT NULL,
+    is_coinbase INTEGER NOT NULL,
+    spent INTEGER NOT NULL DEFAULT 0,
+    spent_daa_score INTEGER,
+    PRIMARY KEY (transaction_id, output_index, context)
+);
+CREATE INDEX IF NOT EXISTS utxos_address ON utxos (address);
+CREATE TABLE IF NOT EXISTS transactions (
+    id TEXT NOT NULL,
+    context TEXT,
+    state TEXT NOT NULL,
+    type TEXT,
+    value INTEGER NOT NULL,
+    block_daa_score INTEGER NOT NULL,
+    unixtime_msec INTEGER,
+    network TEXT,
+    record TEXT NOT NULL,
+    PRIMARY KEY (id, context)
+);
+"""
+
+
+def entry_dict(entry: Any) -> dict:
+    """A UTXO entry as dict, from a `UtxoEntryReference` or its `to_dict()` form."""
+    return entry if isinstance(entry, dict) else entry.to_dict()
+
+
+class UtxoIndex:
+    """Local SQLite index of the UTXOs and transaction records of UtxoContexts.
+
+    UTXOs are mirrored from a `UtxoContext` with `follow()` (or `load()` and
+    `apply()` for custom syncing). Spent UTXOs are kept, marked with the DAA
+    score at which the spend was seen, so `balance(daa_score=...)` returns the
+    balance at past DAA scores since indexing started. Transaction records are
+    stored from the `pending`, `maturity`, `discovery`, `reorg` and `stasis`
+    events of a `UtxoProcessor` passed to `attach()`:
+
+        index = UtxoIndex("wallet.db")
+        index.attach(processor)
+        asyncio.create_task(index.follow(context))
+
+        index.balance(address="kaspa:...")
+        index.utxos(address="kaspa:...")
+    """
+
+    def __init__(self, path: str | os.PathLike = ":memory:"):
+        self.path = os.fspath(path)
+        self._db = sqlite3.connect(self.path, check_same_thread=False)
+        self._db.row_factory = sqlite3.Row
+        self._db.executescript(SCHEMA)
+        self._lock = threading.Lock()
+        self.daa_score: int | None = None
+
+    def attach(self, processor: Any) -> None:
+        """Store the transaction records of `processor` events and track its DAA score."""
+        processor.add_event_listener(["daa-score-change", *RECORD_EVENTS], self.on_event)
+
+    def detach(self, processor: Any) -> None:
+        """Stop listening to `processor`."""
+        processor.remove_event_listener(["daa-score-change", *RECORD_EVENTS], self.on_event)
+
+    def on_event(self, event: dict) -> None:
+        """Handle a UtxoProcessor event (called by listeners registered with `attach()`)."""
+        data = event.get("data") or {}
+        if event.get("type") == "daa-score-change":
+            self.daa_score = data.get("currentDaaScore", self.daa_score)
+        elif event.get("type") in RECORD_EVENTS:
+            self.record(data, event["type"])
+
+    def record(self, record: dict, state: str) -> None:
+        """Store a transaction record, replacing the record previously stored for the same transaction."""
+        # The binding is `{"type": "custom", "data": <context id>}` for UtxoContext records.
+        binding = record.get("binding")
+        context = binding.get("data") if isinstance(binding, dict) else binding
+        kind = (record.get("data") or {}).get("type")
+        with self._lock, self._db:
+            self._db.execute(
+                "INSERT OR REPLACE INTO transactions VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                (
+                    record["id"],
+                    context,
+                    state,
+                    kind,
+                    record["value"],
+                    record["blockDaaScore"],
+                    record.get("unixtimeMsec"),
+                    record.get("network"),
+                    json.dumps(record, default=str),
+                ),
+            )
+
+    def load(self, context: Any) -> None:
+        """Replace the indexed unspent UTXOs of `context` with its current (mature and pending) UTXO set."""
+        entries = [*context.mature_range(0, context.mature_length), *context.pending()]
+        context_id = str(context.id)
+        with self._lock, self._db:
+            self._db.execute("DELETE FROM utxos WHERE context = ? AND spent = 0", (context_id,))
+            self._insert(entries, context_id)
+
+    def apply(self, added: list, removed: list, context: Any = "") -> None:
+        """Apply a batch of UTXO changes, e.g. from `UtxoContext.changes()`.
+
+        Removed entries are marked spent at the current DAA score.
+        """
+        context_id = str(getattr(context, "id", context))
+        with self._lock, self._db:
+            self._insert(added, context_id)
+            for entry in map(entry_dict, removed):
+                outpoint = entry["outpoint"]
+                self._db.execute(
+                    "UPDATE utxos SET spent = 1, spent_daa_score = ? "
+                    "WHERE transaction_id = ? AND output_index = ? AND context = ?",
+                    (self.daa_score, outpoint["transactionId"], outpoint["index"], context_id),
+                )
+
+    async def follow(self, context: Any) -> None:
+        """Load the UTXO set of `context` and apply its changes until its processor stops."""
+        changes = context.changes()
+        self.load(context)
+        async for added, removed in changes:
+            self.apply(added, removed, context)
+
+    def balance(self, address: str | None = None, daa_score: int | None = None) -> int:
+        """Sum of the indexed UTXOs in sompi.
+
+        Args:
+            address: Only count UTXOs of this address.
+            daa_score: The balance at this DAA score: UTXOs created at or before it
+                and not seen spent by then. Defaults to the current balance.
+        """
+        query = "SELECT COALESCE(SUM(amount), 0) FROM utxos WHERE "
+        if daa_score is None:
+            query += "spent = 0"
+            params: list = []
+        else:
+            query += "block_daa_score <= ? AND (spent = 0 OR spent_daa_score > ?)"
+            params = [daa_score, daa_score]
+        if address is not None:
+            query += " AND address = ?"
+            params.append(str(address))
+        with self._lock:
+            return self._db.execute(query, params).fetchone()[0]
+
+    def utxos(self, address: str | None = None, include_spent: bool = False) -> list[dict]:
+        """Indexed UTXOs, oldest first, optionally of one address and including spent ones."""
+        query = "SELECT * FROM utxos WHERE 1 = 1"
+        params = []
+        if not include_spent:
+            query += " AND spent = 0"
+        if address is not None:
+            query += " AND address = ?"
+            params.append(str(address))
+        with self._lock:
+            rows = self._db.execute(query + " ORDER BY block_daa_score, transaction_id, output_index", params)
+            return [{**row, "spent": bool(row["spent"]), "is_coinbase": bool(row["is_coinbase"])} for row in rows]
+
+    def addresses(self) -> dict[str, dict]:
+        """Unspent UTXO count and balance per address: `{address: {"count": int, "balance": int}}`."""
+        with self._lock:
+            rows = self._db.execute(
+                "SELECT address, COUNT(*), SUM(amount) FROM utxos WHERE spent = 0 GROUP BY address ORDER BY address"
+            ).fetchall()
+        return {address: {"count": count, "balance": balance} for address, count, balance in rows}
+
+    def transactions(
+        self,
+        state: str | None = None,
+        from_daa_score: int | None = None,
+        to_daa_score: int | None = None,
+    ) -> list[dict]:
+        """Stored transaction records, ordered by DAA score.
+
+        Args:
+            state: Only records last delivered by this event (`pending`, `maturity`, ...).
+            from_daa_score: Only records at or after this DAA score.
+            to_daa_score: Only records at or before this DAA score.
+        """
+        query = "SELECT state, record FROM transactions WHERE 1 = 1"
+        params: list = []
+        if state is not None:
+            query += " AND state = ?"
+            params.append(state)
+        if from_daa_score is not None:
+            query += " AND block_daa_score >= ?"
+            params.append(from_daa_score)
+        if to_daa_score is not None:
+            query += " AND block_daa_score <= ?"
+            params.append(to_daa_score)
+        with self._lock:
+            rows = self._db.execute(query + " ORDER BY block_daa_score, id", params).fetchall()
+        return [{**json.loads(record), "state": state} for state, record in rows]
+
+    def close(self) -> None:
+        """Close the database."""
+        with self._lock:
+            self._db.close()
+
+    def __enter__(self) -> "UtxoIndex":
+        return self
+
+    def __exit__(self, *exc: Any) -> None:
+        self.close()
+
+    def _insert(self, entries: list, context_id: str) -> None:
+        for entry in map(entry_dict, entries):
+            outpoint = entry["outpoint"]
+            script = entry.get("scriptPublicKey")
+            if isinstance(script, dict):
+                script = f"{script['version']:04x}{script['script']}"
+            self._db.execute(
+                "INSERT OR REPLACE INTO utxos VALUES (?, ?, ?, ?, ?, ?, ?, ?, 0, NULL)",
+                (
+                    outpoint["transactionId"],
+                    outpoint["index"],
+                    context_id,
+                    entry.get("address"),
+                    entry["amount"],
+                    script,
+                    entry["blockDaaScore"],
+                    int(entry.get("isCoinbase", False)),
+                ),
+            )
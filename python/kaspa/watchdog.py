@@ -0,0 +1,185 @@
+"""Detection of stalled node notifications, with automatic resubscription."""
+
+import asyncio
+import inspect
+import threading
+import time
+from typing import Any, Callable
+
+DEFAULT_TIMEOUT = 30.0
+DEFAULT_CHECK_INTERVAL = 5.0
+
+
+class NotificationWatchdog:
+    """Detect node notifications silently stopping and resubscribe.
+
+    A node can drop a subscription without closing the connection, leaving a
+    deposit system connected but blind. The virtual DAA score advances about
+    10 times per second, so the watchdog follows DAA score notifications: when
+    the client is connected but the DAA score has not advanced for `timeout`
+    seconds, the notifications are considered stalled and the watchdog:
+
+    1. Resubscribes: re-issues `subscribe_virtual_daa_score_changed()` on a
+       client, or, for a `UtxoProcessor`, calls `refresh()`, which re-registers
+       the tracked addresses for UTXO notifications and rescans them, so
+       payments missed during the stall are recovered.
+    2. Calls `on_stall` with a `notification-stall` event dict: `type`, `rpc`
+       (the node URL), `daaScore` (the last one seen), `silence` (seconds
+       without progress), `attempt` (stalls in a row), `rescan` (the
+       `refresh()` diff, None for a client) and `error` (the recovery error,
+       None if it succeeded).
+
+    While the stall lasts, recovery is retried every `timeout` seconds. A
+    disconnected client is not considered stalled; reconnects are handled by
+    the client and processor.
+
+        watchdog = NotificationWatchdog(processor, on_stall=alert)
+        await watchdog.start()
+    """
+
+    def __init__(
+        self,
+        source: Any,
+        on_stall: Callable[[dict], Any] | None = None,
+        timeout: float = DEFAULT_TIMEOUT,
+        check_interval: float = DEFAULT_CHECK_INTERVAL,
+        recover: bool = True,
+    ):
+        """Create a watchdog; monitoring begins with `start()`.
+
+        Args:
+            source: A `UtxoProcessor` (following its `daa-score-change` events)
+                or an `RpcClient` (following `virtual-daa-score-changed` notifications).
+            on_stall: Called with each `notification-stall` event; may be a coroutine function.
+            timeout: Seconds without DAA score progress after which notifications are stalled (default: 30).
+            check_interval: Seconds between checks (default: 5).
+            recover: Resubscribe (and rescan, for a processor) on a stall (default: True).
+
+        Raises:
+            ValueError: If `timeout` or `check_interval` is not positive.
+        """
+        if timeout <= 0 or check_interval <= 0:
+            raise ValueError("timeout and check_interval must be positive")
+        # UtxoProcessor exposes its client as `rpc`; an RpcClient has none.
+        self.processor = source if hasattr(source, "rpc") else None
+        self.rpc = source.rpc if self.processor is not None else source
+        self.on_stall = on_stall
+        self.timeout = timeout
+        self.check_interval = check_interval
+        self.recover = recover
+        self.daa_score: int | None = None
+        self.stalls = 0
+        self.last_error: Exception | None = None
+        self._attempt = 0
+        self._advanced = time.monotonic()
+        # Last recovery attempt or reconnect, restarting the timeout without counting as progress.
+        self._rearmed = self._advanced
+        self._lock = threading.Lock()
+        self._task: asyncio.Task | None = None
+
+    @property
+    def silence(self) -> float:
+        """Seconds since the DAA score last advanced (or since monitoring started)."""
+        with self._lock:
+            return time.monotonic() - self._advanced
+
+    @property
+    def is_running(self) -> bool:
+        """Whether the watchdog is monitoring."""
+        return self._task is not None and not self._task.done()
+
+    async def start(self) -> None:
+        """Follow DAA score events and start checking for stalls on the running event loop."""
+        if self.is_running:
+            return
+        with self._lock:
+            self._advanced = self._rearmed = time.monotonic()
+        if self.processor is not None:
+            self.processor.add_event_listener("daa-score-change", self._on_event)
+        else:
+            self.rpc.add_event_listener("virtual-daa-score-changed", self._on_event)
+            await self.rpc.subscribe_virtual_daa_score_changed()
+        self._task = asyncio.get_running_loop().create_task(self._run())
+
+    async def stop(self) -> None:
+        """Stop monitoring. A client's DAA score subscription is kept."""
+        if self._task is None:
+            return
+        self._task.cancel()
+        await asyncio.gather(self._task, return_exceptions=True)
+        self._task = None
+        if self.processor is not None:
+            self.processor.remove_event_listener("daa-score-change", self._on_event)
+        else:
+            self.rpc.remove_event_listener("virtual-daa-score-changed", self._on_event)
+
+    async def __aenter__(self) -> "NotificationWatchdog":
+        await self.start()
+        return self
+
+    async def __aexit__(self, *exc: Any) -> None:
+        await self.stop()
+
+    def _on_event(self, event: dict) -> None:
+        data = event.get("data") or {}
+        daa_score = data.get("currentDaaScore", data.get("virtualDaaScore"))
+        if daa_score is None:
+            return
+        with self._lock:
+            if self.daa_score is None or daa_score > self.daa_score:
+                self.daa_score = daa_score
+                self._advanced = time.monotonic()
+                self._attempt = 0
+
+    async def _run(self) -> None:
+        while True:
+            await asyncio.sleep(self.check_interval)
+            try:
+                await self.check()
+            except Exception as err:
+                # Raised by `on_stall`; keep monitoring.
+                self.last_error = err
+
+    async def check(self) -> dict | None:
+        """Check for a stall now, recovering and reporting it if found.
+
+        Returns:
+            dict | None: The `notification-stall` event, None if notifications are flowing.
+        """
+        with self._lock:
+            if not self.rpc.is_connected:
+                # Give a reconnected client a full timeout to resume notifications.
+                self._rearmed = time.monotonic()
+                return None
+            now = time.monotonic()
+            if now - max(self._advanced, self._rearmed) < self.timeout:
+                return None
+            silence = now - self._advanced
+            self._attempt += 1
+            self._rearmed = now
+            event = {
+                "type": "notification-stall",
+                "rpc": self.rpc.url,
+                "daaScore": self.daa_score,
+                "silence": silence,
+                "attempt": self._attempt,
+                "rescan": None,
+                "error": None,
+            }
+        self.stalls += 1
+
+        if self.recover:
+            try:
+                if self.processor is not None:
+                    event["rescan"] = await self.processor.refresh()
+                else:
+                    await self.rpc.unsubscribe_virtual_daa_score_changed()
+                    await self.rpc.subscribe_virtual_daa_score_changed()
+            except Exception as err:
+                event["error"] = str(err) or type(err).__name__
+
+        if self.on_stall is not None:
+            result = self.on_stall(event)
+            if inspect.isawaitable(result):
+                await result
+        return event
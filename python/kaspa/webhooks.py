@@ -0,0 +1,174 @@
+"""Signed webhook delivery of UtxoProcessor events."""
+
+import hashlib
+import hmac
+import json
+import queue
+import threading
+import time
+import urllib.error
+import urllib.request
+import uuid
+from typing import Any, Callable, Sequence
+
+DEFAULT_EVENTS = ("balance", "maturity")
+DEFAULT_MAX_RETRIES = 5
+DEFAULT_BACKOFF = 1.0
+DEFAULT_TIMEOUT = 10.0
+
+SIGNATURE_HEADER = "X-Kaspa-Signature"
+TIMESTAMP_HEADER = "X-Kaspa-Timestamp"
+EVENT_HEADER = "X-Kaspa-Event"
+
+# HTTP statuses worth retrying; other 4xx responses are final.
+RETRY_STATUSES = {408, 425, 429}
+
+
+def sign(secret: bytes | str, timestamp: str, body: bytes) -> str:
+    """HMAC-SHA256 signature of a webhook, as sent in the `X-Kaspa-Signature` header.
+
+    The signed message is the timestamp header, a `.`, and the raw body, so a
+    captured request cannot be replayed with another timestamp.
+    """
+    if isinstance(secret, str):
+        secret = secret.encode()
+    digest = hmac.new(secret, timestamp.encode() + b"." + body, hashlib.sha256).hexdigest()
+    return f"sha256={digest}"
+
+
+def verify_signature(secret: bytes | str, timestamp: str, body: bytes, signature: str) -> bool:
+    """Check a received webhook against its `X-Kaspa-Signature` and `X-Kaspa-Timestamp` headers."""
+    return hmac.compare_digest(sign(secret, timestamp, body), signature)
+
+
+class WebhookNotifier:
+    """POST UtxoProcessor events to a URL as signed JSON.
+
+    Events are queued by the listener and delivered in order by a background
+    thread, so callbacks never block on the network. Each request carries a
+    JSON body `{"id", "type", "timestamp", "data"}` and, when a secret is set,
+    an HMAC-SHA256 signature of the timestamp and body (see `verify_signature()`).
+    Failed deliveries (connection errors, 5xx, 408, 425 and 429 responses) are
+    retried with exponential backoff; deliveries that still fail are passed to
+    `on_error`.
+
+    Attach it to a `UtxoProcessor`, or anything else with an
+    `add_event_listener(event, callback)` method:
+
+        notifier = WebhookNotifier("https://backend/hooks/kaspa", secret=b"...")
+        notifier.attach(processor)
+    """
+
+    def __init__(
+        self,
+        url: str,
+        secret: bytes | str | None = None,
+        events: Sequence[str] = DEFAULT_EVENTS,
+        max_retries: int = DEFAULT_MAX_RETRIES,
+        backoff: float = DEFAULT_BACKOFF,
+        timeout: float = DEFAULT_TIMEOUT,
+        headers: dict[str, str] | None = None,
+        on_error: Callable[[dict, Exception], Any] | None = None,
+    ):
+        if max_retries < 0:
+            raise ValueError("max_retries must not be negative")
+        if backoff < 0 or timeout <= 0:
+            raise ValueError("backoff must not be negative and timeout must be positive")
+        self.url = url
+        self.secret = secret
+        self.events = list(events)
+        self.max_retries = max_retries
+        self.backoff = backoff
+        self.timeout = timeout
+        self.headers = dict(headers or {})
+        self.on_error = on_error
+        self.delivered = 0
+        self.failed = 0
+        self._queue: queue.Queue[dict | None] = queue.Queue()
+        self._closed = threading.Event()
+        self._worker = threading.Thread(target=self._run, name="kaspa-webhooks", daemon=True)
+        self._worker.start()
+
+    def attach(self, source: Any, **filters: Any) -> None:
+        """Listen to `events` of `source`, e.g. a UtxoProcessor.
+
+        Keyword arguments such as `addresses` or `contexts` are passed to
+        `add_event_listener()` to filter the delivered events.
+        """
+        source.add_event_listener(self.events, self.notify, **filters)
+
+    def detach(self, source: Any) -> None:
+        """Stop listening to `source`."""
+        source.remove_event_listener(self.events, self.notify)
+
+    def notify(self, event: dict) -> None:
+        """Queue an event (a dict with `type` and `data`) for delivery."""
+        if self._closed.is_set():
+            return
+        self._queue.put(
+            {
+                "id": str(uuid.uuid4()),
+                "type": event.get("type"),
+                "timestamp": int(time.time() * 1000),
+                "data": event.get("data"),
+            }
+        )
+
+    @property
+    def pending(self) -> int:
+        """Number of queued events not yet delivered."""
+        return self._queue.qsize()
+
+    def close(self, timeout: float | None = None) -> None:
+        """Deliver the queued events and stop the worker thread."""
+        if not self._closed.is_set():
+            self._closed.set()
+            self._queue.put(None)
+        self._worker.join(timeout)
+
+    def __enter__(self) -> "WebhookNotifier":
+        return self
+
+    def __exit__(self, *exc: Any) -> None:
+        self.close()
+
+    def _run(self) -> None:
+        while (payload := self._queue.get()) is not None:
+            try:
+                self._deliver(payload)
+                self.delivered += 1
+            except Exception as err:
+                self.failed += 1
+                if self.on_error is not None:
+                    try:
+                        self.on_error(payload, err)
+                    except Exception:
+                        pass
+
+    def _deliver(self, payload: dict) -> None:
+        body = json.dumps(payload, default=str, separators=(",", ":")).encode()
+        for attempt in range(self.max_retries + 1):
+            try:
+                self._post(payload["type"], body)
+                return
+            except urllib.error.HTTPError as err:
+                if (err.code < 500 and err.code not in RETRY_STATUSES) or attempt == self.max_retries:
+                    raise
+            except (urllib.error.URLError, OSError):
+                if attempt == self.max_retries:
+                    raise
+            time.sleep(self.backoff * 2**attempt)
+
+    def _post(self, event_type: str, body: bytes) -> None:
+        timestamp = str(int(time.time()))
+        headers = {
+            **self.headers,
+            "Content-Type": "application/json",
+            EVENT_HEADER: event_type or "",
+            TIMESTAMP_HEADER: timestamp,
+        }
+        if self.secret is not None:
+            headers[SIGNATURE_HEADER] = sign(self.secret, timestamp, body)
+        request = urllib.request.Request(self.url, data=body, headers=headers, method="POST")
+        with urllib.request.urlopen(request, timeout=self.timeout) as response:
+            response.read()
@@ -1,7 +1,16 @@
+use std::ffi::CString;
 use std::str::FromStr;
+use std::sync::Mutex;
 
+use crate::consensus::core::network::PyNetworkId;
+use crate::validation;
 use kaspa_addresses::{Address, AddressError, Prefix, Version};
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_consensus_core::network::NetworkId;
+use pyo3::{
+    exceptions::{PyException, PyUserWarning},
+    prelude::*,
+    sync::MutexExt,
+};
 use pyo3_stub_gen::derive::*;
 
 crate::wrap_unit_enum_for_py!(
@@ -49,6 +58,9 @@ impl<'py> FromPyObject<'_, 'py> for PyAddressVersion {
     }
 }
 
+/// Name resolvers registered with `Address.register_resolver()`, by name suffix.
+static RESOLVERS: Mutex<Vec<(String, Py<PyAny>)>> = Mutex::new(Vec::new());
+
 /// A Kaspa blockchain address.
 ///
 /// In string form, the Kaspa addresses are represented by a `bech32`-encoded
@@ -71,11 +83,11 @@ impl PyAddress {
     ///     Address: A new Address instance.
     ///
     /// Raises:
-    ///     Exception: If the address string is invalid.
+    ///     ValueError: If the address string is invalid.
     #[new]
     pub fn constructor(address: &str) -> PyResult<PyAddress> {
         Ok(PyAddress(address.try_into().map_err(
-            |err: AddressError| PyException::new_err(err.to_string()),
+            |err: AddressError| validation::invalid("address", format!("`{address}`: {err}")),
         )?))
     }
 
@@ -92,6 +104,77 @@ impl PyAddress {
         Address::try_from(address).is_ok()
     }
 
+    /// Resolve a name (e.g. "name.kas") to an Address with a registered resolver.
+    ///
+    /// Address strings are returned as is. Other names are passed to the
+    /// resolver registered for the longest matching suffix.
+    ///
+    /// Args:
+    ///     name: The name or address string to resolve.
+    ///
+    /// Returns:
+    ///     Address: The resolved address.
+    ///
+    /// Raises:
+    ///     ValueError: If no resolver handles the name or it does not resolve to a valid address.
+    ///     Exception: Any exception raised by the resolver.
+    #[staticmethod]
+    pub fn resolve(py: Python<'_>, name: &str) -> PyResult<PyAddress> {
+        resolve_name(py, name).map(PyAddress)
+    }
+
+    /// Register a name resolver for names ending in `suffix`.
+    ///
+    /// The resolver is called with the full name and returns an Address, an
+    /// address string, or None if the name is not registered. It replaces any
+    /// resolver previously registered for the same suffix. Resolvers are
+    /// called synchronously, so network lookups block the caller.
+    ///
+    /// Args:
+    ///     suffix: The name suffix handled by the resolver (e.g. ".kas").
+    ///     resolver: A callable taking the name.
+    ///
+    /// Raises:
+    ///     ValueError: If the suffix is empty or the resolver is not callable.
+    #[staticmethod]
+    pub fn register_resolver(
+        suffix: &str,
+        #[gen_stub(override_type(type_repr = "typing.Callable[[str], Address | str | None]"))]
+        resolver: Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        if suffix.is_empty() {
+            return Err(validation::invalid("suffix", "must not be empty"));
+        }
+        if !resolver.is_callable() {
+            return Err(validation::invalid("resolver", "must be callable"));
+        }
+        let suffix = suffix.to_lowercase();
+        let py = resolver.py();
+        let replaced = {
+            let mut resolvers = RESOLVERS.lock_py_attached(py).unwrap();
+            let replaced = take_resolver(&mut resolvers, &suffix);
+            resolvers.push((suffix, resolver.unbind()));
+            replaced
+        };
+        // Dropped after the lock is released: it may run arbitrary finalizers.
+        drop(replaced);
+        Ok(())
+    }
+
+    /// Remove the name resolver registered for `suffix`.
+    ///
+    /// Args:
+    ///     suffix: The name suffix of the resolver.
+    ///
+    /// Returns:
+    ///     bool: True if a resolver was removed.
+    #[staticmethod]
+    pub fn unregister_resolver(py: Python<'_>, suffix: &str) -> bool {
+        let suffix = suffix.to_lowercase();
+        let removed = take_resolver(&mut RESOLVERS.lock_py_attached(py).unwrap(), &suffix);
+        removed.is_some()
+    }
+
     /// The string representation of the Address.
     ///
     /// Returns:
@@ -133,6 +216,82 @@ impl PyAddress {
         self.0.payload_to_string()
     }
 
+    /// Re-encode the address for another network.
+    ///
+    /// The result has the same payload (public key or script hash) under the
+    /// network's prefix, so it is controlled by the same key or script. This
+    /// is meant for moving test fixtures between networks: it does not derive
+    /// a new address, and reusing keys across networks links them. A
+    /// `UserWarning` is emitted when the prefix changes; testnet-10 and
+    /// testnet-11 share a prefix, so converting between them is a copy.
+    ///
+    /// Args:
+    ///     network_id: The target network (e.g. "testnet-10").
+    ///     allow_mainnet: Allow converting to or from mainnet (default False).
+    ///
+    /// Returns:
+    ///     Address: The re-encoded address.
+    ///
+    /// Raises:
+    ///     ValueError: If mainnet is involved and `allow_mainnet` is False.
+    #[pyo3(signature = (network_id, allow_mainnet=false))]
+    pub fn to_network(
+        &self,
+        py: Python<'_>,
+        #[gen_stub(override_type(type_repr = "str | NetworkId"))] network_id: PyNetworkId,
+        allow_mainnet: bool,
+    ) -> PyResult<PyAddress> {
+        let prefix = network_prefix(network_id);
+        let converted = reencode(&self.0, prefix, allow_mainnet)?;
+        if converted.prefix != self.0.prefix {
+            warn_reencoded(py, 1, prefix)?;
+        }
+        Ok(PyAddress(converted))
+    }
+
+    /// Re-encode many addresses for another network (see `to_network()`).
+    ///
+    /// A single `UserWarning` is emitted for the whole batch.
+    ///
+    /// Args:
+    ///     addresses: Addresses or address strings.
+    ///     network_id: The target network (e.g. "testnet-10").
+    ///     allow_mainnet: Allow converting to or from mainnet (default False).
+    ///
+    /// Returns:
+    ///     list[Address]: The re-encoded addresses, in order.
+    ///
+    /// Raises:
+    ///     ValueError: If an address is invalid, or mainnet is involved and `allow_mainnet` is False.
+    #[staticmethod]
+    #[pyo3(signature = (addresses, network_id, allow_mainnet=false))]
+    pub fn to_network_many(
+        py: Python<'_>,
+        #[gen_stub(override_type(type_repr = "typing.Sequence[Address | str]"))] addresses: Vec<
+            Bound<'_, PyAny>,
+        >,
+        #[gen_stub(override_type(type_repr = "str | NetworkId"))] network_id: PyNetworkId,
+        allow_mainnet: bool,
+    ) -> PyResult<Vec<PyAddress>> {
+        let prefix = network_prefix(network_id);
+        let mut changed = 0;
+        let converted = addresses
+            .iter()
+            .map(|address| {
+                let address = extract_address(address)?;
+                let converted = reencode(&address, prefix, allow_mainnet)?;
+                if converted.prefix != address.prefix {
+                    changed += 1;
+                }
+                Ok(PyAddress(converted))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if changed > 0 {
+            warn_reencoded(py, changed, prefix)?;
+        }
+        Ok(converted)
+    }
+
     /// Get a shortened representation of the address.
     ///
     /// Args:
@@ -174,3 +333,96 @@ impl TryFrom<String> for PyAddress {
         Ok(PyAddress(inner))
     }
 }
+
+/// The address prefix of a network.
+fn network_prefix(network_id: PyNetworkId) -> Prefix {
+    Prefix::from(NetworkId::from(network_id).network_type)
+}
+
+/// An Address or address string argument.
+fn extract_address(address: &Bound<'_, PyAny>) -> PyResult<Address> {
+    if let Ok(address) = address.extract::<PyAddress>() {
+        Ok(address.0)
+    } else if let Ok(address) = address.extract::<String>() {
+        Address::try_from(address.as_str())
+            .map_err(|err| validation::invalid("address", format!("`{address}`: {err}")))
+    } else {
+        Err(validation::invalid("address", "must be an Address or str"))
+    }
+}
+
+/// `address` with the same payload under `prefix`.
+fn reencode(address: &Address, prefix: Prefix, allow_mainnet: bool) -> PyResult<Address> {
+    if address.prefix != prefix
+        && !allow_mainnet
+        && (address.prefix == Prefix::Mainnet || prefix == Prefix::Mainnet)
+    {
+        return Err(validation::invalid(
+            "network_id",
+            format!(
+                "re-encoding `{address}` between {} and {prefix} involves mainnet; pass `allow_mainnet=True` to proceed",
+                address.prefix
+            ),
+        ));
+    }
+    Ok(Address::new(prefix, address.version, &address.payload))
+}
+
+fn warn_reencoded(py: Python<'_>, count: usize, prefix: Prefix) -> PyResult<()> {
+    let message = format!(
+        "re-encoded {count} address(es) under the `{prefix}` prefix: the same keys or scripts control them on both networks"
+    );
+    PyErr::warn(
+        py,
+        &py.get_type::<PyUserWarning>(),
+        &CString::new(message)?,
+        1,
+    )
+}
+
+/// Remove the resolver registered for `suffix`, returning it.
+fn take_resolver(resolvers: &mut Vec<(String, Py<PyAny>)>, suffix: &str) -> Option<Py<PyAny>> {
+    let index = resolvers
+        .iter()
+        .position(|(registered, _)| registered == suffix)?;
+    Some(resolvers.remove(index).1)
+}
+
+/// Resolve a name or address string to an Address (see `Address.resolve()`).
+pub(crate) fn resolve_name(py: Python<'_>, name: &str) -> PyResult<Address> {
+    if let Ok(address) = Address::try_from(name) {
+        return Ok(address);
+    }
+    let lowercase = name.to_lowercase();
+    let resolver = RESOLVERS
+        .lock_py_attached(py)
+        .unwrap()
+        .iter()
+        .filter(|(suffix, _)| lowercase.ends_with(suffix.as_str()))
+        .max_by_key(|(suffix, _)| suffix.len())
+        .map(|(_, resolver)| resolver.clone_ref(py))
+        .ok_or_else(|| {
+            validation::invalid("name", format!("no resolver registered for `{name}`"))
+        })?;
+
+    // The lock is released before calling into Python, so resolvers may register others.
+    let resolved = resolver.call1(py, (name,))?;
+    let resolved = resolved.bind(py);
+    if resolved.is_none() {
+        Err(validation::invalid(
+            "name",
+            format!("`{name}` could not be resolved"),
+        ))
+    } else if let Ok(address) = resolved.extract::<PyAddress>() {
+        Ok(address.0)
+    } else if let Ok(address) = resolved.extract::<String>() {
+        Address::try_from(address.as_str()).map_err(|err| {
+            validation::invalid("name", format!("`{name}` resolved to `{address}`: {err}"))
+        })
+    } else {
+        Err(validation::invalid(
+            "name",
+            format!("the resolver of `{name}` must return an Address, str or None"),
+        ))
+    }
+}
@@ -6,7 +6,7 @@ fn main() -> Result<()> {
     let stub = kaspa::stub_info()?;
     stub.generate()?;
 
-    post_process_stub_file("kaspa.pyi");
+    post_process_stub_file("python/kaspa/kaspa.pyi");
 
     Ok(())
 }
@@ -25,7 +25,7 @@ fn post_process_stub_file(path: &str) {
 }
 
 /// Appends the contents of kaspa_rpc.pyi to the stub file.
-/// This includes manually typed RPC request/response TypedDicts.
+/// This includes manually typed RPC request/response and event payload TypedDicts.
 fn append_rpc_types(content: String) -> String {
     let rpc_types_path = "kaspa_rpc.pyi";
 
@@ -99,19 +99,19 @@ fn remove_duplicate_default_none(content: String) -> String {
 /// Inserts overload stubs for UtxoProcessor event listener APIs.
 ///
 /// The Rust/PyO3 signature supports two call patterns:
-/// - add_event_listener(callback, *args, **kwargs)
-/// - add_event_listener(targets, callback, *args, **kwargs)
+/// - add_event_listener(callback, *args, addresses=None, contexts=None, **kwargs)
+/// - add_event_listener(targets, callback, *args, addresses=None, contexts=None, **kwargs)
 ///
 /// pyo3-stub-gen currently emits a single signature with `callback: Optional[Any] = None`,
 /// which makes the `callback` appear optional in all cases. Overloads improve type checking
 /// without changing runtime behavior.
 fn fix_utxo_processor_event_listener_overloads(content: String) -> String {
-    let add_impl = "    def add_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None, *args: typing.Any, **kwargs: typing.Any) -> None:";
+    let add_impl = "    def add_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None, *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None:";
     let add_overloads = concat!(
         "    @typing.overload\n",
-        "    def add_event_listener(self, callback: typing.Callable[..., typing.Any], *args: typing.Any, **kwargs: typing.Any) -> None: ...\n",
+        "    def add_event_listener(self, callback: typing.Callable[..., typing.Any], *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None: ...\n",
         "    @typing.overload\n",
-        "    def add_event_listener(self, event_or_callback: builtins.str | UtxoProcessorEvent | typing.Sequence[builtins.str | UtxoProcessorEvent], callback: typing.Callable[..., typing.Any], *args: typing.Any, **kwargs: typing.Any) -> None: ...\n",
+        "    def add_event_listener(self, event_or_callback: UtxoProcessorEventName | UtxoProcessorEvent | typing.Sequence[UtxoProcessorEventName | UtxoProcessorEvent], callback: typing.Callable[..., typing.Any], *args: typing.Any, addresses: typing.Optional[typing.Sequence[Address | builtins.str]] = None, contexts: typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]] = None, **kwargs: typing.Any) -> None: ...\n",
     );
 
     let remove_impl = "    def remove_event_listener(self, event_or_callback: typing.Any, callback: typing.Optional[typing.Any] = None) -> None:";
@@ -119,7 +119,7 @@ fn fix_utxo_processor_event_listener_overloads(content: String) -> String {
         "    @typing.overload\n",
         "    def remove_event_listener(self, event_or_callback: typing.Callable[..., typing.Any]) -> None: ...\n",
         "    @typing.overload\n",
-        "    def remove_event_listener(self, event_or_callback: builtins.str | UtxoProcessorEvent | typing.Sequence[builtins.str | UtxoProcessorEvent], callback: typing.Optional[typing.Callable[..., typing.Any]] = None) -> None: ...\n",
+        "    def remove_event_listener(self, event_or_callback: UtxoProcessorEventName | UtxoProcessorEvent | typing.Sequence[UtxoProcessorEventName | UtxoProcessorEvent], callback: typing.Optional[typing.Callable[..., typing.Any]] = None) -> None: ...\n",
     );
 
     let mut out = content;
@@ -207,6 +207,7 @@ fn transform_rpc_method_line(line: &str) -> String {
         || method_name == "stop"
         || method_name == "on"
         || method_name == "remove_listener"
+        || method_name == "call"
     {
         return line.to_string();
     }
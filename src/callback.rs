@@ -10,14 +10,19 @@ pub(crate) struct PyCallback {
     callback: Arc<Py<PyAny>>,
     args: Option<Arc<Py<PyTuple>>>,
     kwargs: Option<Arc<Py<PyDict>>>,
+    /// Event loop running when the callback was registered, if any: coroutines
+    /// returned by async callbacks are scheduled on it.
+    event_loop: Option<Arc<Py<PyAny>>>,
 }
 
 impl PyCallback {
     pub(crate) fn new(callback: Py<PyAny>, args: Py<PyTuple>, kwargs: Py<PyDict>) -> Self {
+        let event_loop = Python::attach(running_loop).map(Arc::new);
         Self {
             callback: Arc::new(callback),
             args: Some(Arc::new(args)),
             kwargs: Some(Arc::new(kwargs)),
+            event_loop,
         }
     }
 
@@ -44,6 +49,7 @@ impl PyCallback {
 
         self.callback
             .call(py, args.bind(py), kwargs)
+            .and_then(|result| self.schedule(py, result))
             .map_err(|err| {
                 let traceback = PyModule::import(py, "traceback")
                     .and_then(|traceback| {
@@ -64,4 +70,41 @@ impl PyCallback {
                 PyException::new_err(traceback.to_string())
             })
     }
+
+    /// Schedule a coroutine returned by an async callback on the loop it was
+    /// registered from. Callbacks run on Rust runtime threads without an event
+    /// loop, and the loop is passed explicitly rather than looked up through the
+    /// event loop policy, so this works with uvloop and custom policies alike.
+    fn schedule(&self, py: Python, result: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let asyncio = PyModule::import(py, "asyncio")?;
+        if !asyncio
+            .call_method1("iscoroutine", (result.bind(py),))?
+            .is_truthy()?
+        {
+            return Ok(result);
+        }
+        match &self.event_loop {
+            Some(event_loop) => Ok(asyncio
+                .call_method1(
+                    "run_coroutine_threadsafe",
+                    (result.bind(py), event_loop.bind(py)),
+                )?
+                .unbind()),
+            None => {
+                result.bind(py).call_method0("close")?;
+                Err(PyException::new_err(
+                    "async event listeners must be registered from a running event loop",
+                ))
+            }
+        }
+    }
+}
+
+/// The event loop running in the current thread, if any.
+fn running_loop(py: Python) -> Option<Py<PyAny>> {
+    PyModule::import(py, "asyncio")
+        .and_then(|asyncio| asyncio.call_method0("_get_running_loop"))
+        .ok()
+        .filter(|event_loop| !event_loop.is_none())
+        .map(Bound::unbind)
 }
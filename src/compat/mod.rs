@@ -0,0 +1,342 @@
+//! Canonical test vectors shared with the other Kaspa SDKs (WASM, Go) and
+//! functions checking that this build reproduces them.
+
+mod vectors;
+
+use kaspa_addresses::{Address, Prefix, Version};
+use kaspa_bip32::{DerivationPath, ExtendedPrivateKey, Language, Mnemonic};
+use kaspa_consensus_core::{
+    hashing::{
+        sighash::{SigHashReusedValuesUnsync, calc_schnorr_signature_hash},
+        sighash_type::SIG_HASH_ALL,
+    },
+    subnets::SUBNETWORK_ID_NATIVE,
+    tx::{
+        PopulatedTransaction, ScriptPublicKey, Transaction, TransactionInput, TransactionOutpoint,
+        TransactionOutput, UtxoEntry,
+    },
+};
+use kaspa_hashes::Hash;
+use kaspa_utils::hex::{FromHex, ToHex};
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use secp256k1::SecretKey;
+use std::str::FromStr;
+use vectors::*;
+
+/// Register the `compat` submodule functions.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_test_vectors, m)?)?;
+    m.add_function(wrap_pyfunction!(py_verify, m)?)?;
+    Ok(())
+}
+
+/// The canonical test vectors.
+///
+/// Returns:
+///     dict: Vectors by category (`addresses`, `derivations`, `transactions`).
+///         Transaction vectors include the transaction they were computed from.
+#[pyfunction]
+#[pyo3(name = "test_vectors")]
+fn py_test_vectors(py: Python) -> PyResult<Bound<'_, PyDict>> {
+    let addresses = PyList::empty(py);
+    for vector in ADDRESSES {
+        let dict = PyDict::new(py);
+        dict.set_item("name", vector.name)?;
+        dict.set_item("prefix", vector.prefix)?;
+        dict.set_item("version", version_name(vector.version))?;
+        dict.set_item("payload", vector.payload)?;
+        dict.set_item("address", vector.address)?;
+        addresses.append(dict)?;
+    }
+
+    let derivations = PyList::empty(py);
+    for vector in DERIVATIONS {
+        let dict = PyDict::new(py);
+        dict.set_item("name", vector.name)?;
+        dict.set_item("mnemonic", vector.mnemonic)?;
+        dict.set_item("passphrase", vector.passphrase)?;
+        dict.set_item("seed", vector.seed)?;
+        dict.set_item("path", vector.path)?;
+        dict.set_item("private_key", vector.private_key)?;
+        dict.set_item("public_key", vector.public_key)?;
+        dict.set_item("address", vector.address)?;
+        derivations.append(dict)?;
+    }
+
+    let transactions = PyList::empty(py);
+    for vector in TRANSACTIONS {
+        let input = PyDict::new(py);
+        input.set_item("transaction_id", TRANSACTION_PREVIOUS_ID)?;
+        input.set_item("index", 0)?;
+        input.set_item("sequence", 0)?;
+        input.set_item("sig_op_count", 1)?;
+        input.set_item("utxo_amount", TRANSACTION_UTXO_AMOUNT)?;
+        input.set_item("utxo_script", pay_to_pubkey_script().to_hex())?;
+
+        let output = PyDict::new(py);
+        output.set_item("value", TRANSACTION_OUTPUT_AMOUNT)?;
+        output.set_item("script", pay_to_pubkey_script().to_hex())?;
+
+        let transaction = PyDict::new(py);
+        transaction.set_item("version", 0)?;
+        transaction.set_item("inputs", vec![input])?;
+        transaction.set_item("outputs", vec![output])?;
+        transaction.set_item("lock_time", 0)?;
+        transaction.set_item("subnetwork_id", SUBNETWORK_ID_NATIVE.to_string())?;
+        transaction.set_item("gas", 0)?;
+        transaction.set_item("payload", vector.payload)?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("name", vector.name)?;
+        dict.set_item("transaction", transaction)?;
+        dict.set_item("transaction_id", vector.transaction_id)?;
+        dict.set_item("sighash", vector.sighash)?;
+        dict.set_item("sighash_type", "All")?;
+        transactions.append(dict)?;
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("private_key", PRIVATE_KEY)?;
+    dict.set_item("public_key", PUBLIC_KEY)?;
+    dict.set_item("addresses", addresses)?;
+    dict.set_item("derivations", derivations)?;
+    dict.set_item("transactions", transactions)?;
+    Ok(dict)
+}
+
+/// Recompute all test vectors with this build of the SDK.
+///
+/// Run this after installing or upgrading to make sure address encoding, key
+/// derivation, transaction ids and signature hashes match the other Kaspa SDKs.
+///
+/// Args:
+///     raise_on_failure: Raise instead of returning when a vector does not match (default: False).
+///
+/// Returns:
+///     dict: `passed` (number of matching checks) and `failed` (list of dicts
+///         with `category`, `name`, `field`, `expected` and `actual`).
+///
+/// Raises:
+///     Exception: If `raise_on_failure` is set and a vector does not match.
+#[pyfunction]
+#[pyo3(name = "verify")]
+#[pyo3(signature = (raise_on_failure=false))]
+fn py_verify(py: Python, raise_on_failure: bool) -> PyResult<Bound<'_, PyDict>> {
+    let mut checks = Vec::new();
+    for vector in ADDRESSES {
+        check_address(vector, &mut checks);
+    }
+    for vector in DERIVATIONS {
+        check_derivation(vector, &mut checks);
+    }
+    for vector in TRANSACTIONS {
+        check_transaction(vector, &mut checks);
+    }
+
+    let (passed, failed): (Vec<_>, Vec<_>) = checks
+        .into_iter()
+        .partition(|check| check.actual == check.expected);
+
+    if raise_on_failure && let Some(check) = failed.first() {
+        return Err(PyException::new_err(format!(
+            "{} test vector(s) failed, first: {}/{} {}: expected `{}`, got `{}`",
+            failed.len(),
+            check.category,
+            check.name,
+            check.field,
+            check.expected,
+            check.actual
+        )));
+    }
+
+    let failures = PyList::empty(py);
+    for check in failed {
+        let dict = PyDict::new(py);
+        dict.set_item("category", check.category)?;
+        dict.set_item("name", check.name)?;
+        dict.set_item("field", check.field)?;
+        dict.set_item("expected", check.expected)?;
+        dict.set_item("actual", check.actual)?;
+        failures.append(dict)?;
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("passed", passed.len())?;
+    dict.set_item("failed", failures)?;
+    Ok(dict)
+}
+
+struct Check {
+    category: &'static str,
+    name: &'static str,
+    field: &'static str,
+    expected: String,
+    actual: String,
+}
+
+impl Check {
+    fn new(
+        category: &'static str,
+        name: &'static str,
+        field: &'static str,
+        expected: &str,
+        actual: Result<String, String>,
+    ) -> Self {
+        Self {
+            category,
+            name,
+            field,
+            expected: expected.to_string(),
+            actual: actual.unwrap_or_else(|err| format!("error: {err}")),
+        }
+    }
+}
+
+fn check_address(vector: &AddressVector, checks: &mut Vec<Check>) {
+    let address = Prefix::try_from(vector.prefix)
+        .map_err(|err| err.to_string())
+        .and_then(|prefix| {
+            let payload = Vec::<u8>::from_hex(vector.payload).map_err(|err| err.to_string())?;
+            Ok(Address::new(prefix, vector.version, &payload).to_string())
+        });
+    checks.push(Check::new(
+        "addresses",
+        vector.name,
+        "address",
+        vector.address,
+        address,
+    ));
+}
+
+fn check_derivation(vector: &DerivationVector, checks: &mut Vec<Check>) {
+    let seed = Mnemonic::new(vector.mnemonic, Language::English)
+        .map(|mnemonic| mnemonic.to_seed(vector.passphrase))
+        .map_err(|err| err.to_string());
+    checks.push(Check::new(
+        "derivations",
+        vector.name,
+        "seed",
+        vector.seed,
+        seed.as_ref()
+            .map(|seed| seed.as_bytes().to_vec().to_hex())
+            .map_err(Clone::clone),
+    ));
+
+    let private_key = seed.and_then(|seed| {
+        let path = DerivationPath::from_str(vector.path).map_err(|err| err.to_string())?;
+        ExtendedPrivateKey::<SecretKey>::new(seed.as_bytes())
+            .and_then(|xprv| xprv.derive_path(&path))
+            .map(|xprv| *xprv.private_key())
+            .map_err(|err| err.to_string())
+    });
+    checks.push(Check::new(
+        "derivations",
+        vector.name,
+        "private_key",
+        vector.private_key,
+        private_key
+            .as_ref()
+            .map(|key| key.secret_bytes().to_vec().to_hex())
+            .map_err(Clone::clone),
+    ));
+
+    let public_key = private_key.map(|key| key.x_only_public_key(secp256k1::SECP256K1).0);
+    checks.push(Check::new(
+        "derivations",
+        vector.name,
+        "public_key",
+        vector.public_key,
+        public_key
+            .as_ref()
+            .map(|key| key.serialize().to_vec().to_hex())
+            .map_err(Clone::clone),
+    ));
+
+    let address = public_key
+        .map(|key| Address::new(Prefix::Mainnet, Version::PubKey, &key.serialize()).to_string());
+    checks.push(Check::new(
+        "derivations",
+        vector.name,
+        "address",
+        vector.address,
+        address,
+    ));
+}
+
+fn check_transaction(vector: &TransactionVector, checks: &mut Vec<Check>) {
+    let transaction = build_transaction(vector.payload);
+    checks.push(Check::new(
+        "transactions",
+        vector.name,
+        "transaction_id",
+        vector.transaction_id,
+        transaction
+            .as_ref()
+            .map(|tx| tx.id().to_string())
+            .map_err(Clone::clone),
+    ));
+
+    let sighash = transaction.map(|tx| {
+        let utxo = UtxoEntry::new(
+            TRANSACTION_UTXO_AMOUNT,
+            ScriptPublicKey::from_vec(0, pay_to_pubkey_script()),
+            0,
+            false,
+        );
+        let populated = PopulatedTransaction::new(&tx, vec![utxo]);
+        calc_schnorr_signature_hash(
+            &populated,
+            0,
+            SIG_HASH_ALL,
+            &SigHashReusedValuesUnsync::new(),
+        )
+        .to_string()
+    });
+    checks.push(Check::new(
+        "transactions",
+        vector.name,
+        "sighash",
+        vector.sighash,
+        sighash,
+    ));
+}
+
+fn build_transaction(payload: &str) -> Result<Transaction, String> {
+    let previous_id = Hash::from_hex(TRANSACTION_PREVIOUS_ID).map_err(|err| err.to_string())?;
+    let payload = Vec::<u8>::from_hex(payload).map_err(|err| err.to_string())?;
+    let input = TransactionInput::new(TransactionOutpoint::new(previous_id, 0), vec![], 0, 1);
+    let output = TransactionOutput::new(
+        TRANSACTION_OUTPUT_AMOUNT,
+        ScriptPublicKey::from_vec(0, pay_to_pubkey_script()),
+    );
+    Ok(Transaction::new(
+        0,
+        vec![input],
+        vec![output],
+        0,
+        SUBNETWORK_ID_NATIVE,
+        0,
+        payload,
+    ))
+}
+
+/// `OP_DATA_32 <PUBLIC_KEY> OP_CHECKSIG`
+fn pay_to_pubkey_script() -> Vec<u8> {
+    let public_key = Vec::<u8>::from_hex(PUBLIC_KEY).expect("valid public key vector");
+    std::iter::once(0x20)
+        .chain(public_key)
+        .chain(std::iter::once(0xac))
+        .collect()
+}
+
+fn version_name(version: Version) -> &'static str {
+    match version {
+        Version::PubKey => "PubKey",
+        Version::PubKeyECDSA => "PubKeyECDSA",
+        Version::ScriptHash => "ScriptHash",
+    }
+}
@@ -0,0 +1,116 @@
+use kaspa_addresses::Version;
+
+/// BIP-340 test vector 0 secret key, shared by the address and transaction vectors.
+pub const PRIVATE_KEY: &str = "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef";
+/// X-only public key of `PRIVATE_KEY`.
+pub const PUBLIC_KEY: &str = "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+
+pub struct AddressVector {
+    pub name: &'static str,
+    pub prefix: &'static str,
+    pub version: Version,
+    pub payload: &'static str,
+    pub address: &'static str,
+}
+
+pub const ADDRESSES: &[AddressVector] = &[
+    AddressVector {
+        name: "schnorr-mainnet",
+        prefix: "kaspa",
+        version: Version::PubKey,
+        payload: PUBLIC_KEY,
+        address: "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva",
+    },
+    AddressVector {
+        name: "schnorr-testnet",
+        prefix: "kaspatest",
+        version: Version::PubKey,
+        payload: PUBLIC_KEY,
+        address: "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae",
+    },
+    AddressVector {
+        name: "ecdsa-mainnet",
+        prefix: "kaspa",
+        version: Version::PubKeyECDSA,
+        payload: "02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+        address: "kaspa:qypdluwh0u4xw8zlxcvrwfkmydqmuk874cw69hkwmppjgrmm2q46vkgl9zsrch2",
+    },
+    AddressVector {
+        // BLAKE2b-256 of the pay-to-pubkey script of `PUBLIC_KEY`.
+        name: "script-hash-mainnet",
+        prefix: "kaspa",
+        version: Version::ScriptHash,
+        payload: "37437c64888d1fb95375adf76d78a11e61b5796985c6fd448774631c9125f4f4",
+        address: "kaspa:pqm5xlry3zx3lw2nwkklwmtc5y0xrdtedxzudl2ysa6xx8y3yh60g2ukxsly0",
+    },
+];
+
+pub struct DerivationVector {
+    pub name: &'static str,
+    pub mnemonic: &'static str,
+    pub passphrase: &'static str,
+    pub seed: &'static str,
+    pub path: &'static str,
+    pub private_key: &'static str,
+    pub public_key: &'static str,
+    pub address: &'static str,
+}
+
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const MNEMONIC_SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+pub const DERIVATIONS: &[DerivationVector] = &[
+    DerivationVector {
+        name: "bip44-receive-0",
+        mnemonic: MNEMONIC,
+        passphrase: "",
+        seed: MNEMONIC_SEED,
+        path: "m/44'/111111'/0'/0/0",
+        private_key: "24cd8d2875e8e17e4f88fbc554834a89b7b3e0c6f9d84fae36883604359ba440",
+        public_key: "1bacea84ca721c95d67ecace19bc499a77c03726bc8739af637bcd89abaaf058",
+        address: "kaspa:qqd6e65yefepe9wk0m9vuxdufxd80sphy67gwwd0vdaumzdt4tc9s3qt0lqeh",
+    },
+    DerivationVector {
+        name: "bip44-change-0",
+        mnemonic: MNEMONIC,
+        passphrase: "",
+        seed: MNEMONIC_SEED,
+        path: "m/44'/111111'/0'/1/0",
+        private_key: "a902eabe903438f7e3a7d99b3082a65429f370817329b215c7237406626a5fde",
+        public_key: "276222b7bf62ffce4482c3c40aaa25e21ef06d27cc91c217b464982d042d33fe",
+        address: "kaspa:qqnkyg4hha30lnjystpugz42yh3paurdylxfrsshk3jfstgy95elu2a8l3jcs",
+    },
+];
+
+/// Transaction template of the transaction vectors: a single input spending
+/// output 0 of transaction `01..01` (sequence 0, one sig op) and a single
+/// pay-to-pubkey output to `PUBLIC_KEY`. Lock time, gas and subnetwork are zero.
+pub const TRANSACTION_PREVIOUS_ID: &str =
+    "0101010101010101010101010101010101010101010101010101010101010101";
+pub const TRANSACTION_OUTPUT_AMOUNT: u64 = 100_000_000;
+/// Amount of the UTXO spent by the input, used for the signature hash.
+pub const TRANSACTION_UTXO_AMOUNT: u64 = 200_000_000;
+
+pub struct TransactionVector {
+    pub name: &'static str,
+    pub payload: &'static str,
+    pub transaction_id: &'static str,
+    /// Schnorr signature hash of input 0 with `SighashType.All`.
+    pub sighash: &'static str,
+}
+
+pub const TRANSACTIONS: &[TransactionVector] = &[
+    TransactionVector {
+        name: "p2pk-no-payload",
+        payload: "",
+        transaction_id: "4b6282ac7fe97bbb5aeb6079a545ae2c34563a80847ce0e4db6a605423683124",
+        sighash: "4d32d55f7542de3d186f94d666383484d16c00bf626037f61c669fd6f96688e7",
+    },
+    TransactionVector {
+        name: "p2pk-payload",
+        payload: "6b61737061",
+        transaction_id: "54df65ed5e0608c0bfc0f9c1547ac15062f0733a858d0d2deff412164b996a39",
+        sighash: "e5b5a153832085609c41cf573760a298af6cbb9b2204952d310530fe0547bbc7",
+    },
+];
@@ -0,0 +1,222 @@
+use crate::address::PyAddress;
+use crate::consensus::client::transaction::PyTransaction;
+use crate::consensus::core::network::PyNetworkType;
+use crate::wallet::core::utxo::context::parse_addresses;
+use ahash::AHashMap;
+use kaspa_addresses::{Address, Prefix};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::extract_script_pub_key_address;
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// Groups addresses by the common-input heuristic: addresses spent together
+/// as inputs of one transaction are assumed to share an owner.
+///
+/// Transactions are added in batches with `add_transactions()`; clusters
+/// merge as later transactions link them. Each address gets the cluster id
+/// of the earliest address of its cluster (the index of that address in the
+/// order addresses were first seen), so ids are stable until clusters merge.
+///
+/// The heuristic does not hold for CoinJoin-style transactions combining
+/// inputs of several owners; filter those out before adding them.
+#[gen_stub_pyclass]
+#[pyclass(name = "AddressClusters")]
+pub struct PyAddressClusters {
+    prefix: Option<Prefix>,
+    index: AHashMap<Address, usize>,
+    addresses: Vec<Address>,
+    /// Union-find forest over address indexes; roots are the smallest index of their set.
+    parent: Vec<usize>,
+}
+
+impl PyAddressClusters {
+    fn insert(&mut self, address: Address) -> usize {
+        if let Some(index) = self.index.get(&address) {
+            return *index;
+        }
+        let index = self.addresses.len();
+        self.index.insert(address.clone(), index);
+        self.addresses.push(address);
+        self.parent.push(index);
+        index
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parent[index] != index {
+            // Path halving
+            self.parent[index] = self.parent[self.parent[index]];
+            index = self.parent[index];
+        }
+        index
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parent[a.max(b)] = a.min(b);
+        }
+    }
+
+    /// Input addresses of a `Transaction` (from the UTXO entries of its inputs)
+    /// or of a sequence of addresses.
+    fn input_addresses(&self, transaction: &Bound<'_, PyAny>) -> PyResult<Vec<Address>> {
+        let Ok(transaction) = transaction.extract::<PyTransaction>() else {
+            return parse_addresses(transaction.clone()).map_err(|_| {
+                PyException::new_err(
+                    "transactions must be Transaction objects or sequences of Address or str",
+                )
+            });
+        };
+        Ok(transaction
+            .inner()
+            .inner()
+            .inputs
+            .iter()
+            .filter_map(|input| {
+                let utxo = input.inner().utxo.clone()?;
+                utxo.utxo.address.clone().or_else(|| {
+                    extract_script_pub_key_address(&utxo.utxo.script_public_key, self.prefix?).ok()
+                })
+            })
+            .collect())
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyAddressClusters {
+    /// Create an empty clustering.
+    ///
+    /// Args:
+    ///     network_type: Network used to derive the address of inputs whose UTXO entry has none (default: such inputs are skipped).
+    ///
+    /// Returns:
+    ///     AddressClusters: A new, empty clustering.
+    #[new]
+    #[pyo3(signature = (network_type=None))]
+    pub fn constructor(
+        #[gen_stub(override_type(type_repr = "typing.Optional[str | NetworkType]"))]
+        network_type: Option<PyNetworkType>,
+    ) -> Self {
+        Self {
+            prefix: network_type.map(|network_type| NetworkType::from(network_type).into()),
+            index: AHashMap::new(),
+            addresses: Vec::new(),
+            parent: Vec::new(),
+        }
+    }
+
+    /// Add a batch of transactions, merging the clusters of their input addresses.
+    ///
+    /// Args:
+    ///     transactions: `Transaction` objects whose inputs carry UTXO entries, or sequences of the input addresses of each transaction.
+    ///
+    /// Raises:
+    ///     Exception: If a transaction is neither a Transaction nor a sequence of addresses.
+    pub fn add_transactions(
+        &mut self,
+        #[gen_stub(override_type(
+            type_repr = "typing.Iterable[Transaction | typing.Sequence[Address | str]]"
+        ))]
+        transactions: Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        for transaction in transactions.try_iter()? {
+            let addresses = self.input_addresses(&transaction?)?;
+            let mut indexes = addresses.into_iter().map(|address| self.insert(address));
+            if let Some(first) = indexes.next() {
+                let rest = indexes.collect::<Vec<_>>();
+                for index in rest {
+                    self.union(first, index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The cluster id of an address.
+    ///
+    /// Args:
+    ///     address: The address.
+    ///
+    /// Returns:
+    ///     int | None: The cluster id, None if the address was never seen as an input.
+    pub fn cluster(
+        &mut self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+    ) -> PyResult<Option<usize>> {
+        let Some(index) = self.index.get(&extract_address(&address)?).copied() else {
+            return Ok(None);
+        };
+        Ok(Some(self.find(index)))
+    }
+
+    /// All clusters, ordered by cluster id.
+    ///
+    /// Args:
+    ///     min_size: Only return clusters of at least this many addresses (default: 1).
+    ///
+    /// Returns:
+    ///     list[list[Address]]: The addresses of each cluster, in the order they were first seen.
+    #[pyo3(signature = (min_size=1))]
+    pub fn clusters(&mut self, min_size: usize) -> Vec<Vec<PyAddress>> {
+        let mut clusters: AHashMap<usize, Vec<PyAddress>> = AHashMap::new();
+        for index in 0..self.addresses.len() {
+            let root = self.find(index);
+            clusters
+                .entry(root)
+                .or_default()
+                .push(self.addresses[index].clone().into());
+        }
+        let mut clusters = clusters
+            .into_iter()
+            .filter(|(_, addresses)| addresses.len() >= min_size)
+            .collect::<Vec<_>>();
+        clusters.sort_unstable_by_key(|(root, _)| *root);
+        clusters
+            .into_iter()
+            .map(|(_, addresses)| addresses)
+            .collect()
+    }
+
+    /// The cluster id of every address seen.
+    ///
+    /// Returns:
+    ///     dict[str, int]: Cluster ids by address string.
+    pub fn to_dict<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for index in 0..self.addresses.len() {
+            let root = self.find(index);
+            dict.set_item(self.addresses[index].to_string(), root)?;
+        }
+        Ok(dict)
+    }
+
+    /// The number of clusters.
+    #[getter]
+    pub fn get_cluster_count(&self) -> usize {
+        (0..self.parent.len())
+            .filter(|index| self.parent[*index] == *index)
+            .count()
+    }
+
+    fn __len__(&self) -> usize {
+        self.addresses.len()
+    }
+
+    fn __contains__(
+        &self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        Ok(self.index.contains_key(&extract_address(&address)?))
+    }
+}
+
+fn extract_address(value: &Bound<'_, PyAny>) -> PyResult<Address> {
+    if let Ok(address) = value.extract::<PyAddress>() {
+        Ok(address.into())
+    } else if let Ok(address) = value.extract::<String>() {
+        PyAddress::try_from(address).map(Address::from)
+    } else {
+        Err(PyException::new_err("address must be an Address or str"))
+    }
+}
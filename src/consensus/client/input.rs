@@ -7,7 +7,7 @@ use kaspa_consensus_client::{TransactionInput, UtxoEntryReference};
 use pyo3::{
     exceptions::PyKeyError,
     prelude::*,
-    types::{PyDict, PyType},
+    types::{PyBytes, PyDict, PyType},
 };
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use workflow_core::hex::ToHex;
@@ -79,6 +79,16 @@ impl PyTransactionInput {
             .map(|script| script.to_hex())
     }
 
+    /// The unlocking script (signature) as bytes, or None if not set.
+    #[getter]
+    pub fn get_signature_script_bytes<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
+        self.0
+            .inner()
+            .signature_script
+            .as_ref()
+            .map(|script| PyBytes::new(py, script))
+    }
+
     /// Set the unlocking script (signature).
     ///
     /// Args:
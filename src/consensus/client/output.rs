@@ -8,6 +8,7 @@ use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
 use crate::{
     consensus::core::script_public_key::PyScriptPublicKey, traits::TryToPyDict, types::PyBinary,
+    validation,
 };
 
 /// A transaction output defining a payment destination.
@@ -117,7 +118,7 @@ impl From<PyTransactionOutput> for TransactionOutput {
 impl TryFrom<&Bound<'_, PyDict>> for PyTransactionOutput {
     type Error = PyErr;
     fn try_from(dict: &Bound<PyDict>) -> PyResult<Self> {
-        let value = dict.as_any().get_item("value")?.extract::<u64>()?;
+        let value: u64 = validation::field(dict, "value")?;
 
         let spk_obj = dict.as_any().get_item("scriptPublicKey")?;
         let spk = if let Ok(spk) = spk_obj.extract::<PyScriptPublicKey>() {
@@ -5,6 +5,7 @@ use crate::consensus::core::network::PyNetworkType;
 use crate::crypto::hashes::PyHash;
 use crate::traits::TryToPyDict;
 use crate::types::PyBinary;
+use crate::validation;
 use kaspa_consensus_client::{Transaction, TransactionInput, TransactionOutput};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_consensus_core::subnets;
@@ -14,7 +15,7 @@ use kaspa_txscript::extract_script_pub_key_address;
 use kaspa_utils::hex::FromHex;
 use pyo3::exceptions::PyKeyError;
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyType};
+use pyo3::types::{PyBytes, PyList, PyType};
 use pyo3::{exceptions::PyException, types::PyDict};
 use pyo3_stub_gen::derive::*;
 use workflow_core::hex::ToHex;
@@ -238,19 +239,15 @@ impl PyTransaction {
     /// Set the subnetwork identifier.
     ///
     /// Args:
-    ///     value: The subnetwork ID as a hex string.
+    ///     value: The subnetwork ID as bytes or hex string.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid or has incorrect length.
+    ///     Exception: If the value is invalid or has incorrect length.
     #[setter]
-    pub fn set_subnetwork_id(&mut self, value: &str) -> PyResult<()> {
-        let subnetwork_id = Vec::from_hex(value)
-            .map_err(|err| PyException::new_err(err.to_string()))?
-            .as_slice()
-            .try_into()
-            .map_err(|err| {
-                PyException::new_err(format!("subnetwork_id conversion error: {}", err))
-            })?;
+    pub fn set_subnetwork_id(&mut self, value: PyBinary) -> PyResult<()> {
+        let subnetwork_id = value.data.as_slice().try_into().map_err(|err| {
+            PyException::new_err(format!("subnetwork_id conversion error: {}", err))
+        })?;
         self.0.inner().subnetwork_id = subnetwork_id;
         Ok(())
     }
@@ -261,6 +258,12 @@ impl PyTransaction {
         self.0.inner().payload.to_hex()
     }
 
+    /// The transaction payload data as bytes.
+    #[getter]
+    pub fn get_payload_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.inner().payload)
+    }
+
     /// Set the transaction payload data.
     ///
     /// Args:
@@ -319,6 +322,40 @@ impl PyTransaction {
         Self::try_from(dict)
     }
 
+    /// Serialize the transaction to bytes.
+    ///
+    /// The encoding is the Borsh serialization of the consensus transaction, as
+    /// read by `Transaction.from_bytes()` and `RpcClient.submit_serialized_transaction()`.
+    ///
+    /// Returns:
+    ///     bytes: The serialized transaction.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let tx: cctx::Transaction = self.into();
+        let bytes = borsh::to_vec(&tx).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Create a Transaction from bytes written by `to_bytes()`.
+    ///
+    /// Args:
+    ///     data: The Borsh-serialized consensus transaction, as bytes or a hex string.
+    ///
+    /// Returns:
+    ///     Transaction: A new Transaction instance, with its id computed.
+    ///
+    /// Raises:
+    ///     ValueError: If the data is not a serialized transaction.
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, PyType>, data: PyBinary) -> PyResult<Self> {
+        let mut tx = borsh::from_slice::<cctx::Transaction>(data.as_ref())
+            .map_err(|err| validation::invalid("data", err))?;
+        tx.finalize();
+        Ok(Self(Transaction::from(tx)))
+    }
+
     // Cannot be derived via pyclass(eq) as wrapped Transaction type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyTransaction) -> bool {
         match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
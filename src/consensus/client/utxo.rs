@@ -1,10 +1,17 @@
 use super::outpoint::PyTransactionOutpoint;
 use crate::{
-    address::PyAddress, consensus::core::script_public_key::PyScriptPublicKey, traits::TryToPyDict,
+    address::PyAddress,
+    consensus::core::{network::PyNetworkId, script_public_key::PyScriptPublicKey},
+    traits::TryToPyDict,
     types::PyBinary,
+    validation,
 };
 use kaspa_consensus_client::{UtxoEntry, UtxoEntryReference};
+use kaspa_consensus_core::network::NetworkId;
 use kaspa_utils::hex::FromHex;
+use kaspa_wallet_core::utxo::{
+    coinbase_transaction_maturity_period_daa, user_transaction_maturity_period_daa,
+};
 use pyo3::{
     exceptions::{PyKeyError, PyValueError},
     prelude::*,
@@ -132,10 +139,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntry {
                 .cast::<PyDict>()?,
         )?;
 
-        let amount: u64 = dict
-            .get_item("amount")?
-            .ok_or_else(|| PyKeyError::new_err("Key `amount` not present"))?
-            .extract()?;
+        let amount: u64 = validation::field(&dict, "amount")?;
 
         let spk_obj = dict
             .get_item("scriptPublicKey")?
@@ -156,15 +160,9 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntry {
             ));
         };
 
-        let block_daa_score: u64 = dict
-            .get_item("blockDaaScore")?
-            .ok_or_else(|| PyKeyError::new_err("Key `blockDaaScore` not present"))?
-            .extract()?;
+        let block_daa_score: u64 = validation::field(&dict, "blockDaaScore")?;
 
-        let is_coinbase: bool = dict
-            .get_item("isCoinbase")?
-            .ok_or_else(|| PyKeyError::new_err("Key `isCoinbase` not present"))?
-            .extract()?;
+        let is_coinbase: bool = validation::field(&dict, "isCoinbase")?;
 
         let utxo = UtxoEntry {
             address: address.map(|a| a.into()),
@@ -307,6 +305,36 @@ impl PyUtxoEntryReference {
         self.0.utxo.script_public_key.clone().into()
     }
 
+    /// The DAA score at which this UTXO becomes mature (spendable).
+    ///
+    /// Args:
+    ///     network_id: The network, whose coinbase or user transaction maturity period applies.
+    ///
+    /// Returns:
+    ///     int: The block DAA score plus the maturity period.
+    pub fn maturity_daa_score(&self, network_id: PyNetworkId) -> u64 {
+        let network_id = NetworkId::from(network_id);
+        let period = if self.0.utxo.is_coinbase {
+            coinbase_transaction_maturity_period_daa(&network_id)
+        } else {
+            user_transaction_maturity_period_daa(&network_id)
+        };
+        self.0.utxo.block_daa_score.saturating_add(period)
+    }
+
+    /// The number of DAA score units until this UTXO becomes mature.
+    ///
+    /// Args:
+    ///     network_id: The network, whose coinbase or user transaction maturity period applies.
+    ///     current_daa_score: The current virtual DAA score, e.g. from `get_block_dag_info()`.
+    ///
+    /// Returns:
+    ///     int: The remaining DAA score units (about 10 per second), 0 if mature.
+    pub fn daa_until_mature(&self, network_id: PyNetworkId, current_daa_score: u64) -> u64 {
+        self.maturity_daa_score(network_id)
+            .saturating_sub(current_daa_score)
+    }
+
     /// Get a dictionary representation of the UtxoEntryReference.
     /// Note that this creates a second separate object on the Python heap.
     ///
@@ -392,10 +420,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntryReference {
             dict.clone()
         };
 
-        let amount: u64 = source_dict
-            .get_item("amount")?
-            .ok_or_else(|| PyKeyError::new_err("Key `amount` not present"))?
-            .extract()?;
+        let amount: u64 = validation::field(&source_dict, "amount")?;
 
         let spk_obj = source_dict
             .get_item("scriptPublicKey")?
@@ -418,15 +443,9 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntryReference {
             ));
         };
 
-        let block_daa_score: u64 = source_dict
-            .get_item("blockDaaScore")?
-            .ok_or_else(|| PyKeyError::new_err("Key `blockDaaScore` not present"))?
-            .extract()?;
+        let block_daa_score: u64 = validation::field(&source_dict, "blockDaaScore")?;
 
-        let is_coinbase: bool = source_dict
-            .get_item("isCoinbase")?
-            .ok_or_else(|| PyKeyError::new_err("Key `isCoinbase` not present"))?
-            .extract()?;
+        let is_coinbase: bool = validation::field(&source_dict, "isCoinbase")?;
 
         let utxo = UtxoEntry {
             address: address.map(|a| a.into()),
@@ -43,6 +43,12 @@ impl PyScriptPublicKey {
         self.0.script_as_hex()
     }
 
+    /// The script as bytes.
+    #[getter]
+    pub fn get_script_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.0.script())
+    }
+
     /// The string representation.
     ///
     /// Returns:
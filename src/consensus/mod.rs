@@ -1,3 +1,4 @@
 pub mod client;
 pub mod convert;
 pub mod core;
+pub mod pow;
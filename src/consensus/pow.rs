@@ -0,0 +1,317 @@
+use crate::rpc::wrpc::templates::PyBlockTemplate;
+use kaspa_consensus_core::{hashing::header::hash_override_nonce_time, header::Header};
+use kaspa_math::Uint256;
+use kaspa_rpc_core::model::RpcRawBlock;
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyDict, PyInt},
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
+
+/// The highest (easiest) target allowed by consensus, 2^255 - 1, as float.
+/// Difficulty is the ratio of this target to the block target.
+const MAX_DIFFICULTY_TARGET_AS_F64: f64 = 5.78960446186581e76;
+
+/// A mining job built from a block template, e.g. for a stratum bridge.
+///
+/// Holds the header pre-PoW hash and target miners work on, and validates
+/// nonces (kHeavyHash) locally, so shares can be checked against a pool
+/// target and blocks against the network target before `submit_block()`.
+#[gen_stub_pyclass]
+#[pyclass(name = "MiningJob")]
+pub struct PyMiningJob {
+    block: RpcRawBlock,
+    header: Header,
+    state: kaspa_pow::State,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyMiningJob {
+    /// Create a mining job from a block template.
+    ///
+    /// Args:
+    ///     template: A `BlockTemplate`, a `get_block_template()` response or its `block` dict.
+    ///
+    /// Returns:
+    ///     MiningJob: A new MiningJob instance.
+    ///
+    /// Raises:
+    ///     Exception: If the template cannot be decoded.
+    #[new]
+    pub fn ctor(
+        #[gen_stub(override_type(type_repr = "BlockTemplate | dict"))] template: Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        let block = if let Ok(template) = template.cast::<PyBlockTemplate>() {
+            template.borrow().block().clone()
+        } else if let Ok(dict) = template.cast::<PyDict>() {
+            let block = match dict.get_item("block")? {
+                Some(block) => block,
+                None => dict.clone().into_any(),
+            };
+            serde_pyobject::from_pyobject(block)
+                .map_err(|err| PyException::new_err(format!("Invalid block template: {err}")))?
+        } else {
+            return Err(PyException::new_err(
+                "template must be a BlockTemplate or dict",
+            ));
+        };
+        let header = Header::from(block.header.clone());
+        let state = kaspa_pow::State::new(&header);
+        Ok(Self {
+            block,
+            header,
+            state,
+        })
+    }
+
+    /// The header hash with zero nonce and timestamp, as hex string.
+    #[getter]
+    pub fn get_pre_pow_hash(&self) -> String {
+        hash_override_nonce_time(&self.header, 0, 0).to_string()
+    }
+
+    /// The pre-PoW hash as four little-endian 64-bit words, as sent in stratum `mining.notify` jobs.
+    #[getter]
+    pub fn get_pre_pow_hash_words(&self) -> Vec<u64> {
+        hash_override_nonce_time(&self.header, 0, 0)
+            .to_le_u64()
+            .to_vec()
+    }
+
+    /// The header timestamp in milliseconds.
+    #[getter]
+    pub fn get_timestamp(&self) -> u64 {
+        self.header.timestamp
+    }
+
+    /// The compact network target (difficulty bits).
+    #[getter]
+    pub fn get_bits(&self) -> u32 {
+        self.header.bits
+    }
+
+    /// The network target, as integer.
+    #[getter]
+    #[gen_stub(override_return_type(type_repr = "builtins.int"))]
+    pub fn get_target<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        uint256_to_py(py, Uint256::from_compact_target_bits(self.header.bits))
+    }
+
+    /// Compute the proof-of-work value of a nonce.
+    ///
+    /// Args:
+    ///     nonce: The nonce.
+    ///
+    /// Returns:
+    ///     int: The kHeavyHash PoW value; the nonce is valid for targets greater than or equal to it.
+    #[gen_stub(override_return_type(type_repr = "builtins.int"))]
+    pub fn pow_value<'py>(&self, py: Python<'py>, nonce: u64) -> PyResult<Bound<'py, PyAny>> {
+        uint256_to_py(py, self.state.calculate_pow(nonce))
+    }
+
+    /// Check a nonce against a target.
+    ///
+    /// Args:
+    ///     nonce: The nonce submitted by a miner.
+    ///     target: The target to check against, e.g. a pool share target (default: the network target).
+    ///
+    /// Returns:
+    ///     bool: True if the PoW value of the nonce does not exceed the target.
+    #[pyo3(signature = (nonce, target=None))]
+    pub fn check_nonce(&self, nonce: u64, target: Option<Bound<'_, PyInt>>) -> PyResult<bool> {
+        let pow = self.state.calculate_pow(nonce);
+        let target = match target {
+            Some(target) => uint256_from_py(&target)?,
+            None => Uint256::from_compact_target_bits(self.header.bits),
+        };
+        Ok(pow <= target)
+    }
+
+    /// The template block with `nonce` set, for `submit_block()`.
+    ///
+    /// Args:
+    ///     nonce: The nonce found by a miner.
+    ///
+    /// Returns:
+    ///     dict: The block, e.g. `await client.submit_block({"block": job.block(nonce), "allowNonDaaBlocks": False})`.
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    pub fn block<'py>(&self, py: Python<'py>, nonce: u64) -> PyResult<Bound<'py, PyAny>> {
+        let mut block = self.block.clone();
+        block.header.nonce = nonce;
+        Ok(serde_pyobject::to_pyobject(py, &block)?)
+    }
+}
+
+/// Convert compact difficulty bits to a target.
+///
+/// Args:
+///     bits: The compact target, e.g. a header's `bits`.
+///
+/// Returns:
+///     int: The target.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "bits_to_target")]
+#[gen_stub(override_return_type(type_repr = "builtins.int"))]
+pub fn py_bits_to_target(py: Python<'_>, bits: u32) -> PyResult<Bound<'_, PyAny>> {
+    uint256_to_py(py, Uint256::from_compact_target_bits(bits))
+}
+
+/// Convert a target to compact difficulty bits.
+///
+/// Args:
+///     target: The target.
+///
+/// Returns:
+///     int: The compact target (precision beyond its 3-byte mantissa is lost).
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "target_to_bits")]
+pub fn py_target_to_bits(target: Bound<'_, PyInt>) -> PyResult<u32> {
+    Ok(uint256_from_py(&target)?.compact_target_bits())
+}
+
+/// Convert compact difficulty bits to a difficulty.
+///
+/// Args:
+///     bits: The compact target, e.g. a header's `bits`.
+///
+/// Returns:
+///     float: The difficulty, as reported by `get_block_dag_info()`.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "bits_to_difficulty")]
+pub fn py_bits_to_difficulty(bits: u32) -> f64 {
+    MAX_DIFFICULTY_TARGET_AS_F64 / uint256_to_f64(Uint256::from_compact_target_bits(bits))
+}
+
+/// Convert a target to a difficulty.
+///
+/// Args:
+///     target: The target.
+///
+/// Returns:
+///     float: The difficulty (the maximum target divided by `target`).
+///
+/// Raises:
+///     ValueError: If the target is zero.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "target_to_difficulty")]
+pub fn py_target_to_difficulty(target: Bound<'_, PyInt>) -> PyResult<f64> {
+    let target = uint256_to_f64(uint256_from_py(&target)?);
+    if target == 0.0 {
+        return Err(PyValueError::new_err("target must not be zero"));
+    }
+    Ok(MAX_DIFFICULTY_TARGET_AS_F64 / target)
+}
+
+/// Convert a difficulty to a target, e.g. a pool share target.
+///
+/// Args:
+///     difficulty: The difficulty (at least 1).
+///
+/// Returns:
+///     int: The target.
+///
+/// Raises:
+///     ValueError: If the difficulty is below 1 or not finite.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "difficulty_to_target")]
+#[gen_stub(override_return_type(type_repr = "builtins.int"))]
+pub fn py_difficulty_to_target(py: Python<'_>, difficulty: f64) -> PyResult<Bound<'_, PyAny>> {
+    if !difficulty.is_finite() || difficulty < 1.0 {
+        return Err(PyValueError::new_err(format!(
+            "difficulty must be a finite number of at least 1, got {difficulty}"
+        )));
+    }
+    (MAX_DIFFICULTY_TARGET_AS_F64 / difficulty)
+        .into_pyobject(py)?
+        .call_method0("__int__")
+}
+
+/// Estimate the network hashrate from block headers.
+///
+/// Local counterpart of `RpcClient.estimate_network_hashes_per_second()`: the
+/// blue work accumulated between the headers with the lowest and highest blue
+/// work, divided by the time between them.
+///
+/// Args:
+///     headers: Headers (dicts with `blueWork` and `timestamp` keys), e.g. from `get_headers()` or `get_block()`.
+///
+/// Returns:
+///     float: The estimated hashes per second.
+///
+/// Raises:
+///     ValueError: If fewer than two headers are given or they span no time.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "estimate_hashrate")]
+pub fn py_estimate_hashrate(
+    #[gen_stub(override_type(type_repr = "typing.Sequence[dict]"))] headers: Vec<Bound<'_, PyDict>>,
+) -> PyResult<f64> {
+    let mut samples = headers
+        .iter()
+        .map(|header| {
+            let blue_work = header
+                .get_item("blueWork")?
+                .ok_or_else(|| PyValueError::new_err("Header has no `blueWork` key"))?;
+            let blue_work = match blue_work.extract::<String>() {
+                Ok(hex) => header
+                    .py()
+                    .get_type::<PyInt>()
+                    .call1((hex, 16))?
+                    .cast_into::<PyInt>()?,
+                Err(_) => blue_work.cast_into::<PyInt>()?,
+            };
+            let timestamp: u64 = header
+                .get_item("timestamp")?
+                .ok_or_else(|| PyValueError::new_err("Header has no `timestamp` key"))?
+                .extract()?;
+            Ok((uint256_from_py(&blue_work)?, timestamp))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    if samples.len() < 2 {
+        return Err(PyValueError::new_err("At least two headers are required"));
+    }
+    samples.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let (low_work, low_timestamp) = samples[0];
+    let (high_work, high_timestamp) = samples[samples.len() - 1];
+    if high_timestamp <= low_timestamp {
+        return Err(PyValueError::new_err(
+            "Headers span no time; pass headers from a wider window",
+        ));
+    }
+    let work = uint256_to_f64(high_work - low_work);
+    Ok(work * 1000.0 / (high_timestamp - low_timestamp) as f64)
+}
+
+fn uint256_to_f64(value: Uint256) -> f64 {
+    value
+        .to_le_bytes()
+        .iter()
+        .rev()
+        .fold(0.0, |acc, byte| acc * 256.0 + *byte as f64)
+}
+
+fn uint256_to_py(py: Python<'_>, value: Uint256) -> PyResult<Bound<'_, PyAny>> {
+    py.get_type::<PyInt>().call_method1(
+        "from_bytes",
+        (PyBytes::new(py, &value.to_le_bytes()), "little"),
+    )
+}
+
+fn uint256_from_py(value: &Bound<'_, PyInt>) -> PyResult<Uint256> {
+    let bytes = value
+        .call_method1("to_bytes", (32, "little"))
+        .map_err(|_| PyException::new_err("target must be a non-negative 256-bit integer"))?;
+    let bytes: [u8; 32] = bytes
+        .cast::<PyBytes>()?
+        .as_bytes()
+        .try_into()
+        .map_err(|_| PyException::new_err("target must be a 256-bit integer"))?;
+    Ok(Uint256::from_le_bytes(bytes))
+}
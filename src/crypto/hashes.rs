@@ -1,3 +1,5 @@
+use crate::types::PyBinary;
+use crate::validation;
 use kaspa_hashes::Hash;
 use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use pyo3_stub_gen::derive::*;
@@ -23,14 +25,43 @@ impl PyHash {
     ///     Hash: A new Hash instance.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid.
+    ///     ValueError: If the hex string is invalid.
     #[new]
     pub fn constructor(hex_str: &str) -> PyResult<Self> {
-        let inner =
-            Hash::from_str(hex_str).map_err(|err| PyException::new_err(format!("{}", err)))?;
+        validation::hex("hex_str", hex_str, Some(64))?;
+        let inner = Hash::from_str(hex_str).map_err(|err| validation::invalid("hex_str", err))?;
         Ok(Self(inner))
     }
 
+    /// Create a Hash from its 32-byte binary form.
+    ///
+    /// Args:
+    ///     data: The hash as bytes, bytearray, memoryview, or hex string.
+    ///
+    /// Returns:
+    ///     Hash: A new Hash instance.
+    ///
+    /// Raises:
+    ///     Exception: If the data is not exactly 32 bytes.
+    #[staticmethod]
+    pub fn from_bytes(data: PyBinary) -> PyResult<Self> {
+        let bytes: [u8; 32] = data.data.as_slice().try_into().map_err(|_| {
+            PyException::new_err(format!(
+                "Hash must be 32 bytes, got {} bytes",
+                data.data.len()
+            ))
+        })?;
+        Ok(Self(Hash::from_bytes(bytes)))
+    }
+
+    /// Convert the hash to bytes.
+    ///
+    /// Returns:
+    ///     bytes: The 32-byte hash.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.as_bytes())
+    }
+
     /// Convert the hash to a hex string.
     ///
     /// Returns:
@@ -0,0 +1,529 @@
+//! Columnar exports of UTXO sets, balances and transaction history to
+//! Apache Arrow tables and pandas DataFrames, and of block headers to NumPy arrays.
+//!
+//! Arrow and NumPy buffers are assembled in Rust and handed to `pyarrow` /
+//! `numpy` as is, without creating a Python object per value.
+
+use crate::wallet::core::utxo::context::extract_entries;
+use kaspa_utils::hex::ToHex;
+use pyo3::{
+    exceptions::{PyException, PyImportError},
+    prelude::*,
+    types::{PyByteArray, PyBytes, PyDict, PyList},
+};
+
+/// Register the `dataframe` submodule functions.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_utxos_to_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(py_utxos_to_pandas, m)?)?;
+    m.add_function(wrap_pyfunction!(py_balances_to_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(py_balances_to_pandas, m)?)?;
+    m.add_function(wrap_pyfunction!(py_transactions_to_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(py_transactions_to_pandas, m)?)?;
+    m.add_function(wrap_pyfunction!(py_headers_to_arrays, m)?)?;
+    Ok(())
+}
+
+/// UTXO entries as an Arrow table.
+///
+/// Columns: `address`, `transaction_id`, `index`, `amount`, `script_version`,
+/// `script_public_key` (hex), `block_daa_score` and `is_coinbase`.
+///
+/// Args:
+///     entries: A UtxoContext (mature and pending entries) or a list of UTXO entries.
+///
+/// Returns:
+///     pyarrow.Table: One row per UTXO entry.
+///
+/// Raises:
+///     ImportError: If pyarrow is not installed.
+#[pyfunction]
+#[pyo3(name = "utxos_to_arrow")]
+fn py_utxos_to_arrow<'py>(entries: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = entries.py();
+    let entries = extract_entries(&entries)?;
+
+    let mut table = ArrowTable::new(py)?;
+    table.string(
+        "address",
+        entries
+            .iter()
+            .map(|entry| entry.utxo.address.as_ref().map(ToString::to_string)),
+    )?;
+    table.string(
+        "transaction_id",
+        entries
+            .iter()
+            .map(|entry| Some(entry.id().transaction_id.to_string())),
+    )?;
+    table.primitive(
+        "index",
+        "uint32",
+        entries.iter().map(|entry| Some(entry.id().index)),
+        u32::to_le_bytes,
+    )?;
+    table.primitive(
+        "amount",
+        "uint64",
+        entries.iter().map(|entry| Some(entry.amount())),
+        u64::to_le_bytes,
+    )?;
+    table.primitive(
+        "script_version",
+        "uint16",
+        entries
+            .iter()
+            .map(|entry| Some(entry.script_public_key().version())),
+        u16::to_le_bytes,
+    )?;
+    table.string(
+        "script_public_key",
+        entries
+            .iter()
+            .map(|entry| Some(entry.script_public_key().script().to_vec().to_hex())),
+    )?;
+    table.primitive(
+        "block_daa_score",
+        "uint64",
+        entries.iter().map(|entry| Some(entry.block_daa_score())),
+        u64::to_le_bytes,
+    )?;
+    table.boolean(
+        "is_coinbase",
+        entries.iter().map(|entry| Some(entry.is_coinbase())),
+    )?;
+    table.finish()
+}
+
+/// UTXO entries as a pandas DataFrame.
+///
+/// See `utxos_to_arrow()` for the columns.
+///
+/// Args:
+///     entries: A UtxoContext (mature and pending entries) or a list of UTXO entries.
+///
+/// Returns:
+///     pandas.DataFrame: One row per UTXO entry.
+///
+/// Raises:
+///     ImportError: If pyarrow or pandas is not installed.
+#[pyfunction]
+#[pyo3(name = "utxos_to_pandas")]
+fn py_utxos_to_pandas<'py>(entries: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    py_utxos_to_arrow(entries)?.call_method0("to_pandas")
+}
+
+/// Balances by address as an Arrow table.
+///
+/// Columns: `address` and `balance` (null when the node did not report one).
+///
+/// Args:
+///     balances: A `RpcClient.get_balances_by_addresses()` response or its `entries` list.
+///
+/// Returns:
+///     pyarrow.Table: One row per address.
+///
+/// Raises:
+///     ImportError: If pyarrow is not installed.
+#[pyfunction]
+#[pyo3(name = "balances_to_arrow")]
+fn py_balances_to_arrow<'py>(balances: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = balances.py();
+    let entries = match balances.cast::<PyDict>() {
+        Ok(response) => response
+            .get_item("entries")?
+            .ok_or_else(|| PyException::new_err("Key `entries` not present"))?,
+        Err(_) => balances,
+    };
+    let entries = entries
+        .cast::<PyList>()?
+        .iter()
+        .map(|entry| {
+            let entry = entry.cast_into::<PyDict>()?;
+            Ok((
+                required::<String>(&entry, "address")?,
+                optional::<u64>(&entry, "balance")?,
+            ))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut table = ArrowTable::new(py)?;
+    table.string(
+        "address",
+        entries.iter().map(|(address, _)| Some(address.clone())),
+    )?;
+    table.primitive(
+        "balance",
+        "uint64",
+        entries.iter().map(|(_, balance)| *balance),
+        u64::to_le_bytes,
+    )?;
+    table.finish()
+}
+
+/// Balances by address as a pandas DataFrame.
+///
+/// See `balances_to_arrow()` for the columns.
+///
+/// Args:
+///     balances: A `RpcClient.get_balances_by_addresses()` response or its `entries` list.
+///
+/// Returns:
+///     pandas.DataFrame: One row per address.
+///
+/// Raises:
+///     ImportError: If pyarrow or pandas is not installed.
+#[pyfunction]
+#[pyo3(name = "balances_to_pandas")]
+fn py_balances_to_pandas<'py>(balances: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    py_balances_to_arrow(balances)?.call_method0("to_pandas")
+}
+
+/// Transaction records as an Arrow table.
+///
+/// Columns: `id`, `type` (e.g. `incoming`, `outgoing`), `value`,
+/// `block_daa_score`, `unixtime_msec`, `network` and `note`.
+///
+/// Args:
+///     records: Transaction records, i.e. the `data` of `pending`, `maturity`,
+///         `discovery`, `reorg` and `stasis` UtxoProcessor events.
+///
+/// Returns:
+///     pyarrow.Table: One row per record.
+///
+/// Raises:
+///     ImportError: If pyarrow is not installed.
+#[pyfunction]
+#[pyo3(name = "transactions_to_arrow")]
+fn py_transactions_to_arrow<'py>(records: Bound<'py, PyList>) -> PyResult<Bound<'py, PyAny>> {
+    let py = records.py();
+    let records = records
+        .iter()
+        .map(|record| TransactionRow::try_from(&record.cast_into::<PyDict>()?))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut table = ArrowTable::new(py)?;
+    table.string("id", records.iter().map(|record| Some(record.id.clone())))?;
+    table.string("type", records.iter().map(|record| record.kind.clone()))?;
+    table.primitive(
+        "value",
+        "uint64",
+        records.iter().map(|record| Some(record.value)),
+        u64::to_le_bytes,
+    )?;
+    table.primitive(
+        "block_daa_score",
+        "uint64",
+        records.iter().map(|record| Some(record.block_daa_score)),
+        u64::to_le_bytes,
+    )?;
+    table.primitive(
+        "unixtime_msec",
+        "uint64",
+        records.iter().map(|record| record.unixtime_msec),
+        u64::to_le_bytes,
+    )?;
+    table.string(
+        "network",
+        records.iter().map(|record| record.network.clone()),
+    )?;
+    table.string("note", records.iter().map(|record| record.note.clone()))?;
+    table.finish()
+}
+
+/// Transaction records as a pandas DataFrame.
+///
+/// See `transactions_to_arrow()` for the columns.
+///
+/// Args:
+///     records: Transaction records, i.e. the `data` of `pending`, `maturity`,
+///         `discovery`, `reorg` and `stasis` UtxoProcessor events.
+///
+/// Returns:
+///     pandas.DataFrame: One row per record.
+///
+/// Raises:
+///     ImportError: If pyarrow or pandas is not installed.
+#[pyfunction]
+#[pyo3(name = "transactions_to_pandas")]
+fn py_transactions_to_pandas<'py>(records: Bound<'py, PyList>) -> PyResult<Bound<'py, PyAny>> {
+    py_transactions_to_arrow(records)?.call_method0("to_pandas")
+}
+
+/// Numeric block header fields: name, RPC key and NumPy dtype.
+const HEADER_FIELDS: &[(&str, &str, &str)] = &[
+    ("version", "version", "<u2"),
+    ("timestamp", "timestamp", "<u8"),
+    ("bits", "bits", "<u4"),
+    ("nonce", "nonce", "<u8"),
+    ("daa_score", "daaScore", "<u8"),
+    ("blue_score", "blueScore", "<u8"),
+];
+
+/// Block header fields as NumPy arrays.
+///
+/// Values are read in Rust and copied into one contiguous buffer per field,
+/// which is far faster than accessing the fields of each header from Python.
+///
+/// Args:
+///     headers: Block headers, blocks (with a `header` key), or a
+///         `RpcClient.get_blocks()` / `get_headers()` response.
+///     fields: Fields to extract among `version`, `timestamp`, `bits`, `nonce`,
+///         `daa_score` and `blue_score` (default: all of them).
+///
+/// Returns:
+///     dict[str, numpy.ndarray]: One unsigned integer array per field, in header order.
+///
+/// Raises:
+///     ImportError: If numpy is not installed.
+///     Exception: If a field is unknown or missing from a header.
+#[pyfunction]
+#[pyo3(name = "headers_to_arrays")]
+#[pyo3(signature = (headers, fields=None))]
+fn py_headers_to_arrays<'py>(
+    headers: Bound<'py, PyAny>,
+    fields: Option<Vec<String>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = headers.py();
+    let fields = match fields {
+        Some(fields) => fields
+            .iter()
+            .map(|field| {
+                HEADER_FIELDS
+                    .iter()
+                    .find(|(name, _, _)| name == field)
+                    .ok_or_else(|| {
+                        PyException::new_err(format!(
+                            "Unknown header field `{field}`, expected one of: {}",
+                            HEADER_FIELDS
+                                .iter()
+                                .map(|(name, _, _)| *name)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                    })
+            })
+            .collect::<PyResult<Vec<_>>>()?,
+        None => HEADER_FIELDS.iter().collect(),
+    };
+
+    let headers = match headers.cast::<PyDict>() {
+        Ok(response) => response
+            .get_item("blocks")?
+            .or(response.get_item("headers")?)
+            .ok_or_else(|| PyException::new_err("Key `blocks` or `headers` not present"))?,
+        Err(_) => headers,
+    };
+    let headers = headers.cast::<PyList>()?;
+
+    let mut columns = vec![Vec::new(); fields.len()];
+    for header in headers.iter() {
+        let header = header.cast_into::<PyDict>()?;
+        let header = match header.get_item("header")? {
+            Some(inner) => inner.cast_into::<PyDict>()?,
+            None => header,
+        };
+        for ((_, key, dtype), column) in fields.iter().zip(columns.iter_mut()) {
+            let value = required::<u64>(&header, key)?;
+            match *dtype {
+                "<u2" => column.extend((value as u16).to_le_bytes()),
+                "<u4" => column.extend((value as u32).to_le_bytes()),
+                _ => column.extend(value.to_le_bytes()),
+            }
+        }
+    }
+
+    let numpy = py.import("numpy").map_err(|_| {
+        PyImportError::new_err("numpy is required for array exports: pip install kaspa[dataframe]")
+    })?;
+    let arrays = PyDict::new(py);
+    for ((name, _, dtype), column) in fields.iter().zip(columns) {
+        let array = numpy.call_method1("frombuffer", (PyByteArray::new(py, &column), *dtype))?;
+        arrays.set_item(*name, array)?;
+    }
+    Ok(arrays)
+}
+
+fn required<'py, T: FromPyObjectOwned<'py>>(dict: &Bound<'py, PyDict>, key: &str) -> PyResult<T> {
+    optional(dict, key)?.ok_or_else(|| PyException::new_err(format!("Key `{key}` not present")))
+}
+
+fn optional<'py, T: FromPyObjectOwned<'py>>(
+    dict: &Bound<'py, PyDict>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    match dict.get_item(key)? {
+        Some(value) if !value.is_none() => Ok(Some(value.extract::<T>().map_err(Into::into)?)),
+        _ => Ok(None),
+    }
+}
+
+/// The exported fields of a serialized `TransactionRecord`.
+struct TransactionRow {
+    id: String,
+    kind: Option<String>,
+    value: u64,
+    block_daa_score: u64,
+    unixtime_msec: Option<u64>,
+    network: Option<String>,
+    note: Option<String>,
+}
+
+impl TryFrom<&Bound<'_, PyDict>> for TransactionRow {
+    type Error = PyErr;
+
+    fn try_from(record: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let kind = match record.get_item("data")? {
+            Some(data) => match data.cast_into::<PyDict>() {
+                Ok(data) => optional(&data, "type")?,
+                Err(_) => None,
+            },
+            None => None,
+        };
+        Ok(Self {
+            id: required(record, "id")?,
+            kind,
+            value: required(record, "value")?,
+            block_daa_score: required(record, "blockDaaScore")?,
+            unixtime_msec: optional(record, "unixtimeMsec")?,
+            network: optional(record, "network")?,
+            note: optional(record, "note")?,
+        })
+    }
+}
+
+/// Bit-packed Arrow buffer, used for validity bitmaps and boolean values.
+#[derive(Default)]
+struct Bitmap {
+    bits: Vec<u8>,
+    len: usize,
+    unset: usize,
+}
+
+impl Bitmap {
+    fn push(&mut self, bit: bool) {
+        if self.len % 8 == 0 {
+            self.bits.push(0);
+        }
+        if bit {
+            self.bits[self.len / 8] |= 1 << (self.len % 8);
+        } else {
+            self.unset += 1;
+        }
+        self.len += 1;
+    }
+}
+
+/// Arrow table assembled column by column from raw buffers.
+struct ArrowTable<'py> {
+    pyarrow: Bound<'py, PyModule>,
+    names: Vec<&'static str>,
+    arrays: Vec<Bound<'py, PyAny>>,
+}
+
+impl<'py> ArrowTable<'py> {
+    fn new(py: Python<'py>) -> PyResult<Self> {
+        let pyarrow = py.import("pyarrow").map_err(|_| {
+            PyImportError::new_err(
+                "pyarrow is required for dataframe exports: pip install kaspa[dataframe]",
+            )
+        })?;
+        Ok(Self {
+            pyarrow,
+            names: Vec::new(),
+            arrays: Vec::new(),
+        })
+    }
+
+    fn primitive<T, const N: usize>(
+        &mut self,
+        name: &'static str,
+        data_type: &str,
+        values: impl Iterator<Item = Option<T>>,
+        to_le_bytes: fn(T) -> [u8; N],
+    ) -> PyResult<()> {
+        let mut validity = Bitmap::default();
+        let mut data = Vec::new();
+        for value in values {
+            validity.push(value.is_some());
+            data.extend(value.map(to_le_bytes).unwrap_or([0; N]));
+        }
+        self.push(name, data_type, validity, vec![data])
+    }
+
+    fn boolean(
+        &mut self,
+        name: &'static str,
+        values: impl Iterator<Item = Option<bool>>,
+    ) -> PyResult<()> {
+        let mut validity = Bitmap::default();
+        let mut data = Bitmap::default();
+        for value in values {
+            validity.push(value.is_some());
+            data.push(value.unwrap_or_default());
+        }
+        self.push(name, "bool_", validity, vec![data.bits])
+    }
+
+    fn string(
+        &mut self,
+        name: &'static str,
+        values: impl Iterator<Item = Option<String>>,
+    ) -> PyResult<()> {
+        let mut validity = Bitmap::default();
+        let mut offsets = 0i32.to_le_bytes().to_vec();
+        let mut data = Vec::new();
+        for value in values {
+            validity.push(value.is_some());
+            data.extend(value.unwrap_or_default().into_bytes());
+            let offset = i32::try_from(data.len()).map_err(|_| {
+                PyException::new_err(format!("Column `{name}` exceeds 2 GiB of string data"))
+            })?;
+            offsets.extend(offset.to_le_bytes());
+        }
+        self.push(name, "string", validity, vec![offsets, data])
+    }
+
+    fn push(
+        &mut self,
+        name: &'static str,
+        data_type: &str,
+        validity: Bitmap,
+        data: Vec<Vec<u8>>,
+    ) -> PyResult<()> {
+        let py = self.pyarrow.py();
+        let buffer = |bytes: &[u8]| {
+            self.pyarrow
+                .call_method1("py_buffer", (PyBytes::new(py, bytes),))
+        };
+
+        let mut buffers = vec![match validity.unset {
+            0 => None,
+            _ => Some(buffer(&validity.bits)?),
+        }];
+        for bytes in &data {
+            buffers.push(Some(buffer(bytes)?));
+        }
+
+        let array = self.pyarrow.getattr("Array")?.call_method1(
+            "from_buffers",
+            (
+                self.pyarrow.call_method0(data_type)?,
+                validity.len,
+                buffers,
+                validity.unset,
+            ),
+        )?;
+        self.names.push(name);
+        self.arrays.push(array);
+        Ok(())
+    }
+
+    fn finish(self) -> PyResult<Bound<'py, PyAny>> {
+        let kwargs = PyDict::new(self.pyarrow.py());
+        kwargs.set_item("names", self.names)?;
+        self.pyarrow
+            .getattr("Table")?
+            .call_method("from_arrays", (self.arrays,), Some(&kwargs))
+    }
+}
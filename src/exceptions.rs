@@ -0,0 +1,300 @@
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+use regex::Regex;
+use std::sync::LazyLock;
+
+create_exception!(
+    kaspa.exceptions,
+    TransactionRejectedError,
+    PyException,
+    "A transaction was rejected by the node.
+
+Attributes:
+    reason (str): `already_accepted`, `duplicate`, `double_spend`, `orphan`,
+        `insufficient_fee`, `mass_exceeded`, `bad_signature`, `non_standard` or `unknown`.
+    transaction_id (str | None): The id of the rejected transaction.
+    input_index (int | None): The offending input, when reported by the node.
+    fee (int | None): The fee paid, for `insufficient_fee`.
+    required_fee (int | None): The minimum fee required, for `insufficient_fee`.
+    mass (int | None): The transaction mass, for `mass_exceeded`.
+    max_mass (int | None): The maximum allowed mass, for `mass_exceeded`."
+);
+
+create_exception!(
+    kaspa.exceptions,
+    ScriptVerificationError,
+    PyException,
+    "A transaction input failed local script verification.
+
+Attributes:
+    transaction_id (str): The id of the transaction.
+    input_index (int): The input whose script execution failed.
+    error (str): The script engine error."
+);
+
+create_exception!(
+    kaspa.exceptions,
+    PolicyViolationError,
+    PyException,
+    "A signing policy vetoed signing a transaction (see `add_signing_policy()`).
+
+Attributes:
+    transaction_id (str): The id of the transaction.
+    reason (str): The reason given by the policy."
+);
+
+/// Register the custom exceptions to the `exceptions` submodule.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add(
+        "TransactionRejectedError",
+        m.py().get_type::<TransactionRejectedError>(),
+    )?;
+    m.add(
+        "ScriptVerificationError",
+        m.py().get_type::<ScriptVerificationError>(),
+    )?;
+    m.add(
+        "PolicyViolationError",
+        m.py().get_type::<PolicyViolationError>(),
+    )?;
+    Ok(())
+}
+
+static TRANSACTION_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"transaction ([0-9a-f]{64})").unwrap());
+static INPUT_INDEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"input (?:index )?#?(\d+)").unwrap());
+static INSUFFICIENT_FEE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\d+) fees? which is under the required amount of (\d+)").unwrap()
+});
+static MASS_EXCEEDED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"mass (?:of )?(\d+) (?:is larger|is higher|exceeds)[a-z ]* (?:of )?(\d+)").unwrap()
+});
+
+/// A node rejection message decoded into its reason and details.
+#[derive(Debug, Default)]
+pub(crate) struct Rejection {
+    pub reason: &'static str,
+    pub transaction_id: Option<String>,
+    pub input_index: Option<u32>,
+    pub fee: Option<u64>,
+    pub required_fee: Option<u64>,
+    pub mass: Option<u64>,
+    pub max_mass: Option<u64>,
+}
+
+impl Rejection {
+    /// Decode a rejection message. Returns `None` for errors that are not
+    /// transaction rejections (e.g. connection errors).
+    pub fn parse(message: &str) -> Option<Self> {
+        let lowercase = message.to_lowercase();
+        let contains = |pattern: &str| lowercase.contains(pattern);
+        let capture = |regex: &Regex, group: usize| {
+            regex
+                .captures(&lowercase)
+                .and_then(|captures| captures.get(group)?.as_str().parse().ok())
+        };
+
+        let mut rejection = Self {
+            transaction_id: TRANSACTION_ID
+                .captures(&lowercase)
+                .map(|captures| captures[1].to_string()),
+            input_index: capture(&INPUT_INDEX, 1),
+            ..Default::default()
+        };
+
+        rejection.reason = if contains("already accepted") {
+            "already_accepted"
+        } else if contains("already in the mempool") {
+            "duplicate"
+        } else if contains("already spent") || contains("double spend") {
+            "double_spend"
+        } else if contains("orphan") {
+            "orphan"
+        } else if INSUFFICIENT_FEE.is_match(&lowercase)
+            || (contains("fee") && (contains("insufficient") || contains("too low")))
+        {
+            rejection.fee = capture(&INSUFFICIENT_FEE, 1);
+            rejection.required_fee = capture(&INSUFFICIENT_FEE, 2);
+            "insufficient_fee"
+        } else if MASS_EXCEEDED.is_match(&lowercase)
+            || (contains("mass") && (contains("too high") || contains("exceed")))
+        {
+            rejection.mass = capture(&MASS_EXCEEDED, 1);
+            rejection.max_mass = capture(&MASS_EXCEEDED, 2);
+            "mass_exceeded"
+        } else if (contains("sig") || contains("script"))
+            && (contains("invalid") || contains("verify") || contains("false"))
+        {
+            "bad_signature"
+        } else if contains("not standard") || contains("non-standard") {
+            "non_standard"
+        } else if contains("reject") {
+            "unknown"
+        } else {
+            return None;
+        };
+
+        Some(rejection)
+    }
+}
+
+/// Convert a transaction submission error into a `TransactionRejectedError`
+/// when it is a node rejection, or a plain `Exception` otherwise.
+///
+/// `transaction_id`, when known, takes precedence over an id found in the message.
+pub(crate) fn transaction_rejected(
+    py: Python,
+    message: String,
+    transaction_id: Option<String>,
+) -> PyErr {
+    let Some(rejection) = Rejection::parse(&message) else {
+        return PyException::new_err(message);
+    };
+
+    let err = TransactionRejectedError::new_err(message);
+    let value = err.value(py);
+    let attributes = || -> PyResult<()> {
+        value.setattr("reason", rejection.reason)?;
+        value.setattr(
+            "transaction_id",
+            transaction_id.or(rejection.transaction_id),
+        )?;
+        value.setattr("input_index", rejection.input_index)?;
+        value.setattr("fee", rejection.fee)?;
+        value.setattr("required_fee", rejection.required_fee)?;
+        value.setattr("mass", rejection.mass)?;
+        value.setattr("max_mass", rejection.max_mass)?;
+        Ok(())
+    };
+    match attributes() {
+        Ok(()) => err,
+        Err(attribute_err) => attribute_err,
+    }
+}
+
+/// Create a `ScriptVerificationError` for an input failing script execution.
+pub(crate) fn script_verification_failed(
+    py: Python,
+    transaction_id: String,
+    input_index: usize,
+    error: String,
+) -> PyErr {
+    let err = ScriptVerificationError::new_err(format!(
+        "Script verification of input {input_index} of transaction {transaction_id} failed: {error}"
+    ));
+    let value = err.value(py);
+    let attributes = || -> PyResult<()> {
+        value.setattr("transaction_id", transaction_id)?;
+        value.setattr("input_index", input_index)?;
+        value.setattr("error", error)?;
+        Ok(())
+    };
+    match attributes() {
+        Ok(()) => err,
+        Err(attribute_err) => attribute_err,
+    }
+}
+
+/// Create a `PolicyViolationError` for a transaction vetoed by a signing policy.
+pub(crate) fn policy_violation(py: Python, transaction_id: String, reason: String) -> PyErr {
+    let err = PolicyViolationError::new_err(format!(
+        "Signing transaction {transaction_id} vetoed by policy: {reason}"
+    ));
+    let value = err.value(py);
+    let attributes = || -> PyResult<()> {
+        value.setattr("transaction_id", transaction_id)?;
+        value.setattr("reason", reason)?;
+        Ok(())
+    };
+    match attributes() {
+        Ok(()) => err,
+        Err(attribute_err) => attribute_err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "abababababababababababababababababababababababababababababababab";
+
+    fn parse(message: &str) -> Rejection {
+        Rejection::parse(message).expect("a transaction rejection")
+    }
+
+    #[test]
+    fn orphan() {
+        let rejection = parse(&format!(
+            "Rejected transaction {ID}: transaction {ID} is an orphan where orphan is disallowed"
+        ));
+        assert_eq!(rejection.reason, "orphan");
+        assert_eq!(rejection.transaction_id.as_deref(), Some(ID));
+    }
+
+    #[test]
+    fn already_known() {
+        let accepted = parse(&format!(
+            "transaction {ID} was already accepted by the consensus"
+        ));
+        assert_eq!(accepted.reason, "already_accepted");
+        let duplicate = parse(&format!("transaction {ID} is already in the mempool"));
+        assert_eq!(duplicate.reason, "duplicate");
+    }
+
+    #[test]
+    fn double_spend() {
+        let rejection = parse(&format!(
+            "output ({ID}, 0) already spent by transaction {ID} in the memory pool"
+        ));
+        assert_eq!(rejection.reason, "double_spend");
+    }
+
+    #[test]
+    fn insufficient_fee() {
+        let rejection = parse(&format!(
+            "Rejected transaction {ID}: transaction {ID} has 1000 fees which is under the required amount of 2036"
+        ));
+        assert_eq!(rejection.reason, "insufficient_fee");
+        assert_eq!(rejection.fee, Some(1000));
+        assert_eq!(rejection.required_fee, Some(2036));
+    }
+
+    #[test]
+    fn mass_exceeded() {
+        let rejection = parse(&format!(
+            "transaction {ID} is not standard: transaction storage mass of 200000 is larger than max allowed size of 100000"
+        ));
+        assert_eq!(rejection.reason, "mass_exceeded");
+        assert_eq!(rejection.mass, Some(200000));
+        assert_eq!(rejection.max_mass, Some(100000));
+    }
+
+    #[test]
+    fn bad_signature() {
+        let rejection = parse(&format!(
+            "Rejected transaction {ID}: signature script of input #1 is invalid: false stack entry at end of script execution"
+        ));
+        assert_eq!(rejection.reason, "bad_signature");
+        assert_eq!(rejection.input_index, Some(1));
+    }
+
+    #[test]
+    fn non_standard() {
+        let rejection = parse(&format!(
+            "transaction {ID} is not standard: transaction version 1 is not in the valid range"
+        ));
+        assert_eq!(rejection.reason, "non_standard");
+    }
+
+    #[test]
+    fn unknown_rejection() {
+        let rejection = parse(&format!("Rejected transaction {ID}: something else"));
+        assert_eq!(rejection.reason, "unknown");
+        assert_eq!(rejection.input_index, None);
+    }
+
+    #[test]
+    fn other_errors_are_not_rejections() {
+        assert!(Rejection::parse("WebSocket is not connected").is_none());
+    }
+}
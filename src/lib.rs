@@ -1,11 +1,15 @@
 mod address;
 mod callback;
+mod compat;
 mod consensus;
 mod crypto;
+mod dataframe;
+mod exceptions;
 mod macros;
 mod rpc;
 mod traits;
 mod types;
+mod validation;
 mod wallet;
 
 use pyo3::prelude::*;
@@ -13,21 +17,38 @@ use pyo3_stub_gen::define_stub_info_gatherer;
 
 define_stub_info_gatherer!(stub_info);
 
-#[pymodule]
+// Declared free-threading safe (`gil_used = false`): module-level state is
+// either immutable, atomic (maturity settings) or behind a mutex (name
+// resolvers, signing policies, live UtxoProcessors) that never calls into
+// Python while held. Subinterpreters are not supported: PyO3 refuses to import
+// the module in a subinterpreter.
+#[pymodule(gil_used = false)]
 fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Init logging bridge
     pyo3_log::init();
 
     // Add exceptions submodule
     let exceptions = PyModule::new(py, "exceptions")?;
+    exceptions::register(&exceptions)?;
     m.add_submodule(&exceptions)?;
 
+    // Add compat submodule (cross-SDK test vectors)
+    let compat = PyModule::new(py, "compat")?;
+    compat::register(&compat)?;
+    m.add_submodule(&compat)?;
+
+    // Add dataframe submodule (Arrow / pandas exports)
+    let dataframe = PyModule::new(py, "dataframe")?;
+    dataframe::register(&dataframe)?;
+    m.add_submodule(&dataframe)?;
+
     // Register classes and functions to module
 
     m.add_class::<address::PyAddress>()?;
     m.add_class::<address::PyAddressVersion>()?;
 
     m.add_class::<consensus::client::transaction::PyTransaction>()?;
+    m.add_class::<consensus::client::cluster::PyAddressClusters>()?;
     m.add_class::<consensus::client::input::PyTransactionInput>()?;
     m.add_class::<consensus::client::outpoint::PyTransactionOutpoint>()?;
     m.add_class::<consensus::client::output::PyTransactionOutput>()?;
@@ -69,6 +90,19 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<consensus::core::network::PyNetworkType>()?;
     m.add_class::<consensus::core::script_public_key::PyScriptPublicKey>()?;
     m.add_class::<consensus::core::tx::TransactionId>()?;
+    m.add_class::<consensus::pow::PyMiningJob>()?;
+    m.add_function(wrap_pyfunction!(consensus::pow::py_bits_to_target, m)?)?;
+    m.add_function(wrap_pyfunction!(consensus::pow::py_target_to_bits, m)?)?;
+    m.add_function(wrap_pyfunction!(consensus::pow::py_bits_to_difficulty, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        consensus::pow::py_target_to_difficulty,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        consensus::pow::py_difficulty_to_target,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(consensus::pow::py_estimate_hashrate, m)?)?;
 
     m.add_class::<wallet::bip32::language::PyLanguage>()?;
     m.add_class::<wallet::bip32::phrase::PyMnemonic>()?;
@@ -90,7 +124,10 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         m
     )?)?;
 
+    m.add_class::<types::PyAmount>()?;
+    m.add_function(wrap_pyfunction!(wallet::core::utils::py_format_amount, m)?)?;
     m.add_function(wrap_pyfunction!(wallet::core::utils::py_kaspa_to_sompi, m)?)?;
+    m.add_function(wrap_pyfunction!(wallet::core::utils::py_parse_amount, m)?)?;
     m.add_function(wrap_pyfunction!(wallet::core::utils::py_sompi_to_kaspa, m)?)?;
     m.add_function(wrap_pyfunction!(
         wallet::core::utils::py_sompi_to_kaspa_string_with_suffix,
@@ -103,12 +140,19 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<wallet::core::tx::generator::generator::PyGenerator>()?;
     m.add_class::<wallet::core::tx::generator::pending::PendingTransaction>()?;
+    m.add_class::<wallet::core::tx::generator::plan::PyTransactionPlan>()?;
     m.add_class::<wallet::core::tx::generator::summary::PyGeneratorSummary>()?;
     m.add_class::<wallet::core::utxo::balance::PyBalance>()?;
     m.add_class::<wallet::core::utxo::balance::PyBalanceStrings>()?;
+    m.add_class::<wallet::core::utxo::changes::PyUtxoChanges>()?;
     m.add_class::<wallet::core::utxo::context::PyUtxoContext>()?;
     m.add_class::<wallet::core::utxo::processor::PyUtxoProcessorEvent>()?;
     m.add_class::<wallet::core::utxo::processor::PyUtxoProcessor>()?;
+    m.add_class::<wallet::core::utxo::queue::PyEventQueuePolicy>()?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::utxo::stats::py_analyze_utxos,
+        m
+    )?)?;
 
     m.add_function(wrap_pyfunction!(
         wallet::core::tx::mass::py_maximum_standard_transaction_mass,
@@ -130,7 +174,16 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::mass::py_update_unsigned_transaction_mass,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::mass::py_max_payload_size_for,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::mass::py_plan_payload,
+        m
+    )?)?;
 
+    m.add_class::<wallet::core::tx::fees::PyFees>()?;
     m.add_class::<wallet::core::tx::payment::PyPaymentOutput>()?;
 
     m.add_function(wrap_pyfunction!(
@@ -145,11 +198,31 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::utils::py_estimate_transactions,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::policy::py_add_signing_policy,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::policy::py_remove_signing_policy,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(wallet::core::tx::send::py_send, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::send::py_sweep_private_key,
+        m
+    )?)?;
 
     m.add_class::<rpc::encoding::PyEncoding>()?;
+    m.add_class::<rpc::fee::PyFeeEstimate>()?;
+    m.add_class::<rpc::fee::PyFeerateBucket>()?;
+    m.add_class::<rpc::supply::PyCoinSupply>()?;
     m.add_class::<rpc::wrpc::resolver::PyResolver>()?;
     m.add_class::<rpc::wrpc::client::PyNotificationEvent>()?;
     m.add_class::<rpc::wrpc::client::PyRpcClient>()?;
+    m.add_class::<rpc::wrpc::templates::PyBlockTemplates>()?;
+    m.add_class::<rpc::wrpc::templates::PyBlockTemplate>()?;
+    m.add_class::<rpc::wrpc::traversal::PyHeaderWalk>()?;
+    m.add_class::<rpc::wrpc::snapshot::PyUtxoStream>()?;
 
     m.add_function(wrap_pyfunction!(wallet::core::message::py_sign_message, m)?)?;
     m.add_function(wrap_pyfunction!(
@@ -164,8 +237,21 @@ fn kaspa(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<wallet::keys::publickey::PyPublicKey>()?;
     m.add_class::<wallet::keys::pubkeygen::PyPublicKeyGenerator>()?;
     m.add_class::<wallet::keys::publickey::PyXOnlyPublicKey>()?;
+    m.add_function(wrap_pyfunction!(
+        wallet::keys::vanity::py_generate_vanity_address,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(wallet::keys::shamir::py_split_secret, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::keys::shamir::py_combine_shares,
+        m
+    )?)?;
+    m.add_class::<wallet::keys::vault::PyKeyVault>()?;
+    m.add_class::<wallet::keys::vault::PyKeyHandle>()?;
     m.add_class::<wallet::keys::xprv::PyXPrv>()?;
     m.add_class::<wallet::keys::xpub::PyXPub>()?;
 
+    wallet::core::utxo::processor::register_shutdown_hook(m)?;
+
     Ok(())
 }
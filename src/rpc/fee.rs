@@ -0,0 +1,175 @@
+use kaspa_rpc_core::model::{
+    RpcFeeEstimate, RpcFeeEstimateVerboseExperimentalData, RpcFeerateBucket,
+};
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyDict, PyType},
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// A fee estimate bucket: a fee rate and the expected time to inclusion.
+///
+/// This type is not intended to be instantiated directly from Python.
+/// It is returned by `FeeEstimate` and can be passed to `Fees.from_bucket()`.
+#[gen_stub_pyclass]
+#[pyclass(name = "FeerateBucket")]
+#[derive(Clone)]
+pub struct PyFeerateBucket(RpcFeerateBucket);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFeerateBucket {
+    /// The fee rate in sompi per gram of transaction mass.
+    #[getter]
+    fn get_feerate(&self) -> f64 {
+        self.0.feerate
+    }
+
+    /// The estimated time to inclusion in seconds.
+    #[getter]
+    fn get_estimated_seconds(&self) -> f64 {
+        self.0.estimated_seconds
+    }
+
+    /// The bucket as a dict (`feerate`, `estimatedSeconds`).
+    ///
+    /// Returns:
+    ///     dict: Dictionary representation of the bucket.
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(serde_pyobject::to_pyobject(py, &self.0)?)
+    }
+}
+
+/// Fee estimate of the node's mempool.
+///
+/// Returned by `RpcClient.fee_estimate()`. Buckets are ordered from the highest
+/// fee rate (priority) to the lowest.
+#[gen_stub_pyclass]
+#[pyclass(name = "FeeEstimate")]
+pub struct PyFeeEstimate {
+    estimate: RpcFeeEstimate,
+    verbose: Option<RpcFeeEstimateVerboseExperimentalData>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFeeEstimate {
+    /// The top-priority bucket, for inclusion in the next block.
+    #[getter]
+    fn get_priority_bucket(&self) -> PyFeerateBucket {
+        PyFeerateBucket(self.estimate.priority_bucket.clone())
+    }
+
+    /// Buckets with normal priority, ordered by decreasing fee rate.
+    #[getter]
+    fn get_normal_buckets(&self) -> Vec<PyFeerateBucket> {
+        self.estimate
+            .normal_buckets
+            .iter()
+            .cloned()
+            .map(PyFeerateBucket)
+            .collect()
+    }
+
+    /// Buckets with low priority, ordered by decreasing fee rate.
+    #[getter]
+    fn get_low_buckets(&self) -> Vec<PyFeerateBucket> {
+        self.estimate
+            .low_buckets
+            .iter()
+            .cloned()
+            .map(PyFeerateBucket)
+            .collect()
+    }
+
+    /// All buckets (priority, normal and low), ordered by decreasing fee rate.
+    #[getter]
+    fn get_buckets(&self) -> Vec<PyFeerateBucket> {
+        self.buckets().cloned().map(PyFeerateBucket).collect()
+    }
+
+    /// Verbose mempool data (requested with `verbose=True`), or None.
+    ///
+    /// Contains `mempoolReadyTransactionsCount`, `mempoolReadyTransactionsTotalMass`,
+    /// `networkMassPerSecond` and `nextBlockTemplateFeerateMin`/`Median`/`Max`.
+    #[getter]
+    #[gen_stub(override_return_type(type_repr = "typing.Optional[dict]"))]
+    fn get_verbose<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.verbose
+            .as_ref()
+            .map(|verbose| serde_pyobject::to_pyobject(py, verbose).map_err(Into::into))
+            .transpose()
+    }
+
+    /// Create a FeeEstimate from a `RpcClient.get_fee_estimate()` or
+    /// `RpcClient.get_fee_estimate_experimental()` response dict.
+    ///
+    /// Args:
+    ///     dict: The response dict (with an `estimate` key and optional `verbose` key).
+    ///
+    /// Returns:
+    ///     FeeEstimate: A new FeeEstimate instance.
+    ///
+    /// Raises:
+    ///     Exception: If the dict is not a fee estimate response.
+    #[classmethod]
+    fn from_dict(_cls: &Bound<'_, PyType>, dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let estimate = dict
+            .get_item("estimate")?
+            .ok_or_else(|| PyException::new_err("Key `estimate` not present"))?;
+        let verbose = dict
+            .get_item("verbose")?
+            .filter(|verbose| !verbose.is_none())
+            .map(serde_pyobject::from_pyobject)
+            .transpose()?;
+        Ok(Self {
+            estimate: serde_pyobject::from_pyobject(estimate)?,
+            verbose,
+        })
+    }
+
+    /// The cheapest bucket expected to be included within a deadline.
+    ///
+    /// Args:
+    ///     seconds: The maximum acceptable time to inclusion in seconds.
+    ///
+    /// Returns:
+    ///     FeerateBucket: The lowest fee rate bucket with `estimated_seconds <= seconds`,
+    ///         or the priority bucket if no bucket meets the deadline.
+    fn bucket_for_deadline(&self, seconds: f64) -> PyFeerateBucket {
+        let bucket = self
+            .buckets()
+            .filter(|bucket| bucket.estimated_seconds <= seconds)
+            .min_by(|a, b| a.feerate.total_cmp(&b.feerate))
+            .unwrap_or(&self.estimate.priority_bucket);
+        PyFeerateBucket(bucket.clone())
+    }
+
+    /// The response as a dict (`estimate` and `verbose`).
+    ///
+    /// Returns:
+    ///     dict: Dictionary representation of the fee estimate.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("estimate", serde_pyobject::to_pyobject(py, &self.estimate)?)?;
+        dict.set_item("verbose", self.get_verbose(py)?)?;
+        Ok(dict)
+    }
+}
+
+impl PyFeeEstimate {
+    pub fn new(
+        estimate: RpcFeeEstimate,
+        verbose: Option<RpcFeeEstimateVerboseExperimentalData>,
+    ) -> Self {
+        Self { estimate, verbose }
+    }
+
+    fn buckets(&self) -> impl Iterator<Item = &RpcFeerateBucket> {
+        std::iter::once(&self.estimate.priority_bucket)
+            .chain(self.estimate.normal_buckets.iter())
+            .chain(self.estimate.low_buckets.iter())
+    }
+}
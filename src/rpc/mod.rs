@@ -1,5 +1,7 @@
 pub mod encoding;
+pub mod fee;
 mod messages;
 mod model;
 mod notification;
+pub mod supply;
 pub mod wrpc;
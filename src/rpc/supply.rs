@@ -0,0 +1,97 @@
+use kaspa_rpc_core::model::GetCoinSupplyResponse;
+use kaspa_wallet_core::utils::sompi_to_kaspa_string;
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyDict, PyType},
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// The coin supply of the network.
+///
+/// Returned by `RpcClient.coin_supply()`. Amounts are available in sompi
+/// and as formatted KAS strings (e.g. "28,704,026,601.00000000").
+#[gen_stub_pyclass]
+#[pyclass(name = "CoinSupply")]
+pub struct PyCoinSupply(GetCoinSupplyResponse);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyCoinSupply {
+    /// The maximum supply in sompi.
+    #[getter]
+    fn get_max_supply(&self) -> u64 {
+        self.0.max_sompi
+    }
+
+    /// The circulating supply in sompi.
+    #[getter]
+    fn get_circulating(&self) -> u64 {
+        self.0.circulating_sompi
+    }
+
+    /// The maximum supply as a formatted KAS string.
+    #[getter]
+    fn get_max_supply_kas(&self) -> String {
+        sompi_to_kaspa_string(self.0.max_sompi)
+    }
+
+    /// The circulating supply as a formatted KAS string.
+    #[getter]
+    fn get_circulating_kas(&self) -> String {
+        sompi_to_kaspa_string(self.0.circulating_sompi)
+    }
+
+    /// The share of the maximum supply in circulation, between 0 and 1.
+    #[getter]
+    fn get_circulating_ratio(&self) -> f64 {
+        if self.0.max_sompi == 0 {
+            0.0
+        } else {
+            self.0.circulating_sompi as f64 / self.0.max_sompi as f64
+        }
+    }
+
+    /// Create a CoinSupply from a `RpcClient.get_coin_supply()` response dict.
+    ///
+    /// Args:
+    ///     dict: The response dict (with `maxSompi` and `circulatingSompi` keys).
+    ///
+    /// Returns:
+    ///     CoinSupply: A new CoinSupply instance.
+    ///
+    /// Raises:
+    ///     Exception: If the dict is not a coin supply response.
+    #[classmethod]
+    fn from_dict(_cls: &Bound<'_, PyType>, dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let max_sompi = dict
+            .get_item("maxSompi")?
+            .ok_or_else(|| PyException::new_err("Key `maxSompi` not present"))?
+            .extract()?;
+        let circulating_sompi = dict
+            .get_item("circulatingSompi")?
+            .ok_or_else(|| PyException::new_err("Key `circulatingSompi` not present"))?
+            .extract()?;
+        Ok(Self(GetCoinSupplyResponse::new(
+            max_sompi,
+            circulating_sompi,
+        )))
+    }
+
+    /// The response as a dict (`maxSompi` and `circulatingSompi`).
+    ///
+    /// Returns:
+    ///     dict: Dictionary representation of the coin supply.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("maxSompi", self.0.max_sompi)?;
+        dict.set_item("circulatingSompi", self.0.circulating_sompi)?;
+        Ok(dict)
+    }
+}
+
+impl From<GetCoinSupplyResponse> for PyCoinSupply {
+    fn from(value: GetCoinSupplyResponse) -> Self {
+        Self(value)
+    }
+}
@@ -0,0 +1,127 @@
+use ahash::AHashMap;
+use kaspa_rpc_core::model::GetBlockResponse;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default number of responses kept by the `RpcClient` response cache.
+pub const DEFAULT_RESPONSE_CACHE_SIZE: usize = 1024;
+
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    inserted: Instant,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: AHashMap<String, CacheEntry>,
+    order: VecDeque<String>,
+}
+
+/// LRU cache of RPC responses to immutable queries (e.g. `get_block` by hash),
+/// keyed by method name and serialized request.
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    ttl: Option<Duration>,
+    state: Mutex<CacheState>,
+}
+
+impl ResponseCache {
+    pub fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    pub fn get<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        let entry = state.entries.get(key)?;
+
+        if self.ttl.is_some_and(|ttl| entry.inserted.elapsed() > ttl) {
+            state.entries.remove(key);
+            state.order.retain(|queued| queued != key);
+            return None;
+        }
+
+        let value = entry.value.downcast_ref::<T>()?.clone();
+        // Most recently used entries are at the back.
+        if let Some(position) = state.order.iter().position(|queued| queued == key) {
+            let key = state.order.remove(position).unwrap();
+            state.order.push_back(key);
+        }
+        Some(value)
+    }
+
+    /// Cache the immutable part of a response, and return it.
+    pub fn insert<T: Clone + Send + Sync + 'static>(&self, key: String, value: T) -> T {
+        let value = immutable(value);
+        let mut state = self.state.lock().unwrap();
+        let entry = CacheEntry {
+            value: Arc::new(value.clone()),
+            inserted: Instant::now(),
+        };
+        if state.entries.insert(key.clone(), entry).is_some() {
+            state.order.retain(|queued| *queued != key);
+        }
+        state.order.push_back(key);
+
+        while state.order.len() > self.capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.entries.remove(&oldest);
+            }
+        }
+        value
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.order.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+}
+
+/// The part of a response that does not change once it is returned: the
+/// verbose data of a block (children, chain membership, merge sets) changes
+/// as the DAG grows, so cached blocks go without it.
+fn immutable<T: 'static>(mut response: T) -> T {
+    if let Some(response) = (&mut response as &mut dyn Any).downcast_mut::<GetBlockResponse>() {
+        response.block.verbose_data = None;
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ResponseCache::new(2, None);
+        cache.insert("a".to_string(), 1u64);
+        cache.insert("b".to_string(), 2u64);
+        assert_eq!(cache.get::<u64>("a"), Some(1));
+        cache.insert("c".to_string(), 3u64);
+
+        assert_eq!(cache.get::<u64>("b"), None);
+        assert_eq!(cache.get::<u64>("a"), Some(1));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn hits_do_not_extend_the_ttl() {
+        let cache = ResponseCache::new(2, Some(Duration::from_millis(50)));
+        cache.insert("a".to_string(), 1u64);
+        for _ in 0..3 {
+            std::thread::sleep(Duration::from_millis(20));
+            let _ = cache.get::<u64>("a");
+        }
+        assert_eq!(cache.get::<u64>("a"), None);
+    }
+}
@@ -1,12 +1,34 @@
 use crate::address::PyAddress;
 use crate::callback::PyCallback;
 use crate::consensus::core::network::{PyNetworkId, PyNetworkType};
+use crate::crypto::hashes::PyHash;
+use crate::exceptions::transaction_rejected;
 use crate::rpc::encoding::PyEncoding;
+use crate::rpc::fee::PyFeeEstimate;
 use crate::rpc::model::*;
 use crate::rpc::notification::PyNotification;
+use crate::rpc::supply::PyCoinSupply;
+use crate::rpc::wrpc::cache::{DEFAULT_RESPONSE_CACHE_SIZE, ResponseCache};
+use crate::rpc::wrpc::inflight;
+use crate::rpc::wrpc::keepalive::{
+    self, DEFAULT_KEEP_ALIVE_INTERVAL, DEFAULT_KEEP_ALIVE_TIMEOUT, KeepAlive,
+};
+use crate::rpc::wrpc::limiter::RateLimiter;
+use crate::rpc::wrpc::middleware::Middleware;
 use crate::rpc::wrpc::resolver::PyResolver;
+use crate::rpc::wrpc::retry::{
+    self, DEFAULT_RETRY_ATTEMPTS, DEFAULT_RETRY_BASE_DELAY, DEFAULT_RETRY_MAX_DELAY, RetryPolicy,
+    RetrySettings,
+};
+use crate::rpc::wrpc::snapshot::{self, DEFAULT_SNAPSHOT_BATCH_SIZE, PyUtxoStream};
+use crate::rpc::wrpc::templates::{DEFAULT_TEMPLATE_POLL_INTERVAL, PyBlockTemplates};
+use crate::rpc::wrpc::traversal::PyHeaderWalk;
+use crate::types::PyBinary;
+use crate::validation;
 use ahash::AHashMap;
 use futures::*;
+use kaspa_addresses::Address;
+use kaspa_consensus_core::tx::Transaction;
 use kaspa_notify::listener::ListenerId;
 use kaspa_notify::notification::Notification;
 use kaspa_notify::scope::{
@@ -29,15 +51,20 @@ use pyo3::{
 };
 use pyo3_stub_gen::derive::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::{
     sync::{
-        Arc, Mutex,
-        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
     },
-    time::Duration,
+    time::{Duration, Instant},
+};
+use workflow_core::{
+    channel::{Channel, DuplexChannel},
+    task,
 };
-use workflow_core::channel::{Channel, DuplexChannel};
 use workflow_log::*;
 use workflow_rpc::{client::Ctl, encoding::Encoding};
 
@@ -59,6 +86,7 @@ use workflow_rpc::{client::Ctl, encoding::Encoding};
 ///     - NewBlockTemplate: Triggered when a new block template is available for mining.
 ///     - Connect: Triggered when the RPC client connects to a node.
 ///     - Disconnect: Triggered when the RPC client disconnects from a node.
+///     - StaleConnection: Triggered when a keep-alive ping fails or times out (see `RpcClient.set_keep_alive()`).
 #[gen_stub_pyclass_enum]
 #[pyclass(name = "NotificationEvent", skip_from_py_object, eq)]
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -80,6 +108,9 @@ pub enum PyNotificationEvent {
     // RPC Control
     Connect,
     Disconnect,
+
+    // Connection health
+    StaleConnection,
 }
 
 impl<'py> FromPyObject<'_, 'py> for PyNotificationEvent {
@@ -104,6 +135,7 @@ enum NotificationEvent {
     All,
     Notification(EventType),
     RpcCtl(Ctl),
+    StaleConnection,
 }
 
 impl FromStr for NotificationEvent {
@@ -111,6 +143,8 @@ impl FromStr for NotificationEvent {
     fn from_str(s: &str) -> Result<Self> {
         if s == "all" {
             Ok(NotificationEvent::All)
+        } else if s == "stale-connection" {
+            Ok(NotificationEvent::StaleConnection)
         } else if let Ok(ctl) = Ctl::from_str(s) {
             Ok(NotificationEvent::RpcCtl(ctl))
         } else if let Ok(event) = EventType::from_str(s) {
@@ -161,6 +195,9 @@ impl From<PyNotificationEvent> for NotificationEvent {
             // RPC Control
             PyNotificationEvent::Connect => NotificationEvent::RpcCtl(Ctl::Connect),
             PyNotificationEvent::Disconnect => NotificationEvent::RpcCtl(Ctl::Disconnect),
+
+            // Connection health
+            PyNotificationEvent::StaleConnection => NotificationEvent::StaleConnection,
         }
     }
 }
@@ -173,6 +210,13 @@ pub struct Inner {
     callbacks: Arc<Mutex<AHashMap<NotificationEvent, Vec<PyCallback>>>>,
     listener_id: Arc<Mutex<Option<ListenerId>>>,
     notification_channel: Channel<kaspa_rpc_core::Notification>,
+    middleware: Arc<Middleware>,
+    cache: Mutex<Option<Arc<ResponseCache>>>,
+    keep_alive: Arc<KeepAlive>,
+    limiter: Mutex<Option<Arc<RateLimiter>>>,
+    retry: Mutex<Option<Arc<RetryPolicy>>>,
+    abandoned: Arc<AtomicUsize>,
+    last_notification: Mutex<Option<Instant>>,
 }
 
 impl Inner {
@@ -239,6 +283,13 @@ impl PyRpcClient {
             callbacks: Arc::new(Default::default()),
             listener_id: Arc::new(Mutex::new(None)),
             notification_channel: Channel::unbounded(),
+            middleware: Arc::new(Middleware::default()),
+            cache: Mutex::new(None),
+            keep_alive: Arc::new(KeepAlive::default()),
+            limiter: Mutex::new(None),
+            retry: Mutex::new(None),
+            abandoned: Arc::new(AtomicUsize::new(0)),
+            last_notification: Mutex::new(None),
         }));
 
         Ok(rpc_client)
@@ -255,6 +306,7 @@ impl PyRpcClient {
     ///     url: Optional direct node URL.
     ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant (default: "borsh").
     ///     network_id: Network identifier (default: "mainnet").
+    ///     config: Settings (e.g. from `Config.load()`) for the arguments not given.
     ///
     /// Returns:
     ///     RpcClient: A new RpcClient instance.
@@ -262,14 +314,45 @@ impl PyRpcClient {
     /// Raises:
     ///     Exception: If client creation fails.
     #[new]
-    #[pyo3(signature = (resolver=None, url=None, encoding=None, network_id=None))]
+    #[pyo3(signature = (resolver=None, url=None, encoding=None, network_id=None, config=None))]
     fn ctor(
-        resolver: Option<PyResolver>,
-        url: Option<String>,
+        mut resolver: Option<PyResolver>,
+        mut url: Option<String>,
         #[gen_stub(override_type(type_repr = "str | Encoding | None = Encoding.Borsh"))]
-        encoding: Option<PyEncoding>,
-        network_id: Option<PyNetworkId>,
+        mut encoding: Option<PyEncoding>,
+        mut network_id: Option<PyNetworkId>,
+        #[gen_stub(override_type(type_repr = "typing.Optional[Config]"))] config: Option<
+            Bound<'_, PyAny>,
+        >,
     ) -> PyResult<PyRpcClient> {
+        if let Some(config) = config {
+            let options = config.call_method0("rpc_options")?;
+            let options = options.cast::<PyDict>()?;
+            // An explicit URL or resolver replaces both of the config's.
+            if url.is_none() && resolver.is_none() {
+                url = options
+                    .get_item("url")?
+                    .map(|url| url.extract())
+                    .transpose()?;
+                resolver = options
+                    .get_item("resolver")?
+                    .map(|resolver| resolver.extract())
+                    .transpose()?;
+            }
+            if encoding.is_none() {
+                encoding = options
+                    .get_item("encoding")?
+                    .map(|encoding| encoding.extract())
+                    .transpose()?;
+            }
+            if network_id.is_none() {
+                network_id = options
+                    .get_item("network_id")?
+                    .map(|network_id| network_id.extract())
+                    .transpose()?;
+            }
+        }
+
         let network_id = match network_id {
             Some(id) => id,
             None => PyNetworkId::from_str("mainnet")?,
@@ -345,6 +428,9 @@ impl PyRpcClient {
 
     /// Connect to a Kaspa node (async).
     ///
+    /// A blocking connect can be cancelled (e.g. with `asyncio.wait_for()` or
+    /// Ctrl-C); cancellation disconnects the client and stops retrying.
+    ///
     /// Args:
     ///     block_async_connect: Block until connected (default: True).
     ///     strategy: Connection strategy ("retry" or "fallback", default: "retry").
@@ -387,11 +473,13 @@ impl PyRpcClient {
             .map_err(|err| PyException::new_err(err.to_string()))?;
 
         let client = self.0.client.clone();
+        let guard = block_async_connect.then(|| DisconnectGuard::new(client.clone()));
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            client
-                .connect(Some(options))
-                .await
-                .map_err(|e| PyException::new_err(e.to_string()))?;
+            let result = client.connect(Some(options)).await;
+            if let Some(guard) = guard {
+                guard.defuse();
+            }
+            result.map_err(|e| PyException::new_err(e.to_string()))?;
             Ok(())
         })
     }
@@ -468,7 +556,7 @@ impl PyRpcClient {
     ///
     /// Args:
     ///     event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
-    ///     callback: Function to call when event occurs.
+    ///     callback: Function to call when event occurs. An `async def` callback is scheduled on the event loop running when it is registered.
     ///     *args: Additional arguments to pass to callback.
     ///     **kwargs: Additional keyword arguments to pass to callback.
     ///
@@ -478,6 +566,7 @@ impl PyRpcClient {
     fn add_event_listener(
         &self,
         py: Python,
+        #[gen_stub(override_type(type_repr = "RpcEventName | NotificationEvent"))]
         event: PyNotificationEvent,
         #[gen_stub(override_type(type_repr = "typing.Callable[..., None]"))] callback: Py<PyAny>,
         args: &Bound<'_, PyTuple>,
@@ -516,6 +605,7 @@ impl PyRpcClient {
     #[pyo3(signature = (event, callback=None))]
     fn remove_event_listener(
         &self,
+        #[gen_stub(override_type(type_repr = "RpcEventName | NotificationEvent"))]
         event: PyNotificationEvent,
         #[gen_stub(override_type(type_repr = "None | typing.Callable[..., None]"))]
         callback: Option<Py<PyAny>>,
@@ -573,6 +663,594 @@ impl PyRpcClient {
         *self.0.callbacks.lock().unwrap() = Default::default();
         Ok(())
     }
+
+    /// Register a middleware invoked around each RPC method call.
+    ///
+    /// A middleware is any object implementing some of these methods:
+    ///     - on_request(method, request): Called before the request is sent. Return a dict to replace the request, or None to keep it.
+    ///     - on_response(method, response, latency): Called with the response and the latency in seconds. Return a value to replace the response, or None to keep it.
+    ///     - on_error(method, error, latency): Called when the call fails.
+    ///
+    /// Request hooks run in registration order, response and error hooks in reverse
+    /// order. An exception raised by a hook fails the call. Middleware applies to the
+    /// request/response RPC methods (e.g. `get_block`, `submit_transaction`).
+    ///
+    /// Args:
+    ///     middleware: The middleware object.
+    fn add_middleware(&self, middleware: Py<PyAny>) {
+        self.0.middleware.add(middleware);
+    }
+
+    /// Remove a registered middleware.
+    ///
+    /// Args:
+    ///     middleware: The middleware to remove, or None to remove all.
+    #[pyo3(signature = (middleware=None))]
+    fn remove_middleware(&self, middleware: Option<Py<PyAny>>) {
+        match middleware {
+            Some(middleware) => self.0.middleware.remove(&middleware),
+            None => self.0.middleware.clear(),
+        }
+    }
+
+    /// Enable the response cache for immutable queries.
+    ///
+    /// Responses of `get_block` and `get_utxo_return_address` are kept in an LRU
+    /// cache keyed by the request, so repeated lookups of the same block or
+    /// transaction are served without a round trip. Failed calls are not cached.
+    /// While the cache is enabled, `get_block` responses omit the block's
+    /// `verboseData` (children, chain membership and merge sets change as the
+    /// DAG grows). Enabling the cache again replaces (and empties) the existing
+    /// one.
+    ///
+    /// Args:
+    ///     size: Maximum number of cached responses (default: 1024).
+    ///     ttl: Optional time to live of cached responses in seconds.
+    ///
+    /// Raises:
+    ///     Exception: If `ttl` is negative.
+    #[pyo3(signature = (size=DEFAULT_RESPONSE_CACHE_SIZE, ttl=None))]
+    fn enable_cache(&self, size: usize, ttl: Option<f64>) -> PyResult<()> {
+        let ttl = ttl
+            .map(Duration::try_from_secs_f64)
+            .transpose()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        *self.0.cache.lock().unwrap() = Some(Arc::new(ResponseCache::new(size, ttl)));
+        Ok(())
+    }
+
+    /// Disable and drop the response cache.
+    fn disable_cache(&self) {
+        self.0.cache.lock().unwrap().take();
+    }
+
+    /// Remove all cached responses.
+    fn clear_cache(&self) {
+        if let Some(cache) = self.0.cache.lock().unwrap().as_ref() {
+            cache.clear();
+        }
+    }
+
+    /// Number of cached responses, or None if the cache is disabled.
+    #[getter]
+    fn get_cache_size(&self) -> Option<usize> {
+        self.0
+            .cache
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|cache| cache.len())
+    }
+
+    /// Configure keep-alive pings on the connection.
+    ///
+    /// While connected, the node is pinged every `interval` seconds and the round
+    /// trip is recorded (see `latency()`). When a ping fails or takes longer than
+    /// `timeout`, a `stale-connection` event is emitted to listeners with the `rpc`
+    /// URL and the `error`, so the application can reconnect or fail over before
+    /// requests time out. The event is emitted once per stale period, and again
+    /// only after a successful ping. Keep-alive is disabled by default.
+    ///
+    /// Args:
+    ///     interval: Seconds between pings, or None to disable keep-alive (default: 15).
+    ///     timeout: Seconds a ping may take before the connection is stale (default: 5).
+    ///
+    /// Raises:
+    ///     Exception: If `interval` or `timeout` is not positive.
+    #[pyo3(signature = (interval=Some(DEFAULT_KEEP_ALIVE_INTERVAL), timeout=DEFAULT_KEEP_ALIVE_TIMEOUT))]
+    fn set_keep_alive(&self, interval: Option<f64>, timeout: f64) -> PyResult<()> {
+        let seconds = |value: f64| {
+            Duration::try_from_secs_f64(value)
+                .ok()
+                .filter(|duration| !duration.is_zero())
+                .ok_or_else(|| PyException::new_err("interval and timeout must be positive"))
+        };
+        let interval = interval.map(seconds).transpose()?;
+        let timeout = seconds(timeout)?;
+
+        let generation = self.0.keep_alive.restart();
+        let Some(interval) = interval else {
+            return Ok(());
+        };
+        let inner = Arc::downgrade(&self.0);
+        pyo3_async_runtimes::tokio::get_runtime()
+            .spawn(keep_alive_task(inner, generation, interval, timeout));
+        Ok(())
+    }
+
+    /// Limit the rate and concurrency of RPC method calls.
+    ///
+    /// Calls exceeding `rate` requests per second (after an initial `burst`) or
+    /// `max_in_flight` concurrent requests are queued and sent first come, first
+    /// served, keeping scripts within the limits of public nodes. Limits apply to
+    /// the request/response RPC methods; cached responses are not limited.
+    /// Setting limits again replaces the previous ones, calling without limits
+    /// disables limiting.
+    ///
+    /// Args:
+    ///     rate: Maximum requests per second, or None for no rate limit.
+    ///     burst: Requests that may be sent at once before `rate` applies (default: 1).
+    ///     max_in_flight: Maximum concurrent requests, or None for no limit.
+    ///
+    /// Raises:
+    ///     Exception: If `rate`, `burst` or `max_in_flight` is not positive.
+    #[pyo3(signature = (rate=None, burst=1, max_in_flight=None))]
+    fn set_rate_limit(
+        &self,
+        rate: Option<f64>,
+        burst: usize,
+        max_in_flight: Option<usize>,
+    ) -> PyResult<()> {
+        if rate.is_some_and(|rate| !rate.is_finite() || rate <= 0.0)
+            || burst == 0
+            || max_in_flight == Some(0)
+        {
+            return Err(PyException::new_err(
+                "rate, burst and max_in_flight must be positive",
+            ));
+        }
+        *self.0.limiter.lock().unwrap() = (rate.is_some() || max_in_flight.is_some())
+            .then(|| Arc::new(RateLimiter::new(rate, burst, max_in_flight)));
+        Ok(())
+    }
+
+    /// Number of RPC method calls waiting for the rate limit (see `set_rate_limit()`).
+    #[getter]
+    fn get_queued_requests(&self) -> usize {
+        self.0
+            .limiter
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(0, |limiter| limiter.queued())
+    }
+
+    /// Number of RPC requests cancelled (or timed out) after they were sent,
+    /// before the node answered.
+    ///
+    /// A cancelled request stops waiting for its response and frees its
+    /// `max_in_flight` slot (see `set_rate_limit()`) at once; the response, if
+    /// the node still sends one, is discarded.
+    #[getter]
+    fn get_abandoned_requests(&self) -> usize {
+        self.0.abandoned.load(Ordering::Relaxed)
+    }
+
+    /// Seconds since the last node notification was received, or None if none
+    /// was received yet.
+    #[getter]
+    fn get_last_notification_age(&self) -> Option<f64> {
+        let received = *self.0.last_notification.lock().unwrap();
+        received.map(|received| received.elapsed().as_secs_f64())
+    }
+
+    /// Retry RPC method calls failing with transient errors.
+    ///
+    /// Calls failing because the client is disconnected, the request timed out
+    /// or the node is busy are retried up to `attempts` times in total, waiting
+    /// `base_delay` seconds after the first failure and doubling up to
+    /// `max_delay`. Half of each wait is random (jitter), so clients do not retry
+    /// in lockstep. Other errors are raised immediately, after the middleware
+    /// `on_error` hook sees the final failure only.
+    ///
+    /// Methods with side effects (`submit_transaction()`, `submit_block()`,
+    /// `ban()`, ...) are not retried unless configured in `methods`. Retrying is
+    /// disabled by default; calling with `attempts=None` disables it again.
+    ///
+    /// Args:
+    ///     attempts: Total attempts per call (default: 3), or None to disable retrying.
+    ///     base_delay: Seconds to wait after the first failure (default: 0.1).
+    ///     max_delay: Maximum seconds between attempts (default: 5).
+    ///     methods: Per-method overrides, e.g. `{"submit_transaction": {"attempts": 2},
+    ///         "get_block_template": None}`: a dict with `attempts`, `base_delay`
+    ///         and/or `max_delay` (others inherited), or None to never retry the method.
+    ///
+    /// Raises:
+    ///     Exception: If `attempts` or the delays are not positive, or an override is invalid.
+    #[pyo3(signature = (attempts=Some(DEFAULT_RETRY_ATTEMPTS), base_delay=DEFAULT_RETRY_BASE_DELAY, max_delay=DEFAULT_RETRY_MAX_DELAY, methods=None))]
+    fn set_retry_policy(
+        &self,
+        attempts: Option<usize>,
+        base_delay: f64,
+        max_delay: f64,
+        #[gen_stub(override_type(type_repr = "typing.Optional[dict[str, dict | None]]"))]
+        methods: Option<Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let Some(attempts) = attempts else {
+            *self.0.retry.lock().unwrap() = None;
+            return Ok(());
+        };
+        let settings = |attempts: usize, base_delay: f64, max_delay: f64| {
+            let seconds = |value: f64| {
+                Duration::try_from_secs_f64(value)
+                    .ok()
+                    .filter(|duration| !duration.is_zero())
+            };
+            match (attempts, seconds(base_delay), seconds(max_delay)) {
+                (1.., Some(base_delay), Some(max_delay)) => Ok(RetrySettings {
+                    attempts,
+                    base_delay,
+                    max_delay,
+                }),
+                _ => Err(PyException::new_err(
+                    "attempts, base_delay and max_delay must be positive",
+                )),
+            }
+        };
+        let default = settings(attempts, base_delay, max_delay)?;
+
+        let mut overrides = HashMap::new();
+        for (method, value) in methods.iter().flat_map(|methods| methods.iter()) {
+            let method: String = method.extract()?;
+            if value.is_none() {
+                overrides.insert(method, None);
+                continue;
+            }
+            let value = value.cast::<PyDict>().map_err(|_| {
+                PyException::new_err(format!(
+                    "retry settings of `{method}` must be a dict or None"
+                ))
+            })?;
+            if let Some(key) = value.keys().iter().find(|key| {
+                !key.extract::<String>().is_ok_and(|key| {
+                    matches!(key.as_str(), "attempts" | "base_delay" | "max_delay")
+                })
+            }) {
+                return Err(PyException::new_err(format!(
+                    "unknown retry setting {key} for `{method}`"
+                )));
+            }
+            let get = |key: &str| value.get_item(key);
+            let method_settings = settings(
+                get("attempts")?.map_or(Ok(attempts), |value| value.extract())?,
+                get("base_delay")?.map_or(Ok(base_delay), |value| value.extract())?,
+                get("max_delay")?.map_or(Ok(max_delay), |value| value.extract())?,
+            )?;
+            overrides.insert(method, Some(method_settings));
+        }
+
+        *self.0.retry.lock().unwrap() = Some(Arc::new(RetryPolicy::new(default, overrides)));
+        Ok(())
+    }
+
+    /// Round trip of the last successful ping in seconds.
+    ///
+    /// Updated by keep-alive pings and by `ping()` calls.
+    ///
+    /// Returns:
+    ///     float | None: The latency, or None if no ping succeeded yet.
+    fn latency(&self) -> Option<f64> {
+        self.0
+            .keep_alive
+            .latency()
+            .map(|latency| latency.as_secs_f64())
+    }
+
+    /// Call an RPC method by name (async).
+    ///
+    /// Generic entry point sending the `request` dict to the RPC method `method`,
+    /// e.g. for method names read from configuration or received over an API.
+    /// The method name may be given in snake case (`get_block_count`) or as in the
+    /// node's RPC API (`getBlockCount`, `GetBlockCount`). The request is converted
+    /// as in the wRPC protocol (camelCase keys, addresses, hashes and transactions
+    /// as strings and dicts), without the Python objects the RpcClient methods
+    /// accept. Middleware, caching and rate limiting apply as for direct calls.
+    ///
+    /// Args:
+    ///     method: The RPC method name.
+    ///     request: The request parameters (default: empty).
+    ///
+    /// Returns:
+    ///     dict: The response, as returned by the RPC method.
+    ///
+    /// Raises:
+    ///     Exception: If `method` is not an RPC method of the node, the request does not convert, or the call fails.
+    #[pyo3(signature = (method, request=None))]
+    fn call<'py>(
+        &self,
+        py: Python<'py>,
+        method: &str,
+        request: Option<Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let request = request.unwrap_or_else(|| PyDict::new(py));
+        self.dispatch(py, method, request)
+    }
+
+    /// Iterate over the block templates of the node, e.g. for pool software.
+    ///
+    /// Subscribes to `new-block-template` notifications and fetches the template
+    /// after each one (or every `poll_interval` seconds without one), yielding
+    /// only templates whose parents or transactions changed:
+    /// `async for template in client.block_templates(pay_address):`.
+    ///
+    /// Args:
+    ///     pay_address: The address coinbase rewards are paid to.
+    ///     extra_data: Extra data included in the coinbase transaction (default: empty).
+    ///     poll_interval: Seconds after which the template is refetched without a notification (default: 1.0).
+    ///
+    /// Returns:
+    ///     BlockTemplates: An async iterator of `BlockTemplate`.
+    ///
+    /// Raises:
+    ///     Exception: If `pay_address` is invalid or `poll_interval` is not positive.
+    #[pyo3(signature = (pay_address, extra_data=None, poll_interval=DEFAULT_TEMPLATE_POLL_INTERVAL))]
+    fn block_templates(
+        &self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] pay_address: Bound<'_, PyAny>,
+        extra_data: Option<PyBinary>,
+        poll_interval: f64,
+    ) -> PyResult<PyBlockTemplates> {
+        let pay_address = if let Ok(address) = pay_address.extract::<PyAddress>() {
+            Address::from(address)
+        } else if let Ok(address) = pay_address.extract::<String>() {
+            PyAddress::try_from(address).map(Address::from)?
+        } else {
+            return Err(PyException::new_err(
+                "pay_address must be an Address or str",
+            ));
+        };
+        if !poll_interval.is_finite() || poll_interval <= 0.0 {
+            return Err(PyException::new_err("poll_interval must be positive"));
+        }
+        let request = GetBlockTemplateRequest::new(
+            pay_address,
+            extra_data.map(Vec::from).unwrap_or_default(),
+        );
+        Ok(PyBlockTemplates::new(
+            self.0.client.clone(),
+            request,
+            Duration::from_secs_f64(poll_interval),
+        ))
+    }
+
+    /// Walk the selected parent chain from a block towards genesis.
+    ///
+    /// Yields the header of `from_hash` first, then the header of its selected
+    /// parent and so on. Headers are fetched lazily, one `get_block()` call per
+    /// iteration: `async for header in client.walk_selected_parent_chain(sink, 100):`.
+    ///
+    /// Args:
+    ///     from_hash: The block to start from, e.g. the sink.
+    ///     limit: The maximum number of headers to yield.
+    ///
+    /// Returns:
+    ///     HeaderWalk: An async iterator of header dicts.
+    ///
+    /// Raises:
+    ///     Exception: If `from_hash` is not a valid hash.
+    fn walk_selected_parent_chain(
+        &self,
+        #[gen_stub(override_type(type_repr = "Hash | str"))] from_hash: Bound<'_, PyAny>,
+        limit: u64,
+    ) -> PyResult<PyHeaderWalk> {
+        Ok(PyHeaderWalk::selected_parent_chain(
+            self.0.client.clone(),
+            extract_hash("from_hash", &from_hash)?,
+            limit,
+        ))
+    }
+
+    /// Walk the ancestors of a block breadth-first.
+    ///
+    /// Yields the header of `hash` first, then the headers of its direct
+    /// parents, their parents and so on, each block once. Headers are fetched
+    /// lazily, one `get_block()` call per iteration.
+    ///
+    /// Args:
+    ///     hash: The block to start from.
+    ///     depth: How many parent levels to walk (0 yields `hash` only).
+    ///
+    /// Returns:
+    ///     HeaderWalk: An async iterator of header dicts.
+    ///
+    /// Raises:
+    ///     Exception: If `hash` is not a valid hash.
+    fn get_block_ancestors(
+        &self,
+        #[gen_stub(override_type(type_repr = "Hash | str"))] hash: Bound<'_, PyAny>,
+        depth: u64,
+    ) -> PyResult<PyHeaderWalk> {
+        Ok(PyHeaderWalk::ancestors(
+            self.0.client.clone(),
+            extract_hash("hash", &hash)?,
+            depth,
+        ))
+    }
+
+    /// Stream the UTXOs of many addresses as JSON Lines chunks.
+    ///
+    /// Queries `batch_size` addresses per `get_utxos_by_addresses()` call and
+    /// yields each response as `bytes`, without building a Python dict per
+    /// entry: `async for chunk in client.stream_utxos_by_addresses(addresses):`.
+    ///
+    /// Args:
+    ///     addresses: The addresses to snapshot.
+    ///     batch_size: Addresses queried per call (default: 1000).
+    ///
+    /// Returns:
+    ///     UtxoStream: An async iterator of JSON Lines chunks.
+    ///
+    /// Raises:
+    ///     ValueError: If `batch_size` is 0.
+    #[pyo3(signature = (addresses, batch_size=DEFAULT_SNAPSHOT_BATCH_SIZE))]
+    fn stream_utxos_by_addresses(
+        &self,
+        addresses: Vec<PyAddress>,
+        batch_size: usize,
+    ) -> PyResult<PyUtxoStream> {
+        if batch_size == 0 {
+            return Err(validation::invalid("batch_size", "must be positive"));
+        }
+        Ok(PyUtxoStream::new(
+            self.0.client.clone(),
+            addresses.into_iter().map(Address::from).collect(),
+            batch_size,
+        ))
+    }
+
+    /// Write the UTXOs of many addresses to a file as JSON Lines (async).
+    ///
+    /// Like `stream_utxos_by_addresses()`, but the chunks are written to
+    /// `path` directly, one entry per line in the `get_utxos_by_addresses()`
+    /// entry form. Suited to snapshotting large address sets for research:
+    /// memory use is bounded by one batch.
+    ///
+    /// Args:
+    ///     addresses: The addresses to snapshot.
+    ///     path: The file to write (replaced if it exists).
+    ///     batch_size: Addresses queried per call (default: 1000).
+    ///
+    /// Returns:
+    ///     dict: `entries` (the number of UTXOs written) and `amount` (their total in sompi).
+    ///
+    /// Raises:
+    ///     ValueError: If `batch_size` is 0.
+    ///     OSError: If the file cannot be written.
+    ///     Exception: If an RPC call fails.
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    #[pyo3(signature = (addresses, path, batch_size=DEFAULT_SNAPSHOT_BATCH_SIZE))]
+    fn dump_utxos_by_addresses<'py>(
+        &self,
+        py: Python<'py>,
+        addresses: Vec<PyAddress>,
+        path: PathBuf,
+        batch_size: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if batch_size == 0 {
+            return Err(validation::invalid("batch_size", "must be positive"));
+        }
+        let client = self.0.client.clone();
+        let addresses = addresses.into_iter().map(Address::from).collect();
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            snapshot::dump(client, addresses, batch_size, path),
+        )
+    }
+}
+
+/// A block hash given as `Hash` or hex string.
+fn extract_hash(name: &str, value: &Bound<'_, PyAny>) -> PyResult<kaspa_hashes::Hash> {
+    if let Ok(hash) = value.extract::<PyHash>() {
+        Ok(hash.into())
+    } else if let Ok(hash) = value.extract::<String>() {
+        PyHash::try_from(hash).map(Into::into)
+    } else {
+        Err(PyException::new_err(format!(
+            "{name} must be a Hash or str"
+        )))
+    }
+}
+
+/// Snake case `RpcClient` method name of an RPC method name given in snake,
+/// camel (`getBlockCount`) or Pascal case (`GetBlockCount`).
+fn rpc_method_name(method: &str) -> String {
+    let mut name = String::with_capacity(method.len() + 8);
+    for (index, c) in method.chars().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 && !name.ends_with('_') {
+            name.push('_');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}
+
+/// Ping the node every `interval` while connected, until the client is dropped
+/// or keep-alive is reconfigured, and emit `stale-connection` events.
+async fn keep_alive_task(
+    inner: Weak<Inner>,
+    generation: u64,
+    interval: Duration,
+    timeout: Duration,
+) {
+    let mut stale = false;
+    loop {
+        task::sleep(interval).await;
+        let Some(inner) = inner.upgrade() else {
+            break;
+        };
+        if !inner.keep_alive.is_current(generation) {
+            break;
+        }
+        if !inner.client.is_connected() {
+            stale = false;
+            continue;
+        }
+
+        match keepalive::ping(&inner.client, timeout).await {
+            Ok(latency) => {
+                inner.keep_alive.record(latency);
+                stale = false;
+            }
+            Err(error) if !stale => {
+                stale = true;
+                let Some(handlers) =
+                    inner.notification_callbacks(NotificationEvent::StaleConnection)
+                else {
+                    continue;
+                };
+                let url = inner.client.url();
+                for handler in handlers.into_iter() {
+                    Python::attach(|py| {
+                        let event = PyDict::new(py);
+                        event.set_item("type", "stale-connection")?;
+                        event.set_item("rpc", url.clone())?;
+                        event.set_item("error", error.clone())?;
+                        handler.execute(py, event)
+                    })
+                    .map(|_| ())
+                    .unwrap_or_else(|err| log_error!("stale-connection listener error: {}", err));
+                }
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// Disconnects a client when dropped, unless defused first.
+///
+/// Held across a blocking connect: if the awaiting Python task is cancelled
+/// (e.g. Ctrl-C under `asyncio.run()`), the connect future is dropped and the
+/// client is disconnected, so the retry loop does not keep running in the background.
+pub(crate) struct DisconnectGuard(Option<Arc<KaspaRpcClient>>);
+
+impl DisconnectGuard {
+    pub fn new(client: Arc<KaspaRpcClient>) -> Self {
+        Self(Some(client))
+    }
+
+    pub fn defuse(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for DisconnectGuard {
+    fn drop(&mut self) {
+        if let Some(client) = self.0.take() {
+            pyo3_async_runtimes::tokio::get_runtime().spawn(async move {
+                client.disconnect().await.ok();
+            });
+        }
+    }
 }
 
 impl PyRpcClient {
@@ -664,6 +1342,7 @@ impl PyRpcClient {
                     },
                     msg = notification_receiver.recv().fuse() => {
                         if let Ok(notification) = &msg {
+                            *this.0.last_notification.lock().unwrap() = Some(Instant::now());
                             match &notification {
                                 kaspa_rpc_core::Notification::UtxosChanged(utxos_changed_notification) => {
                                     let event_type = notification.event_type();
@@ -859,6 +1538,96 @@ impl PyRpcClient {
             ))
         }
     }
+
+    /// Get the node's fee estimate as a structured FeeEstimate (async).
+    ///
+    /// Uses the experimental fee estimator endpoint, which can also return
+    /// verbose mempool data.
+    ///
+    /// Args:
+    ///     verbose: Include verbose mempool data (default: False).
+    ///
+    /// Returns:
+    ///     FeeEstimate: The fee estimate buckets and optional verbose data.
+    ///
+    /// Raises:
+    ///     Exception: If the RPC call fails.
+    #[pyo3(signature = (verbose=false))]
+    #[gen_stub(override_return_type(type_repr = "FeeEstimate"))]
+    fn fee_estimate<'py>(&self, py: Python<'py>, verbose: bool) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let response = client
+                .get_fee_estimate_experimental(verbose)
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            Ok(PyFeeEstimate::new(response.estimate, response.verbose))
+        })
+    }
+
+    /// Get the network's coin supply as a structured CoinSupply (async).
+    ///
+    /// Returns:
+    ///     CoinSupply: The maximum and circulating supply.
+    ///
+    /// Raises:
+    ///     Exception: If the RPC call fails.
+    #[gen_stub(override_return_type(type_repr = "CoinSupply"))]
+    fn coin_supply<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let response = client
+                .get_coin_supply()
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            Ok(PyCoinSupply::from(response))
+        })
+    }
+
+    /// Submit an externally built, signed transaction given in serialized form (async).
+    ///
+    /// The transaction is the Borsh encoding of a consensus transaction, as
+    /// returned by `Transaction.to_bytes()` and produced by rusty-kaspa based
+    /// tooling and signers. This is not the hex-encoded wire format of other
+    /// SDKs. Its id is recomputed locally before submission.
+    ///
+    /// Args:
+    ///     data: The Borsh-encoded transaction as bytes or a hex string.
+    ///     allow_orphan: Allow orphan transactions (default: False).
+    ///
+    /// Returns:
+    ///     str: The transaction ID on success.
+    ///
+    /// Raises:
+    ///     TransactionRejectedError: If the node rejects the transaction (see `kaspa.exceptions`).
+    ///     Exception: If the transaction cannot be deserialized or the call fails.
+    #[pyo3(signature = (data, allow_orphan=false))]
+    #[gen_stub(override_return_type(type_repr = "str"))]
+    fn submit_serialized_transaction<'py>(
+        &self,
+        py: Python<'py>,
+        data: PyBinary,
+        allow_orphan: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut transaction = borsh::from_slice::<Transaction>(data.as_ref()).map_err(|err| {
+            PyException::new_err(format!("Invalid serialized transaction: {err}"))
+        })?;
+        transaction.finalize();
+        let transaction_id = transaction.id();
+
+        let client = self.0.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match client
+                .submit_transaction((&transaction).into(), allow_orphan)
+                .await
+            {
+                Ok(id) => Ok(id.to_string()),
+                Err(err) => Err(Python::attach(|py| {
+                    transaction_rejected(py, err.to_string(), Some(transaction_id.to_string()))
+                })),
+            }
+        })
+    }
 }
 
 // Macro to generate subscribe/unsubscribe method implementations for RPC notifications.
@@ -916,6 +1685,91 @@ build_wrpc_python_subscriptions!([
     VirtualDaaScoreChanged,
 ]);
 
+// Body of the generated RPC methods.
+//
+// The request dict goes through the middleware and is converted to the request
+// type of `$name`. The call is served from `$cache` when given one, otherwise it
+// is sent through the rate limiter and the retry policy (and its response is
+// cached). The response is returned to Python through the middleware.
+macro_rules! wrpc_python_call {
+    (@convert typed, $name:ident, $request:ident) => {
+        paste! { [<Py $name Request>]::try_from($request)?.0 }
+    };
+    (@convert serde, $name:ident, $request:ident) => {
+        serde_pyobject::from_pyobject($request)?
+    };
+    ($self:ident, $py:ident, $name:ident, $request:expr, $cache:expr) => {
+        wrpc_python_call!($self, $py, $name, $request, $cache, typed)
+    };
+    // `$conversion` converts the request dict: `typed` with the request wrapper
+    // of the RpcClient method, `serde` with the wRPC request type only.
+    ($self:ident, $py:ident, $name:ident, $request:expr, $cache:expr, $conversion:ident) => {
+        paste! {{
+            let client = $self.0.client.clone();
+            let middleware = $self.0.middleware.clone();
+            let keep_alive = $self.0.keep_alive.clone();
+            let cache: Option<Arc<ResponseCache>> = $cache;
+            let limiter = $self.0.limiter.lock().unwrap().clone();
+            let retry_policy = $self.0.retry.lock().unwrap().clone();
+            let abandoned = $self.0.abandoned.clone();
+            let method = stringify!([<$name:snake>]);
+
+            let request = middleware.on_request($py, method, $request)?;
+            let request: [<$name Request>] = wrpc_python_call!(@convert $conversion, $name, request);
+            let key = cache
+                .as_ref()
+                .and_then(|_| serde_json::to_string(&request).ok())
+                .map(|body| format!("{method}:{body}"));
+
+            pyo3_async_runtimes::tokio::future_into_py($py, async move {
+                let started = Instant::now();
+                let cached = cache
+                    .as_ref()
+                    .zip(key.as_deref())
+                    .and_then(|(cache, key)| cache.get::<[<$name Response>]>(key));
+                let result = match cached {
+                    Some(response) => Ok(response),
+                    // Each attempt takes its own limiter slot, so backoff
+                    // sleeps hold none.
+                    None => retry::retry(retry_policy, method, || {
+                        inflight::send(
+                            limiter.clone(),
+                            abandoned.clone(),
+                            client.[<$name:snake _call>](None, request.clone()),
+                        )
+                    })
+                    .await
+                    .map_err(|err| err.to_string())
+                    // Only fetched responses are cached, so hits keep the
+                    // insertion time the TTL counts from.
+                    .map(|response| match (&cache, key) {
+                        (Some(cache), Some(key)) => cache.insert(key, response),
+                        _ => response,
+                    }),
+                };
+                let elapsed = started.elapsed();
+                let latency = elapsed.as_secs_f64();
+                if method == "ping" && result.is_ok() {
+                    keep_alive.record(elapsed);
+                }
+
+                Python::attach(|py| match result {
+                    Ok(response) => {
+                        let response: [<$name Response>] = response;
+                        let response = serde_pyobject::to_pyobject(py, &response)?;
+                        Ok(middleware.on_response(py, method, response, latency)?.unbind())
+                    }
+                    Err(err) => {
+                        let err = rpc_error(py, method, err);
+                        middleware.on_error(py, method, &err, latency)?;
+                        Err(err)
+                    }
+                })
+            })
+        }}
+    };
+}
+
 // Macro to generate RPC method implementations for RpcClient.
 //
 // For each type name (e.g., `GetBlockCount`), this generates:
@@ -936,22 +1790,13 @@ macro_rules! build_wrpc_python_interface {
                         py: Python<'py>,
                         request: Option<Bound<'_, PyDict>>
                     ) -> PyResult<Bound<'py, PyAny>> {
-                        let client = self.0.client.clone();
-
-                        let request: [<Py $name Request>] = request
-                            .unwrap_or_else(|| PyDict::new(py))
-                            .try_into()?;
-
-                        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                            let response: [<$name Response>] = client
-                                .[<$name:snake _call>](None, request.0)
-                                .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
-
-                            Python::attach(|py| {
-                                Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
-                            })
-                        })
+                        wrpc_python_call!(
+                            self,
+                            py,
+                            $name,
+                            request.unwrap_or_else(|| PyDict::new(py)),
+                            None
+                        )
                     }
                 )*
             }
@@ -979,6 +1824,17 @@ build_wrpc_python_interface!([
     GetSystemInfo,
 ]);
 
+/// Convert an RPC error to a Python exception. Errors of the transaction
+/// submission methods are decoded into `TransactionRejectedError`.
+fn rpc_error(py: Python, method: &str, message: String) -> PyErr {
+    match method {
+        "submit_transaction" | "submit_transaction_replacement" => {
+            transaction_rejected(py, message, None)
+        }
+        _ => PyException::new_err(message),
+    }
+}
+
 // Macro to generate RPC method implementations that require request parameters.
 //
 // Similar to `build_wrpc_python_interface!`, but the `request` parameter is required
@@ -995,20 +1851,7 @@ macro_rules! build_wrpc_python_interface_with_args {
                         py: Python<'py>,
                         request: Bound<'_, PyDict>
                     ) -> PyResult<Bound<'py, PyAny>> {
-                        let client = self.0.client.clone();
-
-                        let request: [<Py $name Request>] = request.try_into()?;
-
-                        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                            let response: [<$name Response>] = client
-                                .[<$name:snake _call>](None, request.0)
-                                .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
-
-                            Python::attach(|py| {
-                                Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
-                            })
-                        })
+                        wrpc_python_call!(self, py, $name, request, None)
                     }
                 )*
             }
@@ -1022,7 +1865,6 @@ build_wrpc_python_interface_with_args!([
     EstimateNetworkHashesPerSecond,
     GetBalanceByAddress,
     GetBalancesByAddresses,
-    GetBlock,
     GetBlocks,
     GetBlockTemplate,
     GetCurrentBlockColor,
@@ -1034,7 +1876,6 @@ build_wrpc_python_interface_with_args!([
     GetMempoolEntry,
     GetSubnetwork,
     GetUtxosByAddresses,
-    GetUtxoReturnAddress,
     GetVirtualChainFromBlock,
     GetVirtualChainFromBlockV2,
     ResolveFinalityConflict,
@@ -1043,3 +1884,117 @@ build_wrpc_python_interface_with_args!([
     SubmitTransactionReplacement,
     Unban,
 ]);
+
+// Macro to generate RPC method implementations for immutable queries.
+//
+// Same as `build_wrpc_python_interface_with_args!`, but responses are served from
+// and stored in the optional response cache (see `RpcClient.enable_cache()`).
+macro_rules! build_wrpc_python_interface_cached {
+    ([$($name:ident),* $(,)?]) => {
+        paste! {
+            #[gen_stub_pymethods]
+            #[pymethods]
+            impl PyRpcClient {
+                $(
+                    fn [<$name:snake>]<'py>(
+                        &self,
+                        py: Python<'py>,
+                        request: Bound<'_, PyDict>
+                    ) -> PyResult<Bound<'py, PyAny>> {
+                        wrpc_python_call!(self, py, $name, request, self.0.cache.lock().unwrap().clone())
+                    }
+                )*
+            }
+        }
+    };
+}
+
+build_wrpc_python_interface_cached!([GetBlock, GetUtxoReturnAddress]);
+
+// Macro to generate the dispatch of `RpcClient.call()` to the RPC methods.
+//
+// Requests are converted with the wRPC request types, so every method of the
+// node's RPC API is reachable by name. Responses of `cached` methods go through
+// the response cache as for the direct calls.
+macro_rules! build_wrpc_python_dispatch {
+    ([$($name:ident),* $(,)?], cached: [$($cached:ident),* $(,)?]) => {
+        paste! {
+            impl PyRpcClient {
+                /// Send `request` to the RPC method `method`, given in snake, camel or
+                /// Pascal case.
+                fn dispatch<'py>(
+                    &self,
+                    py: Python<'py>,
+                    method: &str,
+                    request: Bound<'py, PyDict>,
+                ) -> PyResult<Bound<'py, PyAny>> {
+                    let name = rpc_method_name(method);
+                    $(
+                        if name == stringify!([<$name:snake>]) {
+                            return wrpc_python_call!(self, py, $name, request, None, serde);
+                        }
+                    )*
+                    $(
+                        if name == stringify!([<$cached:snake>]) {
+                            return wrpc_python_call!(
+                                self,
+                                py,
+                                $cached,
+                                request,
+                                self.0.cache.lock().unwrap().clone(),
+                                serde
+                            );
+                        }
+                    )*
+                    Err(PyException::new_err(format!("Unknown RPC method `{method}`")))
+                }
+            }
+        }
+    };
+}
+
+build_wrpc_python_dispatch!(
+    [
+        AddPeer,
+        Ban,
+        EstimateNetworkHashesPerSecond,
+        GetBalanceByAddress,
+        GetBalancesByAddresses,
+        GetBlockCount,
+        GetBlockDagInfo,
+        GetBlocks,
+        GetBlockTemplate,
+        GetCoinSupply,
+        GetConnectedPeerInfo,
+        GetConnections,
+        GetCurrentBlockColor,
+        GetCurrentNetwork,
+        GetDaaScoreTimestampEstimate,
+        GetFeeEstimate,
+        GetFeeEstimateExperimental,
+        GetHeaders,
+        GetInfo,
+        GetMempoolEntries,
+        GetMempoolEntriesByAddresses,
+        GetMempoolEntry,
+        GetMetrics,
+        GetPeerAddresses,
+        GetServerInfo,
+        GetSink,
+        GetSinkBlueScore,
+        GetSubnetwork,
+        GetSyncStatus,
+        GetSystemInfo,
+        GetUtxosByAddresses,
+        GetVirtualChainFromBlock,
+        GetVirtualChainFromBlockV2,
+        Ping,
+        ResolveFinalityConflict,
+        Shutdown,
+        SubmitBlock,
+        SubmitTransaction,
+        SubmitTransactionReplacement,
+        Unban,
+    ],
+    cached: [GetBlock, GetUtxoReturnAddress]
+);
@@ -0,0 +1,85 @@
+use crate::rpc::wrpc::limiter::RateLimiter;
+use std::future::Future;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Held by the awaiting caller: if its future is dropped before the response,
+/// the request is counted as abandoned.
+struct CallerGuard {
+    abandoned: Arc<AtomicUsize>,
+    answered: bool,
+}
+
+impl Drop for CallerGuard {
+    fn drop(&mut self) {
+        if !self.answered {
+            self.abandoned.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Send an RPC request once the rate limiter grants a slot.
+///
+/// The request is awaited in the caller's future, so cancelling the caller
+/// (e.g. with `asyncio.timeout()`) drops it: a request still queued for the
+/// limiter is never sent, and a sent request stops waiting for its response
+/// and frees its in-flight slot at once. `abandoned` counts the requests
+/// cancelled after they were sent.
+pub(crate) async fn send<T, F>(
+    limiter: Option<Arc<RateLimiter>>,
+    abandoned: Arc<AtomicUsize>,
+    request: F,
+) -> T
+where
+    F: Future<Output = T>,
+{
+    let _permit = match &limiter {
+        Some(limiter) => Some(limiter.acquire().await),
+        None => None,
+    };
+    let mut guard = CallerGuard {
+        abandoned,
+        answered: false,
+    };
+    let response = request.await;
+    guard.answered = true;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{FutureExt, executor::block_on, future};
+
+    #[test]
+    fn cancelled_request_frees_its_slot() {
+        let limiter = Arc::new(RateLimiter::new(None, 1, Some(1)));
+        let abandoned = Arc::new(AtomicUsize::new(0));
+
+        let mut call = Box::pin(send(
+            Some(limiter.clone()),
+            abandoned.clone(),
+            future::pending::<()>(),
+        ));
+        assert!((&mut call).now_or_never().is_none());
+        assert!(limiter.acquire().now_or_never().is_none());
+
+        // Cancelling the caller drops the request and releases the slot.
+        drop(call);
+        assert_eq!(abandoned.load(Ordering::Relaxed), 1);
+        assert!(limiter.acquire().now_or_never().is_some());
+    }
+
+    #[test]
+    fn answered_request_is_not_abandoned() {
+        let limiter = Arc::new(RateLimiter::new(None, 1, Some(1)));
+        let abandoned = Arc::new(AtomicUsize::new(0));
+
+        let response = block_on(send(Some(limiter.clone()), abandoned.clone(), async { 7 }));
+        assert_eq!(response, 7);
+        assert_eq!(abandoned.load(Ordering::Relaxed), 0);
+        assert!(limiter.acquire().now_or_never().is_some());
+    }
+}
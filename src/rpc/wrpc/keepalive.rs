@@ -0,0 +1,61 @@
+use futures::{FutureExt, select_biased};
+use kaspa_rpc_core::api::rpc::RpcApi;
+use kaspa_wrpc_client::KaspaRpcClient;
+use std::sync::{
+    Mutex,
+    atomic::{AtomicU64, Ordering},
+};
+use std::time::{Duration, Instant};
+use workflow_core::task;
+
+/// Default interval between keep-alive pings, in seconds.
+pub const DEFAULT_KEEP_ALIVE_INTERVAL: f64 = 15.0;
+/// Default time a keep-alive ping may take before the connection is considered stale, in seconds.
+pub const DEFAULT_KEEP_ALIVE_TIMEOUT: f64 = 5.0;
+
+/// Keep-alive state of an `RpcClient`: the last measured ping latency and the
+/// generation of the running keep-alive task.
+///
+/// Each `(re)start` bumps the generation, so a previously spawned task notices
+/// it was superseded at its next tick and exits.
+#[derive(Default)]
+pub(crate) struct KeepAlive {
+    generation: AtomicU64,
+    latency: Mutex<Option<Duration>>,
+}
+
+impl KeepAlive {
+    /// Invalidate the running task (if any) and return the generation of a new one.
+    pub fn restart(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    pub fn is_current(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    pub fn record(&self, latency: Duration) {
+        *self.latency.lock().unwrap() = Some(latency);
+    }
+
+    pub fn latency(&self) -> Option<Duration> {
+        *self.latency.lock().unwrap()
+    }
+}
+
+/// Ping the node, failing if no response arrives within `timeout`.
+pub(crate) async fn ping(
+    client: &KaspaRpcClient,
+    timeout: Duration,
+) -> std::result::Result<Duration, String> {
+    let started = Instant::now();
+    select_biased! {
+        result = client.ping().fuse() => result
+            .map(|_| started.elapsed())
+            .map_err(|err| err.to_string()),
+        _ = task::sleep(timeout).fuse() => Err(format!(
+            "No ping response within {:.1}s",
+            timeout.as_secs_f64()
+        )),
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex as TurnLock, OwnedSemaphorePermit, Semaphore};
+use workflow_core::task;
+
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Client-side request limiter of an `RpcClient`: a token bucket of `rate`
+/// requests per second (allowing bursts of `burst` requests) and a maximum
+/// number of requests in flight.
+///
+/// Waiting requests are served first come, first served, so a burst of calls
+/// cannot starve calls made earlier: the request at the head of the line holds
+/// the (fair) turn lock while it waits for an in-flight slot and a token.
+pub(crate) struct RateLimiter {
+    rate: Option<f64>,
+    burst: f64,
+    in_flight: Option<Arc<Semaphore>>,
+    turn: TurnLock<()>,
+    bucket: Mutex<Bucket>,
+    queued: AtomicUsize,
+}
+
+/// A granted request slot, released when dropped.
+pub(crate) struct Permit {
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+/// Counts a request as queued until it is granted or cancelled.
+struct Queued<'a>(&'a AtomicUsize);
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl RateLimiter {
+    pub fn new(rate: Option<f64>, burst: usize, max_in_flight: Option<usize>) -> Self {
+        let burst = burst.max(1) as f64;
+        Self {
+            rate,
+            burst,
+            in_flight: max_in_flight.map(|max_in_flight| Arc::new(Semaphore::new(max_in_flight))),
+            turn: TurnLock::new(()),
+            bucket: Mutex::new(Bucket {
+                tokens: burst,
+                refilled: Instant::now(),
+            }),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Wait until the request may be sent.
+    pub async fn acquire(&self) -> Permit {
+        self.queued.fetch_add(1, Ordering::Relaxed);
+        let _queued = Queued(&self.queued);
+        let _turn = self.turn.lock().await;
+
+        let slot = match &self.in_flight {
+            // The semaphore is never closed.
+            Some(in_flight) => in_flight.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+        if let Some(rate) = self.rate {
+            while let Some(wait) = self.take_token(rate) {
+                task::sleep(wait).await;
+            }
+        }
+        Permit { _slot: slot }
+    }
+
+    /// Take a token from the bucket, otherwise return how long until one is available.
+    fn take_token(&self, rate: f64) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(self.burst);
+        bucket.refilled = now;
+
+        if bucket.tokens < 1.0 {
+            return Some(Duration::from_secs_f64((1.0 - bucket.tokens) / rate));
+        }
+        bucket.tokens -= 1.0;
+        None
+    }
+
+    /// Number of requests waiting for their turn.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,90 @@
+use pyo3::{prelude::*, types::PyDict};
+use std::sync::{Arc, Mutex};
+
+/// Python middleware invoked around each RPC method call of an `RpcClient`.
+///
+/// A middleware is any object implementing some of these methods:
+///
+/// - `on_request(method, request)`: called before the request is sent. Returning
+///   a dict replaces the request, returning None keeps it.
+/// - `on_response(method, response, latency)`: called with the response and the
+///   call latency in seconds. Returning a value replaces the response, returning
+///   None keeps it.
+/// - `on_error(method, error, latency)`: called when the call fails.
+///
+/// Request hooks run in registration order, response and error hooks in
+/// reverse order. An exception raised by a hook fails the call.
+#[derive(Default)]
+pub(crate) struct Middleware {
+    handlers: Mutex<Vec<Arc<Py<PyAny>>>>,
+}
+
+impl Middleware {
+    pub fn add(&self, handler: Py<PyAny>) {
+        self.handlers.lock().unwrap().push(Arc::new(handler));
+    }
+
+    pub fn remove(&self, handler: &Py<PyAny>) {
+        self.handlers
+            .lock()
+            .unwrap()
+            .retain(|entry| entry.as_ptr() != handler.as_ptr());
+    }
+
+    pub fn clear(&self) {
+        self.handlers.lock().unwrap().clear();
+    }
+
+    fn handlers(&self) -> Vec<Arc<Py<PyAny>>> {
+        self.handlers.lock().unwrap().clone()
+    }
+
+    pub fn on_request<'py>(
+        &self,
+        py: Python<'py>,
+        method: &str,
+        mut request: Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        for handler in self.handlers() {
+            let handler = handler.bind(py);
+            if !handler.hasattr("on_request")? {
+                continue;
+            }
+            let result = handler.call_method1("on_request", (method, &request))?;
+            if !result.is_none() {
+                request = result.cast_into::<PyDict>()?;
+            }
+        }
+        Ok(request)
+    }
+
+    pub fn on_response<'py>(
+        &self,
+        py: Python<'py>,
+        method: &str,
+        mut response: Bound<'py, PyAny>,
+        latency: f64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        for handler in self.handlers().iter().rev() {
+            let handler = handler.bind(py);
+            if !handler.hasattr("on_response")? {
+                continue;
+            }
+            let result = handler.call_method1("on_response", (method, &response, latency))?;
+            if !result.is_none() {
+                response = result;
+            }
+        }
+        Ok(response)
+    }
+
+    pub fn on_error(&self, py: Python, method: &str, error: &PyErr, latency: f64) -> PyResult<()> {
+        for handler in self.handlers().iter().rev() {
+            let handler = handler.bind(py);
+            if handler.hasattr("on_error")? {
+                handler.call_method1("on_error", (method, error.value(py), latency))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,11 @@
+mod cache;
 pub mod client;
+mod inflight;
+mod keepalive;
+mod limiter;
+mod middleware;
 pub mod resolver;
+mod retry;
+pub mod snapshot;
+pub mod templates;
+pub mod traversal;
@@ -1,4 +1,4 @@
-use crate::{consensus::core::network::PyNetworkId, rpc::encoding::PyEncoding};
+use crate::{consensus::core::network::PyNetworkId, rpc::encoding::PyEncoding, validation};
 use kaspa_wrpc_client::Resolver;
 use pyo3::{exceptions::PyException, prelude::*};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
@@ -31,22 +31,22 @@ impl PyResolver {
     ///
     /// Args:
     ///     urls: Optional list of resolver URLs. Uses defaults if not provided.
-    ///     tls: Use TLS connections (default: False).
+    ///     tls: Only resolve nodes accepting TLS (`wss://`) connections (default: False).
     ///
     /// Returns:
     ///     Resolver: A new Resolver instance.
+    ///
+    /// Raises:
+    ///     ValueError: If `urls` is empty.
     #[new]
     #[pyo3(signature = (urls=None, tls=None))]
     pub fn ctor(urls: Option<Vec<String>>, tls: Option<bool>) -> PyResult<Self> {
-        let tls = tls.unwrap_or(false);
-        if let Some(urls) = urls {
-            Ok(Self(Resolver::new(
-                Some(urls.into_iter().map(Arc::new).collect::<Vec<_>>()),
-                tls,
-            )))
-        } else {
-            Ok(Self(Resolver::default()))
+        if urls.as_ref().is_some_and(Vec::is_empty) {
+            return Err(validation::invalid("urls", "must not be empty"));
         }
+        // `tls` applies to the default resolver URLs too.
+        let urls = urls.map(|urls| urls.into_iter().map(Arc::new).collect::<Vec<_>>());
+        Ok(Self(Resolver::new(urls, tls.unwrap_or(false))))
     }
 }
 
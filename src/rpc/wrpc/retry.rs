@@ -0,0 +1,113 @@
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use workflow_core::task;
+
+pub(crate) const DEFAULT_RETRY_ATTEMPTS: usize = 3;
+pub(crate) const DEFAULT_RETRY_BASE_DELAY: f64 = 0.1;
+pub(crate) const DEFAULT_RETRY_MAX_DELAY: f64 = 5.0;
+
+/// Methods with side effects on the node, only retried when configured per method.
+const NON_IDEMPOTENT_METHODS: &[&str] = &[
+    "add_peer",
+    "ban",
+    "resolve_finality_conflict",
+    "shutdown",
+    "submit_block",
+    "submit_transaction",
+    "submit_transaction_replacement",
+    "unban",
+];
+
+/// Error message fragments of failures worth retrying: the request may succeed
+/// once the connection is back or the node is less loaded.
+const TRANSIENT_ERRORS: &[&str] = &[
+    "not connected",
+    "disconnected",
+    "connection",
+    "timeout",
+    "timed out",
+    "busy",
+    "too many requests",
+    "unavailable",
+];
+
+/// Attempts and backoff of a retried method.
+#[derive(Clone, Copy)]
+pub(crate) struct RetrySettings {
+    pub attempts: usize,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetrySettings {
+    /// Exponential backoff capped at `max_delay`, with "equal jitter": half of
+    /// the delay is fixed, the other half random, so clients retrying together
+    /// spread out without retrying immediately.
+    fn delay(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31) as i32;
+        let delay =
+            (self.base_delay.as_secs_f64() * 2f64.powi(exponent)).min(self.max_delay.as_secs_f64());
+        let jitter = rand::thread_rng().gen_range(0.0..=delay / 2.0);
+        Duration::from_secs_f64(delay / 2.0 + jitter)
+    }
+}
+
+/// Retry policy of an `RpcClient` (see `RpcClient.set_retry_policy()`).
+pub(crate) struct RetryPolicy {
+    default: RetrySettings,
+    /// Per-method settings; `None` disables retrying the method.
+    methods: HashMap<String, Option<RetrySettings>>,
+}
+
+impl RetryPolicy {
+    pub fn new(default: RetrySettings, methods: HashMap<String, Option<RetrySettings>>) -> Self {
+        Self { default, methods }
+    }
+
+    fn settings(&self, method: &str) -> Option<RetrySettings> {
+        match self.methods.get(method) {
+            Some(settings) => *settings,
+            None if NON_IDEMPOTENT_METHODS.contains(&method) => None,
+            None => Some(self.default),
+        }
+    }
+}
+
+fn is_transient(message: &str) -> bool {
+    let message = message.to_lowercase();
+    TRANSIENT_ERRORS
+        .iter()
+        .any(|fragment| message.contains(fragment))
+}
+
+/// Run `call`, retrying it while it fails with a transient error and the
+/// policy allows another attempt for `method`.
+pub(crate) async fn retry<T, E, F, Fut>(
+    policy: Option<Arc<RetryPolicy>>,
+    method: &str,
+    mut call: F,
+) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let settings = policy.and_then(|policy| policy.settings(method));
+    let mut attempt = 1;
+    loop {
+        let result = call().await;
+        let Some(settings) = settings else {
+            return result;
+        };
+        let transient = matches!(&result, Err(err) if is_transient(&err.to_string()));
+        if !transient || attempt >= settings.attempts {
+            return result;
+        }
+        task::sleep(settings.delay(attempt)).await;
+        attempt += 1;
+    }
+}
@@ -0,0 +1,129 @@
+use futures::lock::Mutex;
+use kaspa_addresses::Address;
+use kaspa_rpc_core::{
+    api::rpc::RpcApi,
+    model::{GetUtxosByAddressesRequest, RpcUtxosByAddressesEntry},
+};
+use kaspa_wrpc_client::KaspaRpcClient;
+use pyo3::{
+    exceptions::{PyException, PyStopAsyncIteration},
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+pub const DEFAULT_SNAPSHOT_BATCH_SIZE: usize = 1000;
+
+/// Fetch the UTXOs of one batch of addresses.
+async fn fetch(
+    client: &KaspaRpcClient,
+    addresses: Vec<Address>,
+) -> PyResult<Vec<RpcUtxosByAddressesEntry>> {
+    Ok(client
+        .get_utxos_by_addresses_call(None, GetUtxosByAddressesRequest::new(addresses))
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?
+        .entries)
+}
+
+/// Append `entries` to `out` as JSON Lines, in the `get_utxos_by_addresses()` entry form.
+fn write_lines(out: &mut impl Write, entries: &[RpcUtxosByAddressesEntry]) -> PyResult<()> {
+    for entry in entries {
+        serde_json::to_writer(&mut *out, entry)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        out.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Async iterator over the UTXOs of many addresses, as JSON Lines chunks.
+///
+/// Created by `RpcClient.stream_utxos_by_addresses()`. Each iteration queries
+/// the next batch of addresses with `get_utxos_by_addresses()` and yields its
+/// entries as `bytes`: one JSON object per line, in the form of the
+/// `get_utxos_by_addresses()` entries. No Python objects are built per entry,
+/// and only one batch is held in memory at a time.
+#[gen_stub_pyclass]
+#[pyclass(name = "UtxoStream")]
+pub struct PyUtxoStream {
+    client: Arc<KaspaRpcClient>,
+    batches: Arc<Mutex<VecDeque<Vec<Address>>>>,
+}
+
+impl PyUtxoStream {
+    pub fn new(client: Arc<KaspaRpcClient>, addresses: Vec<Address>, batch_size: usize) -> Self {
+        let batches = addresses
+            .chunks(batch_size)
+            .map(<[Address]>::to_vec)
+            .collect();
+        Self {
+            client,
+            batches: Arc::new(Mutex::new(batches)),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyUtxoStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Fetch the UTXOs of the next batch of addresses.
+    ///
+    /// Returns:
+    ///     bytes: The entries as JSON Lines (empty if the batch holds no UTXOs).
+    ///
+    /// Raises:
+    ///     StopAsyncIteration: When all addresses were queried.
+    ///     Exception: If the RPC call fails.
+    #[gen_stub(override_return_type(type_repr = "typing.Awaitable[bytes]"))]
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let batches = self.batches.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let Some(addresses) = batches.lock().await.pop_front() else {
+                return Err(PyStopAsyncIteration::new_err(()));
+            };
+            let entries = fetch(&client, addresses).await?;
+            let mut chunk = Vec::new();
+            write_lines(&mut chunk, &entries)?;
+            Python::attach(|py| Ok(PyBytes::new(py, &chunk).unbind()))
+        })
+    }
+}
+
+/// Write the UTXOs of `addresses` to `path` as JSON Lines, one batch at a time.
+pub async fn dump(
+    client: Arc<KaspaRpcClient>,
+    addresses: Vec<Address>,
+    batch_size: usize,
+    path: PathBuf,
+) -> PyResult<Py<PyAny>> {
+    let mut out = BufWriter::new(File::create(&path)?);
+    let (mut count, mut amount) = (0u64, 0u64);
+    for batch in addresses.chunks(batch_size) {
+        let entries = fetch(&client, batch.to_vec()).await?;
+        write_lines(&mut out, &entries)?;
+        count += entries.len() as u64;
+        amount += entries
+            .iter()
+            .map(|entry| entry.utxo_entry.amount)
+            .sum::<u64>();
+    }
+    out.flush()?;
+    Python::attach(|py| {
+        let summary = PyDict::new(py);
+        summary.set_item("entries", count)?;
+        summary.set_item("amount", amount)?;
+        Ok(summary.into_any().unbind())
+    })
+}
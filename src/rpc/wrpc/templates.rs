@@ -0,0 +1,205 @@
+use futures::{FutureExt, lock::Mutex, select_biased};
+use kaspa_hashes::Hash;
+use kaspa_notify::{
+    connection::ChannelType,
+    listener::ListenerId,
+    scope::{NewBlockTemplateScope, Scope},
+};
+use kaspa_rpc_core::{
+    Notification,
+    api::rpc::RpcApi,
+    model::{GetBlockTemplateRequest, GetBlockTemplateResponse, RpcRawBlock},
+    notify::connection::ChannelConnection,
+};
+use kaspa_wrpc_client::KaspaRpcClient;
+use pyo3::{
+    exceptions::{PyException, PyStopAsyncIteration},
+    prelude::*,
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use workflow_core::{channel::Channel, task};
+
+/// Default interval at which templates are refetched when no
+/// `new-block-template` notification arrives.
+pub const DEFAULT_TEMPLATE_POLL_INTERVAL: f64 = 1.0;
+
+/// Parents and transactions of a template; templates that only differ in
+/// their timestamp are not yielded again.
+type TemplateKey = (Vec<Vec<Hash>>, Hash);
+
+struct TemplatesState {
+    channel: Channel<Notification>,
+    listener_id: Option<ListenerId>,
+    last: Option<TemplateKey>,
+}
+
+/// Async iterator over the block templates of a node.
+///
+/// Created by `RpcClient.block_templates()`. The first iteration returns the
+/// current template; each following one waits for a `new-block-template`
+/// notification (or `poll_interval` without one), fetches the template and
+/// returns it if its parents or transactions changed.
+///
+/// Iteration stops when the client disconnects.
+#[gen_stub_pyclass]
+#[pyclass(name = "BlockTemplates")]
+pub struct PyBlockTemplates {
+    client: Arc<KaspaRpcClient>,
+    request: GetBlockTemplateRequest,
+    poll_interval: Duration,
+    state: Arc<Mutex<TemplatesState>>,
+}
+
+impl PyBlockTemplates {
+    pub fn new(
+        client: Arc<KaspaRpcClient>,
+        request: GetBlockTemplateRequest,
+        poll_interval: Duration,
+    ) -> Self {
+        Self {
+            client,
+            request,
+            poll_interval,
+            state: Arc::new(Mutex::new(TemplatesState {
+                channel: Channel::unbounded(),
+                listener_id: None,
+                last: None,
+            })),
+        }
+    }
+}
+
+impl Drop for PyBlockTemplates {
+    fn drop(&mut self) {
+        let client = self.client.clone();
+        let state = self.state.clone();
+        pyo3_async_runtimes::tokio::get_runtime().spawn(async move {
+            let mut state = state.lock().await;
+            if let Some(listener_id) = state.listener_id.take() {
+                client.unregister_listener(listener_id).await.ok();
+            }
+            state.channel.close();
+        });
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyBlockTemplates {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Wait for the next changed block template.
+    ///
+    /// Returns:
+    ///     BlockTemplate: The template.
+    ///
+    /// Raises:
+    ///     StopAsyncIteration: When the client is disconnected.
+    ///     Exception: If fetching the template fails.
+    #[gen_stub(override_return_type(type_repr = "typing.Awaitable[BlockTemplate]"))]
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let request = self.request.clone();
+        let poll_interval = self.poll_interval;
+        let state = self.state.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut state = state.lock().await;
+            if !client.is_connected() {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+
+            if state.listener_id.is_none() {
+                let listener_id = client.register_new_listener(ChannelConnection::new(
+                    "kaspapy-block-templates",
+                    state.channel.sender.clone(),
+                    ChannelType::Persistent,
+                ));
+                state.listener_id = Some(listener_id);
+                client
+                    .start_notify(
+                        listener_id,
+                        Scope::NewBlockTemplate(NewBlockTemplateScope {}),
+                    )
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+            } else {
+                state.wait(poll_interval).await;
+            }
+
+            loop {
+                if !client.is_connected() {
+                    return Err(PyStopAsyncIteration::new_err(()));
+                }
+                let response = client
+                    .get_block_template_call(None, request.clone())
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                let header = &response.block.header;
+                let key = (header.parents_by_level.clone(), header.hash_merkle_root);
+                if state.last.as_ref() != Some(&key) {
+                    state.last = Some(key);
+                    return Ok(PyBlockTemplate {
+                        response,
+                        received: Instant::now(),
+                    });
+                }
+                state.wait(poll_interval).await;
+            }
+        })
+    }
+}
+
+impl TemplatesState {
+    /// Wait for a `new-block-template` notification or `poll_interval`,
+    /// dropping notifications queued in the meantime.
+    async fn wait(&mut self, poll_interval: Duration) {
+        select_biased! {
+            _ = self.channel.receiver.recv().fuse() => {},
+            _ = task::sleep(poll_interval).fuse() => {},
+        }
+        while self.channel.receiver.try_recv().is_ok() {}
+    }
+}
+
+/// A block template returned by `RpcClient.block_templates()`.
+#[gen_stub_pyclass]
+#[pyclass(name = "BlockTemplate")]
+pub struct PyBlockTemplate {
+    response: GetBlockTemplateResponse,
+    received: Instant,
+}
+
+impl PyBlockTemplate {
+    pub(crate) fn block(&self) -> &RpcRawBlock {
+        &self.response.block
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyBlockTemplate {
+    /// The block template, as in the `get_block_template()` response.
+    #[getter]
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn get_block<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(serde_pyobject::to_pyobject(py, &self.response.block)?)
+    }
+
+    /// Whether the node was synced when the template was built.
+    #[getter]
+    fn get_is_synced(&self) -> bool {
+        self.response.is_synced
+    }
+
+    /// Seconds since the template was fetched.
+    #[getter]
+    fn get_age(&self) -> f64 {
+        self.received.elapsed().as_secs_f64()
+    }
+}
@@ -0,0 +1,136 @@
+use futures::lock::Mutex;
+use kaspa_hashes::Hash;
+use kaspa_rpc_core::{api::rpc::RpcApi, model::GetBlockRequest};
+use kaspa_wrpc_client::KaspaRpcClient;
+use pyo3::{
+    exceptions::{PyException, PyStopAsyncIteration},
+    prelude::*,
+};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
+
+/// How a walk follows the parents of a block.
+enum WalkMode {
+    /// Follow the selected parent only, for at most `limit` blocks.
+    SelectedParentChain { limit: u64, walked: u64 },
+    /// Follow all direct parents breadth-first, at most `depth` levels deep.
+    Ancestors { depth: u64 },
+}
+
+struct WalkState {
+    mode: WalkMode,
+    /// Blocks to fetch, with their distance from the starting block.
+    queue: VecDeque<(Hash, u64)>,
+    visited: HashSet<Hash>,
+}
+
+/// Async iterator over block headers, walking the DAG towards genesis.
+///
+/// Created by `RpcClient.walk_selected_parent_chain()` and
+/// `RpcClient.get_block_ancestors()`. Each block is fetched with
+/// `get_block()` (without transactions) only when the next header is
+/// requested, so a walk can be abandoned at any point.
+///
+/// Headers are dicts as in the `get_block()` response.
+#[gen_stub_pyclass]
+#[pyclass(name = "HeaderWalk")]
+pub struct PyHeaderWalk {
+    client: Arc<KaspaRpcClient>,
+    state: Arc<Mutex<WalkState>>,
+}
+
+impl PyHeaderWalk {
+    pub fn selected_parent_chain(client: Arc<KaspaRpcClient>, from: Hash, limit: u64) -> Self {
+        Self::new(
+            client,
+            from,
+            WalkMode::SelectedParentChain { limit, walked: 0 },
+        )
+    }
+
+    pub fn ancestors(client: Arc<KaspaRpcClient>, from: Hash, depth: u64) -> Self {
+        Self::new(client, from, WalkMode::Ancestors { depth })
+    }
+
+    fn new(client: Arc<KaspaRpcClient>, from: Hash, mode: WalkMode) -> Self {
+        Self {
+            client,
+            state: Arc::new(Mutex::new(WalkState {
+                mode,
+                queue: VecDeque::from([(from, 0)]),
+                visited: HashSet::from([from]),
+            })),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyHeaderWalk {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Fetch the next header of the walk.
+    ///
+    /// Returns:
+    ///     dict: The header.
+    ///
+    /// Raises:
+    ///     StopAsyncIteration: When the walk reached its limit or genesis.
+    ///     Exception: If fetching a block fails.
+    #[gen_stub(override_return_type(type_repr = "typing.Awaitable[dict]"))]
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let state = self.state.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut state = state.lock().await;
+            if let WalkMode::SelectedParentChain { limit, walked } = state.mode
+                && walked >= limit
+            {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            let Some((hash, distance)) = state.queue.pop_front() else {
+                return Err(PyStopAsyncIteration::new_err(()));
+            };
+
+            let block = client
+                .get_block_call(None, GetBlockRequest::new(hash, false))
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?
+                .block;
+
+            let parents: Vec<Hash> = match &mut state.mode {
+                WalkMode::SelectedParentChain { walked, .. } => {
+                    *walked += 1;
+                    block
+                        .verbose_data
+                        .as_ref()
+                        .map(|data| data.selected_parent_hash)
+                        .filter(|parent| *parent != Hash::default())
+                        .into_iter()
+                        .collect()
+                }
+                WalkMode::Ancestors { depth } if distance < *depth => block
+                    .header
+                    .parents_by_level
+                    .first()
+                    .cloned()
+                    .unwrap_or_default(),
+                WalkMode::Ancestors { .. } => vec![],
+            };
+            for parent in parents {
+                if state.visited.insert(parent) {
+                    state.queue.push_back((parent, distance + 1));
+                }
+            }
+
+            Python::attach(|py| -> PyResult<Py<PyAny>> {
+                Ok(serde_pyobject::to_pyobject(py, &block.header)?.unbind())
+            })
+        })
+    }
+}
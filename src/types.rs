@@ -1,7 +1,9 @@
-use pyo3::exceptions::PyException;
+use crate::validation;
+use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+use pyo3::exceptions::{PyException, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyList};
-use pyo3_stub_gen::derive::gen_stub_pyclass;
+use pyo3::types::{PyBool, PyByteArray, PyBytes, PyFloat, PyInt, PyList, PyMemoryView, PyString};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
 /// Binary data type for flexible input handling.
 ///
@@ -10,8 +12,10 @@ use pyo3_stub_gen::derive::gen_stub_pyclass;
 /// data in multiple convenient forms from Python.
 ///
 /// Accepts:
-///     - str: A hexadecimal string (e.g., "deadbeef").
 ///     - bytes: Python bytes object.
+///     - bytearray: Python bytearray object.
+///     - memoryview: Any contiguous buffer exposed through a memoryview.
+///     - str: A hexadecimal string (e.g., "deadbeef").
 ///     - list[int]: A list of byte values (0-255).
 #[gen_stub_pyclass]
 #[pyclass(name = "Binary")]
@@ -23,18 +27,34 @@ impl<'py> FromPyObject<'_, 'py> for PyBinary {
     type Error = PyErr;
 
     fn extract(value: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
-        if let Ok(str) = value.extract::<String>() {
+        if let Ok(py_bytes) = value.cast::<PyBytes>() {
+            // Python `bytes` type
+            Ok(PyBinary {
+                data: py_bytes.as_bytes().to_vec(),
+            })
+        } else if let Ok(py_bytearray) = value.cast::<PyByteArray>() {
+            // Python `bytearray` type
+            Ok(PyBinary {
+                data: py_bytearray.to_vec(),
+            })
+        } else if let Ok(py_memoryview) = value.cast::<PyMemoryView>() {
+            // Python `memoryview` (copied out through `tobytes()`)
+            let py_bytes = py_memoryview.call_method0("tobytes")?;
+            Ok(PyBinary {
+                data: py_bytes.cast::<PyBytes>()?.as_bytes().to_vec(),
+            })
+        } else if let Ok(str) = value.extract::<String>() {
             // Python `str` (of valid hex)
+            if let Some(reason) = validation::hex_error(&str, None) {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid hex string: {reason}"
+                )));
+            }
             let mut data = vec![0u8; str.len() / 2];
             match faster_hex::hex_decode(str.as_bytes(), &mut data) {
                 Ok(()) => Ok(PyBinary { data }),
-                Err(_) => Err(PyException::new_err("Invalid hex string")),
+                Err(_) => Err(PyValueError::new_err("Invalid hex string")),
             }
-        } else if let Ok(py_bytes) = value.cast::<PyBytes>() {
-            // Python `bytes` type
-            Ok(PyBinary {
-                data: py_bytes.as_bytes().to_vec(),
-            })
         } else if let Ok(op_list) = value.cast::<PyList>() {
             // Python `[int]` (list of bytes)
             let data = op_list
@@ -44,7 +64,7 @@ impl<'py> FromPyObject<'_, 'py> for PyBinary {
             Ok(PyBinary { data })
         } else {
             Err(PyException::new_err(
-                "Expected `str` (of valid hex), `bytes`, or `[int]`",
+                "Expected `bytes`, `bytearray`, `memoryview`, `str` (of valid hex), or `[int]`",
             ))
         }
     }
@@ -53,30 +73,7 @@ impl<'py> FromPyObject<'_, 'py> for PyBinary {
 impl TryFrom<&Bound<'_, PyAny>> for PyBinary {
     type Error = PyErr;
     fn try_from(value: &Bound<PyAny>) -> Result<Self, Self::Error> {
-        if let Ok(str) = value.extract::<String>() {
-            // Python `str` (of valid hex)
-            let mut data = vec![0u8; str.len() / 2];
-            match faster_hex::hex_decode(str.as_bytes(), &mut data) {
-                Ok(()) => Ok(PyBinary { data }), // Hex string
-                Err(_) => Err(PyException::new_err("Invalid hex string")),
-            }
-        } else if let Ok(py_bytes) = value.cast::<PyBytes>() {
-            // Python `bytes` type
-            Ok(PyBinary {
-                data: py_bytes.as_bytes().to_vec(),
-            })
-        } else if let Ok(op_list) = value.cast::<PyList>() {
-            // Python `[int]` (list of bytes)
-            let data = op_list
-                .iter()
-                .map(|item| item.extract::<u8>().unwrap())
-                .collect();
-            Ok(PyBinary { data })
-        } else {
-            Err(PyException::new_err(
-                "Expected `str` (of valid hex), `bytes`, or `[int]`",
-            ))
-        }
+        value.extract::<PyBinary>()
     }
 }
 
@@ -91,3 +88,355 @@ impl AsRef<[u8]> for PyBinary {
         self.data.as_slice()
     }
 }
+
+/// Amount type for flexible input handling.
+///
+/// Allows Rust functions to accept amounts in multiple convenient forms from
+/// Python, converted to sompi (1 KAS = 100,000,000 sompi). `Amount(value)`
+/// parses a value once, e.g. to display it with `format()`.
+///
+/// Accepts:
+///     - int: An amount in sompi.
+///     - str: An amount with a unit, e.g. "1.5 KAS" or "1500 sompi" (case insensitive,
+///       `TKAS`/`SKAS`/`DKAS` are accepted as `KAS`, `,` and `_` separators are ignored).
+///     - decimal.Decimal: An amount in KAS.
+///     - Amount: A parsed amount.
+///
+/// `float` is rejected, as it cannot represent most KAS amounts exactly.
+#[gen_stub_pyclass]
+#[pyclass(name = "Amount")]
+pub struct PyAmount(pub u64);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyAmount {
+    /// Parse an amount.
+    ///
+    /// Args:
+    ///     amount: An `int` in sompi, a string with a unit or a `decimal.Decimal` in KAS.
+    ///
+    /// Raises:
+    ///     TypeError: If the amount is a `float` or of an unsupported type.
+    ///     ValueError: If the amount is invalid.
+    #[new]
+    pub fn constructor(
+        #[gen_stub(override_type(type_repr = "int | str | decimal.Decimal | Amount"))]
+        amount: Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        parse_amount(&amount).map(Self)
+    }
+
+    /// The amount in sompi.
+    #[getter]
+    pub fn get_sompi(&self) -> u64 {
+        self.0
+    }
+
+    /// Format the amount for display.
+    ///
+    /// Args:
+    ///     unit: `KAS` (or `TKAS`/`SKAS`/`DKAS`, the suffix shown) or `sompi` (default: KAS).
+    ///     decimals: Fixed number of decimal places, rounded half up (default: as
+    ///         many as needed, at most 8).
+    ///     locale: Use the separators of a locale, e.g. `de_DE` or `fr`, or
+    ///         `system` for the process locale (default: `,` and `.`).
+    ///     thousands_separator: Separator between groups of 3 digits, overriding the locale.
+    ///     decimal_point: Decimal point, overriding the locale.
+    ///     suffix: Append the unit (default: True).
+    ///
+    /// Returns:
+    ///     str: The formatted amount, e.g. "1,234.5 KAS" or "1.234,5 KAS".
+    ///
+    /// Raises:
+    ///     ValueError: If the unit, decimals or locale are invalid.
+    #[pyo3(signature = (unit="KAS", decimals=None, locale=None, thousands_separator=None, decimal_point=None, suffix=true))]
+    pub fn format(
+        &self,
+        py: Python<'_>,
+        unit: &str,
+        decimals: Option<usize>,
+        locale: Option<&str>,
+        thousands_separator: Option<&str>,
+        decimal_point: Option<&str>,
+        suffix: bool,
+    ) -> PyResult<String> {
+        AmountFormat::new(
+            py,
+            unit,
+            decimals,
+            locale,
+            thousands_separator,
+            decimal_point,
+            suffix,
+        )?
+        .format(self.0)
+    }
+
+    fn __int__(&self) -> u64 {
+        self.0
+    }
+
+    fn __eq__(&self, other: &PyAmount) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Amount({})", self.0)
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        self.format(py, "KAS", None, None, None, None, true)
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyAmount {
+    type Error = PyErr;
+
+    fn extract(value: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        parse_amount(&value).map(PyAmount)
+    }
+}
+
+impl From<PyAmount> for u64 {
+    fn from(value: PyAmount) -> u64 {
+        value.0
+    }
+}
+
+const KAS_DECIMALS: usize = 8;
+
+/// Parse an amount given as `int` (sompi), `str` with a unit or
+/// `decimal.Decimal` (KAS) into sompi.
+pub fn parse_amount(value: &Bound<'_, PyAny>) -> PyResult<u64> {
+    if value.is_instance_of::<PyBool>() {
+        return Err(PyTypeError::new_err("Expected an amount, got `bool`"));
+    }
+    if let Ok(amount) = value.cast::<PyAmount>() {
+        return Ok(amount.borrow().0);
+    }
+    if let Ok(sompi) = value.cast::<PyInt>() {
+        return sompi.extract::<u64>().map_err(|_| {
+            PyValueError::new_err(format!(
+                "Invalid amount {value}: must be a non-negative number of sompi below 2**64"
+            ))
+        });
+    }
+    if value.is_instance_of::<PyFloat>() {
+        return Err(PyTypeError::new_err(format!(
+            "Float amounts lose precision, got {value}: pass sompi as `int`, KAS as `decimal.Decimal` or a string such as \"1.5 KAS\""
+        )));
+    }
+    if let Ok(str) = value.cast::<PyString>() {
+        let str = str.to_cow()?;
+        return parse_amount_str(&str)
+            .map_err(|reason| PyValueError::new_err(format!("Invalid amount {str:?}: {reason}")));
+    }
+    let decimal = value.py().import("decimal")?.getattr("Decimal")?;
+    if value.is_instance(&decimal)? {
+        // Fixed-point notation, without exponent
+        let kas = value
+            .call_method1("__format__", ("f",))?
+            .extract::<String>()?;
+        return parse_kas(&kas).map_err(|reason| {
+            PyValueError::new_err(format!("Invalid amount {kas} KAS: {reason}"))
+        });
+    }
+    Err(PyTypeError::new_err(
+        "Expected `int` (sompi), `str` with a unit (e.g. \"1.5 KAS\") or `decimal.Decimal` (KAS)",
+    ))
+}
+
+fn parse_amount_str(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let split = value
+        .find(|char: char| !(char.is_ascii_digit() || matches!(char, '.' | ',' | '_' | '-')))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number = number.trim().replace([',', '_'], "");
+    match unit.trim().to_ascii_lowercase().as_str() {
+        "kas" | "tkas" | "skas" | "dkas" => parse_kas(&number),
+        "sompi" => {
+            check_number(&number)?;
+            if number.contains('.') {
+                return Err("sompi amounts must be whole numbers".to_string());
+            }
+            number
+                .parse::<u64>()
+                .map_err(|_| "amount out of range".to_string())
+        }
+        "" => Err("missing unit, e.g. \"1.5 KAS\" or \"1500 sompi\"".to_string()),
+        unit => Err(format!("unknown unit `{unit}`, expected `KAS` or `sompi`")),
+    }
+}
+
+fn check_number(number: &str) -> Result<(), String> {
+    if number.starts_with('-') {
+        return Err("must not be negative".to_string());
+    }
+    let mut parts = number.splitn(2, '.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default();
+    let digits = |part: &str| part.chars().all(|char| char.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !digits(integer) || !digits(fraction) {
+        return Err(format!("`{number}` is not a number"));
+    }
+    Ok(())
+}
+
+/// Parse a decimal KAS amount into sompi without going through a float.
+fn parse_kas(number: &str) -> Result<u64, String> {
+    check_number(number)?;
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > KAS_DECIMALS {
+        return Err(format!(
+            "KAS amounts have at most {KAS_DECIMALS} decimal places"
+        ));
+    }
+    let parse = |digits: &str| {
+        if digits.is_empty() {
+            Some(0)
+        } else {
+            digits.parse::<u64>().ok()
+        }
+    };
+    let fraction = format!("{fraction:0<KAS_DECIMALS$}");
+    parse(integer)
+        .and_then(|integer| integer.checked_mul(SOMPI_PER_KASPA))
+        .zip(parse(&fraction))
+        .and_then(|(integer, fraction)| integer.checked_add(fraction))
+        .ok_or_else(|| "amount out of range".to_string())
+}
+
+/// Display options for amounts, shared by `Amount.format()` and `format_amount()`.
+pub struct AmountFormat {
+    /// Decimal places of the unit: 8 for KAS, 0 for sompi.
+    scale: usize,
+    label: String,
+    decimals: Option<usize>,
+    thousands_separator: String,
+    decimal_point: String,
+    suffix: bool,
+}
+
+impl AmountFormat {
+    pub fn new(
+        py: Python<'_>,
+        unit: &str,
+        decimals: Option<usize>,
+        locale: Option<&str>,
+        thousands_separator: Option<&str>,
+        decimal_point: Option<&str>,
+        suffix: bool,
+    ) -> PyResult<Self> {
+        let (scale, label) = match unit.to_ascii_lowercase().as_str() {
+            "kas" | "tkas" | "skas" | "dkas" => (KAS_DECIMALS, unit.to_ascii_uppercase()),
+            "sompi" => (0, "sompi".to_string()),
+            _ => {
+                return Err(validation::invalid(
+                    "unit",
+                    format!("`{unit}` is not `KAS` or `sompi`"),
+                ));
+            }
+        };
+        if let Some(decimals) = decimals
+            && decimals > scale
+        {
+            return Err(validation::invalid(
+                "decimals",
+                format!("{label} amounts have at most {scale} decimal places"),
+            ));
+        }
+        let (locale_thousands, locale_point) = match locale {
+            Some(locale) => locale_separators(py, locale)?,
+            None => (",".to_string(), ".".to_string()),
+        };
+        Ok(Self {
+            scale,
+            label,
+            decimals,
+            thousands_separator: thousands_separator
+                .map(str::to_string)
+                .unwrap_or(locale_thousands),
+            decimal_point: decimal_point.map(str::to_string).unwrap_or(locale_point),
+            suffix,
+        })
+    }
+
+    pub fn format(&self, sompi: u64) -> PyResult<String> {
+        let digits = match self.decimals {
+            Some(decimals) => {
+                // Round half up to `decimals` places, in u128 to avoid overflow.
+                let divisor = 10u128.pow((self.scale - decimals) as u32);
+                let rounded = (sompi as u128 + divisor / 2) / divisor;
+                format!("{rounded:0>width$}", width = decimals + 1)
+            }
+            None => format!("{sompi:0>width$}", width = self.scale + 1),
+        };
+        let decimals = self.decimals.unwrap_or(self.scale);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = match self.decimals {
+            Some(_) => fraction,
+            None => fraction.trim_end_matches('0'),
+        };
+
+        let mut formatted = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                formatted.push_str(&self.thousands_separator);
+            }
+            formatted.push(digit);
+        }
+        if !fraction.is_empty() {
+            formatted.push_str(&self.decimal_point);
+            formatted.push_str(fraction);
+        }
+        if self.suffix {
+            formatted.push(' ');
+            formatted.push_str(&self.label);
+        }
+        Ok(formatted)
+    }
+}
+
+/// The thousands separator and decimal point of a locale such as `de_DE`, `pt-BR` or `fr`.
+fn locale_separators(py: Python<'_>, locale: &str) -> PyResult<(String, String)> {
+    if locale == "system" {
+        let conventions = py.import("locale")?.call_method0("localeconv")?;
+        return Ok((
+            conventions.get_item("thousands_sep")?.extract()?,
+            conventions.get_item("decimal_point")?.extract()?,
+        ));
+    }
+    let normalized = locale
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .replace('-', "_")
+        .to_ascii_lowercase();
+    let (language, region) = normalized
+        .split_once('_')
+        .unwrap_or((normalized.as_str(), ""));
+    let (thousands_separator, decimal_point) = match (language, region) {
+        ("de" | "fr" | "it" | "rm", "ch" | "li") => ("\u{2019}", "."),
+        ("es", "mx" | "us") => (",", "."),
+        ("en" | "ja" | "zh" | "ko" | "th" | "he" | "ms" | "hi" | "fil", _) => (",", "."),
+        (
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "vi" | "ro" | "hr"
+            | "sl" | "sr",
+            _,
+        ) => (".", ","),
+        (
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "uk" | "hu" | "bg"
+            | "lt" | "lv" | "et",
+            _,
+        ) => ("\u{a0}", ","),
+        _ => {
+            return Err(validation::invalid(
+                "locale",
+                format!("unknown locale `{locale}`"),
+            ));
+        }
+    };
+    Ok((thousands_separator.to_string(), decimal_point.to_string()))
+}
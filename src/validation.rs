@@ -0,0 +1,102 @@
+//! Argument validation raising `ValueError`s that name the offending field,
+//! instead of surfacing low-level conversion errors.
+
+use kaspa_addresses::{Address, Prefix};
+use kaspa_consensus_core::network::NetworkId;
+use pyo3::{
+    exceptions::{PyKeyError, PyOverflowError, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use std::fmt::Display;
+
+/// `ValueError` for the argument or dict key `field`.
+pub(crate) fn invalid(field: &str, reason: impl Display) -> PyErr {
+    PyValueError::new_err(format!("Invalid `{field}`: {reason}"))
+}
+
+/// Check that `value` is a hex string, of `len` characters if given.
+pub(crate) fn hex(field: &str, value: &str, len: Option<usize>) -> PyResult<()> {
+    match hex_error(value, len) {
+        Some(reason) => Err(invalid(field, reason)),
+        None => Ok(()),
+    }
+}
+
+/// Why `value` is not a hex string (of `len` characters if given), if it is not.
+pub(crate) fn hex_error(value: &str, len: Option<usize>) -> Option<String> {
+    if let Some((position, char)) = value
+        .char_indices()
+        .find(|(_, char)| !char.is_ascii_hexdigit())
+    {
+        return Some(format!("non-hex character {char:?} at position {position}"));
+    }
+    match len {
+        Some(len) if value.len() != len => Some(format!(
+            "expected {len} hex characters, got {}",
+            value.len()
+        )),
+        None if value.len() % 2 != 0 => {
+            Some(format!("odd number of hex characters ({})", value.len()))
+        }
+        _ => None,
+    }
+}
+
+/// Extract the required key `key` of `dict`.
+///
+/// Raises `KeyError` if the key is missing and `ValueError` naming the key if
+/// its value has the wrong type or is out of range (e.g. a negative amount).
+pub(crate) fn field<'py, T: FromPyObjectOwned<'py>>(
+    dict: &Bound<'py, PyDict>,
+    key: &str,
+) -> PyResult<T> {
+    let value = dict
+        .get_item(key)?
+        .ok_or_else(|| PyKeyError::new_err(format!("Key `{key}` not present")))?;
+    extract(key, &value)
+}
+
+/// Extract `value`, raising `ValueError` naming `field` on failure.
+pub(crate) fn extract<'py, T: FromPyObjectOwned<'py>>(
+    field: &str,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<T> {
+    value.extract::<T>().map_err(|err| {
+        let err: PyErr = err.into();
+        let py = value.py();
+        if err.is_instance_of::<PyOverflowError>(py) {
+            let reason = if value.lt(0).unwrap_or(false) {
+                format!("must not be negative, got {value}")
+            } else {
+                format!("out of range, got {value}")
+            };
+            return invalid(field, reason);
+        }
+        let type_name = value
+            .get_type()
+            .name()
+            .map(|name| name.to_string())
+            .unwrap_or_default();
+        invalid(field, format!("{} (got {type_name})", err.value(py)))
+    })
+}
+
+/// Check that `address` belongs to the network `network_id`.
+pub(crate) fn address_network(
+    field: &str,
+    address: &Address,
+    network_id: &NetworkId,
+) -> PyResult<()> {
+    let prefix = Prefix::from(*network_id);
+    if address.prefix != prefix {
+        return Err(invalid(
+            field,
+            format!(
+                "address `{address}` is a `{}` address, expected `{prefix}` for network `{network_id}`",
+                address.prefix
+            ),
+        ));
+    }
+    Ok(())
+}
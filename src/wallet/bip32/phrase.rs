@@ -13,6 +13,18 @@ use workflow_core::hex::ToHex;
 #[pyclass(name = "Mnemonic")]
 pub struct PyMnemonic(Mnemonic);
 
+impl PyMnemonic {
+    pub fn inner(&self) -> &Mnemonic {
+        &self.0
+    }
+}
+
+impl From<Mnemonic> for PyMnemonic {
+    fn from(value: Mnemonic) -> Self {
+        Self(value)
+    }
+}
+
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyMnemonic {
@@ -1,5 +1,5 @@
 pub use crate::address::PyAddress;
-pub use crate::types::PyBinary;
+pub use crate::types::{PyAmount, PyBinary};
 pub use futures::Stream;
 pub use kaspa_addresses::Address;
 pub use kaspa_consensus_core::network::NetworkId;
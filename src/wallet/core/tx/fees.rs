@@ -0,0 +1,144 @@
+use kaspa_wallet_core::tx::Fees;
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+use crate::types::{PyAmount, parse_amount};
+
+#[derive(Clone)]
+enum FeeMode {
+    Absolute(Fees),
+    Rate(f64),
+}
+
+/// Transaction fee specification.
+///
+/// A fee is either an absolute priority fee in sompi (paid by the sender, or
+/// deducted from the outputs with `receiver_pays`), or a fee rate in sompi per
+/// gram of transaction mass. Functions accepting a `Fees` also accept a plain
+/// amount (`int` sompi, or a string such as "0.01 KAS", see `Amount`), which is
+/// an absolute priority fee paid by the sender.
+///
+/// Precedence: an absolute fee is added on top of the network fee computed from
+/// `fee_rate`. A rate `Fees` replaces `fee_rate`, so passing both raises.
+#[gen_stub_pyclass]
+#[pyclass(name = "Fees", skip_from_py_object)]
+#[derive(Clone)]
+pub struct PyFees(FeeMode);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFees {
+    /// Create an absolute priority fee.
+    ///
+    /// Args:
+    ///     amount: The priority fee in sompi, or an amount string such as "0.01 KAS".
+    ///     receiver_pays: Deduct the fee from the payment outputs instead of the change (default: False).
+    ///
+    /// Returns:
+    ///     Fees: A new absolute Fees instance.
+    #[new]
+    #[pyo3(signature = (amount, receiver_pays=false))]
+    pub fn ctor(amount: PyAmount, receiver_pays: bool) -> Self {
+        let amount = amount.into();
+        if receiver_pays {
+            Self(FeeMode::Absolute(Fees::ReceiverPays(amount)))
+        } else {
+            Self(FeeMode::Absolute(Fees::SenderPays(amount)))
+        }
+    }
+
+    /// Create a fee rate in sompi per gram of transaction mass.
+    ///
+    /// Args:
+    ///     sompi_per_gram: The fee rate (must be a positive finite number).
+    ///
+    /// Returns:
+    ///     Fees: A new rate Fees instance.
+    ///
+    /// Raises:
+    ///     Exception: If the fee rate is not positive.
+    #[staticmethod]
+    pub fn rate(sompi_per_gram: f64) -> PyResult<Self> {
+        if !sompi_per_gram.is_finite() || sompi_per_gram <= 0.0 {
+            return Err(PyException::new_err(format!(
+                "Invalid fee rate {}: must be a positive number",
+                sompi_per_gram
+            )));
+        }
+        Ok(Self(FeeMode::Rate(sompi_per_gram)))
+    }
+
+    /// Create a fee rate from a fee estimate bucket.
+    ///
+    /// Args:
+    ///     bucket: A bucket of `RpcClient.get_fee_estimate()` (dict with a `feerate` key) or any object with a `feerate` attribute.
+    ///
+    /// Returns:
+    ///     Fees: A new rate Fees instance using the bucket feerate.
+    ///
+    /// Raises:
+    ///     Exception: If the bucket has no valid `feerate`.
+    #[staticmethod]
+    pub fn from_bucket(bucket: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let feerate = if let Ok(dict) = bucket.cast::<PyDict>() {
+            dict.get_item("feerate")?
+                .ok_or_else(|| PyException::new_err("Fee bucket has no `feerate` key"))?
+        } else {
+            bucket.getattr("feerate")?
+        };
+        Self::rate(feerate.extract()?)
+    }
+
+    /// The absolute priority fee in sompi, or None for a fee rate.
+    #[getter]
+    pub fn get_amount(&self) -> Option<u64> {
+        match self.0 {
+            FeeMode::Absolute(Fees::SenderPays(amount) | Fees::ReceiverPays(amount)) => {
+                Some(amount)
+            }
+            _ => None,
+        }
+    }
+
+    /// The fee rate in sompi per gram, or None for an absolute fee.
+    #[getter]
+    pub fn get_fee_rate(&self) -> Option<f64> {
+        match self.0 {
+            FeeMode::Rate(rate) => Some(rate),
+            FeeMode::Absolute(_) => None,
+        }
+    }
+
+    /// Whether the absolute fee is deducted from the payment outputs.
+    #[getter]
+    pub fn get_receiver_pays(&self) -> bool {
+        matches!(self.0, FeeMode::Absolute(Fees::ReceiverPays(_)))
+    }
+}
+
+impl PyFees {
+    /// Resolve the priority fee and fee rate passed to a Generator.
+    pub fn resolve(fees: Option<PyFees>, fee_rate: Option<f64>) -> PyResult<(Fees, Option<f64>)> {
+        match fees.map(|fees| fees.0) {
+            None => Ok((Fees::None, fee_rate)),
+            Some(FeeMode::Absolute(fees)) => Ok((fees, fee_rate)),
+            Some(FeeMode::Rate(_)) if fee_rate.is_some() => Err(PyException::new_err(
+                "A fee rate was given both as `fee_rate` and as a rate `Fees`",
+            )),
+            Some(FeeMode::Rate(rate)) => Ok((Fees::None, Some(rate))),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyFees {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(fees) = obj.cast::<PyFees>() {
+            Ok(fees.borrow().clone())
+        } else {
+            let amount = parse_amount(&obj)?;
+            Ok(Self(FeeMode::Absolute(amount.into())))
+        }
+    }
+}
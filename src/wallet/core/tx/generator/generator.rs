@@ -1,11 +1,15 @@
 use super::super::super::imports::*;
 use super::pending::PendingTransaction;
+use super::plan::{PyTransactionPlan, ordered_entries};
 use super::summary::PyGeneratorSummary;
 use crate::consensus::core::network::PyNetworkId;
+use crate::validation;
 use crate::{
-    consensus::client::utxo::PyUtxoEntryReference, wallet::core::tx::payment::PyPaymentOutput,
-    wallet::core::utxo::context::PyUtxoContext,
+    consensus::client::utxo::PyUtxoEntryReference, wallet::core::tx::fees::PyFees,
+    wallet::core::tx::payment::PyPaymentOutput, wallet::core::utxo::context::PyUtxoContext,
+    wallet::core::utxo::inflight::InFlightEntries, wallet::core::utxo::locks::Reservation,
 };
+use ahash::AHashSet;
 use kaspa_consensus_client::UtxoEntryReference;
 use kaspa_wallet_core::result::Result;
 use kaspa_wallet_core::tx::{
@@ -13,6 +17,7 @@ use kaspa_wallet_core::tx::{
 };
 use kaspa_wallet_core::utxo::UtxoContext;
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::sync::Mutex;
 use workflow_core::prelude::Abortable;
 
 /// UTXO entries collection for flexible input handling.
@@ -65,7 +70,7 @@ impl<'py> FromPyObject<'_, 'py> for PyUtxoEntries {
 ///
 /// Accepts:
 ///     list[PaymentOutput]: A list of PaymentOutput objects.
-///     list[dict]: A list of dicts with `address` and `amount` keys.
+///     list[dict]: A list of dicts with `address` and `amount` (an `Amount`) keys.
 #[gen_stub_pyclass]
 #[pyclass(name = "Outputs")]
 pub struct PyOutputs {
@@ -101,13 +106,195 @@ impl<'py> FromPyObject<'_, 'py> for PyOutputs {
     }
 }
 
+/// Rounding of percentage output amounts to whole sompi.
+#[derive(Clone, Copy)]
+pub enum Rounding {
+    Down,
+    Up,
+    Nearest,
+}
+
+/// A percentage of the payment routed to an additional output.
+#[derive(Clone)]
+pub struct PercentageOutput {
+    pub address: Address,
+    /// Share of the payment in millionths of a percent.
+    pub micro_percent: u64,
+    pub rounding: Rounding,
+}
+
+impl PercentageOutput {
+    /// The share of `amount`, rounded to whole sompi.
+    fn amount(&self, amount: u64) -> u64 {
+        let numerator = amount as u128 * self.micro_percent as u128;
+        let denominator = 100_000_000u128;
+        let share = match self.rounding {
+            Rounding::Down => numerator / denominator,
+            Rounding::Up => numerator.div_ceil(denominator),
+            Rounding::Nearest => (numerator + denominator / 2) / denominator,
+        };
+        share as u64
+    }
+}
+
+/// Percentage output templates accepted by the Generator.
+///
+/// Accepts a list of dicts with `address` (an `Address` or address string),
+/// `percent` (a share of the payment between 0 and 100) and an optional
+/// `rounding` (`"down"`, the default, `"up"` or `"nearest"`).
+pub struct PyPercentageOutputs(pub Vec<PercentageOutput>);
+
+impl<'py> FromPyObject<'_, 'py> for PyPercentageOutputs {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let list = obj
+            .cast::<PyList>()
+            .map_err(|_| validation::invalid("percentage_outputs", "expected a list of dicts"))?;
+
+        let mut templates = Vec::with_capacity(list.len());
+        let mut total = 0;
+        for item in list.iter() {
+            let dict = item.cast::<PyDict>().map_err(|_| {
+                validation::invalid("percentage_outputs", "expected a list of dicts")
+            })?;
+            let address = dict
+                .get_item("address")?
+                .ok_or_else(|| validation::invalid("percentage_outputs", "missing `address`"))?;
+            let address = if let Ok(address) = address.extract::<PyAddress>() {
+                address.into()
+            } else {
+                Address::from(PyAddress::try_from(address.extract::<String>()?)?)
+            };
+            let percent: f64 = dict
+                .get_item("percent")?
+                .ok_or_else(|| validation::invalid("percentage_outputs", "missing `percent`"))?
+                .extract()?;
+            if !percent.is_finite() || percent <= 0.0 || percent >= 100.0 {
+                return Err(validation::invalid(
+                    "percentage_outputs",
+                    format!("`percent` must be between 0 and 100, got {percent}"),
+                ));
+            }
+            let rounding = match dict.get_item("rounding")? {
+                None => Rounding::Down,
+                Some(rounding) => match rounding.extract::<String>()?.to_lowercase().as_str() {
+                    "down" => Rounding::Down,
+                    "up" => Rounding::Up,
+                    "nearest" => Rounding::Nearest,
+                    rounding => {
+                        return Err(validation::invalid(
+                            "percentage_outputs",
+                            format!(
+                                "`rounding` must be \"down\", \"up\" or \"nearest\", got {rounding:?}"
+                            ),
+                        ));
+                    }
+                },
+            };
+            let micro_percent = (percent * 1_000_000.0).round() as u64;
+            total += micro_percent;
+            templates.push(PercentageOutput {
+                address,
+                micro_percent,
+                rounding,
+            });
+        }
+        if total >= 100_000_000 {
+            return Err(validation::invalid(
+                "percentage_outputs",
+                "percentages must add up to less than 100",
+            ));
+        }
+        Ok(Self(templates))
+    }
+}
+
+/// Change address strategy accepted by the Generator.
+///
+/// Accepts an `Address`, an address string, or a callable returning either.
+/// A callable is invoked for every set of transactions generated (by the
+/// Generator and by each `TransactionPlan.rebuild()`), so every payment can
+/// send its change to a fresh address.
+pub enum PyChangeAddress {
+    Fixed(Address),
+    Callable(Arc<Py<PyAny>>),
+}
+
+impl PyChangeAddress {
+    fn resolve(&self, py: Python) -> PyResult<Address> {
+        match self {
+            PyChangeAddress::Fixed(address) => Ok(address.clone()),
+            PyChangeAddress::Callable(callable) => next_change_address(py, callable),
+        }
+    }
+
+    fn into_callable(self) -> Option<Arc<Py<PyAny>>> {
+        match self {
+            PyChangeAddress::Fixed(_) => None,
+            PyChangeAddress::Callable(callable) => Some(callable),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyChangeAddress {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if obj.is_callable() {
+            Ok(PyChangeAddress::Callable(Arc::new(obj.to_owned().unbind())))
+        } else {
+            parse_change_address(&obj).map(PyChangeAddress::Fixed)
+        }
+    }
+}
+
+fn next_change_address(py: Python, callable: &Py<PyAny>) -> PyResult<Address> {
+    let address = callable.call0(py)?;
+    parse_change_address(address.bind(py))
+}
+
+fn parse_change_address(value: &Bound<'_, PyAny>) -> PyResult<Address> {
+    if let Ok(address) = value.extract::<PyAddress>() {
+        Ok(address.into())
+    } else if let Ok(address) = value.extract::<String>() {
+        PyAddress::try_from(address).map(Address::from)
+    } else {
+        Err(PyException::new_err(
+            "change_address must be an Address, str, or a callable returning one",
+        ))
+    }
+}
+
+/// `UtxoContext` state shared by a Generator and the transactions it yields.
+#[derive(Clone)]
+pub(crate) struct ContextTracking {
+    pub context: UtxoContext,
+    pub in_flight: Arc<InFlightEntries>,
+    pub reservation: Arc<Reservation>,
+}
+
 /// Transaction generator for building and signing transactions.
 ///
 /// Handles UTXO selection, fee calculation, change outputs, and transaction
 /// splitting for large transfers.
+///
+/// When created from a UtxoContext, the UTXOs it selects are locked on the
+/// context (see `UtxoContext.lock()`) so concurrent Generators do not spend them.
 #[gen_stub_pyclass]
 #[pyclass(name = "Generator")]
-pub struct PyGenerator(Arc<native::Generator>);
+pub struct PyGenerator(
+    Arc<native::Generator>,
+    Option<ContextTracking>,
+    GeneratorSettings,
+    Mutex<Option<FoldedChange>>,
+);
+
+/// A final transaction generated again to fold its change into the fees.
+struct FoldedChange {
+    original: native::PendingTransaction,
+    folded: native::PendingTransaction,
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -117,123 +304,116 @@ impl PyGenerator {
     /// Args:
     ///     network_id: The network to build transactions for (required for UTXO entries).
     ///     entries: UtxoContext or list of UTXO entries to spend from.
-    ///     change_address: Address to send change to.
+    ///     change_address: Address to send change to, or a callable returning a fresh change address (invoked once per Generator, and again by `TransactionPlan.rebuild()`).
     ///     outputs: Optional list of payment outputs.
     ///     payload: Optional transaction payload (OP_RETURN data).
-    ///     fee_rate: Optional fee rate multiplier.
-    ///     priority_fee: Additional fee in sompi.
+    ///     fee_rate: Optional fee rate in sompi per gram of mass.
+    ///     priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
     ///     priority_entries: UTXOs to use first.
     ///     sig_op_count: Signature operations per input (default: 1).
     ///     minimum_signatures: For multisig fee estimation.
+    ///     min_change: Optional minimum change amount in sompi. Change of the final transaction below this amount is added to the fee instead of creating a change output (best effort). With receiver-paid fees, the change covers the fees deducted from the first payment output instead.
+    ///     split_outputs: Optional number of equal outputs each payment output is split into (the remainder goes to the first one). Useful to pre-fragment UTXOs.
+    ///     percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` (`"down"`, the default, `"up"` or `"nearest"`). Each routes `percent` of the payment amount to `address`, taken out of the payment outputs in proportion to their amounts (e.g. a platform fee). With receiver-paid fees, percentages apply to the amount after fees.
     ///
     /// Returns:
     ///     Generator: A new Generator instance.
     ///
     /// Raises:
+    ///     ValueError: If an address does not belong to `network_id`.
     ///     Exception: If generator creation fails.
     #[new]
-    #[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+    #[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, min_change=None, split_outputs=None, percentage_outputs=None))]
     pub fn ctor(
         #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<
             '_,
             PyAny,
         >,
-        change_address: PyAddress,
+        #[gen_stub(override_type(
+            type_repr = "Address | builtins.str | typing.Callable[[], Address | builtins.str]"
+        ))]
+        change_address: PyChangeAddress,
         network_id: Option<PyNetworkId>,
         outputs: Option<PyOutputs>,
         payload: Option<PyBinary>,
         fee_rate: Option<f64>,
-        priority_fee: Option<u64>,
+        #[gen_stub(override_type(type_repr = "typing.Optional[Amount | Fees]"))]
+        priority_fee: Option<PyFees>,
         priority_entries: Option<PyUtxoEntries>,
         sig_op_count: Option<u8>,
         minimum_signatures: Option<u16>,
+        min_change: Option<PyAmount>,
+        split_outputs: Option<usize>,
+        #[gen_stub(override_type(type_repr = "typing.Optional[typing.Sequence[dict]]"))]
+        percentage_outputs: Option<PyPercentageOutputs>,
     ) -> PyResult<Self> {
-        let source = parse_generator_source(entries)?;
-        let settings = GeneratorSettings::new(
+        let (priority_fee, fee_rate) = PyFees::resolve(priority_fee, fee_rate)?;
+        let change_policy = change_address;
+        let change_address = change_policy.resolve(entries.py())?;
+        if let Some(network_id) = network_id.clone().map(NetworkId::from) {
+            validation::address_network("change_address", &change_address, &network_id)?;
+            for output in outputs.iter().flat_map(|outputs| &outputs.outputs) {
+                validation::address_network("outputs", &output.address, &network_id)?;
+            }
+            for template in percentage_outputs.iter().flat_map(|templates| &templates.0) {
+                validation::address_network("percentage_outputs", &template.address, &network_id)?;
+            }
+        }
+        let (source, tracking) = parse_generator_source(entries)?;
+        let outputs = match split_outputs {
+            Some(parts) => outputs
+                .map(|outputs| split_payment_outputs(outputs.outputs, parts))
+                .transpose()?
+                .map(|outputs| PyOutputs { outputs }),
+            None => outputs,
+        };
+        let priority_entries =
+            chain_in_flight(priority_entries.map(|p| p.entries), &source, &tracking);
+        let mut settings = GeneratorSettings::new(
             outputs,
-            change_address.into(),
+            change_address,
             fee_rate,
             priority_fee,
             source,
-            priority_entries.map(|p| p.entries),
+            priority_entries,
             sig_op_count,
             minimum_signatures,
             payload.map(Into::into),
             network_id.map(Into::into),
         );
+        settings.reservation = tracking
+            .as_ref()
+            .map(|tracking| tracking.reservation.clone());
+        if let Some(percentage_outputs) = percentage_outputs {
+            settings.apply_percentage_outputs(&percentage_outputs.0)?;
+        }
+        settings.change_callable = change_policy.into_callable();
+        settings.min_change = min_change.map(u64::from);
 
-        let settings = match settings.source {
-            GeneratorSource::UtxoEntries(utxo_entries) => {
-                let change_address = settings.change_address.ok_or_else(|| {
-                    PyException::new_err(
-                        "changeAddress is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                let network_id = settings.network_id.ok_or_else(|| {
-                    PyException::new_err(
-                        "networkId is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                native::GeneratorSettings::try_new_with_iterator(
-                    network_id,
-                    Box::new(utxo_entries.into_iter()),
-                    settings.priority_utxo_entries,
-                    change_address,
-                    settings.sig_op_count,
-                    settings.minimum_signatures,
-                    settings.final_transaction_destination,
-                    settings.fee_rate,
-                    settings.final_priority_fee,
-                    settings.payload,
-                    settings.multiplexer,
-                )
-                .map_err(|err| PyException::new_err(err.to_string()))?
-            }
-            GeneratorSource::UtxoContext(utxo_context) => {
-                let change_address = settings.change_address.ok_or_else(|| {
-                    PyException::new_err(
-                        "changeAddress is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                native::GeneratorSettings::try_new_with_context(
-                    utxo_context,
-                    settings.priority_utxo_entries,
-                    change_address,
-                    settings.sig_op_count,
-                    settings.minimum_signatures,
-                    settings.final_transaction_destination,
-                    settings.fee_rate,
-                    settings.final_priority_fee,
-                    settings.payload,
-                    settings.multiplexer,
-                )
-                .map_err(|err| PyException::new_err(err.to_string()))?
-            }
-        };
-
-        let abortable = Abortable::default();
-        let generator = native::Generator::try_new(settings, None, Some(&abortable))
-            .map_err(|err| PyException::new_err(err.to_string()))?;
-
-        Ok(Self(Arc::new(generator)))
+        Self::from_settings(settings, tracking, true)
     }
 
     /// Estimate the transaction without generating.
     ///
+    /// The returned plan lists the inputs, outputs and fees of every
+    /// transaction. No UTXOs stay locked until `TransactionPlan.materialize()`
+    /// is called to get the transactions to sign.
+    ///
+    /// Pending signals are checked between transactions, so a long estimate
+    /// can be interrupted with Ctrl-C.
+    ///
     /// Returns:
-    ///     GeneratorSummary: A summary with fee, transaction count, and other details.
+    ///     TransactionPlan: A GeneratorSummary with the planned transactions.
     ///
     /// Raises:
     ///     Exception: If estimation fails.
-    pub fn estimate(&self) -> PyResult<PyGeneratorSummary> {
-        self.0
-            .iter()
-            .collect::<Result<Vec<_>>>()
-            .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(self.0.summary().into())
+    ///     KeyboardInterrupt: If interrupted.
+    #[gen_stub(override_return_type(type_repr = "TransactionPlan"))]
+    pub fn estimate(&self, py: Python) -> PyResult<Py<PyTransactionPlan>> {
+        let result = self.generate_all(py);
+        // Nothing generated here is submitted, release the selected UTXOs.
+        self.release_all();
+        self.plan(py, result?)
     }
 
     /// Get the summary after generation.
@@ -241,13 +421,97 @@ impl PyGenerator {
     /// Returns:
     ///     GeneratorSummary: The generation summary with fees and transaction details.
     pub fn summary(&self) -> PyGeneratorSummary {
-        self.0.summary().into()
+        let mut summary = self.0.summary();
+        if let Some(FoldedChange { original, folded }) = &*self.3.lock().unwrap() {
+            summary.aggregate_fees = summary.aggregate_fees - original.fees() + folded.fees();
+            summary.aggregate_mass = summary.aggregate_mass - original.mass() + folded.mass();
+            summary.final_transaction_id = Some(folded.id());
+        }
+        summary.into()
     }
 }
 
 impl PyGenerator {
-    pub fn iter(&self) -> impl Iterator<Item = Result<native::PendingTransaction>> {
-        self.0.iter()
+    /// Build a Generator. With `reserve`, UTXOs selected from a context are
+    /// locked for the Generator, otherwise locked UTXOs are only skipped.
+    pub(crate) fn from_settings(
+        settings: GeneratorSettings,
+        tracking: Option<ContextTracking>,
+        reserve: bool,
+    ) -> PyResult<Self> {
+        let generator = settings.clone().try_into_generator(reserve)?;
+        Ok(Self(
+            Arc::new(generator),
+            tracking,
+            settings,
+            Mutex::new(None),
+        ))
+    }
+
+    /// Describe the generated transactions as a plan. The plan keeps the
+    /// settings so it can be rebuilt.
+    pub(crate) fn plan(
+        &self,
+        py: Python,
+        transactions: Vec<native::PendingTransaction>,
+    ) -> PyResult<Py<PyTransactionPlan>> {
+        PyTransactionPlan::new(
+            py,
+            self.summary(),
+            transactions,
+            self.1.clone(),
+            self.2.clone(),
+            None,
+        )
+    }
+
+    pub fn pending_transaction(
+        &self,
+        transaction: native::PendingTransaction,
+    ) -> PendingTransaction {
+        PendingTransaction::new(transaction, self.1.clone())
+    }
+
+    /// Release all UTXOs locked by this Generator.
+    pub fn release_all(&self) {
+        if let Some(tracking) = &self.1 {
+            tracking.reservation.release_all();
+        }
+    }
+
+    /// Generate all remaining transactions, checking for pending signals
+    /// (e.g. Ctrl-C) after each one.
+    pub fn generate_all(&self, py: Python) -> PyResult<Vec<native::PendingTransaction>> {
+        let mut transactions = Vec::new();
+        while let Some(transaction) = self.next_transaction() {
+            transactions.push(transaction.map_err(|err| PyException::new_err(err.to_string()))?);
+            py.check_signals()?;
+        }
+        Ok(transactions)
+    }
+
+    /// Generate the next transaction. Change of the final transaction below
+    /// `min_change` is folded into its fees.
+    fn next_transaction(&self) -> Option<Result<native::PendingTransaction>> {
+        let transaction = self.0.iter().next()?;
+        Some(transaction.map(|transaction| self.fold_change(transaction)))
+    }
+
+    /// Generate the final transaction again from the same inputs with its
+    /// change folded into the fees, keeping it as is if that fails.
+    fn fold_change(&self, transaction: native::PendingTransaction) -> native::PendingTransaction {
+        let Some(folded) = self
+            .2
+            .fold_change(&transaction)
+            .and_then(|settings| settings.try_respend(ordered_entries(&transaction)).ok())
+        else {
+            return transaction;
+        };
+        *self.3.lock().unwrap() = Some(FoldedChange {
+            original: transaction,
+            folded: folded.clone(),
+        });
+        folded
     }
 
     #[allow(dead_code)]
@@ -272,24 +536,125 @@ impl PyGenerator {
     /// Raises:
     ///     Exception: If transaction generation fails.
     fn __next__(slf: PyRefMut<Self>) -> PyResult<Option<PendingTransaction>> {
-        match slf.0.iter().next() {
+        match slf.next_transaction() {
             Some(result) => match result {
-                Ok(transaction) => Ok(Some(transaction.into())),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyException, _>(format!(
-                    "{}",
-                    e
-                ))),
+                Ok(transaction) => Ok(Some(slf.pending_transaction(transaction))),
+                Err(e) => {
+                    slf.release_all();
+                    Err(PyErr::new::<pyo3::exceptions::PyException, _>(format!(
+                        "{}",
+                        e
+                    )))
+                }
             },
             None => Ok(None),
         }
     }
 }
 
-fn parse_generator_source(entries: Bound<'_, PyAny>) -> PyResult<GeneratorSource> {
+/// Split every payment output into `parts` outputs of equal value.
+/// The division remainder is added to the first output of each split.
+fn split_payment_outputs(
+    outputs: Vec<PaymentOutput>,
+    parts: usize,
+) -> PyResult<Vec<PaymentOutput>> {
+    if parts == 0 {
+        return Err(PyException::new_err("split_outputs must be greater than 0"));
+    }
+
+    let mut split = Vec::with_capacity(outputs.len() * parts);
+    for output in outputs {
+        let part = output.amount / parts as u64;
+        if part == 0 {
+            return Err(PyException::new_err(format!(
+                "Output amount {} cannot be split into {} outputs",
+                output.amount, parts
+            )));
+        }
+        let remainder = output.amount % parts as u64;
+        split.push(PaymentOutput::new(output.address.clone(), part + remainder));
+        split.extend((1..parts).map(|_| PaymentOutput::new(output.address.clone(), part)));
+    }
+    Ok(split)
+}
+
+/// The payment outputs with `templates` shares of `amount` taken out of them
+/// in proportion to their values (the remainder from the first one), followed
+/// by one output per template.
+fn with_percentage_outputs(
+    payments: &[PaymentOutput],
+    amount: u64,
+    templates: &[PercentageOutput],
+) -> PyResult<PaymentOutputs> {
+    let shares = templates
+        .iter()
+        .map(|template| template.amount(amount))
+        .collect::<Vec<_>>();
+    let total_share = shares.iter().sum::<u64>();
+    let total = payments.iter().map(|output| output.amount).sum::<u64>();
+
+    let mut outputs = payments.to_vec();
+    let mut deducted = 0;
+    for output in outputs.iter_mut() {
+        let deduction = (total_share as u128 * output.amount as u128 / total.max(1) as u128) as u64;
+        output.amount -= deduction;
+        deducted += deduction;
+    }
+    if let Some(first) = outputs.first_mut() {
+        first.amount = first
+            .amount
+            .checked_sub(total_share - deducted)
+            .ok_or_else(|| validation::invalid("percentage_outputs", "payment amount too small"))?;
+    }
+    if outputs.iter().any(|output| output.amount == 0) {
+        return Err(validation::invalid(
+            "percentage_outputs",
+            "payment amount too small",
+        ));
+    }
+
+    for (template, share) in templates.iter().zip(shares) {
+        if share == 0 {
+            return Err(validation::invalid(
+                "percentage_outputs",
+                format!("{} rounds to 0 sompi", template.address),
+            ));
+        }
+        outputs.push(PaymentOutput::new(template.address.clone(), share));
+    }
+    Ok(PaymentOutputs { outputs })
+}
+
+/// Spend the unconfirmed change of previously submitted transactions first.
+fn chain_in_flight(
+    priority_entries: Option<Vec<UtxoEntryReference>>,
+    source: &GeneratorSource,
+    tracking: &Option<ContextTracking>,
+) -> Option<Vec<UtxoEntryReference>> {
+    let (Some(tracking), GeneratorSource::UtxoContext(context)) = (tracking, source) else {
+        return priority_entries;
+    };
+    let chained = tracking.in_flight.entries(context);
+    if chained.is_empty() {
+        return priority_entries;
+    }
+    let mut priority_entries = priority_entries.unwrap_or_default();
+    priority_entries.extend(chained);
+    Some(priority_entries)
+}
+
+fn parse_generator_source(
+    entries: Bound<'_, PyAny>,
+) -> PyResult<(GeneratorSource, Option<ContextTracking>)> {
     if let Ok(context) = entries.extract::<PyUtxoContext>() {
-        Ok(GeneratorSource::UtxoContext(context.into()))
+        let tracking = ContextTracking {
+            context: context.inner().clone(),
+            in_flight: context.in_flight().clone(),
+            reservation: Arc::new(Reservation::new(context.locks().clone())),
+        };
+        Ok((GeneratorSource::UtxoContext(context.into()), Some(tracking)))
     } else if let Ok(entries) = entries.extract::<PyUtxoEntries>() {
-        Ok(GeneratorSource::UtxoEntries(entries.entries))
+        Ok((GeneratorSource::UtxoEntries(entries.entries), None))
     } else {
         Err(PyException::new_err(
             "entries must be a UtxoContext or list of UtxoEntryReference-compatible items",
@@ -298,25 +663,30 @@ fn parse_generator_source(entries: Bound<'_, PyAny>) -> PyResult<GeneratorSource
 }
 
 #[allow(dead_code)]
-enum GeneratorSource {
+#[derive(Clone)]
+pub(crate) enum GeneratorSource {
     UtxoEntries(Vec<UtxoEntryReference>),
     UtxoContext(UtxoContext),
     // Account(Account),
 }
 
 #[allow(dead_code)]
-struct GeneratorSettings {
+#[derive(Clone)]
+pub(crate) struct GeneratorSettings {
     pub network_id: Option<NetworkId>,
     pub source: GeneratorSource,
     pub priority_utxo_entries: Option<Vec<UtxoEntryReference>>,
     pub multiplexer: Option<Multiplexer<Box<Events>>>,
     pub final_transaction_destination: PaymentDestination,
     pub change_address: Option<Address>,
+    pub change_callable: Option<Arc<Py<PyAny>>>,
     pub fee_rate: Option<f64>,
     pub final_priority_fee: Fees,
     pub sig_op_count: u8,
     pub minimum_signatures: u16,
     pub payload: Option<Vec<u8>>,
+    pub reservation: Option<Arc<Reservation>>,
+    pub min_change: Option<u64>,
 }
 
 impl GeneratorSettings {
@@ -324,7 +694,7 @@ impl GeneratorSettings {
         outputs: Option<PyOutputs>,
         change_address: Address,
         fee_rate: Option<f64>,
-        priority_fee: Option<u64>,
+        final_priority_fee: Fees,
         source: GeneratorSource,
         priority_entries: Option<Vec<UtxoEntryReference>>,
         sig_op_count: Option<u8>,
@@ -343,11 +713,6 @@ impl GeneratorSettings {
         let fee_rate =
             fee_rate.and_then(|v| (v.is_finite() && !v.is_nan() && v >= 1e-8).then_some(v));
 
-        let final_priority_fee = match priority_fee {
-            Some(fee) => fee.into(),
-            None => Fees::None,
-        };
-
         let sig_op_count = sig_op_count.unwrap_or(1);
 
         let minimum_signatures = minimum_signatures.unwrap_or(1);
@@ -359,11 +724,202 @@ impl GeneratorSettings {
             multiplexer: None,
             final_transaction_destination,
             change_address: Some(change_address),
+            change_callable: None,
             fee_rate,
             final_priority_fee,
             sig_op_count,
             minimum_signatures,
             payload,
+            reservation: None,
+            min_change: None,
+        }
+    }
+
+    /// Resolve a callable change address again, so the next generation sends
+    /// its change to a fresh address.
+    pub fn next_change_address(&mut self, py: Python) -> PyResult<()> {
+        let Some(callable) = &self.change_callable else {
+            return Ok(());
+        };
+        let change_address = next_change_address(py, callable)?;
+        if let Some(network_id) = &self.network_id {
+            validation::address_network("change_address", &change_address, network_id)?;
+        }
+        self.change_address = Some(change_address);
+        Ok(())
+    }
+
+    /// The settings generating the final `transaction` again with its change
+    /// below `min_change` added to the fees, None if there is nothing to fold.
+    ///
+    /// With receiver-paid fees the change is added to the first payment output,
+    /// covering the fees deducted from it, any excess being added to the fees.
+    fn fold_change(&self, transaction: &native::PendingTransaction) -> Option<GeneratorSettings> {
+        let change = transaction.change_value();
+        if transaction.is_batch() || change == 0 || change >= self.min_change? {
+            return None;
+        }
+        let PaymentDestination::PaymentOutputs(outputs) = &self.final_transaction_destination
+        else {
+            return None;
+        };
+
+        let mut settings = self.clone();
+        settings.final_priority_fee = match self.final_priority_fee {
+            Fees::SenderPays(fee) => Fees::SenderPays(fee + change),
+            Fees::None => Fees::SenderPays(change),
+            Fees::ReceiverPays(fee) => {
+                let mut outputs = PaymentOutputs {
+                    outputs: outputs.outputs.clone(),
+                };
+                outputs.outputs.first_mut()?.amount += change;
+                settings.final_transaction_destination = outputs.into();
+                Fees::ReceiverPays(fee + change.saturating_sub(transaction.fees()))
+            }
+        };
+        Some(settings)
+    }
+
+    /// Route percentages of the payment amount to additional outputs, taken out
+    /// of the payment outputs. With receiver-paid fees, the percentages apply to
+    /// the amount net of the fees of a trial generation including the outputs.
+    pub fn apply_percentage_outputs(&mut self, templates: &[PercentageOutput]) -> PyResult<()> {
+        let PaymentDestination::PaymentOutputs(outputs) = &self.final_transaction_destination
+        else {
+            return Err(validation::invalid(
+                "percentage_outputs",
+                "requires payment outputs",
+            ));
+        };
+        let payments = outputs.outputs.clone();
+        let amount = payments.iter().map(|output| output.amount).sum::<u64>();
+
+        self.final_transaction_destination =
+            with_percentage_outputs(&payments, amount, templates)?.into();
+        if matches!(self.final_priority_fee, Fees::ReceiverPays(_))
+            && let Some(fees) = trial_fees(self.clone())
+        {
+            self.final_transaction_destination =
+                with_percentage_outputs(&payments, amount.saturating_sub(fees), templates)?.into();
         }
+        Ok(())
+    }
+
+    /// Build the native generator. With `reserve`, UTXOs selected from a context
+    /// are locked for the reservation, otherwise locked UTXOs are only skipped.
+    fn try_into_generator(self, reserve: bool) -> PyResult<native::Generator> {
+        try_new_generator(self.try_into_native(reserve)?)
+    }
+
+    /// Generate a single transaction spending exactly `entries`, e.g. the inputs
+    /// of a transaction to replace. The entries are not reserved again.
+    fn try_respend(
+        mut self,
+        entries: Vec<UtxoEntryReference>,
+    ) -> PyResult<native::PendingTransaction> {
+        self.priority_utxo_entries = Some(entries);
+        self.reservation = None;
+        let mut settings = self.try_into_native(false)?;
+        settings.utxo_iterator = Box::new(std::iter::empty());
+        try_new_generator(settings)?
+            .iter()
+            .next()
+            .ok_or_else(|| PyException::new_err("no transaction generated"))?
+            .map_err(|err| PyException::new_err(err.to_string()))
     }
+
+    fn try_into_native(self, reserve: bool) -> PyResult<native::GeneratorSettings> {
+        let mut settings = match self.source {
+            GeneratorSource::UtxoEntries(utxo_entries) => {
+                let change_address = self.change_address.ok_or_else(|| {
+                    PyException::new_err(
+                        "changeAddress is required for Generator constructor with UTXO entries",
+                    )
+                })?;
+
+                let network_id = self.network_id.ok_or_else(|| {
+                    PyException::new_err(
+                        "networkId is required for Generator constructor with UTXO entries",
+                    )
+                })?;
+
+                native::GeneratorSettings::try_new_with_iterator(
+                    network_id,
+                    Box::new(utxo_entries.into_iter()),
+                    self.priority_utxo_entries,
+                    change_address,
+                    self.sig_op_count,
+                    self.minimum_signatures,
+                    self.final_transaction_destination,
+                    self.fee_rate,
+                    self.final_priority_fee,
+                    self.payload,
+                    self.multiplexer,
+                )
+                .map_err(|err| PyException::new_err(err.to_string()))?
+            }
+            GeneratorSource::UtxoContext(utxo_context) => {
+                let change_address = self.change_address.ok_or_else(|| {
+                    PyException::new_err(
+                        "changeAddress is required for Generator constructor with UTXO entries",
+                    )
+                })?;
+
+                native::GeneratorSettings::try_new_with_context(
+                    utxo_context,
+                    self.priority_utxo_entries,
+                    change_address,
+                    self.sig_op_count,
+                    self.minimum_signatures,
+                    self.final_transaction_destination,
+                    self.fee_rate,
+                    self.final_priority_fee,
+                    self.payload,
+                    self.multiplexer,
+                )
+                .map_err(|err| PyException::new_err(err.to_string()))?
+            }
+        };
+
+        if let Some(reservation) = self.reservation {
+            // Priority entries, including chained in-flight change, are selected
+            // first and go through the reservation like the source entries.
+            let priority_entries = settings.priority_utxo_entries.take().unwrap_or_default();
+            let utxo_iterator =
+                std::mem::replace(&mut settings.utxo_iterator, Box::new(std::iter::empty()));
+            let mut selected = AHashSet::new();
+            settings.utxo_iterator = Box::new(
+                priority_entries
+                    .into_iter()
+                    .chain(utxo_iterator)
+                    .filter(move |entry| {
+                        let id = entry.id();
+                        if !selected.insert(id.clone()) {
+                            return false;
+                        }
+                        if reserve {
+                            reservation.try_reserve(id)
+                        } else {
+                            !reservation.is_locked(&id)
+                        }
+                    }),
+            );
+        }
+
+        Ok(settings)
+    }
+}
+
+fn try_new_generator(settings: native::GeneratorSettings) -> PyResult<native::Generator> {
+    let abortable = Abortable::default();
+    native::Generator::try_new(settings, None, Some(&abortable))
+        .map_err(|err| PyException::new_err(err.to_string()))
+}
+
+/// Run a trial generation and return the fees of all transactions.
+/// Returns `None` if the trial fails; the real generator reports the error.
+fn trial_fees(settings: GeneratorSettings) -> Option<u64> {
+    let generator = settings.try_into_generator(false).ok()?;
+    generator.iter().collect::<Result<Vec<_>>>().ok()?;
+    Some(generator.summary().aggregate_fees())
 }
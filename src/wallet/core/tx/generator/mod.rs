@@ -1,8 +1,10 @@
 #[allow(clippy::module_inception)]
 pub mod generator;
 pub mod pending;
+pub mod plan;
 pub mod summary;
 
 pub use generator::*;
 pub use pending::*;
+pub use plan::*;
 pub use summary::*;
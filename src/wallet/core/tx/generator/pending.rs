@@ -1,19 +1,43 @@
 use super::super::super::imports::*;
+use super::generator::ContextTracking;
 use crate::{
     consensus::{
         client::{transaction::PyTransaction, utxo::PyUtxoEntryReference},
         core::hashing::PySighashType,
     },
+    exceptions::{script_verification_failed, transaction_rejected},
     rpc::wrpc::client::PyRpcClient,
-    wallet::keys::privatekey::PyPrivateKey,
+    validation,
+    wallet::{
+        core::tx::{
+            policy::{self, SigningRequest},
+            signer::sign_inputs,
+            verify::{
+                MultisigScript, check_multisig, check_signatures, is_p2pk_ecdsa, verify_scripts,
+            },
+        },
+        core::utxo::inflight,
+        keys::vault::signing_key,
+    },
+};
+use kaspa_addresses::Prefix;
+use kaspa_consensus_client::{Transaction, TransactionOutpoint, UtxoEntry, UtxoEntryReference};
+use kaspa_consensus_core::{
+    hashing::{
+        sighash::{
+            SigHashReusedValuesUnsync, calc_ecdsa_signature_hash, calc_schnorr_signature_hash,
+        },
+        sighash_type::SigHashType,
+        wasm::SighashType,
+    },
+    tx::PopulatedTransaction,
 };
-use kaspa_consensus_client::Transaction;
-use kaspa_consensus_core::hashing::wasm::SighashType;
+use kaspa_txscript::{extract_script_pub_key_address, standard};
 use kaspa_wallet_core::tx::generator as native;
-use pyo3::types::PyList;
+use pyo3::types::{PyDict, PyList};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use workflow_core::hex::ToHex;
-use zeroize::Zeroize;
+use zeroize::Zeroizing;
 
 /// A transaction ready for signing and submission.
 ///
@@ -21,7 +45,55 @@ use zeroize::Zeroize;
 /// along with metadata about fees, amounts, and UTXOs being spent.
 #[gen_stub_pyclass]
 #[pyclass]
-pub struct PendingTransaction(native::PendingTransaction);
+pub struct PendingTransaction(native::PendingTransaction, Option<ContextTracking>);
+
+impl PendingTransaction {
+    pub fn new(transaction: native::PendingTransaction, tracking: Option<ContextTracking>) -> Self {
+        Self(transaction, tracking)
+    }
+
+    /// The change output as an unconfirmed UTXO entry.
+    fn change_entry(&self) -> Option<UtxoEntryReference> {
+        let amount = self.0.change_value();
+        if amount == 0 {
+            return None;
+        }
+
+        // Payments may go to the change address too, match the amount as well.
+        let script = standard::pay_to_address_script(self.0.generator().change_address());
+        let transaction = self.0.transaction();
+        let (index, output) = transaction
+            .outputs
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, output)| output.script_public_key == script && output.value == amount)?;
+
+        let address = self.prefix().and_then(|prefix| {
+            extract_script_pub_key_address(&output.script_public_key, prefix).ok()
+        });
+
+        Some(
+            UtxoEntry {
+                address,
+                outpoint: TransactionOutpoint::new(self.0.id(), index as u32),
+                amount,
+                script_public_key: output.script_public_key.clone(),
+                block_daa_score: u64::MAX,
+                is_coinbase: false,
+            }
+            .into(),
+        )
+    }
+
+    /// The address prefix of the spent UTXO entries.
+    fn prefix(&self) -> Option<Prefix> {
+        self.0
+            .utxo_entries()
+            .values()
+            .find_map(|entry| entry.utxo.address.as_ref().map(|address| address.prefix))
+    }
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -84,6 +156,15 @@ impl PendingTransaction {
         }
     }
 
+    /// The change output as an unconfirmed UTXO entry, or None if the transaction has no change.
+    ///
+    /// The entry can be spent before the transaction is confirmed (e.g. passed in
+    /// `priority_entries`). Its `block_daa_score` is set to the maximum value.
+    #[getter]
+    fn get_change_entry(&self) -> Option<PyUtxoEntryReference> {
+        self.change_entry().map(PyUtxoEntryReference::from)
+    }
+
     /// Get the unique addresses referenced by this transaction's inputs.
     ///
     /// Returns:
@@ -113,34 +194,111 @@ impl PendingTransaction {
     ///
     /// Args:
     ///     input_index: The index of the input to sign.
-    ///     private_key: The private key for signing.
+    ///     private_key: The private key, or a `KeyHandle` from a `KeyVault`.
     ///     sighash_type: The signature hash type (default: All).
     ///
     /// Returns:
     ///     str: The signature as a hex string.
     ///
     /// Raises:
+    ///     PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
     ///     Exception: If signing fails.
     #[pyo3(signature = (input_index, private_key, sighash_type=None))]
     fn create_input_signature(
         &self,
+        py: Python<'_>,
         input_index: u8,
-        private_key: &PyPrivateKey,
+        #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle"))] private_key: Bound<
+            '_,
+            PyAny,
+        >,
         #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
         sighash_type: Option<PySighashType>,
     ) -> PyResult<String> {
+        policy::check(py, || {
+            SigningRequest::pending("create_input_signature", &self.0, false)
+        })?;
         let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
 
-        let mut key_bytes = private_key.secret_bytes();
+        let key_bytes = signing_key(&private_key)?;
         let signature = self
             .0
             .create_input_signature(input_index.into(), &key_bytes, sighash_type.into())
             .map_err(|err| PyException::new_err(format!("{}", err)))?;
-        key_bytes.zeroize();
 
         Ok(signature.to_hex())
     }
 
+    /// The signature hash of an input, the message a signer signs for it.
+    ///
+    /// Lets keys live outside the process (e.g. a signing service or HSM): the
+    /// external signer signs the hash, and the signature is set with `fill_input()`.
+    /// Signing policies are consulted first (see `add_signing_policy()`).
+    ///
+    /// Args:
+    ///     input_index: The index of the input.
+    ///     sighash_type: The signature hash type (default: All).
+    ///     ecdsa: Compute the ECDSA instead of the Schnorr hash (default: ECDSA for P2PK ECDSA inputs).
+    ///
+    /// Returns:
+    ///     str: The 32-byte hash as a hex string.
+    ///
+    /// Raises:
+    ///     PolicyViolationError: If a signing policy vetoes the transaction.
+    ///     ValueError: If `input_index` is out of range.
+    #[pyo3(signature = (input_index, sighash_type=None, ecdsa=None))]
+    fn signature_hash(
+        &self,
+        py: Python<'_>,
+        input_index: usize,
+        #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
+        sighash_type: Option<PySighashType>,
+        ecdsa: Option<bool>,
+    ) -> PyResult<String> {
+        policy::check(py, || {
+            SigningRequest::pending("signature_hash", &self.0, false)
+        })?;
+        let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
+        let sighash_type: SigHashType = sighash_type.into();
+
+        let transaction =
+            Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries());
+        let (cctx, utxos) = transaction
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+        let entry = populated_transaction
+            .entries
+            .get(input_index)
+            .ok_or_else(|| {
+                validation::invalid(
+                    "input_index",
+                    format!(
+                        "{input_index} is out of range for {} inputs",
+                        populated_transaction.entries.len()
+                    ),
+                )
+            })?;
+
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let sig_hash = if ecdsa.unwrap_or_else(|| is_p2pk_ecdsa(entry.script_public_key.script())) {
+            calc_ecdsa_signature_hash(
+                &populated_transaction,
+                input_index,
+                sighash_type,
+                &reused_values,
+            )
+        } else {
+            calc_schnorr_signature_hash(
+                &populated_transaction,
+                input_index,
+                sighash_type,
+                &reused_values,
+            )
+        };
+        Ok(sig_hash.to_string())
+    }
+
     /// Fill an input's signature script with a pre-computed signature.
     ///
     /// Args:
@@ -161,57 +319,290 @@ impl PendingTransaction {
     ///
     /// Args:
     ///     input_index: The index of the input to sign.
-    ///     private_key: The private key for signing.
+    ///     private_key: The private key, or a `KeyHandle` from a `KeyVault`.
     ///     sighash_type: The signature hash type (default: All).
     ///
     /// Raises:
+    ///     PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
     ///     Exception: If signing fails.
     fn sign_input(
         &self,
+        py: Python<'_>,
         input_index: u8,
-        private_key: &PyPrivateKey,
+        #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle"))] private_key: Bound<
+            '_,
+            PyAny,
+        >,
         #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
         sighash_type: Option<PySighashType>,
     ) -> PyResult<()> {
+        policy::check(py, || SigningRequest::pending("sign_input", &self.0, false))?;
         let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
 
-        let mut key_bytes = private_key.secret_bytes();
+        let key_bytes = signing_key(&private_key)?;
         self.0
             .sign_input(input_index.into(), &key_bytes, sighash_type.into())
             .map_err(|err| PyException::new_err(format!("{}", err)))?;
-        key_bytes.zeroize();
 
         Ok(())
     }
 
     /// Sign all inputs with the provided private keys.
     ///
+    /// Inputs spending a P2PK script of one of the keys are signed in parallel
+    /// with the GIL released. Other inputs keep their signature script.
+    ///
     /// Args:
-    ///     private_keys: List of PrivateKey objects for signing.
-    ///     check_fully_signed: Verify all inputs are signed (default: None).
+    ///     private_keys: List of PrivateKey objects or `KeyHandle`s for signing.
+    ///     check_fully_signed: Raise if an input is left without a signature (default: None, treated as True).
     ///
     /// Raises:
+    ///     PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
     ///     Exception: If signing fails or transaction is not fully signed.
     #[pyo3(signature = (private_keys, check_fully_signed=None))]
     fn sign<'py>(
         &self,
+        py: Python<'py>,
         private_keys: Bound<'py, PyList>,
         check_fully_signed: Option<bool>,
     ) -> PyResult<()> {
-        let mut keys: Vec<[u8; 32]> = Vec::with_capacity(private_keys.len());
+        policy::check(py, || SigningRequest::pending("sign", &self.0, false))?;
+        let mut keys = Zeroizing::new(Vec::with_capacity(private_keys.len()));
         for item in private_keys.iter() {
-            let key: PyRef<'_, PyPrivateKey> = item.extract()?;
-            keys.push(key.secret_bytes());
+            keys.push(*signing_key(&item)?);
         }
-        self.0
-            .try_sign_with_keys(&keys, check_fully_signed)
-            .map_err(|err| PyException::new_err(format!("{}", err)))?;
-        keys.zeroize();
-        Ok(())
+        let result = py.detach(|| {
+            let transaction = self.0.transaction();
+            let entries = Transaction::from_cctx_transaction(&transaction, self.0.utxo_entries());
+            let (cctx, utxos) = entries.tx_and_utxos().map_err(|err| err.to_string())?;
+            let signatures = sign_inputs(&PopulatedTransaction::new(&cctx, utxos), &keys)
+                .map_err(|err| err.to_string())?;
+
+            let unsigned = transaction
+                .inputs
+                .iter()
+                .zip(&signatures)
+                .filter(|(input, signature)| {
+                    signature.is_none() && input.signature_script.is_empty()
+                })
+                .count();
+            if unsigned > 0 && check_fully_signed.unwrap_or(true) {
+                return Err(format!(
+                    "Transaction is not fully signed: {unsigned} input(s) without a signature"
+                ));
+            }
+            for (index, signature) in signatures.into_iter().enumerate() {
+                if let Some(signature) = signature {
+                    self.0
+                        .fill_input(index, signature)
+                        .map_err(|err| err.to_string())?;
+                }
+            }
+            Ok(())
+        });
+        result.map_err(PyException::new_err)
+    }
+
+    /// Report the signatures of each input, e.g. to find which input fails verification.
+    ///
+    /// Signatures of P2PK inputs are checked against the key of the spent
+    /// script, signatures of P2SH inputs (e.g. multisig) against the keys of
+    /// the redeem script.
+    ///
+    /// Returns:
+    ///     list[dict]: One dict per input with `index`, `address`, `signed` (the
+    ///         input has signatures), `valid` (all its signatures verify) and
+    ///         `signatures`, a list of dicts with `public_key` (hex, None if the
+    ///         signature matches no key), `sighash_type` (e.g. "All", None if
+    ///         invalid) and `valid`.
+    ///
+    /// Raises:
+    ///     Exception: If a UTXO entry of an input is missing.
+    fn signatures<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let transaction =
+            Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries());
+        let (cctx, utxos) = transaction
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+        let prefix = self.prefix();
+
+        let list = PyList::empty(py);
+        for input in check_signatures(&populated_transaction) {
+            let signatures = PyList::empty(py);
+            for signature in &input.signatures {
+                let dict = PyDict::new(py);
+                dict.set_item(
+                    "public_key",
+                    signature.public_key.as_ref().map(|key| key.to_hex()),
+                )?;
+                dict.set_item(
+                    "sighash_type",
+                    signature.sighash_type.map(sighash_type_name),
+                )?;
+                dict.set_item("valid", signature.valid)?;
+                signatures.append(dict)?;
+            }
+
+            let script_public_key = &populated_transaction.entries[input.index].script_public_key;
+            let dict = PyDict::new(py);
+            dict.set_item("index", input.index)?;
+            dict.set_item(
+                "address",
+                prefix
+                    .and_then(|prefix| {
+                        extract_script_pub_key_address(script_public_key, prefix).ok()
+                    })
+                    .map(|address| address.to_string()),
+            )?;
+            dict.set_item("signed", input.is_signed())?;
+            dict.set_item("valid", input.is_valid())?;
+            dict.set_item("signatures", signatures)?;
+            list.append(dict)?;
+        }
+        Ok(list)
+    }
+
+    /// Finalize a multisig input from the signatures collected from its cosigners.
+    ///
+    /// Each signature is matched against the keys of the redeem script. When
+    /// distinct cosigners reach the threshold, the input's signature script is
+    /// set with their signatures in key order; otherwise the input is left
+    /// unchanged and the report lists the missing cosigners. Signatures
+    /// repeating a cosigner or matching no key are reported and left out.
+    ///
+    /// Args:
+    ///     input_index: The index of the multisig input.
+    ///     redeem_script: The multisig redeem script of the spent P2SH output.
+    ///     signatures: The cosigner signatures, as returned by `create_input_signature()` (or 64 signature bytes followed by the sighash type).
+    ///
+    /// Returns:
+    ///     dict: `index`, `required` (the threshold), `signed` (distinct
+    ///         cosigners with a valid signature), `complete` (the input was
+    ///         finalized), `cosigners` (hex keys that signed), `missing` (hex
+    ///         keys that did not), `duplicates` (hex keys of repeated
+    ///         signatures) and `unmatched` (number of signatures matching no key).
+    ///
+    /// Raises:
+    ///     ValueError: If `input_index` is out of range, the redeem script is not a multisig script or not the one of the spent output, or a signature is malformed.
+    ///     Exception: If a UTXO entry of an input is missing.
+    fn finalize_multisig<'py>(
+        &self,
+        py: Python<'py>,
+        input_index: usize,
+        redeem_script: PyBinary,
+        #[gen_stub(override_type(type_repr = "typing.Sequence[Binary]"))] signatures: Vec<PyBinary>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let script = MultisigScript::parse(&redeem_script.data).ok_or_else(|| {
+            validation::invalid(
+                "redeem_script",
+                "not a multisig script (OP_m <keys> OP_n OP_CHECKMULTISIG)",
+            )
+        })?;
+        let signatures = signatures
+            .into_iter()
+            .map(|signature| match signature.data.as_slice() {
+                // OP_DATA_65 <signature>, as created by `create_input_signature()`.
+                [0x41, signature @ ..] if signature.len() == 65 => Ok(signature.to_vec()),
+                signature if signature.len() == 65 => Ok(signature.to_vec()),
+                _ => Err(validation::invalid(
+                    "signatures",
+                    "each signature must be 65 bytes (64 signature bytes and the sighash type)",
+                )),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let transaction =
+            Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries());
+        let (cctx, utxos) = transaction
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+        let entry = populated_transaction
+            .entries
+            .get(input_index)
+            .ok_or_else(|| {
+                validation::invalid(
+                    "input_index",
+                    format!(
+                        "{input_index} is out of range for {} inputs",
+                        populated_transaction.entries.len()
+                    ),
+                )
+            })?;
+        if entry.script_public_key != standard::pay_to_script_hash_script(&redeem_script.data) {
+            return Err(validation::invalid(
+                "redeem_script",
+                format!("does not hash to the script spent by input {input_index}"),
+            ));
+        }
+
+        let check = check_multisig(&populated_transaction, input_index, &script, &signatures);
+        if let Some(pushes) = check.signature_pushes() {
+            let signature_script =
+                standard::pay_to_script_hash_signature_script(redeem_script.data, pushes)
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+            self.0
+                .fill_input(input_index, signature_script)
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+        }
+
+        let keys = |signed: bool| {
+            check
+                .cosigners
+                .iter()
+                .filter(|(_, signature)| signature.is_some() == signed)
+                .map(|(key, _)| key.to_hex())
+                .collect::<Vec<_>>()
+        };
+        let dict = PyDict::new(py);
+        dict.set_item("index", input_index)?;
+        dict.set_item("required", check.required)?;
+        dict.set_item("signed", check.signed())?;
+        dict.set_item("complete", check.is_complete())?;
+        dict.set_item("cosigners", keys(true))?;
+        dict.set_item("missing", keys(false))?;
+        dict.set_item(
+            "duplicates",
+            check
+                .duplicates
+                .iter()
+                .map(|key| key.to_hex())
+                .collect::<Vec<_>>(),
+        )?;
+        dict.set_item("unmatched", check.unmatched)?;
+        Ok(dict)
+    }
+
+    /// Verify the transaction locally by executing the script of each input
+    /// with the consensus script engine.
+    ///
+    /// Catches invalid or missing signatures before submission, with the
+    /// failing input and script error instead of a node rejection.
+    /// Verification runs with the GIL released.
+    ///
+    /// Raises:
+    ///     ScriptVerificationError: If an input fails script execution (see `kaspa.exceptions`).
+    ///     Exception: If a UTXO entry of an input is missing.
+    fn validate(&self, py: Python<'_>) -> PyResult<()> {
+        let transaction =
+            Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries());
+        let (cctx, utxos) = transaction
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let result = py.detach(|| verify_scripts(&PopulatedTransaction::new(&cctx, utxos)));
+        result.map_err(|(input_index, error)| {
+            script_verification_failed(py, self.0.id().to_string(), input_index, error)
+        })
     }
 
     /// Submit the signed transaction to the network.
     ///
+    /// When generated from a UtxoContext, the change of the submitted transaction
+    /// is tracked as in-flight and spent first by the next Generator of the context,
+    /// and the UTXOs locked for this transaction are released. If the node rejects
+    /// it, the in-flight change it spends is dropped.
+    ///
     /// Args:
     ///     rpc_client: The RPC client for submission.
     ///
@@ -219,6 +610,7 @@ impl PendingTransaction {
     ///     str: The transaction ID on success (async).
     ///
     /// Raises:
+    ///     TransactionRejectedError: If the node rejects the transaction (see `kaspa.exceptions`).
     ///     Exception: If submission fails.
     #[gen_stub(override_return_type(type_repr = "str"))]
     fn submit<'py>(
@@ -228,13 +620,37 @@ impl PendingTransaction {
     ) -> PyResult<Bound<'py, PyAny>> {
         let inner = self.0.clone();
         let rpc: Arc<DynRpcApi> = rpc_client.client().clone();
+        let tracking = self.1.clone().map(|tracking| {
+            let expires_at = inflight::expiry(&tracking.context);
+            (tracking, self.change_entry(), expires_at)
+        });
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let txid = inner
-                .try_submit(&rpc)
-                .await
-                .map_err(|err| PyException::new_err(format!("{}", err)))?;
-            Ok(txid.to_string())
+            let id = inner.id().to_string();
+            let result = inner.try_submit(&rpc).await.map_err(|err| {
+                Python::attach(|py| transaction_rejected(py, err.to_string(), Some(id)))
+            });
+            let Some((tracking, change, expires_at)) = tracking else {
+                return result.map(|txid| txid.to_string());
+            };
+
+            // Spent or rejected, the inputs no longer need to be locked.
+            tracking.reservation.release(inner.utxo_entries().keys());
+            let transaction = inner.transaction();
+            let spent = transaction
+                .inputs
+                .iter()
+                .map(|input| &input.previous_outpoint);
+            match result {
+                Ok(txid) => {
+                    tracking.in_flight.submitted(spent, change, expires_at);
+                    Ok(txid.to_string())
+                }
+                Err(err) => {
+                    tracking.in_flight.rejected(spent);
+                    Err(err)
+                }
+            }
         })
     }
 
@@ -245,8 +661,13 @@ impl PendingTransaction {
     }
 }
 
-impl From<native::PendingTransaction> for PendingTransaction {
-    fn from(pending_transaction: native::PendingTransaction) -> Self {
-        Self(pending_transaction)
+fn sighash_type_name(sighash_type: SigHashType) -> &'static str {
+    match sighash_type.to_u8() {
+        0x01 => "All",
+        0x02 => "None",
+        0x04 => "Single",
+        0x81 => "AllAnyOneCanPay",
+        0x82 => "NoneAnyOneCanPay",
+        _ => "SingleAnyOneCanPay",
     }
 }
@@ -0,0 +1,348 @@
+use super::super::super::imports::*;
+use super::generator::{ContextTracking, GeneratorSettings, GeneratorSource, PyGenerator};
+use super::pending::PendingTransaction;
+use super::summary::PyGeneratorSummary;
+use crate::{consensus::client::utxo::PyUtxoEntryReference, wallet::core::tx::fees::PyFees};
+use ahash::AHashSet;
+use futures::stream::StreamExt;
+use kaspa_addresses::Prefix;
+use kaspa_consensus_client::{UtxoEntryId, UtxoEntryReference};
+use kaspa_txscript::extract_script_pub_key_address;
+use kaspa_wallet_core::tx::generator as native;
+use kaspa_wallet_core::utxo::UtxoStream;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::sync::OnceLock;
+
+/// A transaction plan produced by `Generator.estimate()`.
+///
+/// Extends GeneratorSummary with the inputs selected, the outputs and the
+/// fee of every transaction, without holding any UTXO locks. Show it for
+/// confirmation, then call `materialize()` to get signable transactions.
+///
+/// See Also:
+///     Generator, GeneratorSummary
+#[gen_stub_pyclass]
+#[pyclass(name = "TransactionPlan", extends = PyGeneratorSummary)]
+pub struct PyTransactionPlan {
+    transactions: Vec<native::PendingTransaction>,
+    tracking: Option<ContextTracking>,
+    settings: GeneratorSettings,
+    /// The source UTXOs rebuilds select from, taken by the first rebuild.
+    snapshot: OnceLock<Arc<Vec<UtxoEntryReference>>>,
+}
+
+impl PyTransactionPlan {
+    pub fn new(
+        py: Python,
+        summary: PyGeneratorSummary,
+        transactions: Vec<native::PendingTransaction>,
+        tracking: Option<ContextTracking>,
+        settings: GeneratorSettings,
+        snapshot: Option<Arc<Vec<UtxoEntryReference>>>,
+    ) -> PyResult<Py<Self>> {
+        let plan = Self {
+            transactions,
+            tracking,
+            settings,
+            snapshot: snapshot.map(OnceLock::from).unwrap_or_default(),
+        };
+        Py::new(py, PyClassInitializer::from(summary).add_subclass(plan))
+    }
+
+    /// The UTXOs of the source, collected from a context without holding the GIL.
+    fn snapshot(&self, py: Python) -> Arc<Vec<UtxoEntryReference>> {
+        if let Some(snapshot) = self.snapshot.get() {
+            return snapshot.clone();
+        }
+        let entries = match &self.settings.source {
+            GeneratorSource::UtxoEntries(entries) => entries.clone(),
+            GeneratorSource::UtxoContext(context) => py.detach(|| {
+                futures::executor::block_on(UtxoStream::new(context).collect::<Vec<_>>())
+            }),
+        };
+        self.snapshot.get_or_init(|| Arc::new(entries)).clone()
+    }
+
+    /// Generate the final transaction again, keeping the batch transactions
+    /// consolidating inputs for it. It spends the inputs of the previous final
+    /// transaction first, then other UTXOs of the snapshot if needed.
+    fn rebuild_final(
+        &self,
+        py: Python,
+        mut settings: GeneratorSettings,
+        snapshot: &[UtxoEntryReference],
+    ) -> PyResult<(PyGeneratorSummary, Vec<native::PendingTransaction>)> {
+        let Some((final_transaction, batches)) = self.transactions.split_last() else {
+            return Err(PyException::new_err("the plan has no transactions"));
+        };
+        let spent = self
+            .source_entries()
+            .iter()
+            .map(|entry| entry.id())
+            .collect::<AHashSet<UtxoEntryId>>();
+        let unspent = |entries: &[UtxoEntryReference]| {
+            entries
+                .iter()
+                .filter(|entry| !spent.contains(&entry.id()))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let mut priority_entries = ordered_entries(final_transaction);
+        priority_entries.extend(unspent(
+            settings
+                .priority_utxo_entries
+                .as_deref()
+                .unwrap_or_default(),
+        ));
+        settings.priority_utxo_entries = Some(priority_entries);
+        settings.source = GeneratorSource::UtxoEntries(unspent(snapshot));
+
+        let generator = PyGenerator::from_settings(settings, self.tracking.clone(), false)?;
+        let mut transactions = batches.to_vec();
+        transactions.extend(generator.generate_all(py)?);
+        Ok((generator.summary().with_earlier(batches), transactions))
+    }
+
+    /// Entries spent by the plan that are not outputs of its own transactions.
+    fn source_entries(&self) -> Vec<UtxoEntryReference> {
+        let planned = self
+            .transactions
+            .iter()
+            .map(|transaction| transaction.id())
+            .collect::<AHashSet<_>>();
+        self.transactions
+            .iter()
+            .flat_map(|transaction| ordered_entries(transaction))
+            .filter(|entry| !planned.contains(&entry.id().transaction_id))
+            .collect()
+    }
+
+    fn steps<'py>(&self, py: Python<'py>, prefix: Prefix) -> PyResult<Bound<'py, PyList>> {
+        let steps = PyList::empty(py);
+        for transaction in &self.transactions {
+            steps.append(step_to_dict(py, transaction, prefix)?)?;
+        }
+        Ok(steps)
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyTransactionPlan {
+    /// The UTXOs the plan spends from the Generator source.
+    ///
+    /// Change outputs of batch transactions spent by later stages are not included.
+    #[getter]
+    fn get_inputs(&self) -> Vec<PyUtxoEntryReference> {
+        self.source_entries()
+            .into_iter()
+            .map(PyUtxoEntryReference::from)
+            .collect()
+    }
+
+    /// The planned transactions in submission order.
+    ///
+    /// Each step is a dict with `id`, `type` (`batch` or `final`), `inputs`
+    /// (`transaction_id`, `index`, `amount`, `address`), `outputs` (`address`,
+    /// `amount`), `fee`, `mass` and `change` (the change amount in sompi).
+    #[getter]
+    fn get_steps<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let prefix = Prefix::from(slf.as_super().network_id());
+        slf.steps(py, prefix)
+    }
+
+    /// Reserve the planned inputs and return the transactions to sign.
+    ///
+    /// For a plan estimated from a UtxoContext, the inputs are locked on the
+    /// context again, so this fails if another Generator took one in the meantime.
+    ///
+    /// Returns:
+    ///     list[PendingTransaction]: The transactions to sign and submit, in order.
+    ///
+    /// Raises:
+    ///     Exception: If a planned input is locked by another Generator.
+    fn materialize(&self) -> PyResult<Vec<PendingTransaction>> {
+        if let Some(tracking) = &self.tracking {
+            let ids = self
+                .source_entries()
+                .iter()
+                .map(|entry| entry.id())
+                .collect();
+            tracking.reservation.reserve_all(ids)?;
+        }
+        Ok(self
+            .transactions
+            .iter()
+            .map(|transaction| PendingTransaction::new(transaction.clone(), self.tracking.clone()))
+            .collect())
+    }
+
+    /// Re-run UTXO selection with a different priority fee.
+    ///
+    /// Selection runs against a snapshot of the source UTXOs taken by the first
+    /// rebuild rather than the current state of the source, so a fee slider can
+    /// call this on every change. Only the final transaction is generated again,
+    /// unless a fee rate is given, which changes the fees of every transaction.
+    /// Nothing is locked until the new plan is materialized. A callable
+    /// `change_address` given to the Generator is invoked for the new plan.
+    ///
+    /// Args:
+    ///     priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing the plan's fee rate).
+    ///
+    /// Returns:
+    ///     dict: `plan` (the new TransactionPlan), `added` and `removed` (lists of
+    ///         UtxoEntryReference selected or no longer selected), and the `fees`,
+    ///         `mass` and `transactions` differences to this plan.
+    ///
+    /// Raises:
+    ///     Exception: If the UTXOs do not cover the outputs and fees.
+    ///     KeyboardInterrupt: If interrupted.
+    fn rebuild<'py>(
+        slf: PyRef<'_, Self>,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "Amount | Fees"))] priority_fee: PyFees,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let (priority_fee, fee_rate) = PyFees::resolve(Some(priority_fee), None)?;
+        let mut settings = slf.settings.clone();
+        settings.final_priority_fee = priority_fee;
+        settings.network_id = Some(slf.as_super().network_id());
+        settings.next_change_address(py)?;
+        let snapshot = slf.snapshot(py);
+
+        let (summary, transactions) = if let Some(fee_rate) = fee_rate {
+            settings.fee_rate = Some(fee_rate);
+            let mut generator_settings = settings.clone();
+            generator_settings.source = GeneratorSource::UtxoEntries(snapshot.to_vec());
+            let generator =
+                PyGenerator::from_settings(generator_settings, slf.tracking.clone(), false)?;
+            let transactions = generator.generate_all(py)?;
+            (generator.summary(), transactions)
+        } else {
+            slf.rebuild_final(py, settings.clone(), &snapshot)?
+        };
+        let plan = PyTransactionPlan::new(
+            py,
+            summary,
+            transactions,
+            slf.tracking.clone(),
+            settings,
+            Some(snapshot),
+        )?;
+
+        let rebuilt = plan.bind(py).borrow();
+        let (before, after) = (slf.source_entries(), rebuilt.source_entries());
+        let ids = |entries: &[UtxoEntryReference]| {
+            entries
+                .iter()
+                .map(|entry| entry.id())
+                .collect::<AHashSet<_>>()
+        };
+        let (before_ids, after_ids) = (ids(&before), ids(&after));
+        let difference = |entries: Vec<UtxoEntryReference>, other: &AHashSet<_>| {
+            entries
+                .into_iter()
+                .filter(|entry| !other.contains(&entry.id()))
+                .map(PyUtxoEntryReference::from)
+                .collect::<Vec<_>>()
+        };
+
+        let (old, new) = (slf.as_super(), rebuilt.as_super());
+        let dict = PyDict::new(py);
+        dict.set_item("added", difference(after, &before_ids))?;
+        dict.set_item("removed", difference(before, &after_ids))?;
+        dict.set_item("fees", new.get_fees() as i64 - old.get_fees() as i64)?;
+        dict.set_item("mass", new.get_mass() as i64 - old.get_mass() as i64)?;
+        dict.set_item(
+            "transactions",
+            new.get_transactions() as i64 - old.get_transactions() as i64,
+        )?;
+        dict.set_item("plan", plan)?;
+        Ok(dict)
+    }
+
+    /// Get a dictionary representation of the TransactionPlan.
+    ///
+    /// Returns:
+    ///     dict: The GeneratorSummary fields along with `steps`.
+    fn to_dict<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = slf.as_super().to_dict(py)?;
+        let prefix = Prefix::from(slf.as_super().network_id());
+        dict.set_item("steps", slf.steps(py, prefix)?)?;
+        Ok(dict)
+    }
+}
+
+/// The UTXO entries of a transaction in input order.
+pub(crate) fn ordered_entries(transaction: &native::PendingTransaction) -> Vec<UtxoEntryReference> {
+    let entries = transaction.utxo_entries();
+    transaction
+        .transaction()
+        .inputs
+        .iter()
+        .filter_map(|input| {
+            let outpoint = &input.previous_outpoint;
+            entries
+                .values()
+                .find(|entry| {
+                    let id = entry.id();
+                    id.transaction_id == outpoint.transaction_id && id.index == outpoint.index
+                })
+                .cloned()
+        })
+        .collect()
+}
+
+fn step_to_dict<'py>(
+    py: Python<'py>,
+    transaction: &native::PendingTransaction,
+    prefix: Prefix,
+) -> PyResult<Bound<'py, PyDict>> {
+    let inputs = PyList::empty(py);
+    for entry in ordered_entries(transaction) {
+        let id = entry.id();
+        let input = PyDict::new(py);
+        input.set_item("transaction_id", id.transaction_id.to_string())?;
+        input.set_item("index", id.index)?;
+        input.set_item("amount", entry.amount())?;
+        input.set_item(
+            "address",
+            entry
+                .utxo
+                .address
+                .as_ref()
+                .map(|address| address.to_string()),
+        )?;
+        inputs.append(input)?;
+    }
+
+    let outputs = PyList::empty(py);
+    for output in &transaction.transaction().outputs {
+        let dict = PyDict::new(py);
+        dict.set_item(
+            "address",
+            extract_script_pub_key_address(&output.script_public_key, prefix)
+                .ok()
+                .map(|address| address.to_string()),
+        )?;
+        dict.set_item("amount", output.value)?;
+        outputs.append(dict)?;
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("id", transaction.id().to_string())?;
+    dict.set_item(
+        "type",
+        if transaction.is_batch() {
+            "batch"
+        } else {
+            "final"
+        },
+    )?;
+    dict.set_item("inputs", inputs)?;
+    dict.set_item("outputs", outputs)?;
+    dict.set_item("fee", transaction.fees())?;
+    dict.set_item("mass", transaction.mass())?;
+    dict.set_item("change", transaction.change_value())?;
+    Ok(dict)
+}
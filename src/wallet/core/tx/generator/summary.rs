@@ -1,4 +1,5 @@
 use crate::traits::TryToPyDict;
+use kaspa_consensus_core::network::NetworkId;
 use kaspa_wallet_core::tx::generator as core;
 use pyo3::{prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
@@ -12,7 +13,7 @@ use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 /// See Also:
 ///     create_transactions, Generator
 #[gen_stub_pyclass]
-#[pyclass(name = "GeneratorSummary")]
+#[pyclass(name = "GeneratorSummary", subclass)]
 pub struct PyGeneratorSummary(core::GeneratorSummary);
 
 #[gen_stub_pymethods]
@@ -76,7 +77,7 @@ impl PyGeneratorSummary {
     ///
     /// Returns:
     ///     dict: the GeneratorSummary in dictionary form.
-    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
         self.0.try_to_pydict(py)
     }
 
@@ -89,6 +90,23 @@ impl PyGeneratorSummary {
     }
 }
 
+impl PyGeneratorSummary {
+    pub fn network_id(&self) -> NetworkId {
+        self.0.network_id()
+    }
+
+    /// Include `transactions` generated ahead of the ones summarized.
+    pub(crate) fn with_earlier(mut self, transactions: &[core::PendingTransaction]) -> Self {
+        for transaction in transactions {
+            self.0.aggregated_utxos += transaction.utxo_entries().len();
+            self.0.aggregate_fees += transaction.fees();
+            self.0.aggregate_mass += transaction.mass();
+            self.0.number_of_generated_transactions += 1;
+        }
+        self
+    }
+}
+
 impl From<core::GeneratorSummary> for PyGeneratorSummary {
     fn from(inner: core::GeneratorSummary) -> Self {
         Self(inner)
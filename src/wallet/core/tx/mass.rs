@@ -2,9 +2,14 @@ use crate::consensus::client::transaction::PyTransaction;
 use crate::consensus::core::network::PyNetworkId;
 
 use super::super::imports::*;
+use super::generator::{PyOutputs, PyUtxoEntries};
+use kaspa_consensus_client::{
+    Transaction, TransactionInput, TransactionOutput, UtxoEntryReference,
+};
 use kaspa_consensus_core::config::params::Params;
 use kaspa_consensus_core::mass::{UtxoCell, calc_storage_mass};
-use kaspa_wallet_core::tx::{MAXIMUM_STANDARD_TRANSACTION_MASS, mass};
+use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+use kaspa_wallet_core::tx::{MAXIMUM_STANDARD_TRANSACTION_MASS, PaymentOutput, mass};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 // use pyo3::prelude::*;
 
@@ -164,3 +169,174 @@ pub fn py_calculate_storage_mass(
 
     Ok(storage_mass)
 }
+
+/// Get the largest payload that fits a standard transaction with the given inputs and outputs.
+///
+/// Args:
+///     inputs: The UTXO entries the transaction spends.
+///     outputs: The payment outputs of the transaction (including change, if any).
+///     network_id: The network identifier.
+///     minimum_signatures: Minimum signatures per input (default: 1).
+///
+/// Returns:
+///     int: The maximum payload size in bytes.
+///
+/// Raises:
+///     Exception: If the transaction exceeds the standard mass without a payload, or mass calculation fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "max_payload_size_for")]
+#[pyo3(signature = (inputs, outputs, network_id, minimum_signatures=None))]
+pub fn py_max_payload_size_for(
+    inputs: PyUtxoEntries,
+    outputs: PyOutputs,
+    network_id: PyNetworkId,
+    minimum_signatures: Option<u16>,
+) -> PyResult<usize> {
+    let consensus_params = Params::from(NetworkId::from(network_id));
+    let mc = mass::MassCalculator::new(&consensus_params);
+    let minimum_signatures = minimum_signatures.unwrap_or(1);
+    max_payload_size(&mc, &inputs.entries, &outputs.outputs, minimum_signatures)?.ok_or_else(|| {
+        PyException::new_err("Transaction exceeds the maximum standard mass without a payload")
+    })
+}
+
+/// Plan a transaction carrying a payload of a given size.
+///
+/// Tells whether the transaction fits the standard mass limit and the fee it
+/// needs, e.g. to size data inscriptions before building them.
+///
+/// Args:
+///     payload_size: The desired payload size in bytes.
+///     inputs: The UTXO entries the transaction spends.
+///     outputs: The payment outputs of the transaction (including change, if any).
+///     network_id: The network identifier.
+///     minimum_signatures: Minimum signatures per input (default: 1).
+///     fee_rate: Fee rate in sompi per gram of mass (default: the minimum relay fee).
+///
+/// Returns:
+///     dict: `payload_size`, `mass`, `max_mass`, `fits` (the mass is within
+///         `max_mass`), `max_payload_size` (None if nothing fits) and `fee` (in
+///         sompi, None if the transaction does not fit).
+///
+/// Raises:
+///     Exception: If mass calculation fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "plan_payload")]
+#[pyo3(signature = (payload_size, inputs, outputs, network_id, minimum_signatures=None, fee_rate=None))]
+pub fn py_plan_payload<'py>(
+    py: Python<'py>,
+    payload_size: usize,
+    inputs: PyUtxoEntries,
+    outputs: PyOutputs,
+    network_id: PyNetworkId,
+    minimum_signatures: Option<u16>,
+    fee_rate: Option<f64>,
+) -> PyResult<Bound<'py, PyDict>> {
+    if fee_rate.is_some_and(|fee_rate| !fee_rate.is_finite() || fee_rate < 0.0) {
+        return Err(PyException::new_err(
+            "fee_rate must be a non-negative number",
+        ));
+    }
+    let consensus_params = Params::from(NetworkId::from(network_id));
+    let mc = mass::MassCalculator::new(&consensus_params);
+    let minimum_signatures = minimum_signatures.unwrap_or(1);
+
+    let mass = payload_mass(
+        &mc,
+        &inputs.entries,
+        &outputs.outputs,
+        payload_size,
+        minimum_signatures,
+    )?;
+    let fits = mass <= MAXIMUM_STANDARD_TRANSACTION_MASS;
+    let fee = fits.then(|| {
+        let minimum_fee = mc.calc_fee_for_mass(mass);
+        fee_rate.map_or(minimum_fee, |fee_rate| {
+            minimum_fee.max((mass as f64 * fee_rate).ceil() as u64)
+        })
+    });
+
+    let dict = PyDict::new(py);
+    dict.set_item("payload_size", payload_size)?;
+    dict.set_item("mass", mass)?;
+    dict.set_item("max_mass", MAXIMUM_STANDARD_TRANSACTION_MASS)?;
+    dict.set_item("fits", fits)?;
+    dict.set_item(
+        "max_payload_size",
+        max_payload_size(&mc, &inputs.entries, &outputs.outputs, minimum_signatures)?,
+    )?;
+    dict.set_item("fee", fee)?;
+    Ok(dict)
+}
+
+/// The mass of an unsigned transaction with a payload of `payload_size` bytes.
+fn payload_mass(
+    mc: &mass::MassCalculator,
+    entries: &[UtxoEntryReference],
+    outputs: &[PaymentOutput],
+    payload_size: usize,
+    minimum_signatures: u16,
+) -> PyResult<u64> {
+    let inputs = entries
+        .iter()
+        .enumerate()
+        .map(|(sequence, reference)| {
+            TransactionInput::new(
+                reference.utxo.outpoint.clone(),
+                None,
+                sequence as u64,
+                1,
+                Some(reference.clone()),
+            )
+        })
+        .collect::<Vec<_>>();
+    let outputs = outputs
+        .iter()
+        .cloned()
+        .map(TransactionOutput::from)
+        .collect::<Vec<_>>();
+    let transaction = Transaction::new(
+        None,
+        0,
+        inputs,
+        outputs,
+        0,
+        SUBNETWORK_ID_NATIVE,
+        0,
+        vec![0; payload_size],
+        0,
+    )
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+    mc.calc_overall_mass_for_unsigned_client_transaction(&transaction, minimum_signatures)
+        .map_err(|err| PyException::new_err(err.to_string()))
+}
+
+/// The largest payload size keeping the transaction within the standard mass,
+/// found by bisection as the mass grows with the payload size.
+fn max_payload_size(
+    mc: &mass::MassCalculator,
+    entries: &[UtxoEntryReference],
+    outputs: &[PaymentOutput],
+    minimum_signatures: u16,
+) -> PyResult<Option<usize>> {
+    let fits = |payload_size| {
+        payload_mass(mc, entries, outputs, payload_size, minimum_signatures)
+            .map(|mass| mass <= MAXIMUM_STANDARD_TRANSACTION_MASS)
+    };
+    if !fits(0)? {
+        return Ok(None);
+    }
+    // Every payload byte adds mass, so the payload is smaller than the mass limit.
+    let (mut low, mut high) = (0, MAXIMUM_STANDARD_TRANSACTION_MASS as usize);
+    while low < high {
+        let middle = low + (high - low).div_ceil(2);
+        if fits(middle)? {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Ok(Some(low))
+}
@@ -1,5 +1,9 @@
+pub mod fees;
 pub mod generator;
 pub mod mass;
 pub mod payment;
+pub mod policy;
+pub mod send;
 pub mod signer;
 pub mod utils;
+pub mod verify;
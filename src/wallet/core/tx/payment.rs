@@ -1,12 +1,8 @@
 use kaspa_wallet_core::tx::payment::PaymentOutput;
-use pyo3::{
-    exceptions::{PyException, PyKeyError},
-    prelude::*,
-    types::PyDict,
-};
+use pyo3::{exceptions::PyKeyError, prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
-use crate::address::PyAddress;
+use crate::{address::PyAddress, types::parse_amount, validation};
 
 /// A payment destination with address and amount.
 ///
@@ -45,17 +41,19 @@ impl TryFrom<&Bound<'_, PyDict>> for PyPaymentOutput {
         let address = if let Ok(address) = address_value.extract::<PyAddress>() {
             address
         } else if let Ok(s) = address_value.extract::<String>() {
-            PyAddress::try_from(s).map_err(|err| PyException::new_err(format!("{}", err)))?
+            PyAddress::try_from(s.clone())
+                .map_err(|err| validation::invalid("address", format!("`{s}`: {err}")))?
         } else {
-            return Err(PyException::new_err(
-                "Addresses must be either an Address instance or a string",
+            return Err(validation::invalid(
+                "address",
+                "must be either an Address instance or a string",
             ));
         };
 
-        let amount: u64 = value
+        let amount = value
             .get_item("amount")?
-            .ok_or_else(|| PyKeyError::new_err("Key `amount` not present"))?
-            .extract()?;
+            .ok_or_else(|| PyKeyError::new_err("Key `amount` not present"))?;
+        let amount = parse_amount(&amount)?;
 
         let inner = PaymentOutput::new(address.into(), amount);
 
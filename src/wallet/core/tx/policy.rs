@@ -0,0 +1,235 @@
+use crate::exceptions::policy_violation;
+use crate::validation;
+use kaspa_addresses::Prefix;
+use kaspa_consensus_client::Transaction;
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_txscript::extract_script_pub_key_address;
+use kaspa_wallet_core::tx::generator as native;
+use pyo3::{
+    prelude::*,
+    sync::MutexExt,
+    types::{PyDict, PyList},
+};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use std::sync::Mutex;
+
+/// Signing policies registered with `add_signing_policy()`, in registration order.
+static POLICIES: Mutex<Vec<Py<PyAny>>> = Mutex::new(Vec::new());
+
+/// Register a signing policy, consulted before any transaction is signed.
+///
+/// The policy is called with a dict describing the transaction about to be
+/// signed: `operation` (`sign`, `sign_input`, `create_input_signature`,
+/// `signature_hash`, `sign_transaction`, `send` or `sweep`), `transaction_id`, `amount` (the
+/// sompi leaving the wallet: the outputs other than change), `fee` (None when
+/// unknown) and `outputs`, a list of dicts with `address` (None for
+/// non-standard scripts), `amount` and `change`.
+///
+/// Returning None or True allows signing. Returning False or a reason string,
+/// or raising `PolicyViolationError`, vetoes it: the signing call raises
+/// `PolicyViolationError` and nothing is signed. Policies run in registration
+/// order, on every signing call (`sign_input()` calls it once per input).
+///
+/// Args:
+///     policy: A callable taking the request dict.
+///
+/// Raises:
+///     ValueError: If `policy` is not callable.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "add_signing_policy")]
+pub fn py_add_signing_policy(
+    #[gen_stub(override_type(type_repr = "typing.Callable[[dict], bool | str | None]"))]
+    policy: Bound<'_, PyAny>,
+) -> PyResult<()> {
+    if !policy.is_callable() {
+        return Err(validation::invalid("policy", "must be callable"));
+    }
+    POLICIES
+        .lock_py_attached(policy.py())
+        .unwrap()
+        .push(policy.unbind());
+    Ok(())
+}
+
+/// Remove a signing policy registered with `add_signing_policy()`.
+///
+/// Args:
+///     policy: The registered callable.
+///
+/// Returns:
+///     bool: True if the policy was removed.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "remove_signing_policy")]
+pub fn py_remove_signing_policy(
+    #[gen_stub(override_type(type_repr = "typing.Callable[[dict], bool | str | None]"))]
+    policy: Bound<'_, PyAny>,
+) -> bool {
+    let removed: Vec<_> = {
+        let mut policies = POLICIES.lock_py_attached(policy.py()).unwrap();
+        let (removed, kept) = std::mem::take(&mut *policies)
+            .into_iter()
+            .partition(|registered| registered.bind(policy.py()).is(&policy));
+        *policies = kept;
+        removed
+    };
+    // Dropped after the lock is released: it may run arbitrary finalizers.
+    !removed.is_empty()
+}
+
+/// An output of a transaction about to be signed.
+pub(crate) struct RequestOutput {
+    pub address: Option<String>,
+    pub amount: u64,
+    pub change: bool,
+}
+
+/// A transaction about to be signed, as presented to signing policies.
+pub(crate) struct SigningRequest {
+    pub operation: &'static str,
+    pub transaction_id: String,
+    pub fee: Option<u64>,
+    pub outputs: Vec<RequestOutput>,
+}
+
+impl SigningRequest {
+    /// A request for a Generator transaction. Batch transactions pay the change
+    /// address only; with `sweep`, the outputs of the final one leave the wallet.
+    pub fn pending(
+        operation: &'static str,
+        pending: &native::PendingTransaction,
+        sweep: bool,
+    ) -> Self {
+        let prefix = pending
+            .utxo_entries()
+            .values()
+            .find_map(|entry| entry.utxo.address.as_ref().map(|address| address.prefix));
+        let transaction = pending.transaction();
+        let last = transaction.outputs.len().saturating_sub(1);
+        let change_value = pending.change_value();
+        let outputs = transaction
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let change = pending.is_batch()
+                    || (!sweep
+                        && change_value > 0
+                        && index == last
+                        && output.value == change_value);
+                request_output(output.value, &output.script_public_key, prefix, change)
+            })
+            .collect();
+        Self {
+            operation,
+            transaction_id: pending.id().to_string(),
+            fee: Some(pending.fees()),
+            outputs,
+        }
+    }
+
+    /// A request for a standalone transaction; all its outputs count as leaving the wallet.
+    pub fn transaction(operation: &'static str, transaction: &Transaction) -> Self {
+        let inner = transaction.inner();
+        let prefix = inner.inputs.iter().find_map(|input| {
+            input
+                .get_utxo()
+                .and_then(|utxo| utxo.utxo.address.as_ref().map(|address| address.prefix))
+        });
+        let outputs = inner
+            .outputs
+            .iter()
+            .map(|output| {
+                let output = output.inner();
+                request_output(output.value, &output.script_public_key, prefix, false)
+            })
+            .collect();
+        Self {
+            operation,
+            transaction_id: inner.id.to_string(),
+            fee: None,
+            outputs,
+        }
+    }
+
+    fn amount(&self) -> u64 {
+        self.outputs
+            .iter()
+            .filter(|output| !output.change)
+            .map(|output| output.amount)
+            .sum()
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let outputs = PyList::empty(py);
+        for output in &self.outputs {
+            let dict = PyDict::new(py);
+            dict.set_item("address", &output.address)?;
+            dict.set_item("amount", output.amount)?;
+            dict.set_item("change", output.change)?;
+            outputs.append(dict)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item("operation", self.operation)?;
+        dict.set_item("transaction_id", &self.transaction_id)?;
+        dict.set_item("amount", self.amount())?;
+        dict.set_item("fee", self.fee)?;
+        dict.set_item("outputs", outputs)?;
+        Ok(dict)
+    }
+}
+
+fn request_output(
+    amount: u64,
+    script_public_key: &ScriptPublicKey,
+    prefix: Option<Prefix>,
+    change: bool,
+) -> RequestOutput {
+    let address = prefix.and_then(|prefix| {
+        extract_script_pub_key_address(script_public_key, prefix)
+            .ok()
+            .map(|address| address.to_string())
+    });
+    RequestOutput {
+        address,
+        amount,
+        change,
+    }
+}
+
+/// Consult the registered signing policies, raising `PolicyViolationError` on a veto.
+/// `request` is only built when policies are registered.
+pub(crate) fn check(py: Python<'_>, request: impl FnOnce() -> SigningRequest) -> PyResult<()> {
+    let policies = POLICIES
+        .lock_py_attached(py)
+        .unwrap()
+        .iter()
+        .map(|policy| policy.clone_ref(py))
+        .collect::<Vec<_>>();
+    if policies.is_empty() {
+        return Ok(());
+    }
+
+    // The lock is released before calling into Python, so policies may register others.
+    let request = request();
+    let dict = request.to_dict(py)?;
+    for policy in policies {
+        let result = policy.call1(py, (&dict,))?;
+        let result = result.bind(py);
+        if result.is_none() {
+            continue;
+        }
+        if let Ok(reason) = result.extract::<String>() {
+            return Err(policy_violation(py, request.transaction_id, reason));
+        }
+        if !result.is_truthy()? {
+            return Err(policy_violation(
+                py,
+                request.transaction_id,
+                "rejected by signing policy".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,492 @@
+use super::super::imports::*;
+use crate::address::resolve_name;
+use crate::consensus::core::network::PyNetworkId;
+use crate::exceptions::transaction_rejected;
+use crate::rpc::wrpc::client::{DisconnectGuard, PyRpcClient};
+use crate::wallet::bip32::phrase::PyMnemonic;
+use crate::wallet::core::tx::policy::{self, SigningRequest};
+use crate::wallet::keys::privatekey::PyPrivateKey;
+use crate::wallet::keys::vault::PyKeyHandle;
+use kaspa_addresses::{Prefix, Version};
+use kaspa_bip32::{DerivationPath, ExtendedPrivateKey, Language, Mnemonic};
+use kaspa_consensus_client::{Transaction, UtxoEntryReference};
+use kaspa_consensus_core::{
+    hashing::{
+        sighash::{SigHashReusedValuesUnsync, calc_ecdsa_signature_hash},
+        sighash_type::SIG_HASH_ALL,
+    },
+    tx::PopulatedTransaction,
+};
+use kaspa_rpc_core::api::rpc::RpcApi;
+use kaspa_wallet_core::tx::{
+    Fees, PaymentDestination, PaymentOutput, PaymentOutputs, generator as native,
+};
+use kaspa_wrpc_client::{KaspaRpcClient, client::ConnectOptions, prelude::ConnectStrategy};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use secp256k1::{SECP256K1, SecretKey};
+use std::iter::once;
+use std::str::FromStr;
+use zeroize::Zeroize;
+
+/// Derivation path of the first receive address of a kaspa-ng / KDX BIP-32 account.
+const DEFAULT_DERIVATION_PATH: &str = "m/44'/111111'/0'/0/0";
+
+/// Send KAS to an address in one call (async).
+///
+/// Connects (when given a URL), fetches the UTXOs of the sender address,
+/// generates, signs and submits the transaction(s) and returns the id of the
+/// final transaction. Change is returned to the sender address. Large
+/// transfers may be split into several transactions by the Generator; they
+/// are all submitted in order.
+///
+/// Args:
+///     rpc: A connected RpcClient or a node URL (e.g. "ws://127.0.0.1:17110").
+///     private_key: A PrivateKey, a KeyHandle, a hex private key string, a Mnemonic or a mnemonic phrase. Mnemonics use the first receive address of account 0 (m/44'/111111'/0'/0/0).
+///     to_address: Destination Address or address string.
+///     amount: Amount to send in sompi, or an amount string such as "1.5 KAS" (see `Amount`).
+///     priority_fee: Additional fee, as `amount` (default: no priority fee).
+///     network_id: Network to use (default: derived from the destination address prefix; "testnet-10" for testnet).
+///     resolve_names: Accept a name (e.g. "name.kas") as `to_address`, resolved with `Address.resolve()` (default: False).
+///
+/// Returns:
+///     str: The id of the final transaction.
+///
+/// Raises:
+///     TransactionRejectedError: If the node rejects a transaction (see `kaspa.exceptions`).
+///     PolicyViolationError: If a signing policy vetoes a transaction (see `add_signing_policy()`).
+///     Exception: If the key or address is invalid, the client is not connected, or generation, signing or submission fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "send")]
+#[pyo3(signature = (rpc, private_key, to_address, amount, priority_fee=None, network_id=None, resolve_names=false))]
+#[gen_stub(override_return_type(type_repr = "str"))]
+pub fn py_send<'py>(
+    py: Python<'py>,
+    #[gen_stub(override_type(type_repr = "RpcClient | str"))] rpc: Bound<'_, PyAny>,
+    #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle | Mnemonic | str"))]
+    private_key: Bound<'_, PyAny>,
+    #[gen_stub(override_type(type_repr = "Address | str"))] to_address: Bound<'_, PyAny>,
+    amount: PyAmount,
+    priority_fee: Option<PyAmount>,
+    network_id: Option<PyNetworkId>,
+    resolve_names: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let amount = u64::from(amount);
+    let priority_fee = priority_fee.map(u64::from).unwrap_or_default();
+    let to_address = parse_address(&to_address, "to_address", resolve_names)?;
+    let network_id = match network_id {
+        Some(network_id) => network_id.into(),
+        None => default_network_id(&to_address)?,
+    };
+    if Prefix::from(network_id) != to_address.prefix {
+        return Err(PyException::new_err(format!(
+            "Address `{to_address}` does not belong to network `{network_id}`"
+        )));
+    }
+
+    let mut secret = parse_secret(&private_key)?;
+    let from_address = {
+        let secret_key =
+            SecretKey::from_slice(&secret).map_err(|err| PyException::new_err(err.to_string()))?;
+        let (public_key, _) = secret_key.x_only_public_key(SECP256K1);
+        Address::new(network_id.into(), Version::PubKey, &public_key.serialize())
+    };
+
+    let (client, owned) = match rpc_client(&rpc, network_id) {
+        Ok(client) => client,
+        Err(err) => {
+            secret.zeroize();
+            return Err(err);
+        }
+    };
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let rpc = client.client().clone();
+        // Disconnects the owned client if the send is cancelled midway.
+        let guard = owned.then(|| DisconnectGuard::new(rpc.clone()));
+        if owned && let Err(err) = connect(&rpc).await {
+            secret.zeroize();
+            return Err(err);
+        }
+
+        let result = send_impl(
+            rpc.clone(),
+            network_id,
+            from_address,
+            to_address,
+            amount,
+            priority_fee,
+            &secret,
+        )
+        .await;
+        secret.zeroize();
+
+        if let Some(guard) = guard {
+            guard.defuse();
+            rpc.disconnect().await.ok();
+        }
+        result
+    })
+}
+
+/// Sweep all funds of a private key (e.g. a paper wallet) to an address (async).
+///
+/// Fetches the UTXOs of both the Schnorr (`Version.PubKey`) and the ECDSA
+/// (`Version.PubKeyECDSA`) address of the key and drains them into
+/// `destination`. UTXO sets too large for one transaction are compounded
+/// by the Generator into as many chained transactions as needed; they are
+/// all submitted in order.
+///
+/// Args:
+///     rpc: A connected RpcClient or a node URL (e.g. "ws://127.0.0.1:17110").
+///     private_key: A PrivateKey, a KeyHandle, a hex private key string, a Mnemonic or a mnemonic phrase. Mnemonics use the first receive address of account 0 (m/44'/111111'/0'/0/0).
+///     destination: Address or address string receiving the funds.
+///     fee: Priority fee in sompi, or an amount string such as "0.001 KAS" (default: no priority fee).
+///     network_id: Network to use (default: derived from the destination address prefix; "testnet-10" for testnet).
+///     resolve_names: Accept a name (e.g. "name.kas") as `destination`, resolved with `Address.resolve()` (default: False).
+///
+/// Returns:
+///     list[str]: The ids of the submitted transactions, the final sweep transaction last.
+///
+/// Raises:
+///     TransactionRejectedError: If the node rejects a transaction (see `kaspa.exceptions`).
+///     PolicyViolationError: If a signing policy vetoes a transaction (see `add_signing_policy()`).
+///     Exception: If the key or address is invalid, the key holds no UTXOs, the client is not connected, or generation, signing or submission fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "sweep_private_key")]
+#[pyo3(signature = (rpc, private_key, destination, fee=None, network_id=None, resolve_names=false))]
+#[gen_stub(override_return_type(type_repr = "list[str]"))]
+pub fn py_sweep_private_key<'py>(
+    py: Python<'py>,
+    #[gen_stub(override_type(type_repr = "RpcClient | str"))] rpc: Bound<'_, PyAny>,
+    #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle | Mnemonic | str"))]
+    private_key: Bound<'_, PyAny>,
+    #[gen_stub(override_type(type_repr = "Address | str"))] destination: Bound<'_, PyAny>,
+    fee: Option<PyAmount>,
+    network_id: Option<PyNetworkId>,
+    resolve_names: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let priority_fee = fee.map(u64::from).unwrap_or_default();
+    let destination = parse_address(&destination, "destination", resolve_names)?;
+    let network_id = match network_id {
+        Some(network_id) => network_id.into(),
+        None => default_network_id(&destination)?,
+    };
+    if Prefix::from(network_id) != destination.prefix {
+        return Err(PyException::new_err(format!(
+            "Address `{destination}` does not belong to network `{network_id}`"
+        )));
+    }
+
+    let mut secret = parse_secret(&private_key)?;
+    let addresses = match SecretKey::from_slice(&secret) {
+        Ok(secret_key) => {
+            let public_key = secret_key.public_key(SECP256K1);
+            let (xonly_public_key, _) = public_key.x_only_public_key();
+            vec![
+                Address::new(
+                    network_id.into(),
+                    Version::PubKey,
+                    &xonly_public_key.serialize(),
+                ),
+                Address::new(
+                    network_id.into(),
+                    Version::PubKeyECDSA,
+                    &public_key.serialize(),
+                ),
+            ]
+        }
+        Err(err) => {
+            secret.zeroize();
+            return Err(PyException::new_err(err.to_string()));
+        }
+    };
+
+    let (client, owned) = match rpc_client(&rpc, network_id) {
+        Ok(client) => client,
+        Err(err) => {
+            secret.zeroize();
+            return Err(err);
+        }
+    };
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let rpc = client.client().clone();
+        // Disconnects the owned client if the sweep is cancelled midway.
+        let guard = owned.then(|| DisconnectGuard::new(rpc.clone()));
+        if owned && let Err(err) = connect(&rpc).await {
+            secret.zeroize();
+            return Err(err);
+        }
+
+        let result = sweep_impl(
+            rpc.clone(),
+            network_id,
+            addresses,
+            destination,
+            priority_fee,
+            &secret,
+        )
+        .await;
+        secret.zeroize();
+
+        if let Some(guard) = guard {
+            guard.defuse();
+            rpc.disconnect().await.ok();
+        }
+        result
+    })
+}
+
+async fn send_impl(
+    rpc: Arc<DynRpcApi>,
+    network_id: NetworkId,
+    from_address: Address,
+    to_address: Address,
+    amount: u64,
+    priority_fee: u64,
+    secret: &[u8; 32],
+) -> PyResult<String> {
+    let entries = rpc
+        .get_utxos_by_addresses(vec![from_address.clone()])
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?
+        .into_iter()
+        .map(UtxoEntryReference::from)
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        return Err(PyException::new_err(format!(
+            "No UTXOs found for sender address `{from_address}`"
+        )));
+    }
+
+    let destination = PaymentOutputs {
+        outputs: vec![PaymentOutput::new(to_address, amount)],
+    };
+    let settings = native::GeneratorSettings::try_new_with_iterator(
+        network_id,
+        Box::new(entries.into_iter()),
+        None,
+        from_address,
+        1,
+        1,
+        destination.into(),
+        None,
+        Fees::from(priority_fee),
+        None,
+        None,
+    )
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+    let generator = native::Generator::try_new(settings, None, None)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let mut final_transaction_id = None;
+    for pending in generator.iter() {
+        let pending = pending.map_err(|err| PyException::new_err(err.to_string()))?;
+        Python::attach(|py| {
+            policy::check(py, || SigningRequest::pending("send", &pending, false))
+        })?;
+        pending
+            .try_sign_with_keys(&[*secret], Some(true))
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let transaction_id = pending.try_submit(&rpc).await.map_err(|err| {
+            Python::attach(|py| {
+                transaction_rejected(py, err.to_string(), Some(pending.id().to_string()))
+            })
+        })?;
+        final_transaction_id = Some(transaction_id.to_string());
+    }
+
+    final_transaction_id.ok_or_else(|| PyException::new_err("No transaction was generated"))
+}
+
+async fn sweep_impl(
+    rpc: Arc<DynRpcApi>,
+    network_id: NetworkId,
+    addresses: Vec<Address>,
+    destination: Address,
+    priority_fee: u64,
+    secret: &[u8; 32],
+) -> PyResult<Vec<String>> {
+    let entries = rpc
+        .get_utxos_by_addresses(addresses.clone())
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?
+        .into_iter()
+        .map(UtxoEntryReference::from)
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        let addresses = addresses.iter().map(Address::to_string).collect::<Vec<_>>();
+        return Err(PyException::new_err(format!(
+            "No UTXOs found for addresses `{}`",
+            addresses.join("`, `")
+        )));
+    }
+
+    let settings = native::GeneratorSettings::try_new_with_iterator(
+        network_id,
+        Box::new(entries.into_iter()),
+        None,
+        destination,
+        1,
+        1,
+        PaymentDestination::Change,
+        None,
+        Fees::from(priority_fee),
+        None,
+        None,
+    )
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+    let generator = native::Generator::try_new(settings, None, None)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let mut transaction_ids = Vec::new();
+    for pending in generator.iter() {
+        let pending = pending.map_err(|err| PyException::new_err(err.to_string()))?;
+        Python::attach(|py| {
+            policy::check(py, || SigningRequest::pending("sweep", &pending, true))
+        })?;
+        pending
+            .try_sign_with_keys(&[*secret], Some(false))
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        sign_ecdsa_inputs(&pending, secret)?;
+        let transaction_id = pending.try_submit(&rpc).await.map_err(|err| {
+            Python::attach(|py| {
+                transaction_rejected(py, err.to_string(), Some(pending.id().to_string()))
+            })
+        })?;
+        transaction_ids.push(transaction_id.to_string());
+    }
+
+    Ok(transaction_ids)
+}
+
+// Sign the inputs of `pending` spending the ECDSA P2PK script of `secret`.
+// `try_sign_with_keys()` only produces Schnorr signatures.
+fn sign_ecdsa_inputs(pending: &native::PendingTransaction, secret: &[u8; 32]) -> PyResult<()> {
+    let secret_key =
+        SecretKey::from_slice(secret).map_err(|err| PyException::new_err(err.to_string()))?;
+    // OP_DATA_33 <COMPRESSED PUBKEY> OP_CHECKSIGECDSA
+    let script = once(0x21)
+        .chain(secret_key.public_key(SECP256K1).serialize())
+        .chain(once(0xab))
+        .collect::<Vec<u8>>();
+
+    let transaction =
+        Transaction::from_cctx_transaction(&pending.transaction(), pending.utxo_entries());
+    let (cctx, utxos) = transaction
+        .tx_and_utxos()
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+    let reused_values = SigHashReusedValuesUnsync::new();
+    for (index, entry) in populated_transaction.entries.iter().enumerate() {
+        if entry.script_public_key.script() != script.as_slice() {
+            continue;
+        }
+        let sig_hash =
+            calc_ecdsa_signature_hash(&populated_transaction, index, SIG_HASH_ALL, &reused_values);
+        let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let sig = SECP256K1.sign_ecdsa(&msg, &secret_key).serialize_compact();
+        // OP_DATA_65 <SIGNATURE+SIGHASH_TYPE>
+        let signature_script = once(65u8)
+            .chain(sig)
+            .chain([SIG_HASH_ALL.to_u8()])
+            .collect::<Vec<u8>>();
+        pending
+            .fill_input(index, signature_script)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+    }
+    Ok(())
+}
+
+fn rpc_client(rpc: &Bound<'_, PyAny>, network_id: NetworkId) -> PyResult<(PyRpcClient, bool)> {
+    if let Ok(client) = rpc.extract::<PyRpcClient>() {
+        if !client.client().is_connected() {
+            return Err(PyException::new_err("RpcClient is not connected"));
+        }
+        Ok((client, false))
+    } else if let Ok(url) = rpc.extract::<String>() {
+        Ok((
+            PyRpcClient::new(None, Some(url), None, Some(network_id))?,
+            true,
+        ))
+    } else {
+        Err(PyException::new_err(
+            "rpc must be an RpcClient or a URL string",
+        ))
+    }
+}
+
+async fn connect(rpc: &KaspaRpcClient) -> PyResult<()> {
+    let options = ConnectOptions {
+        block_async_connect: true,
+        strategy: ConnectStrategy::Fallback,
+        url: None,
+        connect_timeout: None,
+        retry_interval: None,
+    };
+    rpc.connect(Some(options))
+        .await
+        .map(|_| ())
+        .map_err(|err| PyException::new_err(err.to_string()))
+}
+
+fn parse_address(value: &Bound<'_, PyAny>, name: &str, resolve_names: bool) -> PyResult<Address> {
+    if let Ok(address) = value.extract::<PyAddress>() {
+        Ok(address.into())
+    } else if let Ok(address) = value.extract::<String>() {
+        if resolve_names {
+            resolve_name(value.py(), &address)
+        } else {
+            PyAddress::try_from(address).map(Address::from)
+        }
+    } else {
+        Err(PyException::new_err(format!(
+            "{name} must be an Address or str"
+        )))
+    }
+}
+
+fn parse_secret(value: &Bound<'_, PyAny>) -> PyResult<[u8; 32]> {
+    if let Ok(key) = value.cast::<PyPrivateKey>() {
+        Ok(key.borrow().secret_bytes())
+    } else if let Ok(handle) = value.cast::<PyKeyHandle>() {
+        handle.borrow().secret_bytes(value.py())
+    } else if let Ok(mnemonic) = value.cast::<PyMnemonic>() {
+        derive_mnemonic_secret(mnemonic.borrow().inner())
+    } else if let Ok(value) = value.extract::<String>() {
+        if value.split_whitespace().count() > 1 {
+            let mnemonic = Mnemonic::new(value.trim(), Language::English)
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            derive_mnemonic_secret(&mnemonic)
+        } else {
+            Ok(PyPrivateKey::try_new(value.trim())?.secret_bytes())
+        }
+    } else {
+        Err(PyException::new_err(
+            "private_key must be a PrivateKey, KeyHandle, Mnemonic or str",
+        ))
+    }
+}
+
+fn derive_mnemonic_secret(mnemonic: &Mnemonic) -> PyResult<[u8; 32]> {
+    let seed = mnemonic.to_seed("");
+    let path = DerivationPath::from_str(DEFAULT_DERIVATION_PATH)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let xprv = ExtendedPrivateKey::<SecretKey>::new(seed.as_bytes())
+        .and_then(|xprv| xprv.derive_path(&path))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    Ok(xprv.private_key().secret_bytes())
+}
+
+fn default_network_id(address: &Address) -> PyResult<NetworkId> {
+    let network_id = match address.prefix {
+        Prefix::Mainnet => "mainnet",
+        Prefix::Testnet => "testnet-10",
+        Prefix::Simnet => "simnet",
+        Prefix::Devnet => "devnet",
+    };
+    NetworkId::from_str(network_id).map_err(|err| PyException::new_err(err.to_string()))
+}
@@ -1,11 +1,19 @@
 use crate::{
     consensus::{client::transaction::PyTransaction, core::hashing::PySighashType},
     crypto::hashes::PyHash,
-    wallet::keys::privatekey::PyPrivateKey,
+    wallet::{
+        core::tx::policy::{self, SigningRequest},
+        keys::vault::signing_key,
+    },
 };
-use kaspa_consensus_client::{Transaction, sign_with_multiple_v3};
+use ahash::AHashMap;
+use kaspa_consensus_client::Transaction;
 use kaspa_consensus_core::{
-    hashing::{sighash_type::SIG_HASH_ALL, wasm::SighashType},
+    hashing::{
+        sighash::{SigHashReusedValuesUnsync, calc_schnorr_signature_hash},
+        sighash_type::SIG_HASH_ALL,
+        wasm::SighashType,
+    },
     sign::{sign_input, verify},
     tx::PopulatedTransaction,
 };
@@ -13,39 +21,49 @@ use kaspa_hashes::Hash;
 use kaspa_wallet_core::result::Result;
 use pyo3::{exceptions::PyException, prelude::*, types::PyList};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rayon::prelude::*;
+use std::iter::once;
 use workflow_core::hex::ToHex;
-use zeroize::Zeroize;
+use zeroize::Zeroizing;
 
 /// Sign a transaction with one or more private keys.
 ///
+/// Inputs are signed in parallel with the GIL released.
+///
 /// Args:
 ///     tx: The transaction to sign.
-///     signer: List of PrivateKey objects for signing.
+///     signer: List of PrivateKey objects or `KeyHandle`s for signing.
 ///     verify_sig: Whether to verify signatures after signing.
 ///
 /// Returns:
 ///     Transaction: The signed transaction.
 ///
 /// Raises:
+///     PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
 ///     Exception: If signing or verification fails.
 #[gen_stub_pyfunction]
 #[pyfunction(name = "sign_transaction")]
 pub fn py_sign_transaction<'py>(
+    py: Python<'py>,
     tx: PyTransaction,
     signer: Bound<'py, PyList>,
     verify_sig: bool,
 ) -> PyResult<PyTransaction> {
-    let mut private_keys: Vec<[u8; 32]> = Vec::with_capacity(signer.len());
+    let transaction: Transaction = tx.into();
+    policy::check(py, || {
+        SigningRequest::transaction("sign_transaction", &transaction)
+    })?;
+    let mut private_keys = Zeroizing::new(Vec::with_capacity(signer.len()));
     for item in signer.iter() {
-        let key: PyRef<'_, PyPrivateKey> = item.extract()?;
-        private_keys.push(key.secret_bytes());
+        private_keys.push(*signing_key(&item)?);
     }
 
-    let transaction: Transaction = tx.into();
-    let tx = sign_transaction(&transaction, &private_keys, verify_sig)
-        .map_err(|err| PyException::new_err(format!("Unable to sign: {err:?}")))?;
-    private_keys.zeroize();
-    Ok(tx.clone().into())
+    let result = py.detach(|| {
+        sign_transaction(&transaction, &private_keys, verify_sig)
+            .map(|tx| tx.clone())
+            .map_err(|err| format!("Unable to sign: {err:?}"))
+    });
+    Ok(result.map_err(PyException::new_err)?.into())
 }
 
 /// Create a signature for a specific transaction input.
@@ -53,25 +71,30 @@ pub fn py_sign_transaction<'py>(
 /// Args:
 ///     tx: The transaction containing the input to sign.
 ///     input_index: The index of the input to sign.
-///     private_key: The private key for signing.
+///     private_key: The private key, or a `KeyHandle` from a `KeyVault`.
 ///     sighash_type: The signature hash type (default: All).
 ///
 /// Returns:
 ///     str: The signature as a hex string.
 ///
 /// Raises:
+///     PolicyViolationError: If a signing policy vetoes the transaction (see `add_signing_policy()`).
 ///     Exception: If signing fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_input_signature")]
 #[pyo3(signature = (tx, input_index, private_key, sighash_type=None))]
 pub fn py_create_input_signature(
+    py: Python<'_>,
     tx: &PyTransaction,
     input_index: u8,
-    private_key: &PyPrivateKey,
+    #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle"))] private_key: Bound<'_, PyAny>,
     #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
     sighash_type: Option<PySighashType>,
 ) -> PyResult<String> {
+    policy::check(py, || {
+        SigningRequest::transaction("create_input_signature", tx.inner())
+    })?;
     let (cctx, utxos) = tx
         .inner()
         .tx_and_utxos()
@@ -80,14 +103,13 @@ pub fn py_create_input_signature(
 
     let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
 
-    let mut key_bytes = private_key.secret_bytes();
+    let key_bytes = signing_key(&private_key)?;
     let signature = sign_input(
         &populated_transaction,
         input_index.into(),
         &key_bytes,
         sighash_type.into(),
     );
-    key_bytes.zeroize();
 
     Ok(signature.to_hex())
 }
@@ -96,7 +118,7 @@ pub fn py_create_input_signature(
 ///
 /// Args:
 ///     script_hash: The script hash to sign as a hex string.
-///     privkey: The private key for signing.
+///     privkey: The private key, or a `KeyHandle` from a `KeyVault`.
 ///
 /// Returns:
 ///     str: The signature as a hex string.
@@ -106,12 +128,14 @@ pub fn py_create_input_signature(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "sign_script_hash")]
-pub fn py_sign_script_hash(script_hash: String, privkey: &PyPrivateKey) -> PyResult<String> {
+pub fn py_sign_script_hash(
+    script_hash: String,
+    #[gen_stub(override_type(type_repr = "PrivateKey | KeyHandle"))] privkey: Bound<'_, PyAny>,
+) -> PyResult<String> {
     let script_hash = PyHash::try_from(script_hash)?;
-    let mut key_bytes = privkey.secret_bytes();
+    let key_bytes = signing_key(&privkey)?;
     let result = sign_hash(script_hash.into(), &key_bytes)
         .map_err(|err| PyException::new_err(err.to_string()))?;
-    key_bytes.zeroize();
     Ok(result.to_hex())
 }
 
@@ -129,11 +153,70 @@ fn sign_transaction<'a>(
     Ok(tx)
 }
 
-// Sign a transaction using schnorr, returns a new transaction with the signatures added.
+// Sign a transaction using schnorr, returns the transaction with the signatures added.
 // The resulting transaction may be partially signed if the supplied keys are not sufficient
 // to sign all of its inputs.
+//
+// Equivalent to `sign_with_multiple_v3()`, except that sighash calculation and signing
+// are spread across inputs on the rayon thread pool.
 fn sign<'a>(tx: &'a Transaction, privkeys: &[[u8; 32]]) -> Result<&'a Transaction> {
-    Ok(sign_with_multiple_v3(tx, privkeys)?.unwrap())
+    let (cctx, utxos) = tx.tx_and_utxos()?;
+    let signatures = sign_inputs(&PopulatedTransaction::new(&cctx, utxos), privkeys)?;
+
+    {
+        let mut inner = tx.inner();
+        for (input, signature) in inner.inputs.iter_mut().zip(signatures) {
+            if let Some(signature) = signature {
+                input.set_signature_script(signature);
+            }
+        }
+    }
+
+    Ok(tx)
+}
+
+/// The schnorr signature scripts of the inputs spending a P2PK script of one
+/// of `privkeys`, computed in parallel on the rayon thread pool. None for the
+/// inputs none of the keys can sign.
+pub(crate) fn sign_inputs(
+    populated_transaction: &PopulatedTransaction<'_>,
+    privkeys: &[[u8; 32]],
+) -> Result<Vec<Option<Vec<u8>>>> {
+    let mut keys = AHashMap::with_capacity(privkeys.len());
+    for privkey in privkeys {
+        let schnorr_key = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, privkey)?;
+        let script_pub_key_script = once(0x20)
+            .chain(schnorr_key.x_only_public_key().0.serialize())
+            .chain(once(0xac))
+            .collect::<Vec<u8>>();
+        keys.insert(script_pub_key_script, schnorr_key);
+    }
+
+    let signatures = (0..populated_transaction.entries.len())
+        .into_par_iter()
+        .map_init(SigHashReusedValuesUnsync::new, |reused_values, index| {
+            let script = populated_transaction.entries[index]
+                .script_public_key
+                .script();
+            let schnorr_key = keys.get(script)?;
+            let sig_hash = calc_schnorr_signature_hash(
+                populated_transaction,
+                index,
+                SIG_HASH_ALL,
+                &*reused_values,
+            );
+            let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).ok()?;
+            let sig: [u8; 64] = *schnorr_key.sign_schnorr(msg).as_ref();
+            // OP_DATA_65 <SIGNATURE+SIGHASH_TYPE>
+            Some(
+                once(65u8)
+                    .chain(sig)
+                    .chain([SIG_HASH_ALL.to_u8()])
+                    .collect::<Vec<u8>>(),
+            )
+        })
+        .collect();
+    Ok(signatures)
 }
 
 fn sign_hash(sig_hash: Hash, privkey: &[u8; 32]) -> Result<Vec<u8>> {
@@ -2,12 +2,12 @@ use crate::consensus::client::transaction::PyTransaction;
 use crate::consensus::core::network::PyNetworkId;
 
 use super::super::imports::*;
+use super::fees::PyFees;
 use super::generator::{
-    PendingTransaction, PyGenerator, PyGeneratorSummary, PyOutputs, PyUtxoEntries,
+    PyChangeAddress, PyGenerator, PyGeneratorSummary, PyOutputs, PyPercentageOutputs, PyUtxoEntries,
 };
 use kaspa_consensus_client::*;
 use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
-use kaspa_wallet_core::result::Result;
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 // use pyo3::{exceptions::PyException, prelude::*};
 
@@ -16,7 +16,7 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Args:
 ///     utxo_entry_source: List of UTXO entries to spend.
 ///     outputs: List of payment outputs.
-///     priority_fee: Priority fee in sompi.
+///     priority_fee: Priority fee in sompi, or an amount string such as "0.001 KAS".
 ///     payload: Optional transaction payload data.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///
@@ -32,10 +32,11 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 pub fn py_create_transaction(
     utxo_entry_source: PyUtxoEntries,
     outputs: PyOutputs,
-    priority_fee: u64,
+    priority_fee: PyAmount,
     payload: Option<PyBinary>,
     sig_op_count: Option<u8>,
 ) -> PyResult<PyTransaction> {
+    let priority_fee = u64::from(priority_fee);
     let payload: Vec<u8> = payload.map(Into::into).unwrap_or_default();
     let sig_op_count = sig_op_count.unwrap_or(1);
 
@@ -95,14 +96,17 @@ pub fn py_create_transaction(
 /// Args:
 ///     network_id: The network to build transactions for (required for UTXO entries).
 ///     entries: UtxoContext or list of UTXO entries to spend from.
-///     change_address: Address to send change to.
+///     change_address: Address to send change to, or a callable returning a fresh change address.
 ///     outputs: Optional list of payment outputs.
 ///     payload: Optional transaction payload data.
-///     fee_rate: Optional fee rate multiplier.
-///     priority_fee: Additional fee in sompi.
+///     fee_rate: Optional fee rate in sompi per gram of mass.
+///     priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     min_change: Optional minimum change amount in sompi; smaller change is added to the fee.
+///     split_outputs: Optional number of equal outputs each payment output is split into.
+///     percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` routing a percentage of the payment amount to additional outputs (see `Generator`).
 ///
 /// Returns:
 ///     dict: Dictionary with "transactions" (list) and "summary" keys.
@@ -112,19 +116,28 @@ pub fn py_create_transaction(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_transactions")]
-#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, min_change=None, split_outputs=None, percentage_outputs=None))]
 pub fn py_create_transactions<'a>(
     py: Python<'a>,
     #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<'_, PyAny>,
-    change_address: PyAddress,
+    #[gen_stub(override_type(
+        type_repr = "Address | builtins.str | typing.Callable[[], Address | builtins.str]"
+    ))]
+    change_address: PyChangeAddress,
     network_id: Option<PyNetworkId>,
     outputs: Option<PyOutputs>,
     payload: Option<PyBinary>,
     fee_rate: Option<f64>,
-    priority_fee: Option<u64>,
+    #[gen_stub(override_type(type_repr = "typing.Optional[Amount | Fees]"))] priority_fee: Option<
+        PyFees,
+    >,
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    min_change: Option<PyAmount>,
+    split_outputs: Option<usize>,
+    #[gen_stub(override_type(type_repr = "typing.Optional[typing.Sequence[dict]]"))]
+    percentage_outputs: Option<PyPercentageOutputs>,
 ) -> PyResult<Bound<'a, PyDict>> {
     let generator = PyGenerator::ctor(
         entries,
@@ -137,13 +150,16 @@ pub fn py_create_transactions<'a>(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        min_change,
+        split_outputs,
+        percentage_outputs,
     )?;
 
     let transactions = generator
-        .iter()
-        .map(|r| r.map(PendingTransaction::from))
-        .collect::<Result<Vec<_>>>()
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+        .generate_all(py)?
+        .into_iter()
+        .map(|transaction| generator.pending_transaction(transaction))
+        .collect::<Vec<_>>();
     let summary = generator.summary();
     let dict = PyDict::new(py);
     dict.set_item("transactions", transactions)?;
@@ -156,14 +172,17 @@ pub fn py_create_transactions<'a>(
 /// Args:
 ///     network_id: The network to estimate for (required for UTXO entries).
 ///     entries: UtxoContext or list of UTXO entries to spend from.
-///     change_address: Address to send change to.
+///     change_address: Address to send change to, or a callable returning a fresh change address.
 ///     outputs: Optional list of payment outputs.
 ///     payload: Optional transaction payload data.
-///     fee_rate: Optional fee rate multiplier.
-///     priority_fee: Additional fee in sompi.
+///     fee_rate: Optional fee rate in sompi per gram of mass.
+///     priority_fee: Additional fee in sompi, or a `Fees` (absolute, receiver-pays, or a fee rate replacing `fee_rate`).
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     min_change: Optional minimum change amount in sompi; smaller change is added to the fee.
+///     split_outputs: Optional number of equal outputs each payment output is split into.
+///     percentage_outputs: Optional list of dicts with `address`, `percent` and `rounding` routing a percentage of the payment amount to additional outputs (see `Generator`).
 ///
 /// Returns:
 ///     GeneratorSummary: Summary with fee, transaction count, and other details.
@@ -173,18 +192,28 @@ pub fn py_create_transactions<'a>(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "estimate_transactions")]
-#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, min_change=None, split_outputs=None, percentage_outputs=None))]
 pub fn py_estimate_transactions(
+    py: Python,
     #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<'_, PyAny>,
-    change_address: PyAddress,
+    #[gen_stub(override_type(
+        type_repr = "Address | builtins.str | typing.Callable[[], Address | builtins.str]"
+    ))]
+    change_address: PyChangeAddress,
     network_id: Option<PyNetworkId>,
     outputs: Option<PyOutputs>,
     payload: Option<PyBinary>,
     fee_rate: Option<f64>,
-    priority_fee: Option<u64>,
+    #[gen_stub(override_type(type_repr = "typing.Optional[Amount | Fees]"))] priority_fee: Option<
+        PyFees,
+    >,
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    min_change: Option<PyAmount>,
+    split_outputs: Option<usize>,
+    #[gen_stub(override_type(type_repr = "typing.Optional[typing.Sequence[dict]]"))]
+    percentage_outputs: Option<PyPercentageOutputs>,
 ) -> PyResult<PyGeneratorSummary> {
     let generator = PyGenerator::ctor(
         entries,
@@ -197,11 +226,11 @@ pub fn py_estimate_transactions(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        min_change,
+        split_outputs,
+        percentage_outputs,
     )?;
 
-    generator
-        .iter()
-        .collect::<Result<Vec<_>>>()
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+    generator.generate_all(py)?;
     Ok(generator.summary())
 }
@@ -0,0 +1,347 @@
+use kaspa_consensus_core::{
+    hashing::{
+        sighash::{
+            SigHashReusedValuesUnsync, calc_ecdsa_signature_hash, calc_schnorr_signature_hash,
+        },
+        sighash_type::SigHashType,
+    },
+    tx::{PopulatedTransaction, VerifiableTransaction},
+};
+use kaspa_txscript::{SigCacheKey, TxScriptEngine, caches::Cache};
+use secp256k1::{Message, PublicKey, SECP256K1, XOnlyPublicKey, ecdsa, schnorr};
+
+const OP_1: u8 = 0x51;
+const OP_16: u8 = 0x60;
+const OP_EQUAL: u8 = 0x87;
+const OP_CHECK_MULTI_SIG_ECDSA: u8 = 0xa9;
+const OP_BLAKE2B: u8 = 0xaa;
+const OP_CHECK_SIG_ECDSA: u8 = 0xab;
+const OP_CHECK_SIG: u8 = 0xac;
+const OP_CHECK_MULTI_SIG: u8 = 0xae;
+
+/// A signature found in the signature script of an input.
+pub struct SignatureCheck {
+    /// The public key the signature verifies against, if any.
+    pub public_key: Option<Vec<u8>>,
+    /// The sighash type appended to the signature, None if it is not a valid type.
+    pub sighash_type: Option<SigHashType>,
+    pub valid: bool,
+}
+
+/// The signatures of a transaction input.
+pub struct InputSignatures {
+    pub index: usize,
+    pub signatures: Vec<SignatureCheck>,
+}
+
+impl InputSignatures {
+    pub fn is_signed(&self) -> bool {
+        !self.signatures.is_empty()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_signed() && self.signatures.iter().all(|signature| signature.valid)
+    }
+}
+
+/// Check the signatures of every input of a transaction.
+///
+/// Inputs spending P2PK (Schnorr or ECDSA) scripts are checked against the key
+/// of the script, P2SH inputs against the keys pushed by their redeem script
+/// (e.g. multisig). Signatures are 65-byte pushes: 64 signature bytes followed
+/// by the sighash type.
+pub fn check_signatures(transaction: &PopulatedTransaction<'_>) -> Vec<InputSignatures> {
+    let reused_values = SigHashReusedValuesUnsync::new();
+    transaction
+        .tx()
+        .inputs
+        .iter()
+        .zip(transaction.entries.iter())
+        .enumerate()
+        .map(|(index, (input, entry))| {
+            let script = entry.script_public_key.script();
+            let pushes = data_pushes(&input.signature_script).unwrap_or_default();
+            let (keys, ecdsa) = signing_keys(script, &pushes).unwrap_or_default();
+            // The last push of a P2SH signature script is the redeem script.
+            let signatures = match pushes.split_last() {
+                Some((_, signatures)) if is_p2sh(script) => signatures,
+                _ => &pushes[..],
+            };
+
+            let signatures = signatures
+                .iter()
+                .filter(|push| push.len() == 65)
+                .map(|push| {
+                    let (sighash_type, public_key) =
+                        match_signature(transaction, index, push, &keys, ecdsa, &reused_values);
+                    SignatureCheck {
+                        valid: public_key.is_some(),
+                        public_key,
+                        sighash_type,
+                    }
+                })
+                .collect();
+            InputSignatures { index, signatures }
+        })
+        .collect()
+}
+
+/// The cosigners of a multisig redeem script:
+/// OP_m <key 1> ... <key n> OP_n OP_CHECKMULTISIG(ECDSA).
+pub struct MultisigScript {
+    pub required: usize,
+    pub keys: Vec<Vec<u8>>,
+    pub ecdsa: bool,
+}
+
+impl MultisigScript {
+    /// Parse a multisig redeem script, None if `script` is not one.
+    pub fn parse(script: &[u8]) -> Option<Self> {
+        let (&first, rest) = script.split_first()?;
+        let (&last, rest) = rest.split_last()?;
+        let (&count, rest) = rest.split_last()?;
+        let ecdsa = match last {
+            OP_CHECK_MULTI_SIG => false,
+            OP_CHECK_MULTI_SIG_ECDSA => true,
+            _ => return None,
+        };
+        if !(OP_1..=OP_16).contains(&first) || !(OP_1..=OP_16).contains(&count) {
+            return None;
+        }
+        let key_len = if ecdsa { 33 } else { 32 };
+        let keys = rest
+            .chunks(key_len + 1)
+            .map(|push| match push {
+                [len, key @ ..] if *len as usize == key_len && key.len() == key_len => {
+                    Some(key.to_vec())
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let required = (first - OP_1 + 1) as usize;
+        (keys.len() == (count - OP_1 + 1) as usize && required <= keys.len()).then_some(Self {
+            required,
+            keys,
+            ecdsa,
+        })
+    }
+}
+
+/// Signatures of a multisig input matched against its cosigners.
+pub struct MultisigCheck {
+    pub required: usize,
+    /// Each declared key, in redeem script order, with the first signature verifying against it.
+    pub cosigners: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+    /// Keys of signatures repeating a cosigner that already signed.
+    pub duplicates: Vec<Vec<u8>>,
+    /// Number of signatures verifying against no declared key.
+    pub unmatched: usize,
+}
+
+impl MultisigCheck {
+    /// Number of distinct cosigners with a valid signature.
+    pub fn signed(&self) -> usize {
+        self.cosigners
+            .iter()
+            .filter(|(_, signature)| signature.is_some())
+            .count()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.signed() >= self.required
+    }
+
+    /// The pushes of the first `required` signatures in key order, the order
+    /// OP_CHECKMULTISIG consumes them in. None if the threshold is not met.
+    pub fn signature_pushes(&self) -> Option<Vec<u8>> {
+        if !self.is_complete() {
+            return None;
+        }
+        let pushes = self
+            .cosigners
+            .iter()
+            .filter_map(|(_, signature)| signature.as_ref())
+            .take(self.required)
+            .flat_map(|signature| {
+                std::iter::once(signature.len() as u8).chain(signature.iter().copied())
+            })
+            .collect();
+        Some(pushes)
+    }
+}
+
+/// Match the signatures (65 bytes: 64 signature bytes and the sighash type)
+/// given for a multisig input against the cosigners of its redeem script.
+pub fn check_multisig(
+    transaction: &PopulatedTransaction<'_>,
+    index: usize,
+    script: &MultisigScript,
+    signatures: &[Vec<u8>],
+) -> MultisigCheck {
+    let reused_values = SigHashReusedValuesUnsync::new();
+    let mut check = MultisigCheck {
+        required: script.required,
+        cosigners: script.keys.iter().map(|key| (key.clone(), None)).collect(),
+        duplicates: Vec::new(),
+        unmatched: 0,
+    };
+    for signature in signatures {
+        let (_, public_key) = match_signature(
+            transaction,
+            index,
+            signature,
+            &script.keys,
+            script.ecdsa,
+            &reused_values,
+        );
+        let Some(public_key) = public_key else {
+            check.unmatched += 1;
+            continue;
+        };
+        // The same key may be declared more than once; fill its first free slot.
+        let slot = check
+            .cosigners
+            .iter_mut()
+            .find(|(key, signed)| *key == public_key && signed.is_none());
+        match slot {
+            Some((_, signed)) => *signed = Some(signature.clone()),
+            None => check.duplicates.push(public_key),
+        }
+    }
+    check
+}
+
+/// Execute the scripts of every input with the consensus script engine.
+///
+/// Returns the index of the first input failing execution and its script error.
+pub fn verify_scripts(transaction: &PopulatedTransaction<'_>) -> Result<(), (usize, String)> {
+    let reused_values = SigHashReusedValuesUnsync::new();
+    let sig_cache = Cache::<SigCacheKey, bool>::new(transaction.entries.len() as u64);
+    for (index, (input, entry)) in transaction.populated_inputs().enumerate() {
+        TxScriptEngine::from_transaction_input(
+            transaction,
+            input,
+            index,
+            entry,
+            &reused_values,
+            &sig_cache,
+        )
+        .and_then(|mut engine| engine.execute())
+        .map_err(|err| (index, err.to_string()))?;
+    }
+    Ok(())
+}
+
+/// The data pushed by a script, skipping other opcodes. None if a push overruns the script.
+pub(crate) fn data_pushes(script: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut pushes = Vec::new();
+    let mut offset = 0;
+    while offset < script.len() {
+        let opcode = script[offset];
+        offset += 1;
+        let len = match opcode {
+            0x00 => 0,
+            0x01..=0x4b => opcode as usize,
+            0x4c => {
+                offset += 1;
+                *script.get(offset - 1)? as usize
+            }
+            0x4d => {
+                offset += 2;
+                u16::from_le_bytes(script.get(offset - 2..offset)?.try_into().ok()?) as usize
+            }
+            0x4e => {
+                offset += 4;
+                u32::from_le_bytes(script.get(offset - 4..offset)?.try_into().ok()?) as usize
+            }
+            _ => continue,
+        };
+        pushes.push(script.get(offset..offset + len)?);
+        offset += len;
+    }
+    Some(pushes)
+}
+
+/// Whether `script` is a P2PK ECDSA script: OP_DATA_33 <compressed key> OP_CHECKSIGECDSA.
+pub(crate) fn is_p2pk_ecdsa(script: &[u8]) -> bool {
+    script.len() == 35 && script[0] == 0x21 && script[34] == OP_CHECK_SIG_ECDSA
+}
+
+fn is_p2sh(script: &[u8]) -> bool {
+    script.len() == 35 && script[0] == OP_BLAKE2B && script[1] == 0x20 && script[34] == OP_EQUAL
+}
+
+/// The keys able to sign an input and whether they are ECDSA keys.
+fn signing_keys(script: &[u8], pushes: &[&[u8]]) -> Option<(Vec<Vec<u8>>, bool)> {
+    match script {
+        [0x20, key @ .., OP_CHECK_SIG] if key.len() == 32 => Some((vec![key.to_vec()], false)),
+        [0x21, key @ .., OP_CHECK_SIG_ECDSA] if key.len() == 33 => Some((vec![key.to_vec()], true)),
+        _ if is_p2sh(script) => {
+            let redeem_script = *pushes.last()?;
+            let ecdsa = matches!(
+                redeem_script.last(),
+                Some(&OP_CHECK_SIG_ECDSA | &OP_CHECK_MULTI_SIG_ECDSA)
+            );
+            let key_len = if ecdsa { 33 } else { 32 };
+            let keys = data_pushes(redeem_script)?
+                .into_iter()
+                .filter(|push| push.len() == key_len)
+                .map(<[u8]>::to_vec)
+                .collect();
+            Some((keys, ecdsa))
+        }
+        _ => None,
+    }
+}
+
+/// The sighash type of a 65-byte signature pushed for input `index` and the
+/// first of `keys` it verifies against.
+fn match_signature(
+    transaction: &PopulatedTransaction<'_>,
+    index: usize,
+    push: &[u8],
+    keys: &[Vec<u8>],
+    ecdsa: bool,
+    reused_values: &SigHashReusedValuesUnsync,
+) -> (Option<SigHashType>, Option<Vec<u8>>) {
+    let Some(sighash_type) = push
+        .get(64)
+        .filter(|_| push.len() == 65)
+        .and_then(|&sighash_type| SigHashType::from_u8(sighash_type).ok())
+    else {
+        return (None, None);
+    };
+    let sig_hash = if ecdsa {
+        calc_ecdsa_signature_hash(transaction, index, sighash_type, reused_values)
+    } else {
+        calc_schnorr_signature_hash(transaction, index, sighash_type, reused_values)
+    };
+    let public_key = Message::from_digest_slice(sig_hash.as_bytes().as_slice())
+        .ok()
+        .and_then(|msg| {
+            keys.iter()
+                .find(|key| verify_signature(&msg, &push[..64], key, ecdsa))
+                .cloned()
+        });
+    (Some(sighash_type), public_key)
+}
+
+fn verify_signature(msg: &Message, signature: &[u8], key: &[u8], ecdsa: bool) -> bool {
+    if ecdsa {
+        let (Ok(signature), Ok(key)) = (
+            ecdsa::Signature::from_compact(signature),
+            PublicKey::from_slice(key),
+        ) else {
+            return false;
+        };
+        SECP256K1.verify_ecdsa(msg, &signature, &key).is_ok()
+    } else {
+        let (Ok(signature), Ok(key)) = (
+            schnorr::Signature::from_slice(signature),
+            XOnlyPublicKey::from_slice(key),
+        ) else {
+            return false;
+        };
+        SECP256K1.verify_schnorr(&signature, msg, &key).is_ok()
+    }
+}
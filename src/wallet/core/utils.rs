@@ -2,6 +2,8 @@ use pyo3::prelude::*;
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 
 use crate::consensus::core::network::PyNetworkType;
+use crate::types::{AmountFormat, PyAmount};
+use crate::validation;
 
 /// Convert KAS to sompi (1 KAS = 100,000,000 sompi).
 ///
@@ -10,11 +12,85 @@ use crate::consensus::core::network::PyNetworkType;
 ///
 /// Returns:
 ///     int: The amount in sompi.
+///
+/// Raises:
+///     ValueError: If the amount is negative or not finite.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "kaspa_to_sompi")]
-pub fn py_kaspa_to_sompi(kaspa: f64) -> u64 {
-    kaspa_wallet_core::utils::kaspa_to_sompi(kaspa)
+pub fn py_kaspa_to_sompi(kaspa: f64) -> PyResult<u64> {
+    if !kaspa.is_finite() || kaspa < 0.0 {
+        return Err(validation::invalid(
+            "kaspa",
+            format!("must be a non-negative amount, got {kaspa}"),
+        ));
+    }
+    Ok(kaspa_wallet_core::utils::kaspa_to_sompi(kaspa))
+}
+
+/// Parse an amount into sompi.
+///
+/// Accepts the same forms as all amount parameters: an `int` in sompi, a
+/// string with a unit such as "1.5 KAS" or "1500 sompi", or a
+/// `decimal.Decimal` in KAS. Unlike `kaspa_to_sompi()`, no float is involved,
+/// so the conversion is exact.
+///
+/// Args:
+///     amount: The amount to parse.
+///
+/// Returns:
+///     int: The amount in sompi.
+///
+/// Raises:
+///     TypeError: If the amount is a `float` or of an unsupported type.
+///     ValueError: If the amount is negative, out of range, has more than 8 decimal places or no unit.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "parse_amount")]
+pub fn py_parse_amount(amount: PyAmount) -> u64 {
+    amount.into()
+}
+
+/// Format an amount for display, with locale-aware separators.
+///
+/// Args:
+///     amount: The amount (an `int` in sompi, a string with a unit, a `decimal.Decimal` in KAS or an `Amount`).
+///     unit: `KAS` (or `TKAS`/`SKAS`/`DKAS`, the suffix shown) or `sompi` (default: KAS).
+///     decimals: Fixed number of decimal places, rounded half up (default: as many as needed).
+///     locale: Use the separators of a locale, e.g. `de_DE` or `fr`, or `system` for the process locale.
+///     thousands_separator: Separator between groups of 3 digits, overriding the locale.
+///     decimal_point: Decimal point, overriding the locale.
+///     suffix: Append the unit (default: True).
+///
+/// Returns:
+///     str: The formatted amount, e.g. "1,234.5 KAS" or "1.234,5 KAS".
+///
+/// Raises:
+///     ValueError: If the amount, unit, decimals or locale are invalid.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "format_amount")]
+#[pyo3(signature = (amount, unit="KAS", decimals=None, locale=None, thousands_separator=None, decimal_point=None, suffix=true))]
+pub fn py_format_amount(
+    py: Python<'_>,
+    amount: PyAmount,
+    unit: &str,
+    decimals: Option<usize>,
+    locale: Option<&str>,
+    thousands_separator: Option<&str>,
+    decimal_point: Option<&str>,
+    suffix: bool,
+) -> PyResult<String> {
+    AmountFormat::new(
+        py,
+        unit,
+        decimals,
+        locale,
+        thousands_separator,
+        decimal_point,
+        suffix,
+    )?
+    .format(amount.into())
 }
 
 /// Convert sompi to KAS (1 KAS = 100,000,000 sompi).
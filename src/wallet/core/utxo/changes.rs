@@ -0,0 +1,264 @@
+use crate::wallet::core::utxo::context::{PyUtxoContext, UtxoDiff, context_utxos};
+use ahash::AHashMap;
+use futures::lock::Mutex;
+use kaspa_consensus_client::{TransactionOutpoint, UtxoEntry, UtxoEntryReference};
+use kaspa_consensus_core::tx::TransactionId;
+use kaspa_wallet_core::events::Events;
+use kaspa_wallet_core::storage::{Binding, TransactionData, TransactionRecord};
+use pyo3::{exceptions::PyStopAsyncIteration, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::sync::Arc;
+use workflow_core::channel::MultiplexerChannel;
+
+type OutpointKey = (TransactionId, u32);
+
+/// The entries of a context reported so far, updated from transaction events.
+#[derive(Default)]
+struct KnownEntries(AHashMap<OutpointKey, UtxoEntryReference>);
+
+impl KnownEntries {
+    fn new(entries: Vec<UtxoEntryReference>) -> Self {
+        let mut known = Self::default();
+        known.apply(entries, std::iter::empty());
+        known
+    }
+
+    /// Record entries `added` to and `spent` from the context, returning the
+    /// entries not reported yet and the known entries spent.
+    fn apply(
+        &mut self,
+        added: Vec<UtxoEntryReference>,
+        spent: impl Iterator<Item = OutpointKey>,
+    ) -> UtxoDiff {
+        let removed = spent
+            .filter_map(|key| self.0.remove(&key))
+            .collect::<Vec<_>>();
+        let added = added
+            .into_iter()
+            .filter(|entry| {
+                let id = entry.id();
+                self.0
+                    .insert((id.transaction_id, id.index), entry.clone())
+                    .is_none()
+            })
+            .collect();
+        UtxoDiff { added, removed }
+    }
+}
+
+struct ChangesState {
+    /// Entries of the context, taken on the first iteration.
+    known: Option<KnownEntries>,
+    events: MultiplexerChannel<Box<Events>>,
+}
+
+impl Drop for ChangesState {
+    fn drop(&mut self) {
+        self.events.close();
+    }
+}
+
+/// Async iterator over the UTXO changes of a UtxoContext.
+///
+/// Created by `UtxoContext.changes()`. The first iteration takes the
+/// context's (mature and pending) UTXO set, then each iteration waits for
+/// transaction events of the context changing it and returns the difference,
+/// so an external store can be kept in sync without diffing full snapshots.
+///
+/// Iteration stops when the UtxoProcessor shuts down.
+#[gen_stub_pyclass]
+#[pyclass(name = "UtxoChanges")]
+pub struct PyUtxoChanges {
+    context: PyUtxoContext,
+    state: Arc<Mutex<ChangesState>>,
+}
+
+impl PyUtxoChanges {
+    pub fn new(context: PyUtxoContext) -> Self {
+        let events = context.inner().processor().multiplexer().channel();
+        Self {
+            context,
+            state: Arc::new(Mutex::new(ChangesState {
+                known: None,
+                events,
+            })),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyUtxoChanges {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Wait for the next batch of changes.
+    ///
+    /// Returns:
+    ///     tuple[list[UtxoEntryReference], list[UtxoEntryReference]]: The
+    ///         entries added and removed since the previous batch.
+    ///
+    /// Raises:
+    ///     StopAsyncIteration: When the UtxoProcessor shuts down.
+    #[gen_stub(override_return_type(
+        type_repr = "typing.Awaitable[tuple[builtins.list[UtxoEntryReference], builtins.list[UtxoEntryReference]]]"
+    ))]
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.context.inner().clone();
+        let state = self.state.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut state = state.lock().await;
+            let state = &mut *state;
+            // Events queued before are reflected in the set already and leave it as is.
+            let known = match &mut state.known {
+                Some(known) => known,
+                None => state
+                    .known
+                    .insert(KnownEntries::new(context_utxos(&context).await)),
+            };
+            let binding = Binding::from(context.binding());
+            loop {
+                let event = match state.events.receiver.recv().await {
+                    Ok(event) if !matches!(*event, Events::UtxoProcStop) => event,
+                    _ => return Err(PyStopAsyncIteration::new_err(())),
+                };
+                let Some(record) = context_record(&event, &binding) else {
+                    continue;
+                };
+                let diff = match *event {
+                    Events::Reorg { .. } | Events::External { .. } => {
+                        let lost = record_outputs(record);
+                        known.apply(Vec::new(), lost.iter().map(key))
+                    }
+                    _ => known.apply(record_outputs(record), record_inputs(record)),
+                };
+                if !diff.is_empty() {
+                    return Ok(diff.into_lists());
+                }
+            }
+        })
+    }
+}
+
+/// The record of a transaction event changing the UTXO set of the context
+/// bound to `binding`. Stasis (immature coinbase) entries are reported once
+/// they mature.
+fn context_record<'a>(event: &'a Events, binding: &Binding) -> Option<&'a TransactionRecord> {
+    let record = match event {
+        Events::Pending { record }
+        | Events::Maturity { record }
+        | Events::Discovery { record }
+        | Events::Reorg { record }
+        | Events::External { record } => record,
+        _ => return None,
+    };
+    let same_binding = borsh::to_vec(record.binding()).ok()? == borsh::to_vec(binding).ok()?;
+    same_binding.then_some(record)
+}
+
+/// The outputs of the record's transaction received by the context, or for
+/// reorg and external records, the entries the context lost.
+fn record_outputs(record: &TransactionRecord) -> Vec<UtxoEntryReference> {
+    let utxo_entries = match record.transaction_data() {
+        TransactionData::Incoming { utxo_entries, .. }
+        | TransactionData::Stasis { utxo_entries, .. }
+        | TransactionData::Reorg { utxo_entries, .. }
+        | TransactionData::External { utxo_entries, .. }
+        | TransactionData::TransferIncoming { utxo_entries, .. }
+        | TransactionData::Change { utxo_entries, .. } => utxo_entries,
+        // The entries of outgoing records are the inputs spent.
+        _ => return Vec::new(),
+    };
+    utxo_entries
+        .iter()
+        .map(|utxo| {
+            UtxoEntry {
+                address: utxo.address.clone(),
+                outpoint: TransactionOutpoint::new(*record.id(), utxo.index),
+                amount: utxo.amount,
+                script_public_key: utxo.script_public_key.clone(),
+                block_daa_score: record.block_daa_score(),
+                is_coinbase: utxo.is_coinbase,
+            }
+            .into()
+        })
+        .collect()
+}
+
+/// The outpoints spent by the record's transaction, when it carries one.
+fn record_inputs(record: &TransactionRecord) -> impl Iterator<Item = OutpointKey> + '_ {
+    let transaction = match record.transaction_data() {
+        TransactionData::Batch { transaction, .. }
+        | TransactionData::Outgoing { transaction, .. }
+        | TransactionData::TransferIncoming { transaction, .. }
+        | TransactionData::TransferOutgoing { transaction, .. }
+        | TransactionData::Change { transaction, .. } => Some(transaction),
+        _ => None,
+    };
+    transaction
+        .into_iter()
+        .flat_map(|transaction| &transaction.inputs)
+        .map(|input| {
+            let outpoint = &input.previous_outpoint;
+            (outpoint.transaction_id, outpoint.index)
+        })
+}
+
+fn key(entry: &UtxoEntryReference) -> OutpointKey {
+    let id = entry.id();
+    (id.transaction_id, id.index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_core::tx::ScriptPublicKey;
+
+    fn entry(transaction: u64, index: u32) -> UtxoEntryReference {
+        UtxoEntry {
+            address: None,
+            outpoint: TransactionOutpoint::new(TransactionId::from_u64_word(transaction), index),
+            amount: 1000,
+            script_public_key: ScriptPublicKey::from_vec(0, vec![]),
+            block_daa_score: 0,
+            is_coinbase: false,
+        }
+        .into()
+    }
+
+    fn ids(entries: &[UtxoEntryReference]) -> Vec<OutpointKey> {
+        let mut ids = entries.iter().map(key).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn reports_added_and_spent_entries() {
+        let mut known = KnownEntries::new(vec![entry(1, 0), entry(1, 1)]);
+
+        // A transaction spends the first entry and returns change.
+        let spent = [key(&entry(1, 0))];
+        let diff = known.apply(vec![entry(2, 0)], spent.into_iter());
+        assert_eq!(ids(&diff.added), ids(&[entry(2, 0)]));
+        assert_eq!(ids(&diff.removed), ids(&[entry(1, 0)]));
+    }
+
+    #[test]
+    fn known_entries_are_reported_once() {
+        let mut known = KnownEntries::new(vec![entry(1, 0)]);
+
+        // Maturity of an entry reported as pending, and a spend of an entry
+        // of another context.
+        let spent = [key(&entry(3, 0))];
+        assert!(known.apply(vec![entry(1, 0)], spent.into_iter()).is_empty());
+
+        let diff = known.apply(Vec::new(), [key(&entry(1, 0))].into_iter());
+        assert_eq!(ids(&diff.removed), ids(&[entry(1, 0)]));
+        assert!(
+            known
+                .apply(Vec::new(), [key(&entry(1, 0))].into_iter())
+                .is_empty()
+        );
+    }
+}
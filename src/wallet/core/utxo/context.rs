@@ -1,26 +1,120 @@
 use crate::address::PyAddress;
+use crate::consensus::client::outpoint::PyTransactionOutpoint;
 use crate::consensus::client::utxo::PyUtxoEntryReference;
 use crate::crypto::hashes::PyHash;
+use crate::validation;
+use crate::wallet::core::tx::generator::PyUtxoEntries;
 use crate::wallet::core::utxo::balance::{PyBalance, PyBalanceStrings};
-use crate::wallet::core::utxo::processor::PyUtxoProcessor;
-use futures::stream::StreamExt;
+use crate::wallet::core::utxo::changes::PyUtxoChanges;
+use crate::wallet::core::utxo::inflight::InFlightEntries;
+use crate::wallet::core::utxo::locks::OutpointLocks;
+use crate::wallet::core::utxo::processor::{ContextRegistry, PyUtxoProcessor};
+use crate::wallet::core::utxo::snapshot::{ContextSnapshot, SnapshotBalance};
+use ahash::{AHashMap, AHashSet};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use kaspa_addresses::Address;
+use kaspa_consensus_client::{TransactionOutpoint, UtxoEntry, UtxoEntryId, UtxoEntryReference};
+use kaspa_consensus_core::network::NetworkId;
 use kaspa_hashes::Hash;
 use kaspa_wallet_core::utxo::balance::BalanceStrings;
 use kaspa_wallet_core::utxo::{UtxoContext, UtxoContextBinding, UtxoContextId, UtxoStream};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
-use std::str::FromStr;
+use serde::Serialize;
+use std::{path::PathBuf, str::FromStr, sync::Arc};
+use workflow_log::*;
+
+/// Number of addresses registered and scanned per request by `track_addresses`.
+pub const DEFAULT_SCAN_BATCH_SIZE: usize = 256;
+/// Number of address batches `track_addresses` registers and scans concurrently.
+pub const DEFAULT_SCAN_CONCURRENCY: usize = 4;
+
+/// Unregisters the context's addresses once the last Python reference to
+/// the `UtxoContext` is dropped, so the node stops sending notifications for them.
+struct ContextGuard {
+    context: UtxoContext,
+    registry: Arc<ContextRegistry>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        self.registry.lock().unwrap().remove(&self.context.id());
+
+        if self.context.addresses().is_empty() || !self.context.processor().is_running() {
+            return;
+        }
+
+        let context = self.context.clone();
+        pyo3_async_runtimes::tokio::get_runtime().spawn(async move {
+            if let Err(err) = context.clear().await {
+                log_error!("UtxoContext: error while releasing context: {err}");
+            }
+            purge_processor_pending(&context);
+        });
+    }
+}
+
+/// Stable, JSON-exportable identity of a `UtxoContext`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContextDescriptor {
+    id: UtxoContextId,
+    network_id: Option<NetworkId>,
+    addresses: Vec<Address>,
+}
 
 /// UTXO context for tracking addresses and balances.
 #[gen_stub_pyclass]
 #[pyclass(name = "UtxoContext")]
 #[derive(Clone)]
-pub struct PyUtxoContext(UtxoContext);
+pub struct PyUtxoContext {
+    inner: UtxoContext,
+    // Keeps the processor alive for as long as any of its contexts.
+    processor: PyUtxoProcessor,
+    in_flight: Arc<InFlightEntries>,
+    locks: Arc<OutpointLocks>,
+    _guard: Arc<ContextGuard>,
+}
 
 impl PyUtxoContext {
     pub fn inner(&self) -> &UtxoContext {
-        &self.0
+        &self.inner
+    }
+
+    pub(crate) fn in_flight(&self) -> &Arc<InFlightEntries> {
+        &self.in_flight
+    }
+
+    pub(crate) fn locks(&self) -> &Arc<OutpointLocks> {
+        &self.locks
+    }
+
+    fn build_snapshot(&self) -> PyResult<ContextSnapshot> {
+        let processor = self.inner.processor();
+        let network_id = processor
+            .network_id()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
+        let addresses = self
+            .inner
+            .addresses()
+            .iter()
+            .map(|address| Address::clone(address.key()))
+            .collect();
+
+        let utxos = futures::executor::block_on(context_utxos(&self.inner))
+            .into_iter()
+            .map(|reference| UtxoEntry::clone(&reference.utxo))
+            .collect();
+
+        ContextSnapshot::new(
+            network_id,
+            self.inner.id(),
+            processor.current_daa_score(),
+            addresses,
+            utxos,
+            self.inner.balance().as_ref().map(SnapshotBalance::from),
+        )
     }
 }
 
@@ -32,6 +126,9 @@ impl PyUtxoContext {
     /// Args:
     ///     processor: The UtxoProcessor to bind to.
     ///     id: Optional 32-byte hex id (string) or Hash.
+    ///
+    /// Raises:
+    ///     ValueError: If a live UtxoContext of the processor already has this id.
     #[new]
     #[pyo3(signature = (processor, id=None))]
     pub fn ctor(
@@ -55,15 +152,51 @@ impl PyUtxoContext {
         };
 
         let inner = UtxoContext::new(processor.inner(), binding);
-        Ok(Self(inner))
+        let registry = processor.contexts().clone();
+        {
+            // The registry and the context guard are keyed by id, so ids must be unique.
+            let mut contexts = registry.lock().unwrap();
+            if contexts.contains_key(&inner.id()) {
+                return Err(validation::invalid(
+                    "id",
+                    "a UtxoContext with this id is already bound to the processor",
+                ));
+            }
+            contexts.insert(inner.id(), inner.clone());
+        }
+        Ok(Self {
+            _guard: Arc::new(ContextGuard {
+                context: inner.clone(),
+                registry,
+            }),
+            inner,
+            processor,
+            in_flight: Arc::new(InFlightEntries::default()),
+            locks: Arc::new(OutpointLocks::default()),
+        })
     }
 
     /// Track and scan a list of addresses (async).
     ///
+    /// Addresses are subscribed to and scanned in batches of `batch_size`, with
+    /// up to `concurrency` batches in flight, so tracking tens of thousands of
+    /// addresses does not wait on one request per address or one huge request.
+    ///
+    /// With `progress`, the callback is invoked after each batch with a dict:
+    /// `scanned` (addresses scanned so far), `total` (addresses to scan) and
+    /// `utxos` (UTXO entries known to the context). An exception raised by the
+    /// callback aborts the scan.
+    ///
     /// Args:
     ///     addresses: List of Address objects or address strings.
     ///     current_daa_score: Optional current DAA score for scan context.
-    #[pyo3(signature = (addresses, current_daa_score=None))]
+    ///     progress: Optional callback receiving scan progress dicts.
+    ///     batch_size: Number of addresses registered and scanned per request (default: 256).
+    ///     concurrency: Maximum number of batches processed concurrently (default: 4).
+    ///
+    /// Raises:
+    ///     Exception: If `batch_size` or `concurrency` is 0, or the scan fails.
+    #[pyo3(signature = (addresses, current_daa_score=None, progress=None, batch_size=DEFAULT_SCAN_BATCH_SIZE, concurrency=DEFAULT_SCAN_CONCURRENCY))]
     #[gen_stub(override_return_type(type_repr = "None"))]
     fn track_addresses<'py>(
         &self,
@@ -71,16 +204,31 @@ impl PyUtxoContext {
         #[gen_stub(override_type(type_repr = "Sequence[Address] | Sequence[str]"))]
         addresses: Bound<'_, PyAny>,
         current_daa_score: Option<u64>,
+        #[gen_stub(override_type(
+            type_repr = "typing.Optional[typing.Callable[[dict], None]] = None"
+        ))]
+        progress: Option<Py<PyAny>>,
+        batch_size: usize,
+        concurrency: usize,
     ) -> PyResult<Bound<'py, PyAny>> {
+        if batch_size == 0 || concurrency == 0 {
+            return Err(PyException::new_err(
+                "batch_size and concurrency must be greater than 0",
+            ));
+        }
         let addresses = parse_addresses(addresses)?;
-        let context = self.0.clone();
+        let context = self.inner.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            context
-                .scan_and_register_addresses(addresses, current_daa_score)
-                .await
-                .map_err(|err| PyException::new_err(err.to_string()))?;
-            Ok(())
+            scan_in_batches(
+                &context,
+                addresses,
+                current_daa_score,
+                batch_size,
+                concurrency,
+                progress,
+            )
+            .await
         })
     }
 
@@ -93,7 +241,7 @@ impl PyUtxoContext {
         addresses: Bound<'_, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let addresses = parse_addresses(addresses)?;
-        let context = self.0.clone();
+        let context = self.inner.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             context
@@ -107,7 +255,7 @@ impl PyUtxoContext {
     /// Clear all tracked addresses and UTXOs (async).
     #[gen_stub(override_return_type(type_repr = "None"))]
     fn clear<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let context = self.0.clone();
+        let context = self.inner.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             context
                 .clear()
@@ -118,10 +266,271 @@ impl PyUtxoContext {
         })
     }
 
+    /// Re-fetch the UTXO set of all tracked addresses from the node (async).
+    ///
+    /// Use this after suspected missed notifications (e.g. a dropped connection).
+    /// The context is cleared and rescanned, and the difference against the
+    /// previously known (mature and pending) UTXO set is returned so the
+    /// application can reconcile its own state.
+    ///
+    /// Returns:
+    ///     dict: Dictionary with "added" and "removed" lists of UtxoEntryReference.
+    ///
+    /// Raises:
+    ///     Exception: If the processor is not running or the rescan fails.
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn rescan<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if !self.inner.processor().is_running() {
+            return Err(PyException::new_err("UtxoProcessor is not running"));
+        }
+
+        let context = self.inner.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let diff = rescan_context(&context).await?;
+            Python::attach(|py| diff.into_pydict(py).map(Bound::unbind))
+        })
+    }
+
+    /// Iterate over changes of the UTXO set.
+    ///
+    /// Each batch holds the entries added and removed since the previous
+    /// batch (or since the first iteration started), e.g. to apply them to
+    /// a database with `async for added, removed in context.changes()`.
+    ///
+    /// Returns:
+    ///     UtxoChanges: An async iterator of `(added, removed)` tuples.
+    fn changes(&self) -> PyUtxoChanges {
+        PyUtxoChanges::new(self.clone())
+    }
+
+    /// Capture the context state as a JSON snapshot.
+    ///
+    /// The snapshot contains the tracked addresses, the known (mature and pending)
+    /// UTXO set, the balance, the current DAA score and a SHA-256 integrity
+    /// checksum. It does not change with the context, so it can be archived
+    /// and compared with a later snapshot using `compare_snapshots()`.
+    ///
+    /// Returns:
+    ///     str: The snapshot as a JSON string.
+    fn snapshot(&self) -> PyResult<String> {
+        self.build_snapshot()?.to_json()
+    }
+
+    /// Write a snapshot of the context state to a file.
+    ///
+    /// The file is written to a temporary path first and then renamed, so an
+    /// interrupted write never leaves a truncated snapshot behind.
+    ///
+    /// Args:
+    ///     path: Destination file path.
+    ///
+    /// Raises:
+    ///     Exception: If the snapshot cannot be written.
+    fn save(&self, #[gen_stub(override_type(type_repr = "str"))] path: PathBuf) -> PyResult<()> {
+        let json = self.build_snapshot()?.to_json()?;
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, json).map_err(|err| PyException::new_err(err.to_string()))?;
+        std::fs::rename(&tmp, &path).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(())
+    }
+
+    /// Resume monitoring from a snapshot without a full rescan (async).
+    ///
+    /// Registers the snapshot addresses for UTXO change notifications and
+    /// restores the known UTXO set. The processor must be started. UTXOs spent
+    /// while the service was offline are not detected; use `track_addresses()`
+    /// when an authoritative rescan is required.
+    ///
+    /// Args:
+    ///     path: Snapshot file written by `save()`.
+    ///     snapshot: Snapshot JSON string returned by `snapshot()` (alternative to `path`).
+    ///
+    /// Raises:
+    ///     Exception: If the snapshot is corrupted, or belongs to another network or context id.
+    #[pyo3(signature = (path=None, *, snapshot=None))]
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn resume<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "typing.Optional[str]"))] path: Option<PathBuf>,
+        snapshot: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let json = match (path, snapshot) {
+            (Some(path), None) => std::fs::read_to_string(path)
+                .map_err(|err| PyException::new_err(err.to_string()))?,
+            (None, Some(snapshot)) => snapshot,
+            _ => {
+                return Err(PyException::new_err(
+                    "Exactly one of `path` or `snapshot` must be provided",
+                ));
+            }
+        };
+        let snapshot = ContextSnapshot::from_json(&json)?;
+
+        let network_id = self
+            .inner
+            .processor()
+            .network_id()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        if snapshot.network_id != network_id {
+            return Err(PyException::new_err(format!(
+                "Snapshot network `{}` does not match processor network `{}`",
+                snapshot.network_id, network_id
+            )));
+        }
+        if snapshot.context_id != self.inner.id() {
+            return Err(PyException::new_err(
+                "Snapshot belongs to a different context id; create the UtxoContext with the snapshot's `contextId`",
+            ));
+        }
+
+        let context = self.inner.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            stream::iter(snapshot.addresses.chunks(DEFAULT_SCAN_BATCH_SIZE))
+                .map(|batch| context.register_addresses(batch))
+                .buffer_unordered(DEFAULT_SCAN_CONCURRENCY)
+                .try_collect::<Vec<_>>()
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+
+            let current_daa_score = context
+                .processor()
+                .current_daa_score()
+                .or(snapshot.daa_score)
+                .unwrap_or_default();
+            let utxos = snapshot
+                .utxos
+                .into_iter()
+                .map(UtxoEntryReference::from)
+                .collect();
+            context
+                .extend_from_scan(utxos, current_daa_score)
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            Ok(())
+        })
+    }
+
+    /// Compare two snapshots of the same context, e.g. for audits and reconciliation.
+    ///
+    /// Args:
+    ///     before: The earlier snapshot JSON string returned by `snapshot()`.
+    ///     after: The later snapshot JSON string.
+    ///
+    /// Returns:
+    ///     dict: `added`/`removed` UTXO entries (list of UtxoEntryReference),
+    ///         `addedAddresses`/`removedAddresses` (list of str), `daaScore` (tuple of
+    ///         the before and after DAA scores) and `balance` (tuple of the before and
+    ///         after balances as dicts with `mature`, `pending` and `outgoing` keys,
+    ///         None if not recorded).
+    ///
+    /// Raises:
+    ///     Exception: If a snapshot is corrupted, or the snapshots belong to different networks or contexts.
+    #[staticmethod]
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn compare_snapshots<'py>(
+        py: Python<'py>,
+        before: &str,
+        after: &str,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let before = ContextSnapshot::from_json(before)?;
+        let after = ContextSnapshot::from_json(after)?;
+        if before.network_id != after.network_id || before.context_id != after.context_id {
+            return Err(PyException::new_err(
+                "Snapshots belong to different networks or context ids",
+            ));
+        }
+
+        let addresses = |snapshot: &ContextSnapshot| {
+            snapshot
+                .addresses
+                .iter()
+                .map(Address::to_string)
+                .collect::<AHashSet<_>>()
+        };
+        let (before_addresses, after_addresses) = (addresses(&before), addresses(&after));
+        let mut added_addresses = after_addresses
+            .difference(&before_addresses)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut removed_addresses = before_addresses
+            .difference(&after_addresses)
+            .cloned()
+            .collect::<Vec<_>>();
+        added_addresses.sort();
+        removed_addresses.sort();
+
+        let balance = |snapshot: &ContextSnapshot| {
+            snapshot
+                .balance
+                .map(|balance| {
+                    let dict = PyDict::new(py);
+                    dict.set_item("mature", balance.mature)?;
+                    dict.set_item("pending", balance.pending)?;
+                    dict.set_item("outgoing", balance.outgoing)?;
+                    Ok::<_, PyErr>(dict)
+                })
+                .transpose()
+        };
+        let balances = (balance(&before)?, balance(&after)?);
+        let daa_scores = (before.daa_score, after.daa_score);
+
+        let references = |utxos: Vec<UtxoEntry>| {
+            utxos
+                .into_iter()
+                .map(UtxoEntryReference::from)
+                .collect::<Vec<_>>()
+        };
+        let dict =
+            UtxoDiff::new(references(before.utxos), references(after.utxos)).into_pydict(py)?;
+        dict.set_item("addedAddresses", added_addresses)?;
+        dict.set_item("removedAddresses", removed_addresses)?;
+        dict.set_item("daaScore", daa_scores)?;
+        dict.set_item("balance", balances)?;
+        Ok(dict)
+    }
+
+    /// The context id.
+    ///
+    /// Stable across restarts when the context is created with an explicit `id`
+    /// (kaspa-ng binds account contexts to the account id).
+    #[getter]
+    fn get_id(&self) -> PyHash {
+        PyHash::from(*self.inner.id())
+    }
+
+    /// Export the context identity as JSON.
+    ///
+    /// The descriptor contains the context id, the network id and the tracked
+    /// addresses, and can be used to key external records against the context.
+    ///
+    /// Returns:
+    ///     str: The descriptor as a JSON string.
+    fn descriptor(&self) -> PyResult<String> {
+        let descriptor = ContextDescriptor {
+            id: self.inner.id(),
+            network_id: self.inner.processor().network_id().ok(),
+            addresses: self
+                .inner
+                .addresses()
+                .iter()
+                .map(|address| Address::clone(address.key()))
+                .collect(),
+        };
+        serde_json::to_string(&descriptor).map_err(|err| PyException::new_err(err.to_string()))
+    }
+
+    /// The UtxoProcessor this context is bound to.
+    #[getter]
+    fn get_processor(&self) -> PyUtxoProcessor {
+        self.processor.clone()
+    }
+
     /// Whether the underlying processor is connected and running.
     #[getter]
     fn get_is_active(&self) -> bool {
-        let processor = self.0.processor();
+        let processor = self.inner.processor();
         processor
             .try_rpc_ctl()
             .map(|ctl| ctl.is_connected())
@@ -133,12 +542,12 @@ impl PyUtxoContext {
     /// Number of mature UTXO entries.
     #[getter]
     fn get_mature_length(&self) -> usize {
-        self.0.mature_utxo_size()
+        self.inner.mature_utxo_size()
     }
 
     /// Return a range of mature UTXO entries.
     fn mature_range(&self, mut from_: usize, mut to: usize) -> PyResult<Vec<PyUtxoEntryReference>> {
-        let total = self.0.mature_utxo_size();
+        let total = self.inner.mature_utxo_size();
         if from_ > to {
             return Err(PyException::new_err("'from_' must be <= 'to'"));
         }
@@ -152,7 +561,7 @@ impl PyUtxoContext {
             return Ok(vec![]);
         }
         let entries = futures::executor::block_on(
-            UtxoStream::new(&self.0)
+            UtxoStream::new(&self.inner)
                 .skip(from_)
                 .take(to - from_)
                 .collect::<Vec<_>>(),
@@ -163,13 +572,85 @@ impl PyUtxoContext {
             .collect())
     }
 
+    /// Unconfirmed change of transactions submitted from this context.
+    ///
+    /// Generators created from this context spend these entries first, so
+    /// payouts can be chained without waiting for confirmation. Entries are
+    /// dropped once the node reports them, a submitted transaction spends them or
+    /// is rejected while spending them, or their transaction would have matured.
+    #[getter]
+    fn get_in_flight(&self) -> Vec<PyUtxoEntryReference> {
+        self.in_flight
+            .entries(&self.inner)
+            .into_iter()
+            .map(PyUtxoEntryReference::from)
+            .collect()
+    }
+
+    /// Forget all in-flight change entries (e.g. after a submitted transaction was rejected).
+    fn clear_in_flight(&self) {
+        self.in_flight.clear();
+    }
+
+    /// Outpoints currently locked, either explicitly via `lock()` or by Generators
+    /// created from this context that have not yet submitted or been dropped.
+    #[getter]
+    fn get_locked(&self) -> Vec<PyTransactionOutpoint> {
+        self.locks
+            .outpoints()
+            .into_iter()
+            .map(|id| TransactionOutpoint::new(id.transaction_id, id.index).into())
+            .collect()
+    }
+
+    /// Lock outpoints so Generators created from this context do not spend them.
+    ///
+    /// Generators lock the UTXOs they select automatically and release them once
+    /// the transaction is submitted, when generation fails, or when the Generator
+    /// and its transactions are dropped. Use this to reserve UTXOs manually.
+    ///
+    /// Args:
+    ///     outpoints: Outpoints (or UTXO entries) to lock.
+    ///
+    /// Raises:
+    ///     Exception: If any of the outpoints is already locked (nothing is locked then).
+    fn lock(
+        &self,
+        #[gen_stub(override_type(
+            type_repr = "Sequence[TransactionOutpoint] | Sequence[UtxoEntryReference]"
+        ))]
+        outpoints: Vec<Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        self.locks.lock_all(parse_outpoint_ids(outpoints)?)
+    }
+
+    /// Release locked outpoints.
+    ///
+    /// Args:
+    ///     outpoints: Outpoints (or UTXO entries) to unlock. Releases all locks if omitted,
+    ///         including those held by Generators.
+    #[pyo3(signature = (outpoints=None))]
+    fn unlock(
+        &self,
+        #[gen_stub(override_type(
+            type_repr = "typing.Optional[Sequence[TransactionOutpoint] | Sequence[UtxoEntryReference]]"
+        ))]
+        outpoints: Option<Vec<Bound<'_, PyAny>>>,
+    ) -> PyResult<()> {
+        match outpoints {
+            Some(outpoints) => self.locks.unlock(parse_outpoint_ids(outpoints)?.iter()),
+            None => self.locks.clear(),
+        }
+        Ok(())
+    }
+
     /// Return pending UTXO entries.
     fn pending(&self) -> PyResult<Vec<PyUtxoEntryReference>> {
-        let context_id = self.0.id();
+        let context_id = self.inner.id();
         // Pending entries are stored on the processor; filter by context id to
         // approximate context-local pending until rusty-kaspa exposes a snapshot.
         let entries = self
-            .0
+            .inner
             .processor()
             .pending()
             .iter()
@@ -185,14 +666,14 @@ impl PyUtxoContext {
     /// Current balance for this context (if available).
     #[getter]
     fn get_balance(&self) -> Option<PyBalance> {
-        self.0.balance().map(PyBalance::from)
+        self.inner.balance().map(PyBalance::from)
     }
 
     /// Current balance formatted as strings (if available).
     #[getter]
     fn get_balance_strings(&self) -> PyResult<Option<PyBalanceStrings>> {
-        let network_id = self.0.processor().network_id().ok();
-        let balance = self.0.balance();
+        let network_id = self.inner.processor().network_id().ok();
+        let balance = self.inner.balance();
         if let (Some(network_id), Some(balance)) = (network_id, balance) {
             let balance_strings: BalanceStrings =
                 balance.to_balance_strings(&network_id.network_type, None);
@@ -205,10 +686,166 @@ impl PyUtxoContext {
 
 impl From<PyUtxoContext> for UtxoContext {
     fn from(value: PyUtxoContext) -> Self {
-        value.0
+        value.inner
+    }
+}
+
+/// UTXO entries that appeared or disappeared between two states of a context.
+#[derive(Default)]
+pub(crate) struct UtxoDiff {
+    pub added: Vec<UtxoEntryReference>,
+    pub removed: Vec<UtxoEntryReference>,
+}
+
+impl UtxoDiff {
+    pub fn new(before: Vec<UtxoEntryReference>, after: Vec<UtxoEntryReference>) -> Self {
+        let mut before = before
+            .into_iter()
+            .map(|reference| (reference.id(), reference))
+            .collect::<AHashMap<UtxoEntryId, _>>();
+        let added = after
+            .into_iter()
+            .filter(|reference| before.remove(&reference.id()).is_none())
+            .collect();
+        Self {
+            added,
+            removed: before.into_values().collect(),
+        }
+    }
+
+    pub fn extend(&mut self, other: UtxoDiff) {
+        self.added.extend(other.added);
+        self.removed.extend(other.removed);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// The added and removed entries.
+    pub fn into_lists(self) -> (Vec<PyUtxoEntryReference>, Vec<PyUtxoEntryReference>) {
+        let convert = |entries: Vec<UtxoEntryReference>| {
+            entries
+                .into_iter()
+                .map(PyUtxoEntryReference::from)
+                .collect::<Vec<_>>()
+        };
+        (convert(self.added), convert(self.removed))
+    }
+
+    pub fn into_pydict(self, py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let (added, removed) = self.into_lists();
+        let dict = PyDict::new(py);
+        dict.set_item("added", added)?;
+        dict.set_item("removed", removed)?;
+        Ok(dict)
     }
 }
 
+/// Register and scan addresses in batches, at most `concurrency` batches at a
+/// time, reporting progress to an optional Python callback after each batch.
+async fn scan_in_batches(
+    context: &UtxoContext,
+    addresses: Vec<Address>,
+    current_daa_score: Option<u64>,
+    batch_size: usize,
+    concurrency: usize,
+    progress: Option<Py<PyAny>>,
+) -> PyResult<()> {
+    let total = addresses.len();
+    let mut scanned = 0;
+    let mut batches = stream::iter(addresses.chunks(batch_size))
+        .map(|batch| async move {
+            context
+                .scan_and_register_addresses(batch.to_vec(), current_daa_score)
+                .await
+                .map(|_| batch.len())
+        })
+        .buffer_unordered(concurrency);
+
+    while let Some(result) = batches.next().await {
+        scanned += result.map_err(|err| PyException::new_err(err.to_string()))?;
+        let Some(progress) = &progress else {
+            continue;
+        };
+
+        let context_id = context.id();
+        let pending = context
+            .processor()
+            .pending()
+            .iter()
+            .filter(|entry| entry.value().utxo_context().id() == context_id)
+            .count();
+        let utxos = context.mature_utxo_size() + pending;
+
+        Python::attach(|py| {
+            let event = PyDict::new(py);
+            event.set_item("scanned", scanned)?;
+            event.set_item("total", total)?;
+            event.set_item("utxos", utxos)?;
+            progress.call1(py, (event,))?;
+            Ok::<_, PyErr>(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Mature and pending UTXO entries known to the context.
+pub(crate) async fn context_utxos(context: &UtxoContext) -> Vec<UtxoEntryReference> {
+    let mut utxos = UtxoStream::new(context).collect::<Vec<_>>().await;
+    let context_id = context.id();
+    utxos.extend(context.processor().pending().iter().filter_map(|pending| {
+        let entry = pending.value();
+        (entry.utxo_context().id() == context_id).then(|| entry.entry().clone())
+    }));
+    utxos
+}
+
+/// UTXO entries given as a UtxoContext (its mature and pending entries) or a
+/// list of UtxoEntryReference-compatible items.
+pub(crate) fn extract_entries(entries: &Bound<'_, PyAny>) -> PyResult<Vec<UtxoEntryReference>> {
+    if let Ok(context) = entries.extract::<PyUtxoContext>() {
+        Ok(futures::executor::block_on(context_utxos(context.inner())))
+    } else if let Ok(entries) = entries.extract::<PyUtxoEntries>() {
+        Ok(entries.entries)
+    } else {
+        Err(PyException::new_err(
+            "entries must be a UtxoContext or list of UtxoEntryReference-compatible items",
+        ))
+    }
+}
+
+/// Clear the context and rescan all of its tracked addresses, returning the UTXO diff.
+pub(crate) async fn rescan_context(context: &UtxoContext) -> PyResult<UtxoDiff> {
+    let before = context_utxos(context).await;
+    let addresses = context
+        .addresses()
+        .iter()
+        .map(|address| Address::clone(address.key()))
+        .collect::<Vec<_>>();
+
+    context
+        .clear()
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    purge_processor_pending(context);
+
+    if !addresses.is_empty() {
+        let current_daa_score = context.processor().current_daa_score();
+        scan_in_batches(
+            context,
+            addresses,
+            current_daa_score,
+            DEFAULT_SCAN_BATCH_SIZE,
+            DEFAULT_SCAN_CONCURRENCY,
+            None,
+        )
+        .await?;
+    }
+
+    Ok(UtxoDiff::new(before, context_utxos(context).await))
+}
+
 fn purge_processor_pending(context: &UtxoContext) {
     // Keep pending() consistent after clear(): purge processor-wide pending
     // entries for this context since pending is not context-owned in RK.
@@ -224,7 +861,7 @@ fn purge_processor_pending(context: &UtxoContext) {
     }
 }
 
-fn parse_addresses(value: Bound<'_, PyAny>) -> PyResult<Vec<Address>> {
+pub(crate) fn parse_addresses(value: Bound<'_, PyAny>) -> PyResult<Vec<Address>> {
     value
         .try_iter()
         .map_err(|_| PyException::new_err("addresses must be an iterable of Address or str"))?
@@ -242,3 +879,20 @@ fn parse_addresses(value: Bound<'_, PyAny>) -> PyResult<Vec<Address>> {
         })
         .collect()
 }
+
+fn parse_outpoint_ids(items: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<UtxoEntryId>> {
+    items
+        .into_iter()
+        .map(|item| {
+            if let Ok(outpoint) = item.extract::<PyTransactionOutpoint>() {
+                Ok(TransactionOutpoint::from(outpoint).inner().clone())
+            } else if let Ok(entry) = item.extract::<PyUtxoEntryReference>() {
+                Ok(UtxoEntryReference::from(entry).id())
+            } else {
+                Err(PyException::new_err(
+                    "outpoints must be a list of TransactionOutpoint or UtxoEntryReference",
+                ))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,274 @@
+use crate::wallet::core::utxo::reorg::{RecordKey, record_key};
+use ahash::AHashMap;
+use kaspa_consensus_core::tx::TransactionId;
+use kaspa_wallet_core::events::Events;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use workflow_log::*;
+
+/// Transaction events are remembered for this many DAA scores past the DAA
+/// score of their transaction (about a day at 10 blocks per second).
+pub const DEDUP_WINDOW_DAA: u64 = 864_000;
+
+/// Transaction event kinds a `reorg` invalidates, forgotten when it is emitted.
+const RECORD_KINDS: [&str; 4] = ["pending", "maturity", "discovery", "stasis"];
+
+/// A seen transaction event: its record key and event kind.
+type SeenKey = (RecordKey, &'static str);
+
+#[derive(Default)]
+struct DedupState {
+    /// Seen events, with the DAA score of their transaction.
+    seen: AHashMap<SeenKey, u64>,
+    /// Highest DAA score seen, and the one the seen-set was last pruned at.
+    newest: u64,
+    pruned: u64,
+    /// The persistence log and its path.
+    log: Option<(PathBuf, File)>,
+}
+
+impl DedupState {
+    fn admit(&mut self, key: RecordKey, kind: &'static str, daa_score: u64) -> bool {
+        let seen = (key, kind);
+        if self.seen.contains_key(&seen) {
+            return false;
+        }
+
+        let mut lines = vec![format!("+{} {daa_score}", log_key(&seen))];
+        let forget: &[&'static str] = match kind {
+            "reorg" => &RECORD_KINDS,
+            // A matured transaction is not reported as pending again.
+            "maturity" => &["reorg", "pending", "stasis"],
+            _ => &["reorg"],
+        };
+        for other in forget {
+            let other = (seen.0.clone(), *other);
+            if self.seen.remove(&other).is_some() {
+                lines.push(format!("-{}", log_key(&other)));
+            }
+        }
+        self.seen.insert(seen, daa_score);
+        self.newest = self.newest.max(daa_score);
+
+        if let Some((path, log)) = self.log.as_mut()
+            && let Err(err) = lines.iter().try_for_each(|line| writeln!(log, "{line}"))
+        {
+            log_error!(
+                "UtxoProcessor: failed to persist seen events to {}: {}",
+                path.display(),
+                err
+            );
+        }
+        if self.newest >= self.pruned + DEDUP_WINDOW_DAA / 16 {
+            self.prune();
+        }
+        true
+    }
+
+    /// Forget the events of transactions older than the window, and compact
+    /// the log to the events kept.
+    fn prune(&mut self) {
+        self.forget_expired();
+        if let Some((path, log)) = self.log.as_mut() {
+            match compact(path, &self.seen) {
+                Ok(compacted) => *log = compacted,
+                Err(err) => log_error!(
+                    "UtxoProcessor: failed to compact seen events in {}: {}",
+                    path.display(),
+                    err
+                ),
+            }
+        }
+    }
+
+    fn forget_expired(&mut self) {
+        let oldest = self.newest.saturating_sub(DEDUP_WINDOW_DAA);
+        self.seen.retain(|_, daa_score| *daa_score >= oldest);
+        self.pruned = self.newest;
+    }
+}
+
+/// Suppresses repeated transaction events, so each (context, transaction,
+/// event kind) is emitted at most once, including across the rescans that
+/// follow reconnects.
+///
+/// A `reorg` forgets the other events of its transaction, so a transaction
+/// included again after a reorg is reported again; emitting any of those
+/// events in turn forgets the `reorg`, and a `maturity` forgets the `pending`
+/// and `stasis` events it supersedes. Events of transactions more than
+/// [`DEDUP_WINDOW_DAA`] below the newest DAA score seen are forgotten.
+///
+/// With a path, the seen-set is kept in an append-only log of `+key daa` /
+/// `-key` lines, compacted when it is loaded and pruned.
+pub(crate) struct EventDeduplicator {
+    state: Mutex<DedupState>,
+    suppressed: AtomicU64,
+}
+
+impl EventDeduplicator {
+    pub fn new(path: Option<PathBuf>) -> std::io::Result<Self> {
+        let mut state = DedupState::default();
+        if let Some(path) = path {
+            state.seen = load(&path)?;
+            state.newest = state.seen.values().copied().max().unwrap_or_default();
+            state.forget_expired();
+            let log = compact(&path, &state.seen)?;
+            state.log = Some((path, log));
+        }
+        Ok(Self {
+            state: Mutex::new(state),
+            suppressed: AtomicU64::new(0),
+        })
+    }
+
+    /// Whether the event should be emitted. Events other than transaction
+    /// record events are always emitted.
+    pub fn admit(&self, event: &Events) -> bool {
+        let (kind, record) = match event {
+            Events::Pending { record } => ("pending", record),
+            Events::Maturity { record } => ("maturity", record),
+            Events::Discovery { record } => ("discovery", record),
+            Events::Stasis { record } => ("stasis", record),
+            Events::Reorg { record } => ("reorg", record),
+            _ => return true,
+        };
+        let Some(key) = record_key(record) else {
+            return true;
+        };
+
+        let admitted = self
+            .state
+            .lock()
+            .unwrap()
+            .admit(key, kind, record.block_daa_score());
+        if !admitted {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+        }
+        admitted
+    }
+
+    /// Number of repeated events suppressed.
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+}
+
+/// A seen event as written to the log: `<binding hex>:<transaction id> <kind>`.
+fn log_key(((binding, id), kind): &SeenKey) -> String {
+    format!("{}:{id} {kind}", faster_hex::hex_string(binding))
+}
+
+/// Parse a log key written by [`log_key`].
+fn parse_log_key(line: &str) -> Option<SeenKey> {
+    let (record, kind) = line.split_once(' ')?;
+    let (binding, id) = record.split_once(':')?;
+    let mut bytes = vec![0u8; binding.len() / 2];
+    faster_hex::hex_decode(binding.as_bytes(), &mut bytes).ok()?;
+    let id = TransactionId::from_str(id).ok()?;
+    let kind = RECORD_KINDS
+        .into_iter()
+        .chain(["reorg"])
+        .find(|known| *known == kind)?;
+    Some(((bytes, id), kind))
+}
+
+/// The seen-set recorded in a log, empty if the file does not exist. Lines
+/// that cannot be parsed are skipped.
+fn load(path: &Path) -> std::io::Result<AHashMap<SeenKey, u64>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(AHashMap::new()),
+        Err(err) => return Err(err),
+    };
+    let mut seen = AHashMap::new();
+    for line in content.lines() {
+        if let Some(entry) = line.strip_prefix('+') {
+            let Some((key, daa_score)) = entry.rsplit_once(' ') else {
+                continue;
+            };
+            if let (Some(key), Ok(daa_score)) = (parse_log_key(key), daa_score.parse()) {
+                seen.insert(key, daa_score);
+            }
+        } else if let Some(key) = line.strip_prefix('-').and_then(parse_log_key) {
+            seen.remove(&key);
+        }
+    }
+    Ok(seen)
+}
+
+/// Rewrite the log with the seen-set only (through a temporary file, so a
+/// crash cannot lose it) and open it for appending.
+fn compact(path: &Path, seen: &AHashMap<SeenKey, u64>) -> std::io::Result<File> {
+    let tmp = path.with_extension("tmp");
+    let mut content = String::new();
+    for (key, daa_score) in seen {
+        content.push_str(&format!("+{} {daa_score}\n", log_key(key)));
+    }
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)?;
+    OpenOptions::new().append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(context: u8, transaction: u64) -> RecordKey {
+        (vec![context], TransactionId::from_u64_word(transaction))
+    }
+
+    #[test]
+    fn repeated_events_are_suppressed() {
+        let mut state = DedupState::default();
+        assert!(state.admit(key(1, 1), "pending", 100));
+        assert!(!state.admit(key(1, 1), "pending", 100));
+        // Another context or event kind of the same transaction is emitted.
+        assert!(state.admit(key(2, 1), "pending", 100));
+        assert!(state.admit(key(1, 1), "maturity", 100));
+    }
+
+    #[test]
+    fn reorg_resets_the_transaction() {
+        let mut state = DedupState::default();
+        assert!(state.admit(key(1, 1), "discovery", 100));
+        assert!(state.admit(key(1, 1), "reorg", 100));
+        assert!(!state.admit(key(1, 1), "reorg", 100));
+        assert!(state.admit(key(1, 1), "discovery", 100));
+        assert!(state.admit(key(1, 1), "reorg", 100));
+    }
+
+    #[test]
+    fn maturity_forgets_pending() {
+        let mut state = DedupState::default();
+        assert!(state.admit(key(1, 1), "pending", 100));
+        assert!(state.admit(key(1, 1), "maturity", 100));
+        assert_eq!(state.seen.len(), 1);
+    }
+
+    #[test]
+    fn old_transactions_are_pruned() {
+        let mut state = DedupState::default();
+        assert!(state.admit(key(1, 1), "discovery", 100));
+        assert!(state.admit(key(1, 2), "discovery", 100 + DEDUP_WINDOW_DAA));
+        // The window is pruned every sixteenth of it.
+        let later = 101 + DEDUP_WINDOW_DAA + DEDUP_WINDOW_DAA / 16;
+        assert!(state.admit(key(1, 3), "discovery", later));
+
+        assert_eq!(state.seen.len(), 2);
+        assert!(!state.admit(key(1, 2), "discovery", 100 + DEDUP_WINDOW_DAA));
+    }
+
+    #[test]
+    fn log_keys_round_trip() {
+        let seen = (
+            (vec![0, 1, 0xab], TransactionId::from_u64_word(7)),
+            "stasis",
+        );
+        assert_eq!(parse_log_key(&log_key(&seen)), Some(seen));
+        assert_eq!(parse_log_key("00:zz pending"), None);
+    }
+}
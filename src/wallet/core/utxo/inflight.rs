@@ -0,0 +1,170 @@
+use ahash::{AHashMap, AHashSet};
+use kaspa_consensus_client::{UtxoEntryId, UtxoEntryReference};
+use kaspa_consensus_core::tx::TransactionOutpoint;
+use kaspa_wallet_core::utxo::{UtxoContext, user_transaction_maturity_period_daa};
+use std::sync::Mutex;
+
+/// Unconfirmed change outputs of transactions submitted from a `UtxoContext`.
+///
+/// The context only learns about change once the node reports it, so these
+/// entries are offered to the next Generator as priority inputs, allowing
+/// payouts to be chained without waiting for confirmation.
+#[derive(Default)]
+pub(crate) struct InFlightEntries {
+    entries: Mutex<AHashMap<UtxoEntryId, InFlightEntry>>,
+}
+
+struct InFlightEntry {
+    entry: UtxoEntryReference,
+    /// DAA score by which the transaction has matured if it was accepted.
+    expires_at: Option<u64>,
+}
+
+impl InFlightEntries {
+    /// Entries that are still unknown to the context.
+    pub fn entries(&self, context: &UtxoContext) -> Vec<UtxoEntryReference> {
+        let processor = context.processor();
+        let pending = processor.pending();
+        // The node has reported the change, the context tracks it from now on.
+        self.retain(
+            |id| !pending.contains_key(id),
+            processor.current_daa_score(),
+        )
+    }
+
+    /// Keep the entries not reported yet and not expired at `current_daa_score`.
+    fn retain(
+        &self,
+        unreported: impl Fn(&UtxoEntryId) -> bool,
+        current_daa_score: Option<u64>,
+    ) -> Vec<UtxoEntryReference> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|id, in_flight| {
+            let expired = matches!(
+                (in_flight.expires_at, current_daa_score),
+                (Some(expires_at), Some(current)) if current >= expires_at
+            );
+            unreported(id) && !expired
+        });
+        entries
+            .values()
+            .map(|in_flight| in_flight.entry.clone())
+            .collect()
+    }
+
+    /// Record a submitted transaction: forget the entries it spends and track its change.
+    ///
+    /// The change expires at `expires_at` (see `expiry()`).
+    pub fn submitted<'a>(
+        &self,
+        spent: impl Iterator<Item = &'a TransactionOutpoint>,
+        change: Option<UtxoEntryReference>,
+        expires_at: Option<u64>,
+    ) {
+        let mut entries = self.entries.lock().unwrap();
+        forget(&mut entries, spent);
+        if let Some(change) = change {
+            entries.insert(
+                change.id(),
+                InFlightEntry {
+                    entry: change,
+                    expires_at,
+                },
+            );
+        }
+    }
+
+    /// Record a rejected transaction: forget the entries it spends, their own
+    /// transaction may have been rejected as well.
+    pub fn rejected<'a>(&self, spent: impl Iterator<Item = &'a TransactionOutpoint>) {
+        forget(&mut self.entries.lock().unwrap(), spent);
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// The DAA score at which change submitted now expires: by then its
+/// transaction has matured and the context tracks the change as mature, or
+/// the transaction was not accepted.
+pub(crate) fn expiry(context: &UtxoContext) -> Option<u64> {
+    let processor = context.processor();
+    let network_id = processor.network_id().ok()?;
+    let current_daa_score = processor.current_daa_score()?;
+    Some(current_daa_score.saturating_add(user_transaction_maturity_period_daa(&network_id)))
+}
+
+fn forget<'a>(
+    entries: &mut AHashMap<UtxoEntryId, InFlightEntry>,
+    spent: impl Iterator<Item = &'a TransactionOutpoint>,
+) {
+    let spent = spent
+        .map(|outpoint| (outpoint.transaction_id, outpoint.index))
+        .collect::<AHashSet<_>>();
+    entries.retain(|id, _| !spent.contains(&(id.transaction_id, id.index)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_consensus_client::{TransactionOutpoint as ClientOutpoint, UtxoEntry};
+    use kaspa_consensus_core::tx::{ScriptPublicKey, TransactionId};
+
+    fn change(transaction: u64) -> UtxoEntryReference {
+        UtxoEntry {
+            address: None,
+            outpoint: ClientOutpoint::new(TransactionId::from_u64_word(transaction), 0),
+            amount: 1000,
+            script_public_key: ScriptPublicKey::from_vec(0, vec![]),
+            block_daa_score: u64::MAX,
+            is_coinbase: false,
+        }
+        .into()
+    }
+
+    fn outpoint(transaction: u64) -> TransactionOutpoint {
+        TransactionOutpoint::new(TransactionId::from_u64_word(transaction), 0)
+    }
+
+    #[test]
+    fn change_expires_once_matured() {
+        let in_flight = InFlightEntries::default();
+        in_flight.submitted(std::iter::empty(), Some(change(1)), Some(100));
+
+        assert_eq!(in_flight.retain(|_| true, Some(99)).len(), 1);
+        assert_eq!(in_flight.retain(|_| true, Some(100)).len(), 0);
+        // Gone for good, even if the DAA score is unknown afterwards.
+        assert_eq!(in_flight.retain(|_| true, None).len(), 0);
+    }
+
+    #[test]
+    fn change_without_expiry_is_kept_until_reported() {
+        let in_flight = InFlightEntries::default();
+        in_flight.submitted(std::iter::empty(), Some(change(1)), None);
+
+        assert_eq!(in_flight.retain(|_| true, Some(u64::MAX)).len(), 1);
+        assert_eq!(in_flight.retain(|_| false, Some(0)).len(), 0);
+    }
+
+    #[test]
+    fn spending_transactions_forget_the_change() {
+        let in_flight = InFlightEntries::default();
+        in_flight.submitted(std::iter::empty(), Some(change(1)), None);
+        in_flight.submitted(std::iter::empty(), Some(change(2)), None);
+
+        // A chained transaction spends the change of the first one.
+        in_flight.submitted([outpoint(1)].iter(), Some(change(3)), None);
+        let remaining = in_flight
+            .retain(|_| true, None)
+            .iter()
+            .map(|entry| entry.id().transaction_id)
+            .collect::<AHashSet<_>>();
+        let expected = [2, 3].map(TransactionId::from_u64_word);
+        assert_eq!(remaining, AHashSet::from_iter(expected));
+
+        // A rejected transaction drops the change it tried to spend.
+        in_flight.rejected([outpoint(2)].iter());
+        assert_eq!(in_flight.retain(|_| true, None).len(), 1);
+    }
+}
@@ -0,0 +1,113 @@
+use ahash::AHashSet;
+use kaspa_consensus_client::UtxoEntryId;
+use pyo3::{exceptions::PyException, prelude::*};
+use std::sync::{Arc, Mutex};
+
+/// Outpoints of a `UtxoContext` reserved by concurrent transaction builders.
+///
+/// Generators created from the context skip locked outpoints, so two workers
+/// spending from the same context never select the same UTXO.
+#[derive(Default)]
+pub(crate) struct OutpointLocks {
+    outpoints: Mutex<AHashSet<UtxoEntryId>>,
+}
+
+impl OutpointLocks {
+    pub fn is_locked(&self, id: &UtxoEntryId) -> bool {
+        self.outpoints.lock().unwrap().contains(id)
+    }
+
+    /// Lock a single outpoint. Returns `false` if it is already locked.
+    pub fn try_lock(&self, id: UtxoEntryId) -> bool {
+        self.outpoints.lock().unwrap().insert(id)
+    }
+
+    /// Lock all outpoints or none of them.
+    pub fn lock_all(&self, ids: Vec<UtxoEntryId>) -> PyResult<()> {
+        let mut outpoints = self.outpoints.lock().unwrap();
+        if let Some(id) = ids.iter().find(|id| outpoints.contains(id)) {
+            return Err(PyException::new_err(format!(
+                "Outpoint {}:{} is already locked",
+                id.transaction_id, id.index
+            )));
+        }
+        outpoints.extend(ids);
+        Ok(())
+    }
+
+    pub fn unlock<'a>(&self, ids: impl Iterator<Item = &'a UtxoEntryId>) {
+        let mut outpoints = self.outpoints.lock().unwrap();
+        for id in ids {
+            outpoints.remove(id);
+        }
+    }
+
+    pub fn clear(&self) {
+        self.outpoints.lock().unwrap().clear();
+    }
+
+    pub fn outpoints(&self) -> Vec<UtxoEntryId> {
+        self.outpoints.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// Outpoints locked by a single Generator and the transactions it yields.
+///
+/// Whatever is still held is released when the reservation is dropped,
+/// i.e. once the Generator and all of its pending transactions are gone.
+pub(crate) struct Reservation {
+    locks: Arc<OutpointLocks>,
+    held: Mutex<AHashSet<UtxoEntryId>>,
+}
+
+impl Reservation {
+    pub fn new(locks: Arc<OutpointLocks>) -> Self {
+        Self {
+            locks,
+            held: Mutex::new(AHashSet::new()),
+        }
+    }
+
+    pub fn is_locked(&self, id: &UtxoEntryId) -> bool {
+        self.locks.is_locked(id)
+    }
+
+    /// Reserve an outpoint for this Generator. Returns `false` if it is locked elsewhere.
+    pub fn try_reserve(&self, id: UtxoEntryId) -> bool {
+        if self.locks.try_lock(id.clone()) {
+            self.held.lock().unwrap().insert(id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reserve all outpoints not already held by this Generator, or none of them.
+    pub fn reserve_all(&self, ids: Vec<UtxoEntryId>) -> PyResult<()> {
+        let mut held = self.held.lock().unwrap();
+        let ids = ids
+            .into_iter()
+            .filter(|id| !held.contains(id))
+            .collect::<Vec<_>>();
+        self.locks.lock_all(ids.clone())?;
+        held.extend(ids);
+        Ok(())
+    }
+
+    pub fn release<'a>(&self, ids: impl Iterator<Item = &'a UtxoEntryId>) {
+        let mut held = self.held.lock().unwrap();
+        let released = ids.filter(|id| held.remove(*id)).collect::<Vec<_>>();
+        self.locks.unlock(released.into_iter());
+    }
+
+    pub fn release_all(&self) {
+        let held = std::mem::take(&mut *self.held.lock().unwrap());
+        self.locks.unlock(held.iter());
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.release_all();
+    }
+}
@@ -1,3 +1,11 @@
 pub mod balance;
+pub mod changes;
 pub mod context;
+pub mod dedup;
+pub mod inflight;
+pub mod locks;
 pub mod processor;
+pub mod queue;
+pub mod reorg;
+pub mod snapshot;
+pub mod stats;
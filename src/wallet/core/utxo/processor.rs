@@ -1,31 +1,47 @@
 use crate::callback::PyCallback;
 use crate::consensus::core::network::PyNetworkId;
+use crate::crypto::hashes::PyHash;
 use crate::rpc::wrpc::client::PyRpcClient;
+use crate::wallet::core::utxo::context::{
+    PyUtxoContext, UtxoDiff, parse_addresses, rescan_context,
+};
+use crate::wallet::core::utxo::dedup::EventDeduplicator;
+use crate::wallet::core::utxo::queue::{
+    DEFAULT_EVENT_QUEUE_CAPACITY, EventQueue, PyEventQueuePolicy,
+};
+use crate::wallet::core::utxo::reorg::{DEFAULT_REORG_TRACKER_CAPACITY, ReorgTracker};
 use ahash::AHashMap;
 use futures::*;
-use kaspa_wallet_core::events::EventKind;
+use kaspa_hashes::Hash;
+use kaspa_wallet_core::events::{EventKind, Events};
 use kaspa_wallet_core::rpc::{DynRpcApi, Rpc};
 use kaspa_wallet_core::utxo::{
-    UtxoProcessor, set_coinbase_transaction_maturity_period_daa,
-    set_user_transaction_maturity_period_daa,
+    UtxoContext, UtxoContextId, UtxoProcessor, coinbase_transaction_maturity_period_daa,
+    set_coinbase_transaction_maturity_period_daa, set_user_transaction_maturity_period_daa,
+    user_transaction_maturity_period_daa,
 };
 use pyo3::{
     exceptions::PyException,
     prelude::*,
-    types::{PyDict, PyTuple},
+    types::{PyCFunction, PyDict, PyTuple},
 };
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pymethods};
 use serde::{Deserialize, Serialize};
 use std::{
+    path::PathBuf,
     str::FromStr,
     sync::{
-        Arc, Mutex,
+        Arc, Mutex, Weak,
         atomic::{AtomicBool, Ordering},
     },
+    time::Duration,
 };
-use workflow_core::channel::DuplexChannel;
+use workflow_core::{channel::DuplexChannel, task};
 use workflow_log::*;
 
+/// Upper bound on the time spent stopping processors from the `atexit` hook.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Event types for `UtxoProcessor` listeners.
 #[gen_stub_pyclass_enum]
 #[pyclass(name = "UtxoProcessorEvent", skip_from_py_object, eq)]
@@ -92,21 +108,179 @@ impl From<PyUtxoProcessorEvent> for EventKind {
     }
 }
 
+/// Optional per-listener filter applied in Rust before a Python callback is invoked.
+///
+/// Values are kept in their serialized (JSON) form so they can be compared
+/// directly against the serialized event payload.
+#[derive(Default)]
+struct EventFilter {
+    addresses: Option<Vec<serde_json::Value>>,
+    contexts: Option<Vec<serde_json::Value>>,
+}
+
+impl EventFilter {
+    fn is_empty(&self) -> bool {
+        self.addresses.is_none() && self.contexts.is_none()
+    }
+
+    /// Events that carry no address (or context) information are not
+    /// filtered on that criterion, e.g. `connect` or `daa-score-change`.
+    fn matches(&self, event_type: EventKind, event: &serde_json::Value) -> bool {
+        let data = event.get("data").unwrap_or(&serde_json::Value::Null);
+
+        if let Some(addresses) = &self.addresses {
+            let mut found = vec![];
+            collect_values_for_key(data, "address", &mut found);
+            if !found.is_empty() && !found.iter().any(|value| addresses.contains(value)) {
+                return false;
+            }
+        }
+
+        if let Some(contexts) = &self.contexts {
+            let mut found = vec![];
+            match event_type {
+                EventKind::Balance => collect_values_for_key(data, "id", &mut found),
+                EventKind::Pending
+                | EventKind::Reorg
+                | EventKind::Stasis
+                | EventKind::Maturity
+                | EventKind::Discovery => collect_values_for_key(data, "binding", &mut found),
+                _ => {}
+            }
+            if !found.is_empty() && !found.iter().any(|value| contexts.contains(value)) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// A registered event listener.
+#[derive(Clone)]
+struct Listener {
+    callback: PyCallback,
+    filter: Option<Arc<EventFilter>>,
+}
+
+type ListenerMap = Mutex<AHashMap<EventKind, Vec<Listener>>>;
+
+/// Live processors, stopped by the `atexit` hook registered in [`register_shutdown_hook`].
+static LIVE_PROCESSORS: Mutex<Vec<Weak<ProcessorGuard>>> = Mutex::new(Vec::new());
+
+/// Stops the processor and its notification task once the last Python
+/// reference to the `UtxoProcessor` is dropped.
+///
+/// Background tasks only hold the pieces they need (never the guard), so
+/// the guard is released together with the Python object.
+struct ProcessorGuard {
+    processor: UtxoProcessor,
+    notification_task: Arc<AtomicBool>,
+    notification_ctl: DuplexChannel,
+}
+
+impl ProcessorGuard {
+    fn is_idle(&self) -> bool {
+        !self.processor.is_running() && !self.notification_task.load(Ordering::SeqCst)
+    }
+
+    fn shutdown(&self) -> impl Future<Output = ()> + Send + 'static {
+        let processor = self.processor.clone();
+        let notification_task = self.notification_task.clone();
+        let notification_ctl = self.notification_ctl.clone();
+        async move {
+            if processor.is_running()
+                && let Err(err) = processor.stop().await
+            {
+                log_error!("UtxoProcessor: error while stopping processor: {err}");
+            }
+            stop_notification_task(&notification_task, &notification_ctl)
+                .await
+                .ok();
+        }
+    }
+}
+
+impl Drop for ProcessorGuard {
+    fn drop(&mut self) {
+        if !self.is_idle() {
+            pyo3_async_runtimes::tokio::get_runtime().spawn(self.shutdown());
+        }
+    }
+}
+
+async fn stop_notification_task(
+    notification_task: &AtomicBool,
+    notification_ctl: &DuplexChannel,
+) -> std::result::Result<(), workflow_core::channel::ChannelError<()>> {
+    if notification_task.load(Ordering::SeqCst) {
+        notification_ctl.signal(()).await?;
+        notification_task.store(false, Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+/// Register an `atexit` hook stopping every live `UtxoProcessor`, so
+/// background tasks and node subscriptions do not outlive the interpreter.
+pub fn register_shutdown_hook(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    let hook = PyCFunction::new_closure(
+        py,
+        None,
+        None,
+        |args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| -> PyResult<()> {
+            let guards = LIVE_PROCESSORS
+                .lock()
+                .unwrap()
+                .drain(..)
+                .filter_map(|guard| guard.upgrade())
+                .collect::<Vec<_>>();
+            if guards.is_empty() {
+                return Ok(());
+            }
+
+            args.py().detach(|| {
+                let shutdown = future::join_all(guards.iter().map(|guard| guard.shutdown()));
+                pyo3_async_runtimes::tokio::get_runtime().block_on(async move {
+                    select_biased! {
+                        _ = shutdown.fuse() => {}
+                        _ = task::sleep(SHUTDOWN_TIMEOUT).fuse() => {
+                            log_warn!("UtxoProcessor: timed out while stopping processors at exit");
+                        }
+                    }
+                });
+            });
+            Ok(())
+        },
+    )?;
+    py.import("atexit")?.call_method1("register", (hook,))?;
+    Ok(())
+}
+
+/// Live `UtxoContext` instances bound to a processor, keyed by context id.
+pub(crate) type ContextRegistry = Mutex<AHashMap<UtxoContextId, UtxoContext>>;
+
 /// UTXO processor coordinating address tracking and UTXO updates.
 #[gen_stub_pyclass]
 #[pyclass(name = "UtxoProcessor")]
 #[derive(Clone)]
 pub struct PyUtxoProcessor {
-    processor: UtxoProcessor,
+    guard: Arc<ProcessorGuard>,
     rpc: PyRpcClient,
-    callbacks: Arc<Mutex<AHashMap<EventKind, Vec<PyCallback>>>>,
-    notification_task: Arc<AtomicBool>,
-    notification_ctl: DuplexChannel,
+    callbacks: Arc<ListenerMap>,
+    event_queue: Arc<EventQueue>,
+    reorg_tracker: Arc<ReorgTracker>,
+    dedup: Option<Arc<EventDeduplicator>>,
+    contexts: Arc<ContextRegistry>,
 }
 
 impl PyUtxoProcessor {
     pub fn inner(&self) -> &UtxoProcessor {
-        &self.processor
+        &self.guard.processor
+    }
+
+    pub(crate) fn contexts(&self) -> &Arc<ContextRegistry> {
+        &self.contexts
     }
 
     fn normalize_event_payload(
@@ -142,8 +316,8 @@ impl PyUtxoProcessor {
         Ok(())
     }
 
-    fn notification_callbacks(&self, event: EventKind) -> Option<Vec<PyCallback>> {
-        let notification_callbacks = self.callbacks.lock().unwrap();
+    fn notification_callbacks(callbacks: &ListenerMap, event: EventKind) -> Option<Vec<Listener>> {
+        let notification_callbacks = callbacks.lock().unwrap();
         let all = notification_callbacks.get(&EventKind::All).cloned();
         let target = notification_callbacks.get(&event).cloned();
         match (all, target) {
@@ -157,8 +331,97 @@ impl PyUtxoProcessor {
         }
     }
 
+    fn dispatch_event(
+        callbacks: &ListenerMap,
+        reorg_tracker: &ReorgTracker,
+        dedup: Option<&EventDeduplicator>,
+        notification: &Events,
+    ) {
+        if let Some(dedup) = dedup
+            && !dedup.admit(notification)
+        {
+            return;
+        }
+
+        let event_type = EventKind::from(notification);
+        let affected = reorg_tracker.observe(notification);
+        let Some(handlers) = Self::notification_callbacks(callbacks, event_type) else {
+            return;
+        };
+
+        // Serialize once (without the GIL) only if a listener is filtered.
+        let filter_payload = handlers
+            .iter()
+            .any(|handler| handler.filter.is_some())
+            .then(|| serde_json::to_value(notification).ok())
+            .flatten();
+
+        for handler in handlers.into_iter() {
+            if let (Some(filter), Some(payload)) = (&handler.filter, &filter_payload)
+                && !filter.matches(event_type, payload)
+            {
+                continue;
+            }
+
+            if let Err(err) = Python::attach(|py| -> PyResult<()> {
+                let event_any = match serde_pyobject::to_pyobject(py, notification) {
+                    Ok(obj) => obj,
+                    Err(err) => {
+                        log_error!(
+                            "UtxoProcessor: failed to serialize event `{}`: {}",
+                            event_type,
+                            err
+                        );
+                        return Ok(());
+                    }
+                };
+
+                let event = match event_any.cast::<PyDict>() {
+                    Ok(dict) => dict,
+                    Err(err) => {
+                        log_error!(
+                            "UtxoProcessor: serialized event `{}` is not a dict: {}",
+                            event_type,
+                            err
+                        );
+                        return Ok(());
+                    }
+                };
+
+                if let Err(err) = Self::normalize_event_payload(py, event_type, event) {
+                    log_error!(
+                        "UtxoProcessor: failed to normalize event payload for `{}`: {}",
+                        event_type,
+                        err
+                    );
+                }
+
+                if let Some(affected) = &affected {
+                    event.set_item("affected", serde_pyobject::to_pyobject(py, affected)?)?;
+                }
+
+                if let Err(err) = handler.callback.execute(py, (*event).clone()) {
+                    log_error!(
+                        "UtxoProcessor: error while executing event listener for `{}`: {}",
+                        event_type,
+                        err
+                    );
+                }
+
+                Ok(())
+            }) {
+                log_error!(
+                    "UtxoProcessor: error while building event payload for `{}`: {}",
+                    event_type,
+                    err
+                );
+            }
+        }
+    }
+
     fn start_notification_task(&self, py: Python) -> PyResult<bool> {
         if self
+            .guard
             .notification_task
             .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
             .is_err()
@@ -166,93 +429,65 @@ impl PyUtxoProcessor {
             return Ok(false);
         }
 
-        let ctl_receiver = self.notification_ctl.request.receiver.clone();
-        let ctl_sender = self.notification_ctl.response.sender.clone();
-        let channel = self.processor.multiplexer().channel();
-        let this = self.clone();
+        let ctl_receiver = self.guard.notification_ctl.request.receiver.clone();
+        let ctl_sender = self.guard.notification_ctl.response.sender.clone();
+        let channel = self.guard.processor.multiplexer().channel();
+        let queue = self.event_queue.clone();
+        let notification_task = self.guard.notification_task.clone();
+        let callbacks = self.callbacks.clone();
+        let reorg_tracker = self.reorg_tracker.clone();
+        let dedup = self.dedup.clone();
 
-        let fut = async move {
-            let mut shutdown_requested = false;
-            loop {
-                if shutdown_requested && channel.receiver.is_empty() {
-                    break;
-                }
+        queue.open();
 
-                select_biased! {
-                    _ = ctl_receiver.recv().fuse() => {
-                        shutdown_requested = true;
+        // Receives events from the multiplexer into the bounded queue so that
+        // a slow Python callback applies the queue policy instead of growing memory.
+        let receiver = {
+            let queue = queue.clone();
+            async move {
+                let mut shutdown_requested = false;
+                loop {
+                    if shutdown_requested && channel.receiver.is_empty() {
+                        break;
                     }
-                    msg = channel.receiver.recv().fuse() => {
-                        match msg {
-                            Ok(notification) => {
-                                let event_type = EventKind::from(notification.as_ref());
-                                if let Some(handlers) = this.notification_callbacks(event_type) {
-                                    for handler in handlers.into_iter() {
-                                        if let Err(err) = Python::attach(|py| -> PyResult<()> {
-                                            let event_any = match serde_pyobject::to_pyobject(py, notification.as_ref()) {
-                                                Ok(obj) => obj,
-                                                Err(err) => {
-                                                    log_error!("UtxoProcessor: failed to serialize event `{}`: {}", event_type, err);
-                                                    return Ok(());
-                                                }
-                                            };
-
-                                            let event = match event_any.cast::<PyDict>() {
-                                                Ok(dict) => dict,
-                                                Err(err) => {
-                                                    log_error!(
-                                                        "UtxoProcessor: serialized event `{}` is not a dict: {}",
-                                                        event_type,
-                                                        err
-                                                    );
-                                                    return Ok(());
-                                                }
-                                            };
-
-                                            if let Err(err) = Self::normalize_event_payload(py, event_type, event) {
-                                                log_error!(
-                                                    "UtxoProcessor: failed to normalize event payload for `{}`: {}",
-                                                    event_type,
-                                                    err
-                                                );
-                                            }
-
-                                            if let Err(err) = handler.execute(py, (*event).clone()) {
-                                                log_error!(
-                                                    "UtxoProcessor: error while executing event listener for `{}`: {}",
-                                                    event_type,
-                                                    err
-                                                );
-                                            }
-
-                                            Ok(())
-                                        }) {
-                                            log_error!(
-                                                "UtxoProcessor: error while building event payload for `{}`: {}",
-                                                event_type,
-                                                err
-                                            );
-                                        }
-                                    }
+
+                    select_biased! {
+                        _ = ctl_receiver.recv().fuse() => {
+                            shutdown_requested = true;
+                        }
+                        msg = channel.receiver.recv().fuse() => {
+                            match msg {
+                                Ok(notification) => queue.push(notification).await,
+                                Err(err) => {
+                                    log_error!("UtxoProcessor: error while receiving multiplexer event: {err}");
+                                    break;
                                 }
                             }
-                            Err(err) => {
-                                log_error!("UtxoProcessor: error while receiving multiplexer event: {err}");
-                                break;
-                            }
                         }
                     }
                 }
+
+                channel.close();
+                queue.close();
+            }
+        };
+
+        let dispatcher = async move {
+            while let Some(notification) = queue.pop().await {
+                Self::dispatch_event(&callbacks, &reorg_tracker, dedup.as_deref(), &notification);
             }
+        };
 
-            channel.close();
-            this.notification_task.store(false, Ordering::SeqCst);
+        let fut = async move {
+            join!(receiver, dispatcher);
+
+            notification_task.store(false, Ordering::SeqCst);
             ctl_sender.send(()).await.ok();
             Python::attach(|_| Ok(()))
         };
 
         if let Err(err) = pyo3_async_runtimes::tokio::future_into_py(py, fut) {
-            self.notification_task.store(false, Ordering::SeqCst);
+            self.guard.notification_task.store(false, Ordering::SeqCst);
             return Err(err);
         }
 
@@ -262,11 +497,7 @@ impl PyUtxoProcessor {
     async fn stop_notification_task(
         &self,
     ) -> std::result::Result<(), workflow_core::channel::ChannelError<()>> {
-        if self.notification_task.load(Ordering::SeqCst) {
-            self.notification_ctl.signal(()).await?;
-            self.notification_task.store(false, Ordering::SeqCst);
-        }
-        Ok(())
+        stop_notification_task(&self.guard.notification_task, &self.guard.notification_ctl).await
     }
 }
 
@@ -278,27 +509,74 @@ impl PyUtxoProcessor {
     /// Args:
     ///     rpc: The RPC client to use for network communication.
     ///     network_id: Network identifier for UTXO processing.
+    ///     queue_capacity: Maximum number of events buffered for Python callbacks (default 1024).
+    ///     queue_policy: Policy applied when the event queue is full (default `EventQueuePolicy.Block`).
+    ///     dedup: Emit each transaction event (`pending`, `maturity`, `discovery`, `reorg`, `stasis`) at most once per context and transaction, suppressing repeats caused by reconnects and rescans (default True).
+    ///     dedup_path: Optional file persisting the seen transaction events, so repeats are also suppressed across restarts (use with UtxoContexts created with a fixed `id`).
+    ///
+    /// Raises:
+    ///     Exception: If `queue_capacity` is 0, `dedup_path` is given with `dedup=False`, or `dedup_path` cannot be read or written.
     #[new]
-    pub fn ctor(rpc: PyRpcClient, network_id: PyNetworkId) -> PyResult<Self> {
+    #[pyo3(signature = (rpc, network_id, queue_capacity=None, queue_policy=None, dedup=true, dedup_path=None))]
+    pub fn ctor(
+        rpc: PyRpcClient,
+        network_id: PyNetworkId,
+        queue_capacity: Option<usize>,
+        #[gen_stub(override_type(type_repr = "typing.Optional[builtins.str | EventQueuePolicy]"))]
+        queue_policy: Option<PyEventQueuePolicy>,
+        dedup: bool,
+        #[gen_stub(override_type(type_repr = "typing.Optional[str]"))] dedup_path: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        if queue_capacity == Some(0) {
+            return Err(PyException::new_err(
+                "queue_capacity must be greater than 0",
+            ));
+        }
+        if !dedup && dedup_path.is_some() {
+            return Err(PyException::new_err(
+                "dedup_path requires dedup to be enabled",
+            ));
+        }
+        let dedup = dedup
+            .then(|| EventDeduplicator::new(dedup_path))
+            .transpose()
+            .map_err(|err| PyException::new_err(err.to_string()))?
+            .map(Arc::new);
+
         let rpc_api: Arc<DynRpcApi> = rpc.client().clone();
         let rpc_ctl = rpc.client().rpc_ctl().clone();
         let rpc_binding = Rpc::new(rpc_api, rpc_ctl);
 
         let processor = UtxoProcessor::new(Some(rpc_binding), Some(network_id.into()), None, None);
 
-        Ok(Self {
+        let guard = Arc::new(ProcessorGuard {
             processor,
-            rpc,
-            callbacks: Arc::new(Mutex::new(Default::default())),
             notification_task: Arc::new(AtomicBool::new(false)),
             notification_ctl: DuplexChannel::oneshot(),
+        });
+
+        let mut live = LIVE_PROCESSORS.lock().unwrap();
+        live.retain(|guard| guard.strong_count() > 0);
+        live.push(Arc::downgrade(&guard));
+
+        Ok(Self {
+            guard,
+            rpc,
+            callbacks: Arc::new(Mutex::new(Default::default())),
+            event_queue: Arc::new(EventQueue::new(
+                queue_capacity.unwrap_or(DEFAULT_EVENT_QUEUE_CAPACITY),
+                queue_policy.unwrap_or_default(),
+            )),
+            reorg_tracker: Arc::new(ReorgTracker::new(DEFAULT_REORG_TRACKER_CAPACITY)),
+            dedup,
+            contexts: Arc::new(Mutex::new(Default::default())),
         })
     }
 
     /// Start UTXO processing (async).
     #[gen_stub(override_return_type(type_repr = "None"))]
     fn start<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let processor = self.processor.clone();
+        let processor = self.guard.processor.clone();
         let slf = self.clone();
         let notification_task_started = self.start_notification_task(py)?;
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
@@ -315,7 +593,7 @@ impl PyUtxoProcessor {
     /// Stop UTXO processing (async).
     #[gen_stub(override_return_type(type_repr = "None"))]
     fn stop<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let processor = self.processor.clone();
+        let processor = self.guard.processor.clone();
         let slf = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let stop_result = processor.stop().await;
@@ -330,6 +608,58 @@ impl PyUtxoProcessor {
         })
     }
 
+    /// Re-fetch the UTXO set from the node for the contexts tracking the given addresses (async).
+    ///
+    /// Use this after suspected missed notifications (e.g. a dropped connection).
+    /// Every affected context is fully rescanned: its UTXO set is cleared and
+    /// reloaded for all of its tracked addresses.
+    ///
+    /// Args:
+    ///     addresses: Optional list of Address objects or address strings. Defaults to all tracked addresses.
+    ///
+    /// Returns:
+    ///     dict: Dictionary with "added" and "removed" lists of UtxoEntryReference.
+    ///
+    /// Raises:
+    ///     Exception: If the processor is not running or a rescan fails.
+    #[pyo3(signature = (addresses=None))]
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn refresh<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(
+            type_repr = "typing.Optional[typing.Sequence[Address | builtins.str]]"
+        ))]
+        addresses: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if !self.guard.processor.is_running() {
+            return Err(PyException::new_err("UtxoProcessor is not running"));
+        }
+
+        let addresses = addresses.map(parse_addresses).transpose()?;
+        let contexts = self
+            .contexts
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|context| match &addresses {
+                Some(addresses) => addresses
+                    .iter()
+                    .any(|address| context.addresses().contains(address)),
+                None => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut diff = UtxoDiff::default();
+            for context in contexts {
+                diff.extend(rescan_context(&context).await?);
+            }
+            Python::attach(|py| diff.into_pydict(py).map(Bound::unbind))
+        })
+    }
+
     /// The associated RPC client.
     #[getter]
     pub fn get_rpc(&self) -> PyRpcClient {
@@ -339,12 +669,16 @@ impl PyUtxoProcessor {
     /// The network id used by the processor (if set).
     #[getter]
     pub fn get_network_id(&self) -> Option<PyNetworkId> {
-        self.processor.network_id().ok().map(PyNetworkId::from)
+        self.guard
+            .processor
+            .network_id()
+            .ok()
+            .map(PyNetworkId::from)
     }
 
     /// Set the network id for the processor.
     pub fn set_network_id(&self, network_id: PyNetworkId) {
-        self.processor.set_network_id(&network_id.into());
+        self.guard.processor.set_network_id(&network_id.into());
     }
 
     /// Set the coinbase transaction maturity period DAA for a network.
@@ -361,23 +695,62 @@ impl PyUtxoProcessor {
         set_user_transaction_maturity_period_daa(&network_id, value);
     }
 
+    /// Get the coinbase transaction maturity period DAA for a network.
+    #[staticmethod]
+    pub fn get_coinbase_transaction_maturity_daa(network_id: PyNetworkId) -> u64 {
+        coinbase_transaction_maturity_period_daa(&network_id.into())
+    }
+
+    /// Get the user transaction maturity period DAA for a network.
+    #[staticmethod]
+    pub fn get_user_transaction_maturity_daa(network_id: PyNetworkId) -> u64 {
+        user_transaction_maturity_period_daa(&network_id.into())
+    }
+
     /// Whether the processor is connected and running.
     #[getter]
     pub fn get_is_active(&self) -> bool {
-        self.processor
+        self.guard
+            .processor
             .try_rpc_ctl()
             .map(|ctl| ctl.is_connected())
             .unwrap_or(false)
-            && self.processor.is_connected()
-            && self.processor.is_running()
+            && self.guard.processor.is_connected()
+            && self.guard.processor.is_running()
+    }
+
+    /// Whether the connected node reports being synced (see the `sync-state` event).
+    #[getter]
+    pub fn get_is_synced(&self) -> bool {
+        self.guard.processor.is_synced()
+    }
+
+    /// Number of events currently queued for delivery to Python callbacks.
+    #[getter]
+    pub fn get_queue_depth(&self) -> usize {
+        self.event_queue.len()
+    }
+
+    /// Number of events dropped or coalesced by the event queue policy.
+    #[getter]
+    pub fn get_dropped_events(&self) -> u64 {
+        self.event_queue.dropped()
+    }
+
+    /// Number of repeated transaction events suppressed by deduplication.
+    #[getter]
+    pub fn get_duplicate_events(&self) -> u64 {
+        self.dedup.as_ref().map_or(0, |dedup| dedup.suppressed())
     }
 
     /// Register a callback for UtxoProcessor events.
     ///
     /// Args:
     ///     event_or_callback: Event target as string (kebab-case), `UtxoProcessorEvent` variant, a list of those, "*" / "all", or a callback (listen to all events).
-    ///     callback: Function to call when event occurs (required when event_or_callback is an event target).
+    ///     callback: Function to call when event occurs (required when event_or_callback is an event target). An `async def` callback is scheduled on the event loop running when it is registered.
     ///     *args: Additional arguments to pass to callback.
+    ///     addresses: Optional keyword-only list of addresses. Events referencing other addresses are not delivered.
+    ///     contexts: Optional keyword-only list of UtxoContext objects (or context ids). Events bound to other contexts are not delivered.
     ///     **kwargs: Additional keyword arguments to pass to callback.
     ///
     /// Returns:
@@ -386,13 +759,31 @@ impl PyUtxoProcessor {
     /// Notes:
     ///     Callback will be invoked as: callback(*args, event, **kwargs)
     ///     Where event is a dict like: {"type": str, "data": ...}
-    #[pyo3(signature = (event_or_callback, callback=None, *args, **kwargs))]
+    ///
+    ///     Filters are evaluated in Rust before the GIL is acquired. Events that
+    ///     carry no address (or context) information, such as `connect` or
+    ///     `daa-score-change`, are not filtered on that criterion.
+    ///
+    ///     `reorg` events additionally carry an "affected" list with the last event
+    ///     previously delivered (via `pending`, `maturity`, `discovery` or `stasis`)
+    ///     for the invalidated transaction and context, as a dict with `id`, `type`
+    ///     and `blockDaaScore`, so credited deposits can be reverted. The list is
+    ///     empty if the transaction was never delivered or has aged out of the tracker.
+    #[pyo3(signature = (event_or_callback, callback=None, *args, addresses=None, contexts=None, **kwargs))]
     fn add_event_listener(
         &self,
         py: Python,
         event_or_callback: Bound<'_, PyAny>,
         callback: Option<Py<PyAny>>,
         args: &Bound<'_, PyTuple>,
+        #[gen_stub(override_type(
+            type_repr = "typing.Optional[typing.Sequence[Address | builtins.str]]"
+        ))]
+        addresses: Option<Bound<'_, PyAny>>,
+        #[gen_stub(override_type(
+            type_repr = "typing.Optional[typing.Sequence[UtxoContext | Hash | builtins.str]]"
+        ))]
+        contexts: Option<Bound<'_, PyAny>>,
         kwargs: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         let (targets, callback) = match callback {
@@ -417,14 +808,19 @@ impl PyUtxoProcessor {
             None => PyDict::new(py).into(),
         };
 
-        let py_callback = PyCallback::new(callback, args, kwargs);
+        let filter = EventFilter {
+            addresses: addresses.map(parse_filter_addresses).transpose()?,
+            contexts: contexts.map(parse_filter_contexts).transpose()?,
+        };
+
+        let listener = Listener {
+            callback: PyCallback::new(callback, args, kwargs),
+            filter: (!filter.is_empty()).then(|| Arc::new(filter)),
+        };
 
         let mut callbacks = self.callbacks.lock().unwrap();
         for target in targets {
-            callbacks
-                .entry(target)
-                .or_default()
-                .push(py_callback.clone());
+            callbacks.entry(target).or_default().push(listener.clone());
         }
         Ok(())
     }
@@ -448,7 +844,7 @@ impl PyUtxoProcessor {
         if callback.is_none() && event_or_callback.is_callable() {
             let callback = event_or_callback.extract::<Py<PyAny>>()?;
             for handlers in callbacks.values_mut() {
-                handlers.retain(|entry| !entry.callback_ptr_eq(&callback));
+                handlers.retain(|entry| !entry.callback.callback_ptr_eq(&callback));
             }
             return Ok(());
         }
@@ -459,7 +855,7 @@ impl PyUtxoProcessor {
             Some(callback) => {
                 for target in targets {
                     if let Some(handlers) = callbacks.get_mut(&target) {
-                        handlers.retain(|entry| !entry.callback_ptr_eq(&callback));
+                        handlers.retain(|entry| !entry.callback.callback_ptr_eq(&callback));
                     }
                 }
             }
@@ -525,3 +921,172 @@ fn parse_event_kind(s: &str) -> PyResult<EventKind> {
     }
     EventKind::from_str(s).map_err(|err| PyException::new_err(err.to_string()))
 }
+
+fn parse_filter_addresses(value: Bound<'_, PyAny>) -> PyResult<Vec<serde_json::Value>> {
+    parse_addresses(value)?
+        .iter()
+        .map(|address| {
+            serde_json::to_value(address).map_err(|err| PyException::new_err(err.to_string()))
+        })
+        .collect()
+}
+
+fn parse_filter_contexts(value: Bound<'_, PyAny>) -> PyResult<Vec<serde_json::Value>> {
+    value
+        .try_iter()
+        .map_err(|_| {
+            PyException::new_err("contexts must be an iterable of UtxoContext, Hash, or str")
+        })?
+        .map(|item| {
+            let item = item?;
+            let id = if let Ok(context) = item.extract::<PyUtxoContext>() {
+                context.inner().id()
+            } else if let Ok(hash) = item.extract::<PyHash>() {
+                UtxoContextId::new(hash.into())
+            } else if let Ok(hex) = item.extract::<String>() {
+                let hash =
+                    Hash::from_str(&hex).map_err(|err| PyException::new_err(err.to_string()))?;
+                UtxoContextId::new(hash)
+            } else {
+                return Err(PyException::new_err(
+                    "contexts must be an iterable of UtxoContext, Hash, or str",
+                ));
+            };
+            serde_json::to_value(id).map_err(|err| PyException::new_err(err.to_string()))
+        })
+        .collect()
+}
+
+/// Recursively collect every value stored under `key` in a serialized event payload.
+fn collect_values_for_key(value: &serde_json::Value, key: &str, out: &mut Vec<serde_json::Value>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map {
+                if k == key {
+                    match v {
+                        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                            collect_leaf_values(v, out)
+                        }
+                        serde_json::Value::Null => {}
+                        _ => out.push(v.clone()),
+                    }
+                } else {
+                    collect_values_for_key(v, key, out);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_values_for_key(item, key, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_leaf_values(value: &serde_json::Value, out: &mut Vec<serde_json::Value>) {
+    match value {
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_leaf_values(v, out)),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_leaf_values(v, out)),
+        serde_json::Value::Null => {}
+        _ => out.push(value.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::{Address, Prefix, Version};
+    use kaspa_consensus_client::{TransactionOutpoint, UtxoEntry};
+    use kaspa_consensus_core::network::{NetworkId, NetworkType};
+    use kaspa_consensus_core::tx::{ScriptPublicKey, TransactionId};
+    use kaspa_wallet_core::storage::{TransactionKind, TransactionRecord};
+    use kaspa_wallet_core::utxo::UtxoContextBinding;
+
+    fn address(byte: u8) -> Address {
+        Address::new(Prefix::Testnet, Version::PubKey, &[byte; 32])
+    }
+
+    fn context_id(id: u64) -> UtxoContextId {
+        UtxoContextId::new(Hash::from_u64_word(id))
+    }
+
+    /// A serialized `pending` event of a transaction paying `address` in context `id`.
+    fn pending(id: u64, address: Address) -> serde_json::Value {
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 10);
+        let processor = UtxoProcessor::new(None, Some(network_id), None, None);
+        let context = UtxoContext::new(&processor, UtxoContextBinding::Id(context_id(id)));
+        let entry = UtxoEntry {
+            address: Some(address),
+            outpoint: TransactionOutpoint::new(TransactionId::from_u64_word(1), 0),
+            amount: 1000,
+            script_public_key: ScriptPublicKey::from_vec(0, vec![]),
+            block_daa_score: 0,
+            is_coinbase: false,
+        };
+        let record = TransactionRecord::new_incoming(
+            &context,
+            TransactionKind::Incoming,
+            TransactionId::from_u64_word(1),
+            vec![entry.into()],
+        );
+        serde_json::to_value(Events::Pending { record }).unwrap()
+    }
+
+    /// A serialized `balance` event of context `id`.
+    fn balance(id: u64) -> serde_json::Value {
+        serde_json::to_value(Events::Balance {
+            balance: None,
+            id: context_id(id),
+        })
+        .unwrap()
+    }
+
+    fn addresses(addresses: &[Address]) -> EventFilter {
+        EventFilter {
+            addresses: Some(
+                addresses
+                    .iter()
+                    .map(|address| serde_json::to_value(address).unwrap())
+                    .collect(),
+            ),
+            contexts: None,
+        }
+    }
+
+    fn contexts(ids: &[u64]) -> EventFilter {
+        EventFilter {
+            addresses: None,
+            contexts: Some(
+                ids.iter()
+                    .map(|id| serde_json::to_value(context_id(*id)).unwrap())
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn no_filter_matches_every_event() {
+        let filter = EventFilter::default();
+        assert!(filter.matches(EventKind::Pending, &pending(1, address(1))));
+        assert!(filter.matches(EventKind::Balance, &balance(1)));
+    }
+
+    #[test]
+    fn address_filter_matches_events_of_its_addresses() {
+        let filter = addresses(&[address(1), address(3)]);
+        assert!(filter.matches(EventKind::Pending, &pending(1, address(1))));
+        assert!(!filter.matches(EventKind::Pending, &pending(1, address(2))));
+        // Events without addresses are not filtered by address.
+        assert!(filter.matches(EventKind::Balance, &balance(1)));
+    }
+
+    #[test]
+    fn context_filter_matches_events_of_its_contexts() {
+        let filter = contexts(&[1]);
+        assert!(filter.matches(EventKind::Pending, &pending(1, address(1))));
+        assert!(!filter.matches(EventKind::Pending, &pending(2, address(1))));
+        assert!(filter.matches(EventKind::Balance, &balance(1)));
+        assert!(!filter.matches(EventKind::Balance, &balance(2)));
+    }
+}
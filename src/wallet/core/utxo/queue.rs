@@ -0,0 +1,238 @@
+use kaspa_wallet_core::events::Events;
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::gen_stub_pyclass_enum;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{
+    Mutex,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use workflow_core::channel::Channel;
+
+/// Default number of events buffered between the UtxoProcessor and Python callbacks.
+pub const DEFAULT_EVENT_QUEUE_CAPACITY: usize = 1024;
+
+/// Policy applied when the `UtxoProcessor` event queue is full.
+///
+/// Variants:
+///     - Block: Wait for Python callbacks to catch up. No events are dropped.
+///     - DropOldest: Drop the oldest queued event to make room for the new one.
+///     - CoalesceBalance: Drop the queued `balance` event of the same context, the newer one being queued last, otherwise wait.
+#[gen_stub_pyclass_enum]
+#[pyclass(name = "EventQueuePolicy", skip_from_py_object, eq)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PyEventQueuePolicy {
+    #[default]
+    Block,
+    DropOldest,
+    CoalesceBalance,
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyEventQueuePolicy {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.extract::<String>() {
+            serde_json::from_value::<PyEventQueuePolicy>(serde_json::Value::String(s))
+                .map_err(|err| PyException::new_err(err.to_string()))
+        } else if let Ok(t) = obj.cast::<PyEventQueuePolicy>() {
+            Ok(*t.borrow())
+        } else {
+            Err(PyException::new_err(
+                "Expected type `str` or `EventQueuePolicy`",
+            ))
+        }
+    }
+}
+
+/// Bounded queue sitting between the processor multiplexer and the Python dispatcher.
+pub(crate) struct EventQueue {
+    capacity: usize,
+    policy: PyEventQueuePolicy,
+    events: Mutex<VecDeque<Box<Events>>>,
+    dropped: AtomicU64,
+    closed: AtomicBool,
+    ready: Channel<()>,
+    space: Channel<()>,
+}
+
+impl EventQueue {
+    pub fn new(capacity: usize, policy: PyEventQueuePolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+            events: Mutex::new(VecDeque::new()),
+            dropped: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+            ready: Channel::bounded(1),
+            space: Channel::bounded(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    /// Enqueue an event, applying the configured policy when the queue is full.
+    pub async fn push(&self, event: Box<Events>) {
+        let mut event = Some(event);
+        loop {
+            {
+                let mut events = self.events.lock().unwrap();
+
+                if events.len() >= self.capacity {
+                    match self.policy {
+                        PyEventQueuePolicy::DropOldest => {
+                            events.pop_front();
+                            self.dropped.fetch_add(1, Ordering::SeqCst);
+                        }
+                        // The newer balance supersedes the queued one; it is queued last
+                        // so events keep their order.
+                        PyEventQueuePolicy::CoalesceBalance => {
+                            if let Some(Events::Balance { id, .. }) = event.as_deref()
+                                && let Some(index) = events.iter().position(|queued| {
+                                    matches!(queued.as_ref(), Events::Balance { id: queued_id, .. } if queued_id == id)
+                                })
+                            {
+                                events.remove(index);
+                                self.dropped.fetch_add(1, Ordering::SeqCst);
+                            }
+                        }
+                        PyEventQueuePolicy::Block => {}
+                    }
+                }
+
+                if events.len() < self.capacity {
+                    events.push_back(event.take().unwrap());
+                    self.ready.try_send(()).ok();
+                    return;
+                }
+            }
+
+            if self.space.recv().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Dequeue the next event, waiting if the queue is empty.
+    /// Returns `None` once the queue has been closed and drained.
+    pub async fn pop(&self) -> Option<Box<Events>> {
+        loop {
+            {
+                let mut events = self.events.lock().unwrap();
+                if let Some(event) = events.pop_front() {
+                    self.space.try_send(()).ok();
+                    return Some(event);
+                }
+                if self.closed.load(Ordering::SeqCst) {
+                    return None;
+                }
+            }
+
+            if self.ready.recv().await.is_err() {
+                return None;
+            }
+        }
+    }
+
+    /// Re-open a previously closed queue (processor restart).
+    pub fn open(&self) {
+        self.closed.store(false, Ordering::SeqCst);
+    }
+
+    /// Stop accepting new events. Queued events are still delivered by `pop()`.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.ready.try_send(()).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use kaspa_hashes::Hash;
+    use kaspa_wallet_core::utxo::UtxoContextId;
+
+    #[derive(PartialEq)]
+    enum Queued {
+        Balance(UtxoContextId),
+        DaaScore(u64),
+    }
+
+    fn context(word: u64) -> UtxoContextId {
+        UtxoContextId::new(Hash::from_u64_word(word))
+    }
+
+    fn balance(word: u64) -> Box<Events> {
+        Box::new(Events::Balance {
+            balance: None,
+            id: context(word),
+        })
+    }
+
+    fn daa_score(score: u64) -> Box<Events> {
+        Box::new(Events::DaaScoreChange {
+            current_daa_score: score,
+        })
+    }
+
+    fn drain(queue: &EventQueue) -> Vec<Queued> {
+        queue.close();
+        let mut events = Vec::new();
+        while let Some(event) = block_on(queue.pop()) {
+            events.push(match *event {
+                Events::Balance { id, .. } => Queued::Balance(id),
+                Events::DaaScoreChange { current_daa_score } => Queued::DaaScore(current_daa_score),
+                _ => unreachable!(),
+            });
+        }
+        events
+    }
+
+    #[test]
+    fn coalesce_balance_keeps_events_below_capacity() {
+        let queue = EventQueue::new(8, PyEventQueuePolicy::CoalesceBalance);
+        block_on(queue.push(balance(1)));
+        block_on(queue.push(balance(1)));
+
+        assert_eq!(queue.dropped(), 0);
+        assert!(drain(&queue) == vec![Queued::Balance(context(1)), Queued::Balance(context(1))]);
+    }
+
+    #[test]
+    fn coalesce_balance_on_overflow_keeps_order() {
+        let queue = EventQueue::new(3, PyEventQueuePolicy::CoalesceBalance);
+        block_on(queue.push(balance(1)));
+        block_on(queue.push(daa_score(1)));
+        block_on(queue.push(balance(2)));
+        block_on(queue.push(balance(1)));
+
+        assert_eq!(queue.dropped(), 1);
+        assert!(
+            drain(&queue)
+                == vec![
+                    Queued::DaaScore(1),
+                    Queued::Balance(context(2)),
+                    Queued::Balance(context(1)),
+                ]
+        );
+    }
+
+    #[test]
+    fn drop_oldest_on_overflow() {
+        let queue = EventQueue::new(2, PyEventQueuePolicy::DropOldest);
+        for score in 1..=3 {
+            block_on(queue.push(daa_score(score)));
+        }
+
+        assert_eq!(queue.dropped(), 1);
+        assert!(drain(&queue) == vec![Queued::DaaScore(2), Queued::DaaScore(3)]);
+    }
+}
@@ -0,0 +1,161 @@
+use ahash::AHashMap;
+use kaspa_consensus_core::tx::TransactionId;
+use kaspa_wallet_core::events::Events;
+use kaspa_wallet_core::storage::TransactionRecord;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Number of delivered transaction records remembered for reorg reconciliation.
+pub const DEFAULT_REORG_TRACKER_CAPACITY: usize = 4096;
+
+/// A record's context (`binding`, Borsh-encoded) and transaction id.
+pub(crate) type RecordKey = (Vec<u8>, TransactionId);
+
+/// The last event delivered for a transaction, reported in the `affected`
+/// list of the `reorg` event invalidating it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Delivered {
+    pub id: TransactionId,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub block_daa_score: u64,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    delivered: AHashMap<RecordKey, Delivered>,
+    order: VecDeque<RecordKey>,
+}
+
+/// Remembers the transactions delivered through `pending`, `maturity`,
+/// `discovery` and `stasis` events so that a later `reorg` event can report
+/// those it invalidates (the deposits an application may have credited).
+pub(crate) struct ReorgTracker {
+    capacity: usize,
+    state: Mutex<TrackerState>,
+}
+
+impl ReorgTracker {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(TrackerState::default()),
+        }
+    }
+
+    /// Record a transaction event. For `reorg` events, returns the previously
+    /// delivered event of the same transaction and context, if any.
+    pub fn observe(&self, event: &Events) -> Option<Vec<Delivered>> {
+        let (kind, record) = match event {
+            Events::Pending { record } => ("pending", record),
+            Events::Maturity { record } => ("maturity", record),
+            Events::Discovery { record } => ("discovery", record),
+            Events::Stasis { record } => ("stasis", record),
+            Events::Reorg { record } => {
+                let affected = record_key(record).and_then(|key| self.invalidate(&key));
+                return Some(affected.into_iter().collect());
+            }
+            _ => return None,
+        };
+        if let Some(key) = record_key(record) {
+            self.track(
+                key,
+                Delivered {
+                    id: *record.id(),
+                    kind,
+                    block_daa_score: record.block_daa_score(),
+                },
+            );
+        }
+        None
+    }
+
+    fn track(&self, key: RecordKey, delivered: Delivered) {
+        let mut state = self.state.lock().unwrap();
+        if !state.delivered.contains_key(&key) {
+            state.order.push_back(key.clone());
+        }
+        state.delivered.insert(key, delivered);
+
+        while state.order.len() > self.capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.delivered.remove(&oldest);
+            }
+        }
+    }
+
+    fn invalidate(&self, key: &RecordKey) -> Option<Delivered> {
+        let mut state = self.state.lock().unwrap();
+        let delivered = state.delivered.remove(key)?;
+        state.order.retain(|queued| queued != key);
+        Some(delivered)
+    }
+}
+
+/// The tracking key of a record.
+pub(crate) fn record_key(record: &TransactionRecord) -> Option<RecordKey> {
+    Some((borsh::to_vec(record.binding()).ok()?, *record.id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(context: u8, transaction: u64) -> RecordKey {
+        (vec![context], TransactionId::from_u64_word(transaction))
+    }
+
+    fn delivered(transaction: u64, kind: &'static str) -> Delivered {
+        Delivered {
+            id: TransactionId::from_u64_word(transaction),
+            kind,
+            block_daa_score: 100 + transaction,
+        }
+    }
+
+    #[test]
+    fn reorg_reports_the_last_delivered_event() {
+        let tracker = ReorgTracker::new(8);
+        tracker.track(key(1, 1), delivered(1, "pending"));
+        tracker.track(key(1, 1), delivered(1, "maturity"));
+
+        assert_eq!(
+            tracker.invalidate(&key(1, 1)),
+            Some(delivered(1, "maturity"))
+        );
+        // A reorg invalidates a transaction once.
+        assert_eq!(tracker.invalidate(&key(1, 1)), None);
+    }
+
+    #[test]
+    fn reorg_is_scoped_to_the_context() {
+        let tracker = ReorgTracker::new(8);
+        tracker.track(key(1, 1), delivered(1, "pending"));
+        tracker.track(key(2, 1), delivered(1, "discovery"));
+
+        assert_eq!(
+            tracker.invalidate(&key(2, 1)),
+            Some(delivered(1, "discovery"))
+        );
+        assert_eq!(
+            tracker.invalidate(&key(1, 1)),
+            Some(delivered(1, "pending"))
+        );
+    }
+
+    #[test]
+    fn oldest_transactions_age_out() {
+        let tracker = ReorgTracker::new(2);
+        for transaction in 1..=3 {
+            tracker.track(key(1, transaction), delivered(transaction, "pending"));
+        }
+
+        assert_eq!(tracker.invalidate(&key(1, 1)), None);
+        assert_eq!(
+            tracker.invalidate(&key(1, 3)),
+            Some(delivered(3, "pending"))
+        );
+    }
+}
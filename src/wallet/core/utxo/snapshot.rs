@@ -0,0 +1,127 @@
+use kaspa_addresses::Address;
+use kaspa_consensus_client::UtxoEntry;
+use kaspa_consensus_core::network::NetworkId;
+use kaspa_wallet_core::utxo::{UtxoContextId, balance::Balance};
+use pyo3::{exceptions::PyException, prelude::*};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use workflow_core::hex::ToHex;
+
+/// Current on-disk snapshot format version.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Persistent state of a `UtxoContext`: tracked addresses, known UTXO set,
+/// balance and the DAA score at the time the snapshot was taken.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextSnapshot {
+    pub version: u32,
+    pub network_id: NetworkId,
+    pub context_id: UtxoContextId,
+    pub daa_score: Option<u64>,
+    pub addresses: Vec<Address>,
+    pub utxos: Vec<UtxoEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<SnapshotBalance>,
+    #[serde(default)]
+    pub checksum: String,
+}
+
+/// Balance of a `UtxoContext` at the time the snapshot was taken.
+///
+/// Optional, so snapshots written before it was recorded still verify.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct SnapshotBalance {
+    pub mature: u64,
+    pub pending: u64,
+    pub outgoing: u64,
+}
+
+impl From<&Balance> for SnapshotBalance {
+    fn from(balance: &Balance) -> Self {
+        Self {
+            mature: balance.mature,
+            pending: balance.pending,
+            outgoing: balance.outgoing,
+        }
+    }
+}
+
+impl ContextSnapshot {
+    pub fn new(
+        network_id: NetworkId,
+        context_id: UtxoContextId,
+        daa_score: Option<u64>,
+        addresses: Vec<Address>,
+        utxos: Vec<UtxoEntry>,
+        balance: Option<SnapshotBalance>,
+    ) -> PyResult<Self> {
+        let mut snapshot = Self {
+            version: SNAPSHOT_VERSION,
+            network_id,
+            context_id,
+            daa_score,
+            addresses,
+            utxos,
+            balance,
+            checksum: String::new(),
+        };
+        snapshot.checksum = snapshot.compute_checksum()?;
+        Ok(snapshot)
+    }
+
+    /// SHA-256 over the snapshot serialized with an empty checksum.
+    fn compute_checksum(&self) -> PyResult<String> {
+        let body = serde_json::to_vec(&ContextSnapshotRef {
+            version: self.version,
+            network_id: &self.network_id,
+            context_id: &self.context_id,
+            daa_score: self.daa_score,
+            addresses: &self.addresses,
+            utxos: &self.utxos,
+            balance: self.balance.as_ref(),
+        })
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Sha256::digest(body).as_slice().to_hex())
+    }
+
+    /// Check the format version and checksum of a loaded snapshot.
+    pub fn verify(&self) -> PyResult<()> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(PyException::new_err(format!(
+                "Unsupported snapshot version {} (expected {})",
+                self.version, SNAPSHOT_VERSION
+            )));
+        }
+        if self.compute_checksum()? != self.checksum {
+            return Err(PyException::new_err(
+                "Snapshot checksum mismatch: the snapshot is corrupted or was modified",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|err| PyException::new_err(err.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        let snapshot: Self =
+            serde_json::from_str(json).map_err(|err| PyException::new_err(err.to_string()))?;
+        snapshot.verify()?;
+        Ok(snapshot)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContextSnapshotRef<'a> {
+    version: u32,
+    network_id: &'a NetworkId,
+    context_id: &'a UtxoContextId,
+    daa_score: Option<u64>,
+    addresses: &'a [Address],
+    utxos: &'a [UtxoEntry],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    balance: Option<&'a SnapshotBalance>,
+}
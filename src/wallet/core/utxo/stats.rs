@@ -0,0 +1,150 @@
+use crate::validation;
+use crate::wallet::core::utxo::context::extract_entries;
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+/// Outputs below 0.1 KAS exceed the standard transaction mass limit on their
+/// own under KIP-9 storage mass (10^12 / amount > 100,000), so they can only be
+/// created alongside larger outputs and are uneconomic to hold.
+pub const DEFAULT_DUST_THRESHOLD: u64 = 10_000_000;
+
+/// Upper bounds (exclusive) of the default age buckets, in DAA score units:
+/// about 10 seconds, 100 seconds, 17 minutes, 3 hours, 28 hours and 12 days
+/// at 10 BPS. Older entries fall in a final, unbounded bucket.
+pub const DEFAULT_AGE_BUCKETS: [u64; 6] = [100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+
+/// Count, total and age distribution of UTXO amounts.
+struct UtxoStats {
+    amounts: Vec<u64>,
+    total: u128,
+    dust: u64,
+    coinbase: u64,
+    /// Count and total amount per age bucket, the last one unbounded.
+    buckets: Vec<(u64, u128)>,
+}
+
+impl UtxoStats {
+    fn new(
+        entries: impl Iterator<Item = (u64, u64, bool)>,
+        daa_score: u64,
+        dust_threshold: u64,
+        age_buckets: &[u64],
+    ) -> Self {
+        let mut stats = Self {
+            amounts: Vec::new(),
+            total: 0,
+            dust: 0,
+            coinbase: 0,
+            buckets: vec![(0, 0); age_buckets.len() + 1],
+        };
+        for (amount, block_daa_score, is_coinbase) in entries {
+            stats.amounts.push(amount);
+            stats.total += amount as u128;
+            stats.dust += (amount < dust_threshold) as u64;
+            stats.coinbase += is_coinbase as u64;
+            let age = daa_score.saturating_sub(block_daa_score);
+            let bucket = age_buckets.partition_point(|bound| *bound <= age);
+            stats.buckets[bucket].0 += 1;
+            stats.buckets[bucket].1 += amount as u128;
+        }
+        stats.amounts.sort_unstable();
+        stats
+    }
+
+    fn mean(&self) -> Option<f64> {
+        (!self.amounts.is_empty()).then(|| self.total as f64 / self.amounts.len() as f64)
+    }
+
+    fn median(&self) -> Option<f64> {
+        let len = self.amounts.len();
+        match len {
+            0 => None,
+            _ if len % 2 == 1 => Some(self.amounts[len / 2] as f64),
+            _ => Some((self.amounts[len / 2 - 1] as f64 + self.amounts[len / 2] as f64) / 2.0),
+        }
+    }
+}
+
+/// Summarize a set of UTXOs for dashboards and reports.
+///
+/// The aggregation runs in Rust, without iterating over the entries in
+/// Python. Entry ages are measured in DAA score units from `daa_score`.
+///
+/// Args:
+///     entries: A UtxoContext (mature and pending entries) or a list of UTXO entries.
+///     daa_score: The DAA score ages are measured from, e.g. the virtual DAA score (default: the highest block DAA score of the entries).
+///     dust_threshold: Amounts below this many sompi are counted as dust (default: 0.1 KAS).
+///     age_buckets: Ascending upper bounds of the age buckets, in DAA score units (default: 100, 1,000, ... 10,000,000).
+///
+/// Returns:
+///     dict: `count`, `total`, `min`, `max`, `mean` and `median` (amounts in
+///     sompi; None without entries), `dust` and `coinbase` (entry counts) and
+///     `age_buckets`, a list of dicts with `max_age` (exclusive; None for the
+///     last bucket), `count` and `amount`.
+///
+/// Raises:
+///     ValueError: If `age_buckets` is not strictly ascending.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "analyze_utxos")]
+#[pyo3(signature = (entries, daa_score=None, dust_threshold=DEFAULT_DUST_THRESHOLD, age_buckets=None))]
+pub fn py_analyze_utxos<'py>(
+    #[gen_stub(override_type(
+        type_repr = "UtxoContext | typing.Sequence[UtxoEntryReference | dict]"
+    ))]
+    entries: Bound<'py, PyAny>,
+    daa_score: Option<u64>,
+    dust_threshold: u64,
+    age_buckets: Option<Vec<u64>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = entries.py();
+    let age_buckets = age_buckets.unwrap_or_else(|| DEFAULT_AGE_BUCKETS.to_vec());
+    if age_buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(validation::invalid(
+            "age_buckets",
+            "must be strictly ascending",
+        ));
+    }
+    let entries = extract_entries(&entries)?;
+    let daa_score = daa_score.unwrap_or_else(|| {
+        entries
+            .iter()
+            .map(|entry| entry.block_daa_score())
+            .max()
+            .unwrap_or_default()
+    });
+
+    let stats = py.detach(|| {
+        UtxoStats::new(
+            entries
+                .iter()
+                .map(|entry| (entry.amount(), entry.block_daa_score(), entry.is_coinbase())),
+            daa_score,
+            dust_threshold,
+            &age_buckets,
+        )
+    });
+
+    let summary = PyDict::new(py);
+    summary.set_item("count", stats.amounts.len())?;
+    summary.set_item("total", stats.total)?;
+    summary.set_item("min", stats.amounts.first())?;
+    summary.set_item("max", stats.amounts.last())?;
+    summary.set_item("mean", stats.mean())?;
+    summary.set_item("median", stats.median())?;
+    summary.set_item("dust", stats.dust)?;
+    summary.set_item("coinbase", stats.coinbase)?;
+    let buckets = PyList::empty(py);
+    for (index, (count, amount)) in stats.buckets.iter().enumerate() {
+        let bucket = PyDict::new(py);
+        bucket.set_item("max_age", age_buckets.get(index))?;
+        bucket.set_item("count", count)?;
+        bucket.set_item("amount", amount)?;
+        buckets.append(bucket)?;
+    }
+    summary.set_item("age_buckets", buckets)?;
+    Ok(summary)
+}
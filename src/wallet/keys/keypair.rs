@@ -1,5 +1,5 @@
 use super::privatekey::PyPrivateKey;
-use crate::{address::PyAddress, consensus::core::network::PyNetworkType};
+use crate::{address::PyAddress, consensus::core::network::PyNetworkType, validation};
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_wallet_keys::{privatekey::PrivateKey, publickey::PublicKey};
@@ -20,6 +20,18 @@ pub struct PyKeypair {
     xonly_public_key: secp256k1::XOnlyPublicKey,
 }
 
+impl PyKeypair {
+    pub(crate) fn from_secret_key(secret_key: secp256k1::SecretKey) -> Self {
+        let public_key = secp256k1::PublicKey::from_secret_key(secp256k1::SECP256K1, &secret_key);
+        let (xonly_public_key, _) = public_key.x_only_public_key();
+        PyKeypair {
+            secret_key,
+            public_key,
+            xonly_public_key,
+        }
+    }
+}
+
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyKeypair {
@@ -34,15 +46,18 @@ impl PyKeypair {
     ///     Keypair: A new Keypair instance.
     ///
     /// Raises:
-    ///     Exception: If any key format is invalid.
+    ///     ValueError: If any key format is invalid.
     #[new]
     pub fn new(secret_key: &str, public_key: &str, xonly_public_key: &str) -> PyResult<Self> {
+        validation::hex("secret_key", secret_key, Some(64))?;
+        validation::hex("public_key", public_key, None)?;
+        validation::hex("xonly_public_key", xonly_public_key, Some(64))?;
         let secret_key = secp256k1::SecretKey::from_str(secret_key)
-            .map_err(|err| PyException::new_err(err.to_string()))?;
+            .map_err(|err| validation::invalid("secret_key", err))?;
         let public_key = secp256k1::PublicKey::from_str(public_key)
-            .map_err(|err| PyException::new_err(err.to_string()))?;
+            .map_err(|err| validation::invalid("public_key", err))?;
         let xonly_public_key = secp256k1::XOnlyPublicKey::from_str(xonly_public_key)
-            .map_err(|err| PyException::new_err(err.to_string()))?;
+            .map_err(|err| validation::invalid("xonly_public_key", err))?;
 
         Ok(PyKeypair {
             secret_key,
@@ -4,5 +4,8 @@ pub mod privatekey;
 pub mod privkeygen;
 pub mod pubkeygen;
 pub mod publickey;
+pub mod shamir;
+pub mod vanity;
+pub mod vault;
 pub mod xprv;
 pub mod xpub;
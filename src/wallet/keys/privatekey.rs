@@ -1,6 +1,7 @@
 use super::publickey::PyPublicKey;
 use crate::{
-    address::PyAddress, consensus::core::network::PyNetworkType, wallet::keys::keypair::PyKeypair,
+    address::PyAddress, consensus::core::network::PyNetworkType, validation,
+    wallet::keys::keypair::PyKeypair,
 };
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
@@ -41,11 +42,12 @@ impl PyPrivateKey {
     ///     PrivateKey: A new PrivateKey instance.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid.
+    ///     ValueError: If the hex string is invalid.
     #[new]
     pub fn try_new(key: &str) -> PyResult<PyPrivateKey> {
+        validation::hex("key", key, Some(64))?;
         let private_key =
-            PrivateKey::try_new(key).map_err(|err| PyException::new_err(format!("{}", err)))?;
+            PrivateKey::try_new(key).map_err(|err| validation::invalid("key", err))?;
         Ok(PyPrivateKey(private_key))
     }
 
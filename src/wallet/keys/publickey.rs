@@ -1,4 +1,4 @@
-use crate::{address::PyAddress, consensus::core::network::PyNetworkType};
+use crate::{address::PyAddress, consensus::core::network::PyNetworkType, validation};
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_wallet_keys::{prelude::XOnlyPublicKey, publickey::PublicKey};
@@ -25,11 +25,11 @@ impl PyPublicKey {
     ///     PublicKey: A new PublicKey instance.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid.
+    ///     ValueError: If the hex string is invalid.
     #[new]
     pub fn try_new(key: &str) -> PyResult<PyPublicKey> {
-        let public_key =
-            PublicKey::try_new(key).map_err(|err| PyException::new_err(err.to_string()))?;
+        validation::hex("key", key, None)?;
+        let public_key = PublicKey::try_new(key).map_err(|err| validation::invalid("key", err))?;
         Ok(PyPublicKey(public_key))
     }
 
@@ -144,11 +144,12 @@ impl PyXOnlyPublicKey {
     ///     XOnlyPublicKey: A new XOnlyPublicKey instance.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid.
+    ///     ValueError: If the hex string is invalid.
     #[new]
     pub fn try_new(key: &str) -> PyResult<PyXOnlyPublicKey> {
+        validation::hex("key", key, Some(64))?;
         let xonly_public_key =
-            XOnlyPublicKey::try_new(key).map_err(|err| PyException::new_err(err.to_string()))?;
+            XOnlyPublicKey::try_new(key).map_err(|err| validation::invalid("key", err))?;
         // let xonly_public_key = secp256k1::XOnlyPublicKey::from_str(key).map_err(|err| PyException::new_err(format!("{}", err)))?;
         Ok(PyXOnlyPublicKey(xonly_public_key))
     }
@@ -0,0 +1,326 @@
+use crate::{types::PyBinary, validation, wallet::bip32::phrase::PyMnemonic};
+use kaspa_bip32::{Language, Mnemonic};
+use kaspa_utils::hex::FromHex;
+use pyo3::{IntoPyObjectExt, prelude::*, types::PyBytes};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+use workflow_core::hex::ToHex;
+use zeroize::Zeroizing;
+
+/// Share format version.
+const VERSION: u8 = 1;
+
+/// Bytes before the share data: version, identifier (2), threshold, index
+/// and kind.
+const HEADER_LEN: usize = 6;
+
+/// Bytes of the secret digest appended to the secret before it is split, so
+/// it is shared with it and fewer than `threshold` shares reveal nothing
+/// about it either.
+const DIGEST_LEN: usize = 4;
+
+/// Bytes of the checksum ending each share.
+const CHECKSUM_LEN: usize = 4;
+
+/// What a share set encodes, restored by `combine_shares()`.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum SecretKind {
+    Bytes = 0,
+    /// The entropy of an English BIP-39 mnemonic.
+    Mnemonic = 1,
+}
+
+/// Exponents and logarithms of GF(2^8) with the AES polynomial (0x11b), generator 3.
+const TABLES: ([u8; 255], [u8; 256]) = {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        // x * 3 = x * 2 + x
+        let mut doubled = x << 1;
+        if doubled & 0x100 != 0 {
+            doubled ^= 0x11b;
+        }
+        x = doubled ^ x;
+        i += 1;
+    }
+    (exp, log)
+};
+
+fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let (exp, log) = &TABLES;
+    exp[(log[a as usize] as usize + log[b as usize] as usize) % 255]
+}
+
+fn div(a: u8, b: u8) -> u8 {
+    if a == 0 {
+        return 0;
+    }
+    let (exp, log) = &TABLES;
+    exp[(log[a as usize] as usize + 255 - log[b as usize] as usize) % 255]
+}
+
+/// A parsed share.
+struct Share {
+    identifier: [u8; 2],
+    threshold: u8,
+    index: u8,
+    kind: u8,
+    data: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    fn encode(&self) -> String {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(
+            HEADER_LEN + self.data.len() + CHECKSUM_LEN,
+        ));
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.identifier);
+        bytes.extend_from_slice(&[self.threshold, self.index, self.kind]);
+        bytes.extend_from_slice(&self.data);
+        let checksum = Sha256::digest(&*bytes);
+        bytes.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+        bytes.to_hex()
+    }
+
+    fn decode(share: &str) -> PyResult<Self> {
+        let bytes = Zeroizing::new(
+            Vec::<u8>::from_hex(share.trim())
+                .map_err(|_| validation::invalid("shares", "a share is not a hex string"))?,
+        );
+        if bytes.len() <= HEADER_LEN + DIGEST_LEN + CHECKSUM_LEN {
+            return Err(validation::invalid("shares", "a share is too short"));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+        if Sha256::digest(body)[..CHECKSUM_LEN] != *checksum {
+            return Err(validation::invalid(
+                "shares",
+                "a share has an invalid checksum (mistyped or damaged)",
+            ));
+        }
+        if body[0] != VERSION {
+            return Err(validation::invalid(
+                "shares",
+                format!("unsupported share version {}", body[0]),
+            ));
+        }
+        if body[3] == 0 || body[4] == 0 {
+            return Err(validation::invalid("shares", "a share is malformed"));
+        }
+        Ok(Self {
+            identifier: [body[1], body[2]],
+            threshold: body[3],
+            index: body[4],
+            kind: body[5],
+            data: Zeroizing::new(body[HEADER_LEN..].to_vec()),
+        })
+    }
+}
+
+fn secret_digest(secret: &[u8]) -> [u8; DIGEST_LEN] {
+    Sha256::digest(secret)[..DIGEST_LEN].try_into().unwrap()
+}
+
+/// The secret to split: the entropy of a mnemonic (object or phrase) or raw
+/// bytes (bytes or a hex string).
+fn extract_secret(secret: &Bound<'_, PyAny>) -> PyResult<(SecretKind, Zeroizing<Vec<u8>>)> {
+    let entropy = |mnemonic: &Mnemonic| {
+        Vec::<u8>::from_hex(&mnemonic.get_entropy())
+            .map(Zeroizing::new)
+            .map_err(|err| validation::invalid("secret", err))
+    };
+    if let Ok(mnemonic) = secret.cast::<PyMnemonic>() {
+        Ok((SecretKind::Mnemonic, entropy(mnemonic.borrow().inner())?))
+    } else if let Ok(bytes) = secret.extract::<PyBinary>() {
+        Ok((SecretKind::Bytes, Zeroizing::new(bytes.data)))
+    } else if let Ok(phrase) = secret.extract::<String>() {
+        let phrase = Zeroizing::new(phrase);
+        let mnemonic = Mnemonic::new(phrase.as_str(), Language::English)
+            .map_err(|err| validation::invalid("secret", err))?;
+        Ok((SecretKind::Mnemonic, entropy(&mnemonic)?))
+    } else {
+        Err(validation::invalid(
+            "secret",
+            "must be a Mnemonic, mnemonic phrase, bytes or hex string",
+        ))
+    }
+}
+
+/// Split a mnemonic or secret into shares, any `threshold` of which restore it.
+///
+/// Shamir's secret sharing over GF(256): each share is a point of a random
+/// polynomial of degree `threshold - 1` whose constant term is the secret, so
+/// fewer than `threshold` shares reveal nothing about it. For a mnemonic, its
+/// entropy is split, so the shares are as long as the entropy (16 to 32
+/// bytes) plus 14 bytes of metadata.
+///
+/// Shares are hex strings carrying a set identifier, the threshold, their
+/// index and a checksum (to catch typos). A digest of the secret is split
+/// along with it, to verify its reconstruction without revealing anything to
+/// holders of fewer than `threshold` shares. They are not compatible with
+/// SLIP-0039. The secret must be restored with `combine_shares()`; the
+/// mnemonic passphrase, if any, is not part of the shares.
+///
+/// Args:
+///     secret: A Mnemonic, an (English) mnemonic phrase, or the bytes of a secret (e.g. a seed) as bytes or a hex string.
+///     threshold: Number of shares needed to restore the secret (1 to `shares`).
+///     shares: Number of shares to create (at most 255).
+///
+/// Returns:
+///     list[str]: The shares, as hex strings.
+///
+/// Raises:
+///     ValueError: If the secret is invalid or empty, or the threshold or share count is out of range.
+#[gen_stub_pyfunction]
+#[pyfunction(name = "split_secret")]
+pub fn py_split_secret(
+    #[gen_stub(override_type(type_repr = "Mnemonic | str | bytes"))] secret: Bound<'_, PyAny>,
+    threshold: u8,
+    shares: u8,
+) -> PyResult<Vec<String>> {
+    let (kind, mut secret) = extract_secret(&secret)?;
+    if secret.is_empty() {
+        return Err(validation::invalid("secret", "must not be empty"));
+    }
+    if threshold == 0 || threshold > shares {
+        return Err(validation::invalid(
+            "threshold",
+            format!("must be between 1 and the number of shares ({shares})"),
+        ));
+    }
+
+    let digest = secret_digest(&secret);
+    secret.extend_from_slice(&digest);
+
+    let mut identifier = [0u8; 2];
+    OsRng.fill_bytes(&mut identifier);
+    // Coefficients of each byte's polynomial, the constant term being the secret byte.
+    let mut coefficients = Zeroizing::new(vec![0u8; secret.len() * threshold as usize]);
+    for (byte, polynomial) in secret
+        .iter()
+        .zip(coefficients.chunks_mut(threshold as usize))
+    {
+        polynomial[0] = *byte;
+        OsRng.fill_bytes(&mut polynomial[1..]);
+    }
+
+    let encoded = (1..=shares)
+        .map(|index| {
+            let data = coefficients
+                .chunks(threshold as usize)
+                .map(|polynomial| {
+                    // Horner's method, highest degree first.
+                    polynomial
+                        .iter()
+                        .rev()
+                        .fold(0, |acc, coefficient| mul(acc, index) ^ coefficient)
+                })
+                .collect();
+            Share {
+                identifier,
+                threshold,
+                index,
+                kind: kind as u8,
+                data: Zeroizing::new(data),
+            }
+            .encode()
+        })
+        .collect();
+    Ok(encoded)
+}
+
+/// Restore a secret from shares created by `split_secret()`.
+///
+/// Args:
+///     shares: At least `threshold` distinct shares of the same set, in any order.
+///
+/// Returns:
+///     Mnemonic | bytes: The Mnemonic when a mnemonic was split, else the secret bytes.
+///
+/// Raises:
+///     ValueError: If a share is malformed, the shares belong to different sets, are too few, or do not restore the secret.
+#[gen_stub_pyfunction]
+#[pyfunction(name = "combine_shares")]
+#[gen_stub(override_return_type(type_repr = "Mnemonic | bytes"))]
+pub fn py_combine_shares(
+    py: Python<'_>,
+    #[gen_stub(override_type(type_repr = "typing.Sequence[builtins.str]"))] shares: Vec<String>,
+) -> PyResult<Py<PyAny>> {
+    let mut parsed = shares
+        .iter()
+        .map(|share| Share::decode(share))
+        .collect::<PyResult<Vec<_>>>()?;
+    let Some(first) = parsed.first() else {
+        return Err(validation::invalid("shares", "no shares given"));
+    };
+    if parsed.iter().any(|share| {
+        share.identifier != first.identifier
+            || share.threshold != first.threshold
+            || share.kind != first.kind
+            || share.data.len() != first.data.len()
+    }) {
+        return Err(validation::invalid(
+            "shares",
+            "the shares belong to different sets",
+        ));
+    }
+    parsed.sort_by_key(|share| share.index);
+    parsed.dedup_by(|a, b| a.index == b.index && a.data[..] == b.data[..]);
+    if parsed.windows(2).any(|pair| pair[0].index == pair[1].index) {
+        return Err(validation::invalid(
+            "shares",
+            "two different shares have the same index",
+        ));
+    }
+    let threshold = parsed[0].threshold as usize;
+    if parsed.len() < threshold {
+        return Err(validation::invalid(
+            "shares",
+            format!(
+                "{} distinct share(s) given, {threshold} needed",
+                parsed.len()
+            ),
+        ));
+    }
+
+    // Lagrange interpolation at x = 0.
+    let points = &parsed[..threshold];
+    let mut secret = Zeroizing::new(vec![0u8; points[0].data.len()]);
+    for (i, share) in points.iter().enumerate() {
+        let basis = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(1, |acc, (_, other)| {
+                mul(acc, div(other.index, other.index ^ share.index))
+            });
+        for (byte, value) in secret.iter_mut().zip(share.data.iter()) {
+            *byte ^= mul(basis, *value);
+        }
+    }
+    let (secret, digest) = secret.split_at(secret.len() - DIGEST_LEN);
+    if secret_digest(secret) != *digest {
+        return Err(validation::invalid(
+            "shares",
+            "the shares do not restore the secret (a share is wrong)",
+        ));
+    }
+
+    if parsed[0].kind == SecretKind::Mnemonic as u8 {
+        // Wiped if dropped before `from_entropy()` takes it over.
+        let mut entropy = Zeroizing::new(secret.to_vec());
+        let mnemonic = Mnemonic::from_entropy(std::mem::take(&mut *entropy), Language::English)
+            .map_err(|err| validation::invalid("shares", err))?;
+        PyMnemonic::from(mnemonic).into_py_any(py)
+    } else {
+        Ok(PyBytes::new(py, secret).into_any().unbind())
+    }
+}
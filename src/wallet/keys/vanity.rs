@@ -0,0 +1,305 @@
+use super::keypair::PyKeypair;
+use crate::{consensus::core::network::PyNetworkType, validation};
+use kaspa_addresses::{Prefix, Version};
+use kaspa_consensus_core::network::NetworkType;
+use pyo3::{prelude::*, types::PyDict};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The bech32 alphabet, by 5-bit value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Keys each worker tries between updates of the shared attempt counter.
+const BATCH: u64 = 4096;
+
+/// Address payload characters (version byte and 32-byte key) before the checksum.
+const PAYLOAD_CHARS: usize = (33 * 8) / 5;
+
+/// The secret key 1, whose public key is the generator point.
+const ONE: [u8; 32] = {
+    let mut one = [0; 32];
+    one[31] = 1;
+    one
+};
+
+/// State shared between the workers and the calling thread.
+struct Search {
+    pattern: Vec<u8>,
+    version: Version,
+    stop: AtomicBool,
+    attempts: AtomicU64,
+    found: Mutex<Option<SecretKey>>,
+}
+
+impl Search {
+    /// Try consecutive keys from a random one until a match is found or the search stops.
+    fn run(&self) {
+        let secp = Secp256k1::new();
+        let generator = PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&ONE).unwrap());
+        let (mut secret_key, mut public_key) = secp.generate_keypair(&mut rand::thread_rng());
+        while !self.stop.load(Ordering::Relaxed) {
+            for _ in 0..BATCH {
+                if self.matches(&public_key) {
+                    self.stop.store(true, Ordering::Relaxed);
+                    self.found.lock().unwrap().get_or_insert(secret_key);
+                    return;
+                }
+                // k + 1 pairs with P + G, a point addition instead of a multiplication.
+                secret_key = secret_key.add_tweak(&Scalar::ONE).unwrap();
+                public_key = public_key.combine(&generator).unwrap();
+            }
+            self.attempts.fetch_add(BATCH, Ordering::Relaxed);
+        }
+    }
+
+    fn matches(&self, public_key: &PublicKey) -> bool {
+        match self.version {
+            Version::PubKeyECDSA => matches_payload(&self.pattern, 1, &public_key.serialize()),
+            _ => matches_payload(
+                &self.pattern,
+                0,
+                &public_key.x_only_public_key().0.serialize(),
+            ),
+        }
+    }
+}
+
+/// Whether the address payload of `version` and `payload` starts with `pattern`
+/// (5-bit values), computing only the characters compared.
+fn matches_payload(pattern: &[u8], version: u8, payload: &[u8]) -> bool {
+    let mut acc = version as u32;
+    let mut bits = 8;
+    let mut bytes = payload.iter();
+    for expected in pattern {
+        if bits < 5 {
+            acc = (acc << 8) | *bytes.next().unwrap_or(&0) as u32;
+            bits += 8;
+        }
+        bits -= 5;
+        if ((acc >> bits) & 31) as u8 != *expected {
+            return false;
+        }
+    }
+    true
+}
+
+/// Leading address payload bits fixed by the address version: the version
+/// byte, and for ECDSA the compressed key's 0x02/0x03 tag up to its parity bit.
+fn fixed_bits(version: Version) -> Vec<u8> {
+    let version = version as u8;
+    let mut bits = (0..8)
+        .rev()
+        .map(|bit| (version >> bit) & 1)
+        .collect::<Vec<_>>();
+    if version == Version::PubKeyECDSA as u8 {
+        bits.extend([0, 0, 0, 0, 0, 0, 1]);
+    }
+    bits
+}
+
+/// The 5-bit values of a pattern, checked against the address format.
+fn parse_pattern(pattern: &str, prefix: Prefix, version: Version) -> PyResult<Vec<u8>> {
+    let lowercase = pattern.to_lowercase();
+    let payload = match lowercase.split_once(':') {
+        Some((given, payload)) if given == prefix.to_string() => payload,
+        Some((given, _)) => {
+            return Err(validation::invalid(
+                "prefix_pattern",
+                format!("prefix `{given}` does not match the network prefix `{prefix}`"),
+            ));
+        }
+        None => lowercase.as_str(),
+    };
+    let values = payload
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&v| v == c).map(|v| v as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            validation::invalid(
+                "prefix_pattern",
+                format!("`{pattern}` contains characters outside the bech32 alphabet `qpzry9x8gf2tvdw0s3jn54khce6mua7l`"),
+            )
+        })?;
+    if values.len() > PAYLOAD_CHARS {
+        return Err(validation::invalid(
+            "prefix_pattern",
+            format!("must be at most {PAYLOAD_CHARS} characters"),
+        ));
+    }
+    let fixed = fixed_bits(version);
+    let conflicts = values.iter().enumerate().any(|(index, value)| {
+        (0..5).any(|bit| {
+            fixed
+                .get(index * 5 + bit)
+                .is_some_and(|&fixed| fixed != (value >> (4 - bit)) & 1)
+        })
+    });
+    if conflicts {
+        let start = fixed
+            .chunks_exact(5)
+            .map(|bits| CHARSET[bits.iter().fold(0, |acc, bit| (acc << 1) | bit) as usize] as char)
+            .collect::<String>();
+        return Err(validation::invalid(
+            "prefix_pattern",
+            format!(
+                "`{pattern}` cannot start an address of this version, which starts with `{start}`"
+            ),
+        ));
+    }
+    Ok(values)
+}
+
+/// Generate a keypair whose address starts with a pattern (a vanity address).
+///
+/// Keys are searched on `threads` threads with the GIL released, so other
+/// Python threads keep running. Each character multiplies the expected
+/// number of attempts by 32, except for the leading ones fixed by the address
+/// version: Schnorr addresses start with `q` followed by one of `qpzr`
+/// (about 2^(5n-8) attempts for n characters), ECDSA addresses with `qyp`
+/// (about 2^(5n-15)).
+///
+/// With `progress`, the callback is invoked every `progress_interval` seconds
+/// with a dict: `attempts` (keys tried so far), `elapsed` (seconds), `rate`
+/// (keys per second) and `expected` (expected total attempts). Returning
+/// False from the callback cancels the search; an exception raised by it
+/// aborts the search and is re-raised. Ctrl-C (KeyboardInterrupt) also stops
+/// the workers.
+///
+/// Args:
+///     prefix_pattern: The leading address characters, with or without the network prefix (e.g. "qqkas" or "kaspa:qqkas").
+///     network: The network type for address encoding (default: "mainnet").
+///     threads: Number of worker threads (default: the number of CPUs).
+///     progress: Optional callback receiving progress dicts.
+///     progress_interval: Seconds between progress callbacks (default: 1).
+///     timeout: Seconds after which the search gives up (default: none).
+///     ecdsa: Search ECDSA instead of Schnorr addresses (default: False).
+///
+/// Returns:
+///     Keypair | None: The keypair (its address is `to_address(network)`, or `to_address_ecdsa(network)` with `ecdsa`), None if cancelled or timed out.
+///
+/// Raises:
+///     ValueError: If the pattern cannot appear in an address, `threads` or `progress_interval` is not positive, or `timeout` is negative.
+///     KeyboardInterrupt: If interrupted.
+#[gen_stub_pyfunction]
+#[pyfunction(name = "generate_vanity_address")]
+#[pyo3(signature = (prefix_pattern, network=None, threads=None, progress=None, progress_interval=1.0, timeout=None, ecdsa=false))]
+#[allow(clippy::too_many_arguments)]
+pub fn py_generate_vanity_address(
+    py: Python<'_>,
+    prefix_pattern: &str,
+    #[gen_stub(override_type(type_repr = "typing.Optional[str | NetworkType]"))] network: Option<
+        PyNetworkType,
+    >,
+    threads: Option<usize>,
+    #[gen_stub(override_type(
+        type_repr = "typing.Optional[typing.Callable[[dict], bool | None]]"
+    ))]
+    progress: Option<Py<PyAny>>,
+    progress_interval: f64,
+    timeout: Option<f64>,
+    ecdsa: bool,
+) -> PyResult<Option<PyKeypair>> {
+    let version = if ecdsa {
+        Version::PubKeyECDSA
+    } else {
+        Version::PubKey
+    };
+    let prefix = Prefix::from(network.map_or(NetworkType::Mainnet, NetworkType::from));
+    let pattern = parse_pattern(prefix_pattern, prefix, version)?;
+    let threads = match threads {
+        Some(0) => return Err(validation::invalid("threads", "must be positive")),
+        Some(threads) => threads,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let progress_interval = Duration::try_from_secs_f64(progress_interval)
+        .ok()
+        .filter(|interval| !interval.is_zero())
+        .ok_or_else(|| validation::invalid("progress_interval", "must be positive"))?;
+    let timeout = timeout
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .map_err(|_| validation::invalid("timeout", "must not be negative"))?;
+    let free_bits = (5 * pattern.len()).saturating_sub(fixed_bits(version).len());
+    let expected = 2f64.powi(free_bits as i32);
+
+    let search = Arc::new(Search {
+        pattern,
+        version,
+        stop: AtomicBool::new(false),
+        attempts: AtomicU64::new(0),
+        found: Mutex::new(None),
+    });
+    let workers = (0..threads)
+        .map(|_| {
+            let search = search.clone();
+            thread::spawn(move || search.run())
+        })
+        .collect::<Vec<_>>();
+
+    let started = Instant::now();
+    let deadline = timeout.and_then(|timeout| started.checked_add(timeout));
+    let result = loop {
+        let wait = progress_interval.min(deadline.map_or(Duration::MAX, |deadline| {
+            deadline.saturating_duration_since(Instant::now())
+        }));
+        py.detach(|| {
+            // Wake up early when a worker finds a match.
+            let until = Instant::now() + wait;
+            while !search.stop.load(Ordering::Relaxed) && Instant::now() < until {
+                thread::sleep(
+                    Duration::from_millis(10).min(until.saturating_duration_since(Instant::now())),
+                );
+            }
+        });
+
+        if let Some(secret_key) = *search.found.lock().unwrap() {
+            break Ok(Some(PyKeypair::from_secret_key(secret_key)));
+        }
+        if let Err(err) = py.check_signals() {
+            break Err(err);
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            break Ok(None);
+        }
+        if let Some(progress) = &progress {
+            let attempts = search.attempts.load(Ordering::Relaxed);
+            match report_progress(py, progress, attempts, started.elapsed(), expected) {
+                Ok(true) => {}
+                Ok(false) => break Ok(None),
+                Err(err) => break Err(err),
+            }
+        }
+    };
+
+    search.stop.store(true, Ordering::Relaxed);
+    py.detach(|| {
+        for worker in workers {
+            worker.join().ok();
+        }
+    });
+    result
+}
+
+/// Call the progress callback, returning whether to continue.
+fn report_progress(
+    py: Python<'_>,
+    progress: &Py<PyAny>,
+    attempts: u64,
+    elapsed: Duration,
+    expected: f64,
+) -> PyResult<bool> {
+    let elapsed = elapsed.as_secs_f64();
+    let event = PyDict::new(py);
+    event.set_item("attempts", attempts)?;
+    event.set_item("elapsed", elapsed)?;
+    event.set_item("rate", attempts as f64 / elapsed)?;
+    event.set_item("expected", expected)?;
+    let result = progress.call1(py, (event,))?;
+    Ok(!matches!(result.extract::<bool>(py), Ok(false)))
+}
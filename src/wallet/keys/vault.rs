@@ -0,0 +1,288 @@
+use crate::{validation, wallet::keys::privatekey::PyPrivateKey};
+use kaspa_wallet_core::{
+    encryption::{decrypt_xchacha20poly1305, encrypt_xchacha20poly1305},
+    secret::Secret,
+};
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use secp256k1::{Keypair, SECP256K1, SecretKey};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use workflow_core::hex::ToHex;
+use zeroize::{Zeroize, Zeroizing};
+
+struct VaultEntry {
+    encrypted: Vec<u8>,
+    /// The decrypted key and the instant it locks again, if unlocked.
+    unlocked: Option<(Zeroizing<[u8; 32]>, Option<Instant>)>,
+}
+
+impl VaultEntry {
+    fn unlocked_key(&mut self) -> Option<[u8; 32]> {
+        if let Some((_, Some(expiry))) = &self.unlocked
+            && *expiry <= Instant::now()
+        {
+            self.unlocked = None;
+        }
+        self.unlocked.as_ref().map(|(key, _)| **key)
+    }
+}
+
+type Entries = Arc<Mutex<HashMap<String, VaultEntry>>>;
+
+/// An in-memory store of private keys encrypted at rest.
+///
+/// Imported keys are encrypted with a secret (XChaCha20-Poly1305, key derived
+/// with Argon2) and only decrypted for a signing operation, or while unlocked
+/// with `unlock()` for at most `ttl` seconds. Signing APIs accept `KeyHandle`s
+/// from `key()` in place of `PrivateKey`s, so long-running processes do not keep
+/// raw keys around.
+#[gen_stub_pyclass]
+#[pyclass(name = "KeyVault")]
+pub struct PyKeyVault(Entries);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyKeyVault {
+    /// Create an empty vault.
+    #[new]
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(HashMap::new())))
+    }
+
+    /// The ids of the stored keys.
+    #[getter]
+    pub fn get_ids(&self) -> Vec<String> {
+        let mut ids = self.0.lock().unwrap().keys().cloned().collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    /// Encrypt and store a private key.
+    ///
+    /// Args:
+    ///     private_key: The key to store.
+    ///     secret: The secret encrypting the key.
+    ///
+    /// Returns:
+    ///     str: The key id, its x-only public key as a hex string.
+    ///
+    /// Raises:
+    ///     ValueError: If `secret` is empty.
+    pub fn import_key(
+        &self,
+        py: Python<'_>,
+        private_key: &PyPrivateKey,
+        secret: &str,
+    ) -> PyResult<String> {
+        if secret.is_empty() {
+            return Err(validation::invalid("secret", "must not be empty"));
+        }
+        let mut key = private_key.secret_bytes();
+        let id = key_id(&key);
+        let secret = Secret::new(secret.as_bytes().to_vec());
+        let encrypted = py.detach(|| encrypt_xchacha20poly1305(&key, &secret));
+        key.zeroize();
+        let encrypted = encrypted.map_err(|err| PyException::new_err(err.to_string()))?;
+        self.0.lock().unwrap().insert(
+            id.clone(),
+            VaultEntry {
+                encrypted,
+                unlocked: None,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Remove a key from the vault.
+    ///
+    /// Returns:
+    ///     bool: True if the key was stored.
+    pub fn remove(&self, id: &str) -> bool {
+        self.0.lock().unwrap().remove(id).is_some()
+    }
+
+    /// Keep a key decrypted so handles without a secret can sign with it.
+    ///
+    /// Args:
+    ///     id: The key id.
+    ///     secret: The secret the key was imported with.
+    ///     ttl: Seconds until the key locks again (default: until `lock()`).
+    ///
+    /// Raises:
+    ///     ValueError: If the id is unknown, the secret is wrong or `ttl` is not positive.
+    #[pyo3(signature = (id, secret, ttl=None))]
+    pub fn unlock(&self, py: Python<'_>, id: &str, secret: &str, ttl: Option<f64>) -> PyResult<()> {
+        let expiry = match ttl {
+            Some(ttl) if !ttl.is_finite() || ttl <= 0.0 => {
+                return Err(validation::invalid(
+                    "ttl",
+                    "must be a positive number of seconds",
+                ));
+            }
+            Some(ttl) => Some(Instant::now() + Duration::from_secs_f64(ttl)),
+            None => None,
+        };
+        let key = decrypt(py, &self.0, id, secret)?;
+        if let Some(entry) = self.0.lock().unwrap().get_mut(id) {
+            entry.unlocked = Some((key, expiry));
+        }
+        Ok(())
+    }
+
+    /// Lock a key, or all keys, erasing their decrypted copies.
+    #[pyo3(signature = (id=None))]
+    pub fn lock(&self, id: Option<&str>) {
+        let mut entries = self.0.lock().unwrap();
+        match id {
+            Some(id) => {
+                if let Some(entry) = entries.get_mut(id) {
+                    entry.unlocked = None;
+                }
+            }
+            None => entries.values_mut().for_each(|entry| entry.unlocked = None),
+        }
+    }
+
+    /// Whether a key is unlocked.
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .get_mut(id)
+            .is_some_and(|entry| entry.unlocked_key().is_some())
+    }
+
+    /// A handle signing with a stored key, accepted by signing APIs in place of a `PrivateKey`.
+    ///
+    /// Args:
+    ///     id: The key id.
+    ///     secret: Decrypt the key with this secret for each operation. Without
+    ///         it, the key must be unlocked when signing.
+    ///
+    /// Returns:
+    ///     KeyHandle: The handle.
+    ///
+    /// Raises:
+    ///     ValueError: If the id is unknown.
+    #[pyo3(signature = (id, secret=None))]
+    pub fn key(&self, id: &str, secret: Option<String>) -> PyResult<PyKeyHandle> {
+        if !self.0.lock().unwrap().contains_key(id) {
+            return Err(unknown_id(id));
+        }
+        Ok(PyKeyHandle {
+            entries: self.0.clone(),
+            id: id.to_string(),
+            secret: secret.map(Zeroizing::new),
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    fn __contains__(&self, id: &str) -> bool {
+        self.0.lock().unwrap().contains_key(id)
+    }
+}
+
+impl Default for PyKeyVault {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A reference to a key stored in a `KeyVault`.
+#[gen_stub_pyclass]
+#[pyclass(name = "KeyHandle")]
+pub struct PyKeyHandle {
+    entries: Entries,
+    id: String,
+    secret: Option<Zeroizing<String>>,
+}
+
+impl PyKeyHandle {
+    /// The decrypted key: the unlocked copy, or decrypted with the handle's secret.
+    pub fn secret_bytes(&self, py: Python<'_>) -> PyResult<[u8; 32]> {
+        let unlocked = match self.entries.lock().unwrap().get_mut(&self.id) {
+            Some(entry) => entry.unlocked_key(),
+            None => return Err(unknown_id(&self.id)),
+        };
+        if let Some(key) = unlocked {
+            return Ok(key);
+        }
+        match &self.secret {
+            Some(secret) => Ok(*decrypt(py, &self.entries, &self.id, secret)?),
+            None => Err(PyException::new_err(format!(
+                "Key `{}` is locked; unlock it or pass its secret",
+                self.id
+            ))),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyKeyHandle {
+    /// The key id, its x-only public key as a hex string.
+    #[getter]
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("KeyHandle({})", self.id)
+    }
+}
+
+/// The secret bytes of a `PrivateKey` or `KeyHandle`, for signing APIs accepting
+/// either. They are zeroized when dropped, including on early returns.
+pub(crate) fn signing_key(key: &Bound<'_, PyAny>) -> PyResult<Zeroizing<[u8; 32]>> {
+    if let Ok(private_key) = key.cast::<PyPrivateKey>() {
+        Ok(Zeroizing::new(private_key.borrow().secret_bytes()))
+    } else if let Ok(handle) = key.cast::<PyKeyHandle>() {
+        handle.borrow().secret_bytes(key.py()).map(Zeroizing::new)
+    } else {
+        Err(PyException::new_err("expected a PrivateKey or KeyHandle"))
+    }
+}
+
+fn decrypt(
+    py: Python<'_>,
+    entries: &Entries,
+    id: &str,
+    secret: &str,
+) -> PyResult<Zeroizing<[u8; 32]>> {
+    let encrypted = match entries.lock().unwrap().get(id) {
+        Some(entry) => entry.encrypted.clone(),
+        None => return Err(unknown_id(id)),
+    };
+    let secret = Secret::new(secret.as_bytes().to_vec());
+    let decrypted = py
+        .detach(|| decrypt_xchacha20poly1305(&encrypted, &secret))
+        .map_err(|_| validation::invalid("secret", format!("cannot decrypt key `{id}`")))?;
+    let key: [u8; 32] = decrypted
+        .as_ref()
+        .try_into()
+        .map_err(|_| PyException::new_err(format!("Key `{id}` is corrupted")))?;
+    Ok(Zeroizing::new(key))
+}
+
+fn key_id(key: &[u8; 32]) -> String {
+    SecretKey::from_slice(key)
+        .map(|secret_key| {
+            Keypair::from_secret_key(SECP256K1, &secret_key)
+                .x_only_public_key()
+                .0
+                .serialize()
+                .to_hex()
+        })
+        .unwrap_or_default()
+}
+
+fn unknown_id(id: &str) -> PyErr {
+    validation::invalid("id", format!("no key `{id}` in the vault"))
+}
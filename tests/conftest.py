@@ -2,6 +2,9 @@
 Shared fixtures for Kaspa Python SDK tests.
 """
 
+import asyncio
+import os
+
 import pytest
 import pytest_asyncio
 
@@ -14,6 +17,9 @@ from kaspa import (
     Address,
     RpcClient,
     Resolver,
+    NetworkId,
+    UtxoContext,
+    UtxoProcessor,
 )
 
 
@@ -92,6 +98,64 @@ def known_mainnet_address() -> Address:
     return Address(TEST_MAINNET_ADDRESS)
 
 
+# =============================================================================
+# UtxoContext Fixtures
+# =============================================================================
+
+@pytest.fixture
+def make_context():
+    """
+    Return a factory of UtxoContexts on a new, unstarted UtxoProcessor.
+
+    The factory takes the network id and an optional context id.
+    """
+    def factory(network_id="testnet-10", id=None):
+        client = RpcClient(resolver=Resolver(), network_id=network_id)
+        processor = UtxoProcessor(client, NetworkId(network_id))
+        return UtxoContext(processor, id) if id else UtxoContext(processor)
+
+    return factory
+
+
+@pytest.fixture
+def set_maturity_periods():
+    """
+    Return a function setting the coinbase and user transaction maturity
+    periods (in DAA) of a network. The previous periods are restored after the test.
+    """
+    saved = []
+
+    def set_periods(network_id, coinbase, user):
+        saved.append((
+            network_id,
+            UtxoProcessor.get_coinbase_transaction_maturity_daa(network_id),
+            UtxoProcessor.get_user_transaction_maturity_daa(network_id),
+        ))
+        UtxoProcessor.set_coinbase_transaction_maturity_daa(network_id, coinbase)
+        UtxoProcessor.set_user_transaction_maturity_daa(network_id, user)
+
+    yield set_periods
+
+    for network_id, coinbase, user in reversed(saved):
+        UtxoProcessor.set_coinbase_transaction_maturity_daa(network_id, coinbase)
+        UtxoProcessor.set_user_transaction_maturity_daa(network_id, user)
+
+
+# =============================================================================
+# Event Loop Fixtures
+# =============================================================================
+
+@pytest.fixture(scope="session")
+def event_loop_policy():
+    """
+    Event loop policy of async tests: uvloop's if KASPA_TEST_EVENT_LOOP=uvloop.
+    """
+    if os.environ.get("KASPA_TEST_EVENT_LOOP") == "uvloop":
+        uvloop = pytest.importorskip("uvloop")
+        return uvloop.EventLoopPolicy()
+    return asyncio.DefaultEventLoopPolicy()
+
+
 # =============================================================================
 # Integration Test Fixtures (Network Required)
 # =============================================================================
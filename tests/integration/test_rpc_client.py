@@ -6,13 +6,20 @@ These tests require network access and connect to the Kaspa testnet.
 
 import pytest
 import asyncio
+import json
+import time
 
 from kaspa import (
+    MiningJob,
     RpcClient,
     Resolver,
     Address,
     Encoding,
+    PaymentOutput,
+    UtxoEntryReference,
+    create_transaction,
 )
+from kaspa.exceptions import TransactionRejectedError
 
 
 class TestResolver:
@@ -50,6 +57,18 @@ class TestResolver:
         node = await resolver.get_node(encoding, "testnet-10")
         assert isinstance(node, dict)
 
+    async def test_resolver_tls_with_default_urls(self):
+        """Test tls=True selects wss:// nodes without custom URLs."""
+        resolver = Resolver(tls=True)
+        assert resolver.urls() == Resolver().urls()
+        url = await resolver.get_url("borsh", "testnet-10")
+        assert url.startswith("wss://")
+
+    async def test_resolver_rejects_empty_urls(self):
+        """Test an empty URL list is rejected."""
+        with pytest.raises(ValueError):
+            Resolver(urls=[])
+
 
 class TestRpcClientConnection:
     """Tests for RPC client connection functionality."""
@@ -120,6 +139,12 @@ class TestRpcClientCalls:
         result = await testnet_rpc_client.get_coin_supply()
         assert isinstance(result, dict)
 
+    async def test_coin_supply(self, testnet_rpc_client):
+        """Test coin_supply returns a CoinSupply."""
+        supply = await testnet_rpc_client.coin_supply()
+        assert 0 < supply.circulating <= supply.max_supply
+        assert isinstance(supply.circulating_kas, str)
+
     async def test_get_sink(self, testnet_rpc_client):
         """Test get_sink RPC call."""
         result = await testnet_rpc_client.get_sink()
@@ -212,3 +237,353 @@ class TestRpcClientCalls:
             "filterTransactionPool": False
         })
         assert isinstance(result, dict)
+
+
+class RecordingMiddleware:
+    def __init__(self):
+        self.calls = []
+
+    def on_request(self, method, request):
+        self.calls.append(("request", method))
+
+    def on_response(self, method, response, latency):
+        self.calls.append(("response", method, latency))
+        return {**response, "observed": True}
+
+
+class TestRpcClientMiddleware:
+    """Tests for RPC client middleware."""
+
+    async def test_middleware_observes_and_modifies(self, testnet_rpc_client):
+        """Test middleware sees the call and can replace the response."""
+        middleware = RecordingMiddleware()
+        testnet_rpc_client.add_middleware(middleware)
+        try:
+            result = await testnet_rpc_client.get_block_count()
+        finally:
+            testnet_rpc_client.remove_middleware(middleware)
+
+        assert result["observed"] is True
+        assert middleware.calls[0] == ("request", "get_block_count")
+        assert middleware.calls[1][:2] == ("response", "get_block_count")
+        assert middleware.calls[1][2] >= 0
+
+    async def test_removed_middleware_is_not_called(self, testnet_rpc_client):
+        """Test a removed middleware is no longer invoked."""
+        middleware = RecordingMiddleware()
+        testnet_rpc_client.add_middleware(middleware)
+        testnet_rpc_client.remove_middleware()
+
+        result = await testnet_rpc_client.get_block_count()
+
+        assert "observed" not in result
+        assert middleware.calls == []
+
+
+class TestRpcClientCache:
+    """Tests for the RPC client response cache."""
+
+    async def test_get_block_is_cached(self, testnet_rpc_client):
+        """Test a second get_block for the same hash is served from the cache."""
+        sink = (await testnet_rpc_client.get_sink())["sink"]
+        request = {"hash": sink, "includeTransactions": False}
+
+        testnet_rpc_client.enable_cache(size=8)
+        try:
+            assert testnet_rpc_client.cache_size == 0
+            first = await testnet_rpc_client.get_block(request)
+            assert testnet_rpc_client.cache_size == 1
+
+            # Disconnected, only the cache can answer.
+            await testnet_rpc_client.disconnect()
+            try:
+                second = await testnet_rpc_client.get_block(request)
+            finally:
+                await testnet_rpc_client.connect()
+            assert second == first
+            assert testnet_rpc_client.cache_size == 1
+            # The changing verbose data of the block is not cached.
+            assert first["block"].get("verboseData") is None
+
+            testnet_rpc_client.clear_cache()
+            assert testnet_rpc_client.cache_size == 0
+        finally:
+            testnet_rpc_client.disable_cache()
+
+        assert testnet_rpc_client.cache_size is None
+
+    async def test_enable_cache_rejects_negative_ttl(self, testnet_rpc_client):
+        """Test a negative ttl raises."""
+        with pytest.raises(Exception):
+            testnet_rpc_client.enable_cache(ttl=-1.0)
+
+
+
+class TestRpcClientKeepAlive:
+    """Tests for keep-alive pings and latency."""
+
+    async def test_ping_records_latency(self, testnet_rpc_client):
+        """Test ping() updates latency()."""
+        await testnet_rpc_client.ping()
+        latency = testnet_rpc_client.latency()
+        assert latency is not None and latency > 0
+
+    async def test_keep_alive_pings(self):
+        """Test keep-alive pings record latency without stale events."""
+        client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+        await client.connect()
+        stale = []
+        client.add_event_listener("stale-connection", stale.append)
+        try:
+            assert client.latency() is None
+            client.set_keep_alive(interval=0.2, timeout=5)
+            await asyncio.sleep(1.5)
+            assert client.latency() is not None
+            assert stale == []
+        finally:
+            client.set_keep_alive(None)
+            await client.disconnect()
+
+    async def test_set_keep_alive_rejects_non_positive(self, testnet_rpc_client):
+        """Test a zero or negative interval raises."""
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.set_keep_alive(interval=0)
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.set_keep_alive(interval=1, timeout=-1)
+
+
+class TestRpcClientCall:
+    """Tests for calling RPC methods by name."""
+
+    async def test_call_by_name(self, testnet_rpc_client):
+        """Test snake, camel and Pascal case method names."""
+        for method in ("get_block_dag_info", "getBlockDagInfo", "GetBlockDagInfo"):
+            response = await testnet_rpc_client.call(method)
+            assert "virtualDaaScore" in response
+
+    async def test_call_with_request(self, testnet_rpc_client):
+        """Test request parameters are passed to the method."""
+        sink = (await testnet_rpc_client.get_sink())["sink"]
+        request = {"hash": sink, "includeTransactions": False}
+        assert await testnet_rpc_client.call("getBlock", request) == await testnet_rpc_client.get_block(request)
+
+    async def test_call_converts_wire_format(self, testnet_rpc_client):
+        """Test requests are converted as in the wRPC protocol."""
+        request = {"address": TESTNET_ADDRESS}
+        response = await testnet_rpc_client.call("getBalanceByAddress", request)
+        assert response == await testnet_rpc_client.get_balance_by_address(request)
+
+    async def test_call_unknown_method_raises(self, testnet_rpc_client):
+        """Test unknown and non-RPC method names raise."""
+        for method in ("getNotAMethod", "disconnect", "set_rate_limit"):
+            with pytest.raises(Exception, match="Unknown RPC method"):
+                testnet_rpc_client.call(method)
+
+
+class TestRpcClientRateLimit:
+    """Tests for client-side rate limiting."""
+
+    async def test_rate_limit_spaces_requests(self, testnet_rpc_client):
+        """Test calls over the rate are delayed."""
+        testnet_rpc_client.set_rate_limit(rate=10, burst=1)
+        try:
+            started = time.monotonic()
+            await asyncio.gather(*(testnet_rpc_client.get_block_count() for _ in range(6)))
+            assert time.monotonic() - started >= 0.45
+            assert testnet_rpc_client.queued_requests == 0
+        finally:
+            testnet_rpc_client.set_rate_limit()
+
+    async def test_max_in_flight(self, testnet_rpc_client):
+        """Test concurrent calls complete with a single request in flight."""
+        testnet_rpc_client.set_rate_limit(max_in_flight=1)
+        try:
+            results = await asyncio.gather(*(testnet_rpc_client.get_server_info() for _ in range(5)))
+            assert len(results) == 5
+        finally:
+            testnet_rpc_client.set_rate_limit()
+
+    async def test_set_rate_limit_rejects_non_positive(self, testnet_rpc_client):
+        """Test zero or negative limits raise."""
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.set_rate_limit(rate=0)
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.set_rate_limit(rate=1, burst=0)
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.set_rate_limit(max_in_flight=0)
+
+    async def test_timed_out_requests_are_cancelled(self, testnet_rpc_client):
+        """Test a timed-out request is cancelled and releases its slot."""
+        testnet_rpc_client.set_rate_limit(max_in_flight=1)
+        try:
+            abandoned = testnet_rpc_client.abandoned_requests
+            with pytest.raises(asyncio.TimeoutError):
+                await asyncio.wait_for(testnet_rpc_client.get_block_dag_info(), timeout=0.001)
+            assert testnet_rpc_client.abandoned_requests <= abandoned + 1
+            # The slot is free at once: the next call does not wait for the node
+            # to answer the cancelled request.
+            assert testnet_rpc_client.queued_requests == 0
+            assert "virtualDaaScore" in await testnet_rpc_client.get_block_dag_info()
+        finally:
+            testnet_rpc_client.set_rate_limit()
+
+TESTNET_ADDRESS = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+
+
+class TestRpcClientBlockTemplates:
+    """Tests for the block template iterator."""
+
+    async def test_block_templates(self, testnet_rpc_client):
+        """Test templates are yielded with their age."""
+        templates = testnet_rpc_client.block_templates(TESTNET_ADDRESS, extra_data=b"test")
+        template = await templates.__anext__()
+        assert "header" in template.block
+        assert isinstance(template.is_synced, bool)
+        assert template.age >= 0
+
+        following = await asyncio.wait_for(templates.__anext__(), timeout=30)
+        assert following.block["header"] != template.block["header"]
+
+    async def test_mining_job(self, testnet_rpc_client):
+        """Test building a mining job from a template and checking nonces."""
+        template = await testnet_rpc_client.block_templates(TESTNET_ADDRESS).__anext__()
+        job = MiningJob(template)
+        assert len(job.pre_pow_hash) == 64
+        assert len(job.pre_pow_hash_words) == 4
+        assert job.target > 0
+        assert job.timestamp == template.block["header"]["timestamp"]
+
+        assert job.check_nonce(1, target=2**256 - 1)
+        assert not job.check_nonce(1, target=0)
+        assert job.check_nonce(1) == (job.pow_value(1) <= job.target)
+        assert job.block(42)["header"]["nonce"] == 42
+
+        response = await testnet_rpc_client.get_block_template({"payAddress": TESTNET_ADDRESS, "extraData": []})
+        assert MiningJob(response).bits == response["block"]["header"]["bits"]
+
+    def test_block_templates_invalid_arguments(self, testnet_rpc_client):
+        """Test an invalid address or poll interval raises."""
+        with pytest.raises(Exception):
+            testnet_rpc_client.block_templates("not-an-address")
+        with pytest.raises(Exception, match="must be positive"):
+            testnet_rpc_client.block_templates(TESTNET_ADDRESS, poll_interval=0)
+
+
+class TestRpcClientDagWalk:
+    """Tests for the DAG traversal iterators."""
+
+    async def test_walk_selected_parent_chain(self, testnet_rpc_client):
+        """Test the chain is walked through selected parents up to the limit."""
+        sink = (await testnet_rpc_client.get_sink())["sink"]
+        headers = [header async for header in testnet_rpc_client.walk_selected_parent_chain(sink, 5)]
+        assert len(headers) == 5
+        assert headers[0]["hash"] == sink
+        blue_scores = [header["blueScore"] for header in headers]
+        assert blue_scores == sorted(blue_scores, reverse=True)
+
+    async def test_get_block_ancestors(self, testnet_rpc_client):
+        """Test ancestors are walked breadth-first without duplicates."""
+        sink = (await testnet_rpc_client.get_sink())["sink"]
+        only_sink = [header async for header in testnet_rpc_client.get_block_ancestors(sink, 0)]
+        assert [header["hash"] for header in only_sink] == [sink]
+
+        headers = [header async for header in testnet_rpc_client.get_block_ancestors(sink, 2)]
+        hashes = [header["hash"] for header in headers]
+        assert len(hashes) == len(set(hashes))
+        assert set(headers[0]["parentsByLevel"][0]) <= set(hashes)
+
+    def test_invalid_hash(self, testnet_rpc_client):
+        """Test an invalid starting hash raises."""
+        with pytest.raises(Exception):
+            testnet_rpc_client.walk_selected_parent_chain("not-a-hash", 1)
+
+
+class TestRpcClientUtxoSnapshot:
+    """Tests for streaming UTXO snapshots."""
+
+    async def test_stream_utxos_by_addresses(self, testnet_rpc_client):
+        """Test chunks are JSON Lines matching get_utxos_by_addresses()."""
+        addresses = [Address(TESTNET_ADDRESS)]
+        chunks = [chunk async for chunk in testnet_rpc_client.stream_utxos_by_addresses(addresses, batch_size=1)]
+        assert len(chunks) == 1
+        entries = [json.loads(line) for line in chunks[0].splitlines()]
+        for entry in entries:
+            assert entry["address"] == TESTNET_ADDRESS
+            assert {"outpoint", "utxoEntry"} <= entry.keys()
+
+    async def test_dump_utxos_by_addresses(self, testnet_rpc_client, tmp_path):
+        """Test the snapshot file holds one entry per line."""
+        path = tmp_path / "utxos.jsonl"
+        summary = await testnet_rpc_client.dump_utxos_by_addresses([Address(TESTNET_ADDRESS)], path)
+        lines = path.read_text().splitlines()
+        assert summary["entries"] == len(lines)
+        assert summary["amount"] == sum(json.loads(line)["utxoEntry"]["amount"] for line in lines)
+
+    def test_invalid_batch_size(self, testnet_rpc_client):
+        """Test a zero batch size raises."""
+        with pytest.raises(ValueError):
+            testnet_rpc_client.stream_utxos_by_addresses([Address(TESTNET_ADDRESS)], batch_size=0)
+
+
+class TestSubmitTransactionErrors:
+    """Tests for decoded transaction rejections."""
+
+    async def test_orphan_rejection_is_decoded(self, testnet_rpc_client):
+        """Test spending an unknown outpoint raises a TransactionRejectedError."""
+        entry = UtxoEntryReference.from_dict({
+            "address": TESTNET_ADDRESS,
+            "outpoint": {"transactionId": "ab" * 32, "index": 0},
+            "amount": 100_000_000,
+            "scriptPublicKey": {"version": 0, "script": "20dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659ac"},
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        })
+        tx = create_transaction([entry], [PaymentOutput(Address(TESTNET_ADDRESS), 90_000_000)], 0)
+
+        with pytest.raises(TransactionRejectedError) as excinfo:
+            await testnet_rpc_client.submit_transaction({"transaction": tx, "allowOrphan": False})
+
+        # The node looks up the inputs before verifying signatures.
+        assert excinfo.value.reason == "orphan"
+        assert excinfo.value.transaction_id == tx.id
+
+
+class TestBlockingConnect:
+    """Tests for kaspa.connect()."""
+
+    def test_connect_testnet(self):
+        import kaspa
+
+        with kaspa.connect("testnet-10") as rpc:
+            assert rpc.is_connected
+            info = rpc.get_server_info()
+            assert info["networkId"] == "testnet-10"
+
+
+class TestFailoverRpcClient:
+    """Tests for FailoverRpcClient."""
+
+    async def test_requests_and_failover(self):
+        """Test requests survive the primary node going down."""
+        from kaspa import FailoverRpcClient
+
+        client = FailoverRpcClient(resolver=Resolver(), network_id="testnet-10", nodes=2, health_interval=1)
+        scores = []
+        client.add_event_listener("virtual-daa-score-changed", scores.append)
+        await client.connect()
+        try:
+            assert (await client.get_server_info())["isSynced"]
+            await client.subscribe_virtual_daa_score_changed()
+
+            await client.client.disconnect()
+            info = await client.get_server_info()
+            assert info["networkId"] == "testnet-10"
+
+            # The next health check recovers the primary and re-subscribes.
+            await asyncio.sleep(3)
+            assert client.is_connected
+            scores.clear()
+            await asyncio.sleep(2)
+            assert scores
+        finally:
+            await client.disconnect()
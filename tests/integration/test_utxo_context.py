@@ -9,6 +9,7 @@ import pytest
 from kaspa import NetworkId, UtxoContext, UtxoProcessor
 
 TEST_ADDRESS = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+TEST_ADDRESS_2 = "kaspatest:qqkl0ct62rv6dz74pff2kx5sfyasl4z28uekevau23g877r5gt6userwyrmtt"
 
 
 class TestUtxoContext:
@@ -47,6 +48,44 @@ class TestUtxoContext:
         finally:
             await processor.stop()
 
+    async def test_track_addresses_progress(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            events = []
+            await context.track_addresses([TEST_ADDRESS, TEST_ADDRESS_2], progress=events.append, batch_size=1)
+
+            assert [event["scanned"] for event in events] == [1, 2]
+            assert all(event["total"] == 2 for event in events)
+            assert events[-1]["utxos"] >= 0
+        finally:
+            await processor.stop()
+
+    async def test_track_addresses_sequential_batches(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            events = []
+            await context.track_addresses(
+                [TEST_ADDRESS, TEST_ADDRESS_2], progress=events.append, batch_size=1, concurrency=1
+            )
+
+            assert [event["scanned"] for event in events] == [1, 2]
+        finally:
+            await processor.stop()
+
+    async def test_track_addresses_zero_concurrency(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            with pytest.raises(Exception, match="greater than 0"):
+                await context.track_addresses([TEST_ADDRESS], concurrency=0)
+        finally:
+            await processor.stop()
+
     async def test_track_addresses_invalid_address(self, testnet_rpc_client):
         processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
         await processor.start()
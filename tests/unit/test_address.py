@@ -2,9 +2,18 @@
 Unit tests for the Address class.
 """
 
+import warnings
+
 import pytest
 
-from kaspa import Address, PublicKey, ScriptPublicKey, pay_to_address_script, address_from_script_public_key
+from kaspa import (
+    Address,
+    PublicKey,
+    ScriptPublicKey,
+    address_from_script_public_key,
+    generate_vanity_address,
+    pay_to_address_script,
+)
 from tests.conftest import TEST_MAINNET_ADDRESS
 
 
@@ -124,3 +133,145 @@ class TestScriptPublicKeyAddress:
         spk = pay_to_address_script(known_mainnet_address)
         recovered_address = address_from_script_public_key(spk, "mainnet")
         assert recovered_address.to_string() == known_mainnet_address.to_string()
+
+
+class TestAddressResolve:
+    """Tests for Address name resolution."""
+
+    @pytest.fixture(autouse=True)
+    def resolver(self):
+        names = {"alice.kas": TEST_MAINNET_ADDRESS, "bob.kas": Address(TEST_MAINNET_ADDRESS), "invalid.kas": "nope"}
+        Address.register_resolver(".kas", names.get)
+        yield
+        Address.unregister_resolver(".kas")
+
+    def test_resolve_name(self):
+        """Test names resolve through the resolver registered for their suffix."""
+        assert Address.resolve("alice.kas").to_string() == TEST_MAINNET_ADDRESS
+        assert Address.resolve("bob.kas") == Address(TEST_MAINNET_ADDRESS)
+
+    def test_resolve_address_string(self):
+        """Test address strings resolve to themselves."""
+        assert Address.resolve(TEST_MAINNET_ADDRESS).to_string() == TEST_MAINNET_ADDRESS
+
+    def test_resolve_unknown_name_raises(self):
+        """Test unregistered and unresolved names raise ValueError."""
+        with pytest.raises(ValueError, match="could not be resolved"):
+            Address.resolve("carol.kas")
+        with pytest.raises(ValueError, match="no resolver"):
+            Address.resolve("alice.eth")
+        with pytest.raises(ValueError, match="resolved to"):
+            Address.resolve("invalid.kas")
+
+    def test_longest_suffix_wins(self):
+        """Test the resolver with the longest matching suffix is used."""
+        Address.register_resolver(".pay.kas", lambda name: None)
+        try:
+            with pytest.raises(ValueError, match="could not be resolved"):
+                Address.resolve("alice.pay.kas")
+        finally:
+            assert Address.unregister_resolver(".pay.kas")
+
+    def test_register_rejects_non_callable(self):
+        """Test a non-callable resolver raises ValueError."""
+        with pytest.raises(ValueError):
+            Address.register_resolver(".kas", 42)
+
+
+class TestAddressToNetwork:
+    """Tests for re-encoding addresses under another network prefix."""
+
+    def test_to_network_keeps_payload(self, known_mainnet_address):
+        """Test the converted address has the same version and payload."""
+        with pytest.warns(UserWarning):
+            testnet = known_mainnet_address.to_network("testnet-10", allow_mainnet=True)
+        assert testnet.prefix == "kaspatest"
+        assert testnet.version == known_mainnet_address.version
+        assert testnet.payload == known_mainnet_address.payload
+        assert Address.validate(testnet.to_string())
+
+    def test_to_network_mainnet_requires_opt_in(self, known_mainnet_address):
+        """Test conversions from or to mainnet raise without allow_mainnet."""
+        with pytest.raises(ValueError, match="allow_mainnet"):
+            known_mainnet_address.to_network("testnet-10")
+        with pytest.warns(UserWarning):
+            testnet = known_mainnet_address.to_network("testnet-10", allow_mainnet=True)
+        with pytest.raises(ValueError, match="allow_mainnet"):
+            testnet.to_network("mainnet")
+
+    def test_to_network_same_prefix_does_not_warn(self, known_mainnet_address):
+        """Test testnets sharing a prefix convert without a warning."""
+        with pytest.warns(UserWarning):
+            testnet = known_mainnet_address.to_network("testnet-10", allow_mainnet=True)
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            assert testnet.to_network("testnet-11") == testnet
+
+    def test_to_network_many(self, known_mainnet_address):
+        """Test bulk conversion of Address objects and strings with one warning."""
+        with pytest.warns(UserWarning) as record:
+            converted = Address.to_network_many(
+                [known_mainnet_address, TEST_MAINNET_ADDRESS], "devnet", allow_mainnet=True
+            )
+        assert len(record) == 1
+        assert [address.prefix for address in converted] == ["kaspadev", "kaspadev"]
+        assert converted[0] == converted[1]
+
+    def test_to_network_many_invalid_address_raises(self):
+        """Test an invalid address string raises ValueError."""
+        with pytest.raises(ValueError):
+            Address.to_network_many(["not-an-address"], "testnet-10")
+
+
+class TestVanityAddress:
+    """Tests for vanity address generation."""
+
+    def test_generate_matching_address(self):
+        """Test the generated keypair's address starts with the pattern."""
+        keypair = generate_vanity_address("qqq", threads=2)
+        assert keypair.to_address("mainnet").to_string().startswith("kaspa:qqq")
+
+    def test_generate_with_network_prefix(self):
+        """Test patterns may include the network prefix."""
+        keypair = generate_vanity_address("kaspatest:qp", network="testnet")
+        assert keypair.to_address("testnet").to_string().startswith("kaspatest:qp")
+
+    def test_generate_ecdsa(self):
+        """Test ECDSA vanity addresses."""
+        keypair = generate_vanity_address("qypq", ecdsa=True)
+        assert keypair.to_address_ecdsa("mainnet").to_string().startswith("kaspa:qypq")
+
+    @pytest.mark.parametrize("pattern", ["qb", "pq", "qy", "kaspatest:qq"])
+    def test_impossible_pattern_raises(self, pattern):
+        """Test patterns outside the alphabet, address version or network raise ValueError."""
+        with pytest.raises(ValueError):
+            generate_vanity_address(pattern)
+
+    def test_impossible_ecdsa_pattern_raises(self):
+        """Test ECDSA patterns not starting with `qyp` raise ValueError."""
+        with pytest.raises(ValueError, match="qyp"):
+            generate_vanity_address("qyq", ecdsa=True)
+
+    def test_progress_cancels(self):
+        """Test returning False from the progress callback cancels the search."""
+        events = []
+
+        def progress(event):
+            events.append(event)
+            return False
+
+        assert generate_vanity_address("qqkaspakaspa", threads=1, progress=progress, progress_interval=0.05) is None
+        assert set(events[0]) == {"attempts", "elapsed", "rate", "expected"}
+
+    def test_progress_exception_propagates(self):
+        """Test an exception raised by the progress callback aborts the search."""
+
+        def progress(event):
+            raise RuntimeError("stop")
+
+        with pytest.raises(RuntimeError, match="stop"):
+            generate_vanity_address("qqkaspakaspa", threads=1, progress=progress, progress_interval=0.05)
+
+    def test_timeout_returns_none(self):
+        """Test the search gives up after the timeout."""
+        assert generate_vanity_address("qqkaspakaspa", threads=1, timeout=0.1) is None
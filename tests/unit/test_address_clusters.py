@@ -0,0 +1,74 @@
+import pytest
+
+from kaspa import (
+    AddressClusters,
+    Hash,
+    PrivateKey,
+    ScriptPublicKey,
+    Transaction,
+    TransactionInput,
+    TransactionOutpoint,
+    TransactionOutput,
+    UtxoEntryReference,
+)
+
+KEYS = [PrivateKey(f"{index:064x}") for index in range(1, 7)]
+A, B, C, D, E, F = (key.to_address("mainnet").to_string() for key in KEYS)
+
+
+def transaction(*addresses):
+    """A transaction spending one UTXO entry of each address."""
+    inputs = []
+    for index, address in enumerate(addresses):
+        utxo = UtxoEntryReference.from_dict({
+            "address": address,
+            "outpoint": {"transactionId": "a" * 64, "index": index},
+            "amount": 1000,
+            "scriptPublicKey": {"version": 0, "script": "51"},
+            "blockDaaScore": 1,
+            "isCoinbase": False,
+        })
+        outpoint = TransactionOutpoint(Hash("a" * 64), index)
+        inputs.append(TransactionInput(outpoint, "", 0, 1, utxo))
+    output = TransactionOutput(1000, ScriptPublicKey(0, "51"))
+    return Transaction(0, inputs, [output], 0, "0" * 40, 0, "", 0)
+
+
+class TestAddressClusters:
+    def test_common_inputs_are_clustered(self):
+        clusters = AddressClusters()
+        clusters.add_transactions([[A, B], [C], [D, E]])
+
+        assert len(clusters) == 5
+        assert clusters.cluster_count == 3
+        assert clusters.cluster(A) == clusters.cluster(B) == 0
+        assert clusters.cluster(C) == 2
+        assert clusters.cluster(D) == clusters.cluster(E) == 3
+        assert clusters.cluster(F) is None
+        assert F not in clusters
+
+    def test_clusters_merge_across_batches(self):
+        clusters = AddressClusters()
+        clusters.add_transactions([[A, B], [C, D]])
+        clusters.add_transactions([[D, B]])
+
+        assert clusters.cluster_count == 1
+        assert clusters.to_dict() == {A: 0, B: 0, C: 0, D: 0}
+
+    def test_clusters_list(self):
+        clusters = AddressClusters()
+        clusters.add_transactions([[C], [A, B], [B, D]])
+
+        assert [[address.to_string() for address in cluster] for cluster in clusters.clusters()] == [[C], [A, B, D]]
+        assert len(clusters.clusters(min_size=2)) == 1
+
+    def test_transaction_inputs(self):
+        clusters = AddressClusters()
+        clusters.add_transactions([transaction(A, B), transaction(C)])
+
+        assert clusters.cluster(A) == clusters.cluster(B)
+        assert clusters.cluster(C) != clusters.cluster(A)
+
+    def test_rejects_invalid_transactions(self):
+        with pytest.raises(Exception):
+            AddressClusters().add_transactions([42])
@@ -0,0 +1,29 @@
+import threading
+
+import pytest
+
+import kaspa
+from kaspa import BlockingRpcClient, RpcClient
+
+
+class TestBlockingClient:
+    def test_connect_unreachable_raises(self):
+        threads = threading.active_count()
+
+        with pytest.raises(Exception):
+            kaspa.connect("testnet-10", url="ws://127.0.0.1:1", timeout=500)
+
+        assert threading.active_count() == threads
+
+    def test_wraps_client(self):
+        client = RpcClient(url="ws://127.0.0.1:17110", network_id="mainnet")
+
+        with BlockingRpcClient(client) as rpc:
+            assert rpc.client is client
+            assert rpc.is_connected is False
+            assert rpc.encoding == client.encoding
+
+    def test_close_is_idempotent(self):
+        rpc = BlockingRpcClient(RpcClient(url="ws://127.0.0.1:17110", network_id="mainnet"))
+        rpc.close()
+        rpc.close()
@@ -0,0 +1,28 @@
+import asyncio
+
+import pytest
+
+from kaspa import RpcClient
+
+UNREACHABLE_URL = "ws://127.0.0.1:1"
+
+
+async def test_connect_retry_is_cancellable():
+    client = RpcClient(url=UNREACHABLE_URL, network_id="testnet-10")
+
+    with pytest.raises(asyncio.TimeoutError):
+        await asyncio.wait_for(
+            client.connect(strategy="retry", retry_interval=100), timeout=0.5
+        )
+
+    assert not client.is_connected
+
+
+async def test_connect_cancel_propagates_cancelled_error():
+    client = RpcClient(url=UNREACHABLE_URL, network_id="testnet-10")
+    task = asyncio.ensure_future(client.connect(strategy="retry", retry_interval=100))
+    await asyncio.sleep(0.2)
+
+    task.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await task
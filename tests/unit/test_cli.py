@@ -0,0 +1,66 @@
+import json
+
+import pytest
+
+import kaspa.__main__ as cli
+from kaspa.__main__ import main, parse_args
+
+ADDRESS = "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+
+
+class TestCli:
+    def test_validate_valid(self, capsys):
+        assert main(["validate", ADDRESS]) == 0
+        assert capsys.readouterr().out.strip() == f"{ADDRESS}: valid (kaspa, PubKey)"
+
+    def test_validate_invalid(self, capsys):
+        assert main(["validate", "kaspa:invalid"]) == 1
+        assert "invalid" in capsys.readouterr().out
+
+    def test_validate_json(self, capsys):
+        assert main(["--json", "validate", ADDRESS]) == 0
+        result = json.loads(capsys.readouterr().out)
+        assert result == {"address": ADDRESS, "valid": True, "prefix": "kaspa", "version": "PubKey"}
+
+    def test_missing_command(self):
+        with pytest.raises(SystemExit):
+            main([])
+
+    def test_unreachable_node_reports_error(self, capsys):
+        assert main(["--url", "ws://127.0.0.1:1", "info"]) == 1
+        assert capsys.readouterr().err.startswith("error: ")
+
+    @pytest.mark.parametrize(
+        ("amount", "sompi"),
+        [("1", 100_000_000), ("0.1", 10_000_000), ("1.00000001", 100_000_001), ("123456.78901234", 12_345_678_901_234)],
+    )
+    def test_send_amount_is_exact(self, amount, sompi):
+        assert parse_args(["send", ADDRESS, amount]).amount == sompi
+
+    @pytest.mark.parametrize("amount", ["1.000000001", "-1", "abc", "nan"])
+    def test_send_rejects_invalid_amount(self, amount, capsys):
+        with pytest.raises(SystemExit):
+            parse_args(["send", ADDRESS, amount])
+        assert "invalid amount" in capsys.readouterr().err
+
+    def test_send_passes_sompi(self, monkeypatch, capsys):
+        calls = []
+
+        class Client:
+            async def disconnect(self):
+                pass
+
+        async def connect(args):
+            return Client()
+
+        async def send(client, private_key, to, amount, priority_fee, network_id):
+            calls.append((to, amount, priority_fee))
+            return "ab" * 32
+
+        monkeypatch.setenv(cli.PRIVATE_KEY_ENV, "key")
+        monkeypatch.setattr(cli, "connect", connect)
+        monkeypatch.setattr(cli, "send", send)
+
+        assert main(["send", ADDRESS, "0.3", "--priority-fee", "1000"]) == 0
+        assert calls == [(ADDRESS, 30_000_000, 1000)]
+        assert capsys.readouterr().out.strip() == "ab" * 32
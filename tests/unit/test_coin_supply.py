@@ -0,0 +1,34 @@
+"""
+Unit tests for CoinSupply.
+"""
+
+import pytest
+
+from kaspa import CoinSupply
+
+
+RESPONSE = {"maxSompi": 2_900_000_000 * 100_000_000, "circulatingSompi": 1_450_000_000 * 100_000_000}
+
+
+def test_from_dict_amounts():
+    supply = CoinSupply.from_dict(RESPONSE)
+
+    assert supply.max_supply == RESPONSE["maxSompi"]
+    assert supply.circulating == RESPONSE["circulatingSompi"]
+    assert supply.circulating_ratio == pytest.approx(0.5)
+
+
+def test_formatted_kas():
+    supply = CoinSupply.from_dict(RESPONSE)
+
+    assert supply.max_supply_kas.startswith("2,900,000,000")
+    assert supply.circulating_kas.startswith("1,450,000,000")
+
+
+def test_to_dict_round_trip():
+    assert CoinSupply.from_dict(RESPONSE).to_dict() == RESPONSE
+
+
+def test_from_dict_missing_key():
+    with pytest.raises(Exception, match="circulatingSompi"):
+        CoinSupply.from_dict({"maxSompi": 1})
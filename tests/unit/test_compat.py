@@ -0,0 +1,42 @@
+import kaspa
+from kaspa import Address, PrivateKey
+
+
+class TestCompat:
+    def test_verify_passes(self):
+        report = kaspa.compat.verify()
+
+        assert report["failed"] == []
+        # 4 addresses, 2 derivations x 4 fields, 2 transactions x 2 fields
+        assert report["passed"] == 16
+
+    def test_verify_raise_on_failure(self):
+        kaspa.compat.verify(raise_on_failure=True)
+
+    def test_vector_categories(self):
+        vectors = kaspa.compat.test_vectors()
+
+        assert len(vectors["addresses"]) == 4
+        assert len(vectors["derivations"]) == 2
+        assert len(vectors["transactions"]) == 2
+
+    def test_address_vectors_match_address_class(self):
+        for vector in kaspa.compat.test_vectors()["addresses"]:
+            assert Address.validate(vector["address"])
+            assert Address(vector["address"]).version == vector["version"]
+
+    def test_private_key_vector(self):
+        vectors = kaspa.compat.test_vectors()
+        schnorr = next(v for v in vectors["addresses"] if v["name"] == "schnorr-mainnet")
+
+        address = PrivateKey(vectors["private_key"]).to_address("mainnet")
+        assert address.to_string() == schnorr["address"]
+
+    def test_transaction_vector_shape(self):
+        vector = kaspa.compat.test_vectors()["transactions"][0]
+
+        assert len(vector["transaction"]["inputs"]) == 1
+        assert len(vector["transaction"]["outputs"]) == 1
+        assert vector["sighash_type"] == "All"
+        assert len(vector["transaction_id"]) == 64
+
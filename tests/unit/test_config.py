@@ -0,0 +1,169 @@
+import json
+
+import pytest
+
+from kaspa import Config, FailoverRpcClient, NetworkId, RpcClient
+
+PROFILES = {
+    "encoding": "json",
+    "profile": "testnet-10",
+    "wallet": {"vault_key": "default"},
+    "profiles": {
+        "mainnet": {"resolver": True, "tls": True},
+        "testnet-10": {"url": "ws://127.0.0.1:17210", "wallet": {"secret_env": "TEST_WALLET_SECRET"}},
+        "local": {"network": "testnet-11", "url": "ws://127.0.0.1:17310", "timeout": 2000},
+    },
+}
+
+TOML = """
+encoding = "json"
+
+[profiles.mainnet]
+resolver = ["https://resolver.example"]
+
+[profiles.mainnet.wallet]
+secret = "hunter2"
+"""
+
+
+@pytest.fixture
+def profile_file(tmp_path):
+    path = tmp_path / "sdk.json"
+    path.write_text(json.dumps(PROFILES))
+    return path
+
+
+class TestConfig:
+    def test_defaults(self):
+        config = Config()
+
+        assert config.network == "mainnet"
+        assert config.url is None
+        assert config.encoding == "borsh"
+        assert config.make_resolver() is not None
+
+    def test_default_profile(self, profile_file):
+        config = Config.load(profile_file)
+
+        assert config.profile == "testnet-10"
+        assert config.network == "testnet-10"
+        assert config.url == "ws://127.0.0.1:17210"
+        assert config.encoding == "json"
+        assert config.wallet == {"vault_key": "default", "secret_env": "TEST_WALLET_SECRET"}
+        assert config.make_resolver() is None
+
+    def test_named_profile(self, profile_file):
+        config = Config.load(profile_file, profile="local")
+
+        assert config.network == "testnet-11"
+        assert config.timeout == 2000
+        assert config.network_id == NetworkId("testnet-11")
+
+    def test_toml(self, tmp_path):
+        try:
+            import tomllib  # noqa: F401
+        except ImportError:
+            pytest.importorskip("tomli")
+        path = tmp_path / "sdk.toml"
+        path.write_text(TOML)
+        config = Config.load(path, profile="mainnet")
+
+        assert config.resolver == ["https://resolver.example"]
+        assert config.make_resolver().urls() == ["https://resolver.example"]
+        assert config.secret() == "hunter2"
+        assert "hunter2" not in repr(config)
+
+    def test_secret_from_environment(self, profile_file, monkeypatch):
+        monkeypatch.setenv("TEST_WALLET_SECRET", "from-env")
+        config = Config.load(profile_file)
+
+        assert config.secret() == "from-env"
+        assert config.secret("mnemonic") is None
+
+    def test_invalid_files(self, profile_file, tmp_path):
+        with pytest.raises(ValueError, match="No profile"):
+            Config.load(profile_file, profile="testnet-12")
+        path = tmp_path / "typo.json"
+        path.write_text(json.dumps({"urls": ["ws://127.0.0.1:17110"]}))
+        with pytest.raises(ValueError, match="urls"):
+            Config.load(path)
+        with pytest.raises(FileNotFoundError):
+            Config.load(tmp_path / "missing.toml")
+
+    def test_rpc_client(self):
+        client = RpcClient(config=Config(network="testnet-10", url="ws://127.0.0.1:17210"))
+        assert client.resolver is None
+
+        client = RpcClient(config=Config(network="testnet-10"))
+        assert client.resolver is not None
+
+    def test_explicit_arguments_take_precedence(self):
+        client = RpcClient(url="ws://127.0.0.1:17110", config=Config(resolver=True))
+
+        assert client.resolver is None
+
+    def test_failover_client(self):
+        config = Config(network="testnet-10", url="ws://127.0.0.1:17210")
+        client = FailoverRpcClient(config=config)
+
+        assert [node["url"] for node in client.nodes] == ["ws://127.0.0.1:17210"]
+
+
+class TestConfigFromEnv:
+    def test_empty_environment(self):
+        config = Config.from_env({})
+
+        assert config.network == "mainnet"
+        assert config.url is None
+        assert config.log_level is None
+
+    def test_variables(self):
+        config = Config.from_env({
+            "KASPA_NETWORK": "testnet-10",
+            "KASPA_URL": "ws://127.0.0.1:17210",
+            "KASPA_ENCODING": "json",
+            "KASPA_TIMEOUT": "2500",
+            "KASPA_TLS": "yes",
+        })
+
+        assert config.network == "testnet-10"
+        assert config.url == "ws://127.0.0.1:17210"
+        assert config.encoding == "json"
+        assert config.timeout == 2500
+        assert config.tls is True
+        assert config.make_resolver() is None
+
+    def test_resolver(self):
+        assert Config.from_env({"KASPA_URL": "ws://127.0.0.1:17110", "KASPA_RESOLVER": "on"}).resolver is True
+        assert Config.from_env({"KASPA_RESOLVER": "false"}).make_resolver() is None
+        config = Config.from_env({"KASPA_RESOLVER": "https://a.example, https://b.example"})
+        assert config.resolver == ["https://a.example", "https://b.example"]
+
+    def test_overrides_profile(self, profile_file):
+        config = Config.from_env({"KASPA_CONFIG": str(profile_file), "KASPA_PROFILE": "local", "KASPA_TIMEOUT": "500"})
+
+        assert config.profile == "local"
+        assert config.url == "ws://127.0.0.1:17310"
+        assert config.timeout == 500
+
+    def test_log_level(self):
+        import logging
+
+        logger = logging.getLogger("kaspa_wrpc_client")
+        previous = logger.level
+        try:
+            config = Config.from_env({"KASPA_LOG_LEVEL": "debug"})
+            assert config.log_level == "debug"
+            assert logger.level == logging.DEBUG
+        finally:
+            logger.setLevel(previous)
+
+    def test_invalid_values(self):
+        with pytest.raises(ValueError, match="KASPA_TLS"):
+            Config.from_env({"KASPA_TLS": "maybe"})
+        with pytest.raises(ValueError, match="KASPA_TIMEOUT"):
+            Config.from_env({"KASPA_TIMEOUT": "10s"})
+        with pytest.raises(ValueError, match="log level"):
+            Config.from_env({"KASPA_LOG_LEVEL": "chatty"})
+        with pytest.raises(Exception):
+            Config.from_env({"KASPA_NETWORK": "moonnet"})
@@ -0,0 +1,85 @@
+import xml.etree.ElementTree as ET
+
+import pytest
+
+from kaspa import export_dag
+
+NS = {"g": "http://graphml.graphdrawing.org/xmlns"}
+
+
+def block(hash, parents, selected_parent=None, blues=(), reds=(), chain=False, blue_score=0):
+    return {
+        "header": {
+            "hash": hash,
+            "parentsByLevel": [list(parents)] if parents else [],
+            "blueScore": blue_score,
+            "daaScore": blue_score,
+            "timestamp": 1000 + blue_score,
+        },
+        "verboseData": {
+            "selectedParentHash": selected_parent or "",
+            "mergeSetBluesHashes": list(blues),
+            "mergeSetRedsHashes": list(reds),
+            "isChainBlock": chain,
+        },
+    }
+
+
+# tip merges b (selected parent, blue) and c (red); b and c both build on a.
+BLOCKS = {
+    "tip": block("tip", ["b", "c"], "b", blues=["b"], reds=["c"], blue_score=3),
+    "b": block("b", ["a"], "a", blues=["a"], chain=True, blue_score=2),
+    "c": block("c", ["a"], "a", blues=["a"], blue_score=2),
+    "a": block("a", ["genesis"], "genesis", blues=["genesis"], chain=True, blue_score=1),
+}
+
+
+class FakeRpc:
+    def __init__(self):
+        self.requests = []
+
+    async def get_block(self, request):
+        self.requests.append(request["hash"])
+        return {"block": BLOCKS[request["hash"]]}
+
+
+class TestExportDag:
+    async def test_graphml(self, tmp_path):
+        rpc = FakeRpc()
+        path = tmp_path / "dag.graphml"
+        document = await export_dag(rpc, "tip", 2, path=path)
+
+        assert path.read_text() == document
+        assert rpc.requests == ["tip", "b", "c", "a"]
+        graph = ET.fromstring(document).find("g:graph", NS)
+        colors = {
+            node.get("id"): node.find("g:data[@key='color']", NS).text for node in graph.findall("g:node", NS)
+        }
+        assert colors == {"tip": "unknown", "b": "blue", "c": "red", "a": "blue"}
+        edges = {
+            (edge.get("source"), edge.get("target")): edge.find("g:data[@key='selected']", NS).text
+            for edge in graph.findall("g:edge", NS)
+        }
+        assert edges == {("tip", "b"): "true", ("tip", "c"): "false", ("b", "a"): "true", ("c", "a"): "true"}
+
+    async def test_dot(self):
+        document = await export_dag(FakeRpc(), "tip", 1, format="dot")
+
+        assert document.startswith("digraph dag {")
+        assert '"tip" -> "b" [style=bold];' in document
+        assert '"tip" -> "c" [style=dashed];' in document
+        assert "fillcolor=salmon" in document
+        assert '"a"' not in document
+
+    async def test_depth_zero(self):
+        rpc = FakeRpc()
+        document = await export_dag(rpc, "tip", 0, format="dot")
+
+        assert rpc.requests == ["tip"]
+        assert "->" not in document
+
+    async def test_invalid_arguments(self):
+        with pytest.raises(ValueError, match="format"):
+            await export_dag(FakeRpc(), "tip", 1, format="svg")
+        with pytest.raises(ValueError, match="depth"):
+            await export_dag(FakeRpc(), "tip", -1)
@@ -0,0 +1,66 @@
+import pytest
+
+import kaspa
+from kaspa import UtxoEntryReference
+
+pa = pytest.importorskip("pyarrow")
+
+ADDRESS = "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+
+
+def utxo_entry(index, amount, is_coinbase=False):
+    return UtxoEntryReference.from_dict({
+        "address": ADDRESS,
+        "outpoint": {"transactionId": "a" * 64, "index": index},
+        "amount": amount,
+        "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+        "blockDaaScore": 12345,
+        "isCoinbase": is_coinbase,
+    })
+
+
+class TestDataFrame:
+    def test_utxos_to_arrow(self):
+        table = kaspa.dataframe.utxos_to_arrow([utxo_entry(0, 1000), utxo_entry(1, 2000, True)])
+
+        assert table.num_rows == 2
+        assert table.column("address").to_pylist() == [ADDRESS, ADDRESS]
+        assert table.column("index").to_pylist() == [0, 1]
+        assert table.column("amount").type == pa.uint64()
+        assert table.column("amount").to_pylist() == [1000, 2000]
+        assert table.column("is_coinbase").to_pylist() == [False, True]
+        assert table.column("block_daa_score").to_pylist() == [12345, 12345]
+
+    def test_utxos_to_arrow_empty(self):
+        table = kaspa.dataframe.utxos_to_arrow([])
+
+        assert table.num_rows == 0
+        assert "transaction_id" in table.column_names
+
+    def test_balances_to_arrow(self):
+        response = {"entries": [{"address": ADDRESS, "balance": 5}, {"address": ADDRESS, "balance": None}]}
+        table = kaspa.dataframe.balances_to_arrow(response)
+
+        assert table.column("balance").to_pylist() == [5, None]
+        assert kaspa.dataframe.balances_to_arrow(response["entries"]).equals(table)
+
+    def test_transactions_to_arrow(self):
+        record = {
+            "id": "b" * 64,
+            "value": 1000,
+            "blockDaaScore": 10,
+            "unixtimeMsec": None,
+            "network": "mainnet",
+            "data": {"type": "incoming", "data": {}},
+        }
+        table = kaspa.dataframe.transactions_to_arrow([record])
+
+        assert table.column("type").to_pylist() == ["incoming"]
+        assert table.column("unixtime_msec").to_pylist() == [None]
+        assert table.column("note").to_pylist() == [None]
+
+    def test_utxos_to_pandas(self):
+        pytest.importorskip("pandas")
+        df = kaspa.dataframe.utxos_to_pandas([utxo_entry(0, 1000)])
+
+        assert list(df["amount"]) == [1000]
@@ -0,0 +1,90 @@
+"""
+Unit tests for difficulty, target and hashrate conversions.
+"""
+
+import pytest
+
+from kaspa import (
+    bits_to_difficulty,
+    bits_to_target,
+    difficulty_to_target,
+    estimate_hashrate,
+    target_to_bits,
+    target_to_difficulty,
+)
+
+MAX_TARGET = 2**255 - 1
+
+
+class TestTargetConversion:
+    """Tests for bits and target conversions."""
+
+    def test_bits_to_target(self):
+        """Test decoding compact bits."""
+        assert bits_to_target(0x1D00FFFF) == 0xFFFF << (8 * (0x1D - 3))
+
+    def test_round_trip(self):
+        """Test that bits survive a round trip through the target."""
+        for bits in (0x1D00FFFF, 0x1B0404CB, 0x207FFFFF):
+            assert target_to_bits(bits_to_target(bits)) == bits
+
+    def test_target_too_large(self):
+        """Test that a target beyond 256 bits is rejected."""
+        with pytest.raises(Exception):
+            target_to_bits(2**256)
+
+
+class TestDifficulty:
+    """Tests for difficulty conversions."""
+
+    def test_max_target_is_difficulty_one(self):
+        """Test that the maximum target has difficulty 1."""
+        assert target_to_difficulty(MAX_TARGET) == pytest.approx(1.0)
+
+    def test_bits_to_difficulty(self):
+        """Test that halving the target doubles the difficulty."""
+        bits = 0x1D00FFFF
+        target = bits_to_target(bits)
+        assert bits_to_difficulty(bits) == pytest.approx(target_to_difficulty(target))
+        assert target_to_difficulty(target // 2) == pytest.approx(2 * target_to_difficulty(target))
+
+    def test_difficulty_to_target(self):
+        """Test converting a difficulty back to a target."""
+        target = difficulty_to_target(4096)
+        assert target_to_difficulty(target) == pytest.approx(4096)
+
+    def test_invalid_difficulty(self):
+        """Test that difficulties below 1 are rejected."""
+        with pytest.raises(ValueError):
+            difficulty_to_target(0.5)
+
+    def test_zero_target(self):
+        """Test that a zero target is rejected."""
+        with pytest.raises(ValueError):
+            target_to_difficulty(0)
+
+
+class TestEstimateHashrate:
+    """Tests for the local hashrate estimate."""
+
+    def test_estimate(self):
+        """Test work over time with hex and int blue work."""
+        headers = [
+            {"blueWork": "3e8", "timestamp": 1_000},
+            {"blueWork": 3000, "timestamp": 3_000},
+        ]
+        assert estimate_hashrate(headers) == pytest.approx(1000.0)
+
+    def test_order_independent(self):
+        """Test that header order does not matter."""
+        headers = [
+            {"blueWork": 5000, "timestamp": 5_000},
+            {"blueWork": 1000, "timestamp": 1_000},
+            {"blueWork": 3000, "timestamp": 3_000},
+        ]
+        assert estimate_hashrate(headers) == pytest.approx(1000.0)
+
+    def test_too_few_headers(self):
+        """Test that a single header is rejected."""
+        with pytest.raises(ValueError):
+            estimate_hashrate([{"blueWork": 1, "timestamp": 1}])
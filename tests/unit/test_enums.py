@@ -0,0 +1,64 @@
+"""
+Unit tests for the Python enums in kaspa.enums.
+"""
+
+import pytest
+
+import kaspa
+from kaspa import NetworkId, PrivateKey, Resolver, RpcClient, ScriptBuilder, UtxoProcessor
+from kaspa.enums import EventKind, NetworkType, Opcodes, RpcEventKind, SighashType
+
+
+def native_members(cls):
+    return {name: getattr(cls, name) for name in dir(cls) if isinstance(getattr(cls, name), cls)}
+
+
+class TestEnumParity:
+    """Tests the Python enums match the native enums."""
+
+    @pytest.mark.parametrize(
+        "enum, native",
+        [
+            (NetworkType, kaspa.NetworkType),
+            (SighashType, kaspa.SighashType),
+            (EventKind, kaspa.UtxoProcessorEvent),
+            (RpcEventKind, kaspa.NotificationEvent),
+            (Opcodes, kaspa.Opcodes),
+        ],
+    )
+    def test_member_names(self, enum, native):
+        names = {name.lstrip("_") for name in enum.__members__}
+        assert names == set(native_members(native))
+
+    def test_opcode_values(self):
+        for name, opcode in native_members(kaspa.Opcodes).items():
+            assert Opcodes[name] == opcode.value
+
+
+class TestEnumArguments:
+    """Tests enum members are accepted where strings or opcodes are."""
+
+    def test_network_type(self):
+        key = PrivateKey("1" * 64)
+        assert key.to_address(NetworkType.Testnet).to_string() == key.to_address("testnet").to_string()
+
+    def test_opcodes(self):
+        script = ScriptBuilder().add_ops([Opcodes.OpTrue, Opcodes.OpVerify]).to_string()
+        assert script == ScriptBuilder().add_ops([0x51, 0x69]).to_string()
+
+    @pytest.mark.parametrize("event", list(RpcEventKind))
+    def test_rpc_event_kind(self, event):
+        client = RpcClient(url="ws://127.0.0.1:17110")
+        client.add_event_listener(event, lambda event: None)
+        client.remove_event_listener(event)
+
+    def test_event_kind(self):
+        client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+        processor = UtxoProcessor(client, NetworkId("testnet-10"))
+        processor.add_event_listener(list(EventKind), lambda event: None)
+        processor.remove_event_listener(EventKind.Balance)
+
+    def test_str(self):
+        assert str(EventKind.UtxoProcStart) == "utxo-proc-start"
+        assert EventKind.Balance == "balance"
+        assert SighashType("none") is SighashType._None
@@ -0,0 +1,87 @@
+import asyncio
+import threading
+
+import pytest
+
+from kaspa import RpcClient, runtime
+
+UNREACHABLE_URL = "ws://127.0.0.1:1"
+
+
+class CustomEventLoop(asyncio.SelectorEventLoop):
+    pass
+
+
+def uvloop_factory():
+    uvloop = pytest.importorskip("uvloop")
+    return uvloop.new_event_loop()
+
+
+LOOP_FACTORIES = {
+    "asyncio": asyncio.new_event_loop,
+    "custom": CustomEventLoop,
+    "uvloop": uvloop_factory,
+}
+
+
+@pytest.fixture(params=LOOP_FACTORIES)
+def run(request):
+    """Run a coroutine to completion on a fresh loop of each implementation."""
+    loop = LOOP_FACTORIES[request.param]()
+
+    def run(coroutine):
+        return loop.run_until_complete(coroutine)
+
+    yield run
+    loop.close()
+
+
+async def failed_connect():
+    client = RpcClient(url=UNREACHABLE_URL, network_id="testnet-10")
+    with pytest.raises(Exception):
+        await client.connect(timeout_duration=1000)
+    return client.is_connected
+
+
+async def cancelled_connect():
+    client = RpcClient(url=UNREACHABLE_URL, network_id="testnet-10")
+    with pytest.raises(asyncio.TimeoutError):
+        await asyncio.wait_for(client.connect(strategy="retry", retry_interval=100), timeout=0.5)
+    return client.is_connected
+
+
+def test_connect_error_resolves_on_loop(run):
+    assert run(failed_connect()) is False
+
+
+def test_cancellation_on_loop(run):
+    assert run(cancelled_connect()) is False
+
+
+def test_loop_outside_main_thread():
+    """Awaitables resolve on the running loop, not the thread's policy loop."""
+    results = []
+    thread = threading.Thread(target=lambda: results.append(asyncio.run(failed_connect())))
+    thread.start()
+    thread.join()
+    assert results == [False]
+
+
+class TestEventLoopBackend:
+    @pytest.mark.parametrize("name", LOOP_FACTORIES)
+    def test_detects_implementation(self, name):
+        loop = LOOP_FACTORIES[name]()
+        try:
+            expected = CustomEventLoop.__module__.split(".")[0] if name == "custom" else name
+            assert runtime.event_loop_backend(loop) == expected
+        finally:
+            loop.close()
+
+    async def test_running_loop(self):
+        assert runtime.event_loop_backend() in ("asyncio", "uvloop")
+        assert runtime.uses_uvloop() == (runtime.event_loop_backend() == "uvloop")
+
+    def test_no_running_loop(self):
+        with pytest.raises(RuntimeError):
+            runtime.event_loop_backend()
+        assert runtime.uses_uvloop() is False
@@ -11,3 +11,24 @@ class TestExceptionsSubmodule:
         from kaspa import exceptions
 
         assert exceptions is not None
+
+    def test_transaction_rejected_error(self):
+        """Test that TransactionRejectedError is exposed and catchable as Exception."""
+        from kaspa.exceptions import TransactionRejectedError
+
+        assert issubclass(TransactionRejectedError, Exception)
+        assert TransactionRejectedError.__module__ == "kaspa.exceptions"
+
+    def test_script_verification_error(self):
+        """Test that ScriptVerificationError is exposed and catchable as Exception."""
+        from kaspa.exceptions import ScriptVerificationError
+
+        assert issubclass(ScriptVerificationError, Exception)
+        assert ScriptVerificationError.__module__ == "kaspa.exceptions"
+
+    def test_policy_violation_error(self):
+        """Test that PolicyViolationError is exposed and catchable as Exception."""
+        from kaspa.exceptions import PolicyViolationError
+
+        assert issubclass(PolicyViolationError, Exception)
+        assert PolicyViolationError.__module__ == "kaspa.exceptions"
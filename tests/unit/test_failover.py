@@ -0,0 +1,73 @@
+import pytest
+
+from kaspa import FailoverRpcClient, RpcClient
+
+
+class TestFailoverRpcClient:
+    def test_rejects_non_positive_settings(self):
+        with pytest.raises(ValueError):
+            FailoverRpcClient(urls=["ws://127.0.0.1:17110"], health_interval=0)
+        with pytest.raises(ValueError):
+            FailoverRpcClient(nodes=0)
+
+    def test_nodes(self):
+        client = FailoverRpcClient(urls=["ws://127.0.0.1:17110", "ws://127.0.0.1:17111"])
+
+        assert [node["url"] for node in client.nodes] == ["ws://127.0.0.1:17110", "ws://127.0.0.1:17111"]
+        assert not any(node["healthy"] for node in client.nodes)
+        assert client.client is None
+        assert client.is_connected is False
+
+    def test_mirrors_rpc_client_methods(self):
+        client = FailoverRpcClient(urls=["ws://127.0.0.1:17110"])
+
+        assert callable(client.get_block_dag_info)
+        assert callable(client.subscribe_block_added)
+        with pytest.raises(AttributeError):
+            client.not_a_method
+
+    async def test_connect_without_healthy_node_raises(self):
+        client = FailoverRpcClient(urls=["ws://127.0.0.1:1"], network_id="testnet-10", health_timeout=0.5)
+
+        with pytest.raises(Exception, match="No healthy node"):
+            await client.connect()
+
+        assert client.nodes[0]["error"]
+        assert client.is_connected is False
+
+    async def test_call_before_connect_raises(self):
+        client = FailoverRpcClient(urls=["ws://127.0.0.1:1"])
+
+        with pytest.raises(Exception, match="No healthy node"):
+            await client.get_server_info()
+        with pytest.raises(Exception, match="not connected"):
+            await client.subscribe_block_added()
+
+    def test_configuration_applies_to_all_nodes(self):
+        client = FailoverRpcClient(urls=["ws://127.0.0.1:17110", "ws://127.0.0.1:17111"])
+        client.enable_cache(size=4)
+
+        assert [node.client.cache_size for node in client._nodes] == [0, 0]
+        assert isinstance(client._nodes[0].client, RpcClient)
+
+    def test_routes(self):
+        local, remote = "ws://127.0.0.1:17110", "ws://127.0.0.1:17111"
+        client = FailoverRpcClient(urls=[local, remote], routes={"submit": [local], "get_info": [remote]})
+        for node in client._nodes:
+            node.healthy = True
+        client._nodes[0].latency = 0.5
+        client._nodes[1].latency = 0.1
+
+        assert client._select("submit_transaction").url == local
+        assert client._select("get_server_info").url == remote
+        assert client._select("get_info").url == remote
+
+        client._nodes[0].healthy = False
+        with pytest.raises(Exception, match="No healthy node available for `submit_transaction`"):
+            client._select("submit_transaction")
+
+    def test_route_without_nodes_raises(self):
+        with pytest.raises(ValueError, match="targets no node"):
+            FailoverRpcClient(urls=["ws://127.0.0.1:17110"], routes={"submit": ["ws://127.0.0.1:1"]})
+        with pytest.raises(ValueError, match="targets no node"):
+            FailoverRpcClient(urls=["ws://127.0.0.1:17110"], routes={"read": ["resolver"]})
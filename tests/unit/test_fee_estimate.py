@@ -0,0 +1,50 @@
+"""
+Unit tests for FeeEstimate and FeerateBucket.
+"""
+
+import pytest
+
+from kaspa import FeeEstimate, Fees
+
+
+RESPONSE = {
+    "estimate": {
+        "priorityBucket": {"feerate": 10.0, "estimatedSeconds": 0.5},
+        "normalBuckets": [
+            {"feerate": 5.0, "estimatedSeconds": 5.0},
+            {"feerate": 2.0, "estimatedSeconds": 30.0},
+        ],
+        "lowBuckets": [{"feerate": 1.0, "estimatedSeconds": 600.0}],
+    },
+}
+
+
+def test_from_dict_buckets():
+    estimate = FeeEstimate.from_dict(RESPONSE)
+
+    assert estimate.priority_bucket.feerate == 10.0
+    assert [bucket.feerate for bucket in estimate.buckets] == [10.0, 5.0, 2.0, 1.0]
+    assert estimate.low_buckets[0].estimated_seconds == 600.0
+    assert estimate.verbose is None
+
+
+def test_bucket_for_deadline():
+    estimate = FeeEstimate.from_dict(RESPONSE)
+
+    assert estimate.bucket_for_deadline(60).feerate == 2.0
+    assert estimate.bucket_for_deadline(5).feerate == 5.0
+    assert estimate.bucket_for_deadline(3600).feerate == 1.0
+    # No bucket meets the deadline, fall back to the priority bucket.
+    assert estimate.bucket_for_deadline(0.1).feerate == 10.0
+
+
+def test_bucket_to_fees():
+    bucket = FeeEstimate.from_dict(RESPONSE).bucket_for_deadline(60)
+
+    assert Fees.from_bucket(bucket).fee_rate == 2.0
+    assert bucket.to_dict() == {"feerate": 2.0, "estimatedSeconds": 30.0}
+
+
+def test_from_dict_requires_estimate():
+    with pytest.raises(Exception):
+        FeeEstimate.from_dict({})
@@ -0,0 +1,53 @@
+import sys
+import sysconfig
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from kaspa import Address, PrivateKey, add_signing_policy, remove_signing_policy
+from tests.conftest import TEST_MAINNET_ADDRESS, TEST_PRIVATE_KEY_HEX
+
+THREADS = 8
+
+
+def run_threads(function, count=THREADS):
+    with ThreadPoolExecutor(max_workers=count) as executor:
+        return list(executor.map(function, range(count)))
+
+
+@pytest.mark.skipif(not sysconfig.get_config_var("Py_GIL_DISABLED"), reason="requires a free-threaded build")
+def test_gil_stays_disabled():
+    """Importing kaspa on a free-threaded build does not re-enable the GIL."""
+    assert not sys._is_gil_enabled()
+
+
+def test_concurrent_resolver_registration():
+    def register(index):
+        suffix = f".thread{index}"
+        for _ in range(100):
+            Address.register_resolver(suffix, lambda name: TEST_MAINNET_ADDRESS)
+            assert Address.resolve(f"name{suffix}").to_string() == TEST_MAINNET_ADDRESS
+            assert Address.unregister_resolver(suffix)
+        return True
+
+    assert all(run_threads(register))
+
+
+def test_concurrent_signing_policies():
+    def policy(request):
+        return None
+
+    def register(index):
+        for _ in range(100):
+            add_signing_policy(policy)
+            remove_signing_policy(policy)
+        return True
+
+    assert all(run_threads(register))
+    assert not remove_signing_policy(policy)
+
+
+def test_concurrent_key_derivation():
+    key = PrivateKey(TEST_PRIVATE_KEY_HEX)
+    expected = key.to_address("mainnet").to_string()
+    assert run_threads(lambda index: key.to_address("mainnet").to_string()) == [expected] * THREADS
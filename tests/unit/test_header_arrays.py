@@ -0,0 +1,51 @@
+import pytest
+
+import kaspa
+
+np = pytest.importorskip("numpy")
+
+
+def header(blue_score, timestamp, daa_score):
+    return {
+        "hash": "a" * 64,
+        "version": 1,
+        "timestamp": timestamp,
+        "bits": 453326332,
+        "nonce": 2**63 + 1,
+        "daaScore": daa_score,
+        "blueScore": blue_score,
+    }
+
+
+class TestHeadersToArrays:
+    def test_selected_fields(self):
+        headers = [header(10, 1000, 20), header(11, 2000, 21)]
+        arrays = kaspa.dataframe.headers_to_arrays(headers, fields=["blue_score", "timestamp"])
+
+        assert list(arrays) == ["blue_score", "timestamp"]
+        assert arrays["blue_score"].dtype == np.uint64
+        assert arrays["blue_score"].tolist() == [10, 11]
+        assert arrays["timestamp"].tolist() == [1000, 2000]
+
+    def test_all_fields(self):
+        arrays = kaspa.dataframe.headers_to_arrays([header(10, 1000, 20)])
+
+        assert set(arrays) == {"version", "timestamp", "bits", "nonce", "daa_score", "blue_score"}
+        assert arrays["version"].dtype == np.uint16
+        assert arrays["bits"].dtype == np.uint32
+        assert arrays["nonce"].tolist() == [2**63 + 1]
+
+    def test_blocks_response(self):
+        response = {"blockHashes": [], "blocks": [{"header": header(5, 500, 6), "transactions": []}]}
+        arrays = kaspa.dataframe.headers_to_arrays(response, fields=["daa_score"])
+
+        assert arrays["daa_score"].tolist() == [6]
+
+    def test_empty(self):
+        arrays = kaspa.dataframe.headers_to_arrays([], fields=["timestamp"])
+
+        assert len(arrays["timestamp"]) == 0
+
+    def test_unknown_field_raises(self):
+        with pytest.raises(Exception, match="Unknown header field"):
+            kaspa.dataframe.headers_to_arrays([], fields=["parents"])
@@ -0,0 +1,62 @@
+from kaspa import FailoverRpcClient, NetworkId, RpcClient, UtxoContext, UtxoProcessor, health
+
+
+class FakeClient:
+    is_connected = True
+    url = "ws://127.0.0.1:17110"
+    node_id = "node"
+    last_notification_age = 2.5
+    queued_requests = 0
+    abandoned_requests = 0
+
+    def latency(self):
+        return 0.01
+
+
+class FakeProcessor:
+    rpc = FakeClient()
+    is_active = True
+    is_synced = True
+    queue_depth = 3
+    dropped_events = 0
+
+
+class TestHealth:
+    def test_nothing_to_check(self):
+        report = health()
+
+        assert report == {"healthy": True, "problems": [], "rpc": None, "processor": None, "contexts": []}
+
+    def test_disconnected_client(self):
+        client = RpcClient(url="ws://127.0.0.1:1", network_id="testnet-10")
+        processor = UtxoProcessor(client, NetworkId("testnet-10"))
+        context = UtxoContext(processor)
+
+        report = health(processor=processor, contexts=[context])
+
+        assert not report["healthy"]
+        assert "rpc not connected" in report["problems"]
+        assert "processor not running" in report["problems"]
+        assert report["rpc"]["last_notification_age"] is None
+        assert report["processor"]["queue_depth"] == 0
+        assert report["contexts"][0]["id"] == str(context.id)
+        assert report["contexts"][0]["mature"] == 0
+
+    def test_healthy(self):
+        report = health(processor=FakeProcessor(), max_notification_age=10, max_queue_depth=100)
+
+        assert report["healthy"]
+        assert report["rpc"]["latency"] == 0.01
+        assert report["processor"]["synced"]
+
+    def test_thresholds(self):
+        report = health(processor=FakeProcessor(), max_notification_age=1, max_queue_depth=2)
+
+        assert not report["healthy"]
+        assert report["problems"] == ["last notification 2s ago", "event queue depth 3 above 2"]
+
+    def test_failover_client_without_primary(self):
+        report = health(FailoverRpcClient(urls=["ws://127.0.0.1:1"]))
+
+        assert report["rpc"]["connected"] is False
+        assert report["problems"] == ["rpc not connected"]
@@ -0,0 +1,84 @@
+import pytest
+
+from kaspa import EventJournal
+
+
+@pytest.fixture(params=["events.jsonl", "events.db"])
+def path(request, tmp_path):
+    return tmp_path / request.param
+
+
+class FakeProcessor:
+    def __init__(self):
+        self.listeners = []
+
+    def add_event_listener(self, events, callback, **filters):
+        self.listeners.append((events, callback, filters))
+
+    def remove_event_listener(self, events, callback):
+        self.listeners = [listener for listener in self.listeners if listener[1] != callback]
+
+
+class TestEventJournal:
+    def test_format_from_path(self, tmp_path):
+        with EventJournal(tmp_path / "events.sqlite") as journal:
+            assert journal.format == "sqlite"
+        with EventJournal(tmp_path / "events.log") as journal:
+            assert journal.format == "jsonl"
+        with pytest.raises(ValueError):
+            EventJournal(tmp_path / "events.csv", format="csv")
+
+    def test_append_and_replay(self, path):
+        with EventJournal(path) as journal:
+            assert journal.cursor == 0
+            assert journal.append({"type": "pending", "data": {"id": "a", "value": 1}}) == 1
+            assert journal.append({"type": "maturity", "data": {"id": "a", "value": 1}}) == 2
+            assert journal.append({"type": "reorg", "data": None}) == 3
+
+            entries = list(journal.replay())
+            assert [entry["cursor"] for entry in entries] == [1, 2, 3]
+            assert entries[0]["type"] == "pending"
+            assert entries[0]["data"] == {"id": "a", "value": 1}
+            assert [entry["type"] for entry in journal.replay(2)] == ["reorg"]
+            assert list(journal.replay(3)) == []
+
+    def test_cursor_survives_reopen(self, path):
+        with EventJournal(path) as journal:
+            journal.append({"type": "pending", "data": 1})
+            journal.append({"type": "pending", "data": 2})
+
+        with EventJournal(path) as journal:
+            assert journal.cursor == 2
+            assert journal.append({"type": "maturity", "data": 3}) == 3
+            assert [entry["data"] for entry in journal.replay(1)] == [2, 3]
+
+    def test_skips_truncated_line(self, tmp_path):
+        path = tmp_path / "events.jsonl"
+        with EventJournal(path) as journal:
+            journal.append({"type": "pending", "data": 1})
+        with open(path, "a") as file:
+            file.write('{"cursor": 2, "type": "pen')
+
+        with EventJournal(path) as journal:
+            assert journal.cursor == 1
+            assert journal.append({"type": "maturity", "data": 2}) == 2
+            assert [entry["type"] for entry in journal.replay()] == ["pending", "maturity"]
+
+    def test_non_serializable_data(self, path):
+        with EventJournal(path) as journal:
+            journal.append({"type": "balance", "data": {"address": object()}})
+            assert isinstance(next(journal.replay())["data"]["address"], str)
+
+    def test_attach_and_detach(self, tmp_path):
+        processor = FakeProcessor()
+        with EventJournal(tmp_path / "events.jsonl") as journal:
+            journal.attach(processor, ["pending", "maturity"], contexts=["ctx"])
+            events, callback, filters = processor.listeners[0]
+            assert events == ["pending", "maturity"]
+            assert filters == {"contexts": ["ctx"]}
+
+            callback({"type": "pending", "data": {}})
+            assert journal.cursor == 1
+
+            journal.detach(processor, ["pending", "maturity"])
+            assert processor.listeners == []
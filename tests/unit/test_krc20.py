@@ -0,0 +1,119 @@
+import json
+import threading
+from decimal import Decimal
+from http.server import BaseHTTPRequestHandler, HTTPServer
+from urllib.parse import parse_qs, urlparse
+
+import pytest
+
+from kaspa import KasplexClient
+from kaspa.krc20 import IndexerError, to_decimal
+
+ADDRESS = "kaspatest:qz0000000000000000000000000000000000000000000000000000000q"
+
+
+class Indexer:
+    """Local HTTP server answering Kasplex API paths from `routes`."""
+
+    def __init__(self, routes: dict):
+        self.requests: list[tuple[str, dict]] = []
+        indexer = self
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_GET(self):
+                url = urlparse(self.path)
+                params = {key: values[0] for key, values in parse_qs(url.query).items()}
+                indexer.requests.append((url.path, params))
+                route = routes.get(url.path)
+                if route is None:
+                    self.send_response(404)
+                    self.end_headers()
+                    return
+                body = json.dumps(route(params) if callable(route) else route).encode()
+                self.send_response(200)
+                self.send_header("Content-Type", "application/json")
+                self.end_headers()
+                self.wfile.write(body)
+
+            def log_message(self, *args):
+                pass
+
+        self.server = HTTPServer(("127.0.0.1", 0), Handler)
+        self.url = f"http://127.0.0.1:{self.server.server_port}/v1"
+        threading.Thread(target=self.server.serve_forever, daemon=True).start()
+
+    def close(self):
+        self.server.shutdown()
+
+
+@pytest.fixture
+def indexer(request):
+    indexer = Indexer(request.param)
+    yield indexer
+    indexer.close()
+
+
+def routes(**routes):
+    return pytest.mark.parametrize("indexer", [routes], indirect=True)
+
+
+TOKEN = {"tick": "KASP", "max": "2100000000000000", "lim": "100000000", "dec": "8", "minted": "50000000", "state": "deployed"}
+
+
+def oplist(params):
+    if params.get("next") == "2":
+        return {"message": "successful", "result": [{"op": "mint", "amt": "3"}], "next": None}
+    return {"message": "successful", "result": [{"op": "transfer", "amt": "1"}, {"op": "mint", "amt": "2"}], "next": "2"}
+
+
+class TestKasplexClient:
+    def test_rejects_unknown_network(self):
+        with pytest.raises(ValueError, match="url"):
+            KasplexClient("simnet")
+
+    def test_default_urls(self):
+        assert KasplexClient().url == "https://api.kasplex.org/v1"
+        assert KasplexClient("testnet-10").url == "https://tn10api.kasplex.org/v1"
+
+    @routes(**{"/v1/krc20/token/KASP": {"message": "successful", "result": [TOKEN]}})
+    def test_token_converts_numbers(self, indexer):
+        token = KasplexClient(url=indexer.url).token("kasp")
+
+        assert token["max"] == 2_100_000_000_000_000
+        assert token["dec"] == 8
+        assert token["state"] == "deployed"
+        assert to_decimal(token["minted"], token["dec"]) == Decimal("0.5")
+
+    @routes(**{"/v1/krc20/token/NONE": {"message": "successful", "result": []}})
+    def test_token_not_found(self, indexer):
+        with pytest.raises(IndexerError) as excinfo:
+            KasplexClient(url=indexer.url).token("NONE")
+        assert excinfo.value.status == 404
+
+    @routes(**{f"/v1/krc20/address/{ADDRESS}/token/KASP": {"message": "successful", "result": [{"tick": "KASP", "balance": "42", "locked": "0", "dec": "8"}]}})
+    def test_balance(self, indexer):
+        balance = KasplexClient(url=indexer.url).balance(ADDRESS, "KASP")
+        assert balance["balance"] == 42
+
+    @routes(**{"/v1/krc20/oplist": oplist})
+    def test_operations_follow_pages(self, indexer):
+        ops = list(KasplexClient(url=indexer.url).operations(address=ADDRESS, tick="kasp"))
+
+        assert [op["amt"] for op in ops] == [1, 2, 3]
+        assert indexer.requests[0][1] == {"address": ADDRESS, "tick": "KASP"}
+        assert indexer.requests[1][1]["next"] == "2"
+
+    def test_operations_require_filter(self):
+        with pytest.raises(ValueError):
+            next(KasplexClient().operations())
+
+    @routes(**{"/v1/info": {"message": "invalid request"}})
+    def test_error_message_raises(self, indexer):
+        with pytest.raises(IndexerError, match="invalid request"):
+            KasplexClient(url=indexer.url).info()
+
+    @routes()
+    def test_http_error_raises(self, indexer):
+        with pytest.raises(IndexerError) as excinfo:
+            KasplexClient(url=indexer.url).info()
+        assert excinfo.value.status == 404
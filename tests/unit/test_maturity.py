@@ -0,0 +1,95 @@
+import pytest
+
+from kaspa import MaturityWatcher, NetworkId, UtxoEntryReference
+from tests.conftest import TEST_MAINNET_ADDRESS
+
+NETWORK_ID = NetworkId("devnet")
+
+
+class FakeProcessor:
+    network_id = NETWORK_ID
+
+    def __init__(self):
+        self.listeners = []
+
+    def add_event_listener(self, events, callback):
+        self.listeners.append((events, callback))
+
+    def remove_event_listener(self, events, callback):
+        self.listeners = [listener for listener in self.listeners if listener[1] != callback]
+
+    def emit(self, event_type, data):
+        for events, callback in self.listeners:
+            if event_type in events:
+                callback({"type": event_type, "data": data})
+
+
+@pytest.fixture(autouse=True)
+def maturity_periods(set_maturity_periods):
+    set_maturity_periods(NETWORK_ID, 1000, 100)
+
+
+def record(id: str, daa_score: int) -> dict:
+    return {"id": id * 64, "value": 1000, "blockDaaScore": daa_score}
+
+
+class TestMaturityWatcher:
+    def test_rejects_invalid_thresholds(self):
+        with pytest.raises(ValueError):
+            MaturityWatcher(FakeProcessor(), thresholds=[0])
+        with pytest.raises(ValueError):
+            MaturityWatcher(FakeProcessor(), thresholds=[1.5])
+
+    def test_pending_records_report_thresholds(self):
+        processor = FakeProcessor()
+        events = []
+        watcher = MaturityWatcher(processor, on_progress=events.append)
+
+        processor.emit("pending", record("a", 1000))
+        processor.emit("daa-score-change", {"currentDaaScore": 1030})
+        assert [event["threshold"] for event in events] == [0.25]
+        assert events[0]["remainingDaa"] == 70
+        assert events[0]["estimatedSeconds"] == pytest.approx(7.0)
+
+        processor.emit("daa-score-change", {"currentDaaScore": 1080})
+        assert [event["threshold"] for event in events] == [0.25, 0.5, 0.75]
+
+        processor.emit("maturity", record("a", 1000))
+        assert [event["threshold"] for event in events] == [0.25, 0.5, 0.75, 1.0]
+        assert events[-1]["remainingDaa"] == 0
+        assert watcher.watching == []
+
+    def test_reorg_stops_watching(self):
+        processor = FakeProcessor()
+        watcher = MaturityWatcher(processor, on_progress=lambda event: None)
+
+        processor.emit("pending", record("a", 1000))
+        processor.emit("reorg", record("a", 1000))
+        assert watcher.watching == []
+
+    def test_watch_utxo_with_custom_thresholds(self):
+        processor = FakeProcessor()
+        watcher = MaturityWatcher(processor)
+        matured = []
+        entry = UtxoEntryReference.from_dict(
+            {
+                "address": TEST_MAINNET_ADDRESS,
+                "outpoint": {"transactionId": "b" * 64, "index": 1},
+                "amount": 1000,
+                "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+                "blockDaaScore": 500,
+                "isCoinbase": True,
+            }
+        )
+
+        assert watcher.watch(entry, matured.append, thresholds=[1.0]) == "b" * 64 + ":1"
+        processor.emit("daa-score-change", {"currentDaaScore": 1400})
+        assert matured == []
+        processor.emit("daa-score-change", {"currentDaaScore": 1500})
+        assert [event["maturityDaaScore"] for event in matured] == [1500]
+
+    def test_close_removes_listener(self):
+        processor = FakeProcessor()
+        watcher = MaturityWatcher(processor)
+        watcher.close()
+        assert processor.listeners == []
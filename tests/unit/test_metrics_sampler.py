@@ -0,0 +1,103 @@
+import asyncio
+
+import pytest
+
+from kaspa import MetricsSampler
+
+
+class FakeRpc:
+    def __init__(self, fail=False):
+        self.calls = 0
+        self.fail = fail
+
+    async def get_metrics(self, request):
+        self.calls += 1
+        if self.fail:
+            raise Exception("node unavailable")
+        return {
+            "consensusMetrics": {"networkVirtualDaaScore": 1000 + self.calls, "networkMempoolSize": self.calls},
+            "processMetrics": {"cpuUsage": 0.5},
+        }
+
+
+class TestMetricsSampler:
+    def test_rejects_non_positive_settings(self):
+        with pytest.raises(ValueError):
+            MetricsSampler(FakeRpc(), interval=0)
+        with pytest.raises(ValueError):
+            MetricsSampler(FakeRpc(), capacity=0)
+
+    async def test_sample(self):
+        sampler = MetricsSampler(FakeRpc())
+        sample = await sampler.sample()
+
+        assert sample["daa_score"] == 1001
+        assert sample["mempool_size"] == 1
+        assert sample["cpu_usage"] == 0.5
+        assert sample["active_peers"] is None
+        assert sampler.samples == [sample]
+
+    async def test_capacity(self):
+        sampler = MetricsSampler(FakeRpc(), capacity=2)
+        for _ in range(3):
+            await sampler.sample()
+
+        assert [sample["daa_score"] for sample in sampler.samples] == [1002, 1003]
+
+    async def test_polls_until_stopped(self):
+        rpc = FakeRpc()
+        async with MetricsSampler(rpc, interval=0.01) as sampler:
+            assert sampler.is_running
+            await asyncio.sleep(0.1)
+
+        assert not sampler.is_running
+        assert len(sampler.samples) == rpc.calls >= 3
+        calls = rpc.calls
+        await asyncio.sleep(0.05)
+        assert rpc.calls == calls
+
+    async def test_stream(self):
+        sampler = MetricsSampler(FakeRpc(), interval=0.01)
+        sampler.start()
+        samples = []
+        async for sample in sampler.stream():
+            samples.append(sample)
+            if len(samples) == 3:
+                break
+        await sampler.stop()
+
+        assert [sample["mempool_size"] for sample in samples] == sorted(sample["mempool_size"] for sample in samples)
+
+    async def test_stop_ends_streams(self):
+        sampler = MetricsSampler(FakeRpc(), interval=0.01)
+        sampler.start()
+
+        async def consume():
+            return [sample async for sample in sampler.stream()]
+
+        task = asyncio.create_task(consume())
+        await asyncio.sleep(0.05)
+        await sampler.stop()
+
+        assert len(await asyncio.wait_for(task, 1)) >= 1
+
+    async def test_errors_are_counted(self):
+        async with MetricsSampler(FakeRpc(fail=True), interval=0.01) as sampler:
+            await asyncio.sleep(0.05)
+
+        assert sampler.errors >= 1
+        assert str(sampler.last_error) == "node unavailable"
+        assert sampler.samples == []
+
+    async def test_arrays(self):
+        np = pytest.importorskip("numpy")
+        sampler = MetricsSampler(FakeRpc())
+        await sampler.sample()
+        await sampler.sample()
+
+        arrays = sampler.arrays(["daa_score", "active_peers"])
+        assert list(arrays) == ["daa_score", "active_peers"]
+        assert arrays["daa_score"].tolist() == [1001, 1002]
+        assert np.isnan(arrays["active_peers"]).all()
+        with pytest.raises(ValueError, match="Unknown fields"):
+            sampler.arrays(["hashrate"])
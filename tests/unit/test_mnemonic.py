@@ -2,9 +2,12 @@
 Unit tests for the Mnemonic class.
 """
 
+import hashlib
+import itertools
+
 import pytest
 
-from kaspa import Mnemonic, Language
+from kaspa import Language, Mnemonic, combine_shares, split_secret
 from tests.conftest import TEST_MNEMONIC_PHRASE
 
 
@@ -139,3 +142,66 @@ class TestMnemonicDeterminism:
         # Generate again and verify consistency
         seed2 = known_mnemonic.to_seed()
         assert seed == seed2
+
+
+class TestSecretSharing:
+    """Tests for Shamir secret sharing of mnemonics and secrets."""
+
+    def test_any_threshold_shares_restore_mnemonic(self):
+        """Test every combination of `threshold` shares restores the mnemonic."""
+        shares = split_secret(TEST_MNEMONIC_PHRASE, 3, 5)
+        assert len(shares) == 5
+        for combination in itertools.combinations(shares, 3):
+            restored = combine_shares(list(reversed(combination)))
+            assert isinstance(restored, Mnemonic)
+            assert restored.phrase == TEST_MNEMONIC_PHRASE
+
+    def test_split_mnemonic_object(self, known_mnemonic):
+        """Test splitting a Mnemonic object."""
+        shares = split_secret(known_mnemonic, 2, 2)
+        assert combine_shares(shares).entropy == known_mnemonic.entropy
+
+    def test_bytes_secret(self):
+        """Test raw secrets are restored as bytes."""
+        secret = bytes(range(64))
+        shares = split_secret(secret, 2, 3)
+        assert combine_shares(shares[1:]) == secret
+
+    def test_hex_secret(self):
+        """Test hex string secrets are split as bytes, not parsed as phrases."""
+        secret = bytes(range(32))
+        shares = split_secret(secret.hex(), 2, 3)
+        assert combine_shares(shares[:2]) == secret
+
+    def test_shares_do_not_reveal_digest(self):
+        """Test shares carry no plain digest of the secret."""
+        secret = bytes(range(32))
+        digest = hashlib.sha256(secret).hexdigest()[:8]
+        for share in split_secret(secret, 2, 3):
+            assert digest not in share
+
+    def test_too_few_shares_raises(self):
+        """Test fewer than `threshold` distinct shares raise ValueError."""
+        shares = split_secret(TEST_MNEMONIC_PHRASE, 3, 5)
+        with pytest.raises(ValueError, match="needed"):
+            combine_shares([shares[0], shares[1], shares[1]])
+
+    def test_mixed_sets_raise(self):
+        """Test shares of different splits are not combined."""
+        first = split_secret(TEST_MNEMONIC_PHRASE, 2, 3)
+        second = split_secret(TEST_MNEMONIC_PHRASE, 2, 3)
+        with pytest.raises(ValueError, match="different sets|do not restore"):
+            combine_shares([first[0], second[1]])
+
+    def test_damaged_share_raises(self):
+        """Test a mistyped share fails its checksum."""
+        shares = split_secret(TEST_MNEMONIC_PHRASE, 2, 3)
+        damaged = shares[0][:-1] + ("0" if shares[0][-1] != "0" else "1")
+        with pytest.raises(ValueError, match="checksum"):
+            combine_shares([damaged, shares[1]])
+
+    @pytest.mark.parametrize("threshold, shares", [(0, 3), (4, 3)])
+    def test_invalid_threshold_raises(self, threshold, shares):
+        """Test thresholds outside 1..shares raise ValueError."""
+        with pytest.raises(ValueError):
+            split_secret(TEST_MNEMONIC_PHRASE, threshold, shares)
@@ -0,0 +1,128 @@
+import pytest
+
+from kaspa import NotificationWatchdog
+
+
+class FakeRpc:
+    def __init__(self):
+        self.is_connected = True
+        self.url = "ws://127.0.0.1:17110"
+        self.listeners = {}
+        self.calls = []
+
+    def add_event_listener(self, event, callback):
+        self.listeners[event] = callback
+
+    def remove_event_listener(self, event, callback=None):
+        self.listeners.pop(event, None)
+
+    async def subscribe_virtual_daa_score_changed(self):
+        self.calls.append("subscribe")
+
+    async def unsubscribe_virtual_daa_score_changed(self):
+        self.calls.append("unsubscribe")
+
+
+class FakeProcessor:
+    def __init__(self, fail=False):
+        self.rpc = FakeRpc()
+        self.listeners = {}
+        self.refreshes = 0
+        self.fail = fail
+
+    def add_event_listener(self, event, callback):
+        self.listeners[event] = callback
+
+    def remove_event_listener(self, event, callback=None):
+        self.listeners.pop(event, None)
+
+    async def refresh(self):
+        self.refreshes += 1
+        if self.fail:
+            raise Exception("UtxoProcessor is not running")
+        return {"added": [], "removed": []}
+
+
+def daa_event(score):
+    return {"type": "virtual-daa-score-changed", "data": {"virtualDaaScore": score}}
+
+
+class TestNotificationWatchdog:
+    def test_rejects_non_positive_settings(self):
+        with pytest.raises(ValueError):
+            NotificationWatchdog(FakeRpc(), timeout=0)
+        with pytest.raises(ValueError):
+            NotificationWatchdog(FakeRpc(), check_interval=0)
+
+    async def test_no_stall_while_advancing(self):
+        rpc = FakeRpc()
+        watchdog = NotificationWatchdog(rpc, timeout=0.01, check_interval=60)
+        await watchdog.start()
+        try:
+            rpc.listeners["virtual-daa-score-changed"](daa_event(100))
+            assert watchdog.daa_score == 100
+            assert await watchdog.check() is None
+        finally:
+            await watchdog.stop()
+        assert rpc.calls == ["subscribe"]
+        assert rpc.listeners == {}
+
+    async def test_client_stall_resubscribes(self):
+        rpc = FakeRpc()
+        events = []
+        watchdog = NotificationWatchdog(rpc, on_stall=events.append, timeout=0.01, check_interval=60)
+        async with watchdog:
+            rpc.listeners["virtual-daa-score-changed"](daa_event(100))
+            watchdog._advanced -= 1
+            watchdog._rearmed -= 1
+            event = await watchdog.check()
+
+        assert events == [event]
+        assert event["type"] == "notification-stall"
+        assert event["daaScore"] == 100
+        assert event["silence"] >= 1
+        assert event["attempt"] == 1
+        assert event["error"] is None
+        assert rpc.calls == ["subscribe", "unsubscribe", "subscribe"]
+        assert watchdog.stalls == 1
+
+    async def test_processor_stall_rescans(self):
+        processor = FakeProcessor()
+        events = []
+
+        async def on_stall(event):
+            events.append(event)
+
+        async with NotificationWatchdog(processor, on_stall=on_stall, timeout=0.01, check_interval=60) as watchdog:
+            processor.listeners["daa-score-change"]({"type": "daa-score-change", "data": {"currentDaaScore": 5}})
+            watchdog._advanced -= 2
+            watchdog._rearmed -= 2
+            await watchdog.check()
+            assert await watchdog.check() is None
+            watchdog._rearmed -= 1
+            await watchdog.check()
+
+        assert processor.refreshes == 2
+        assert [event["attempt"] for event in events] == [1, 2]
+        assert events[1]["silence"] >= 2
+        assert events[0]["rescan"] == {"added": [], "removed": []}
+
+    async def test_recovery_error_is_reported(self):
+        watchdog = NotificationWatchdog(FakeProcessor(fail=True), timeout=0.01)
+        watchdog._advanced -= 1
+        watchdog._rearmed -= 1
+
+        event = await watchdog.check()
+
+        assert event["error"] == "UtxoProcessor is not running"
+
+    async def test_disconnected_client_is_not_stalled(self):
+        rpc = FakeRpc()
+        rpc.is_connected = False
+        watchdog = NotificationWatchdog(rpc, timeout=0.01, recover=False)
+        watchdog._advanced -= 1
+        watchdog._rearmed -= 1
+
+        assert await watchdog.check() is None
+        rpc.is_connected = True
+        assert await watchdog.check() is None
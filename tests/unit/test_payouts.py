@@ -0,0 +1,144 @@
+import pytest
+
+from kaspa import PayoutBatch, PayoutStore, PrivateKey, RpcClient, UtxoEntryReference
+from tests.conftest import TEST_MAINNET_ADDRESS, TEST_PRIVATE_KEY_HEX
+
+TESTNET_ADDRESS = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+
+
+def entries(amount=10_000_000_000):
+    return [
+        UtxoEntryReference.from_dict(
+            {
+                "address": TEST_MAINNET_ADDRESS,
+                "outpoint": {"transactionId": "a" * 64, "index": 0},
+                "amount": amount,
+                "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+                "blockDaaScore": 12345,
+                "isCoinbase": False,
+            }
+        )
+    ]
+
+
+def batch(rows=5, amount=100_000_000, **kwargs):
+    payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, **kwargs)
+    payouts.extend((TEST_MAINNET_ADDRESS, amount, f"withdrawal-{row}") for row in range(rows))
+    return payouts
+
+
+class TestPayoutBatch:
+    def test_rejects_invalid_settings(self):
+        with pytest.raises(ValueError):
+            PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, max_outputs=0)
+        with pytest.raises(ValueError):
+            PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, retries=-1)
+
+    def test_add_validates_rows(self):
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS)
+
+        assert payouts.add(TEST_MAINNET_ADDRESS, "1.5 KAS") == 0
+        assert payouts.total == 150_000_000
+        with pytest.raises(ValueError, match="Invalid address"):
+            payouts.add("kaspa:not-an-address", 1000)
+        with pytest.raises(ValueError, match="network"):
+            payouts.add(TESTNET_ADDRESS, 1000)
+        with pytest.raises(ValueError, match="positive"):
+            payouts.add(TEST_MAINNET_ADDRESS, 0)
+        assert len(payouts) == 1
+
+    def test_extend_skip_invalid(self):
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS)
+
+        rejected = payouts.extend(
+            [
+                (TEST_MAINNET_ADDRESS, 1000),
+                {"address": TEST_MAINNET_ADDRESS, "amount": 2000, "reference": "b"},
+                ("kaspa:invalid", 1000),
+                {"amount": 1000},
+            ],
+            skip_invalid=True,
+        )
+
+        assert len(payouts) == 2
+        assert [row["row"] for row in rejected] == [2, 3]
+
+    def test_extend_raises_on_invalid_row(self):
+        with pytest.raises(ValueError, match="row 1"):
+            PayoutBatch("mainnet", TEST_MAINNET_ADDRESS).extend([(TEST_MAINNET_ADDRESS, 1000), ("kaspa:invalid", 1000)])
+
+    def test_plan_chunks_rows_by_max_outputs(self):
+        report = batch(rows=5, max_outputs=2).plan(entries())
+
+        assert [row["status"] for row in report] == ["planned"] * 5
+        assert [row["output_index"] for row in report] == [0, 1, 0, 1, 0]
+        assert len({row["transaction_id"] for row in report}) == 3
+        assert report[3]["reference"] == "withdrawal-3"
+
+    def test_plan_marks_unpayable_rows_failed(self):
+        payouts = batch(rows=2)
+        payouts.add(TEST_MAINNET_ADDRESS, 1_000_000_000_000)
+
+        report = payouts.plan(entries())
+
+        assert [row["status"] for row in report] == ["planned", "planned", "failed"]
+        assert report[2]["error"]
+
+    async def test_submit_failure_skips_remaining_rows(self):
+        report = await batch(rows=3, max_outputs=1, retries=0).submit(
+            RpcClient(network_id="mainnet"), entries(), [PrivateKey(TEST_PRIVATE_KEY_HEX)]
+        )
+
+        assert [row["status"] for row in report] == ["failed", "skipped", "skipped"]
+        assert report[0]["error"]
+
+
+class TestPayoutStore:
+    async def submit(self, payouts):
+        return await payouts.submit(RpcClient(network_id="mainnet"), entries(), [PrivateKey(TEST_PRIVATE_KEY_HEX)])
+
+    def test_add_rejects_duplicate_keys(self, tmp_path):
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, store=tmp_path / "payouts.db")
+        payouts.add(TEST_MAINNET_ADDRESS, 1000, key="w-1")
+
+        with pytest.raises(ValueError, match="Duplicate"):
+            payouts.add(TEST_MAINNET_ADDRESS, 1000, key="w-1")
+
+    async def test_recorded_keys_are_not_paid_again(self, tmp_path):
+        store = PayoutStore(tmp_path / "payouts.db")
+        store.reserve([("w-1", TEST_MAINNET_ADDRESS, 100_000_000, "c" * 64, 0)])
+        store.complete(["w-1"])
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, store=store)
+        payouts.add(TEST_MAINNET_ADDRESS, 100_000_000, key="w-1")
+
+        report = await self.submit(payouts)
+
+        assert report[0]["status"] == "submitted"
+        assert report[0]["transaction_id"] == "c" * 64
+
+    async def test_key_reused_for_another_payout_fails(self, tmp_path):
+        store = PayoutStore(tmp_path / "payouts.db")
+        store.reserve([("w-1", TEST_MAINNET_ADDRESS, 100_000_000, "c" * 64, 0)])
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, store=store)
+        payouts.add(TEST_MAINNET_ADDRESS, 200_000_000, key="w-1")
+
+        report = await self.submit(payouts)
+
+        assert report[0]["status"] == "failed"
+        assert "another payout" in report[0]["error"]
+
+    async def test_unknown_outcome_blocks_key_until_forgotten(self, tmp_path):
+        path = tmp_path / "payouts.db"
+        payouts = PayoutBatch("mainnet", TEST_MAINNET_ADDRESS, retries=0, store=path)
+        payouts.extend([(TEST_MAINNET_ADDRESS, 100_000_000, "a", "w-1"), (TEST_MAINNET_ADDRESS, 100_000_000)])
+
+        report = await self.submit(payouts)
+        assert [row["status"] for row in report] == ["unconfirmed", "failed"]
+        assert PayoutStore(path).get("w-1")["status"] == "pending"
+
+        rerun = await self.submit(payouts)
+        assert rerun[0]["status"] == "unconfirmed"
+        assert rerun[0]["transaction_id"] == report[0]["transaction_id"]
+
+        PayoutStore(path).forget(["w-1"])
+        assert PayoutStore(path).get("w-1") is None
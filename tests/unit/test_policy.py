@@ -0,0 +1,131 @@
+import pytest
+
+from kaspa import (
+    Address,
+    Generator,
+    PaymentOutput,
+    PrivateKey,
+    SpendLimitPolicy,
+    UtxoEntryReference,
+    add_signing_policy,
+    remove_signing_policy,
+)
+from kaspa.exceptions import PolicyViolationError
+from tests.conftest import TEST_MAINNET_ADDRESS, TEST_PRIVATE_KEY_HEX
+
+DESTINATION = "kaspa:qz0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+
+
+def request(transaction_id="a", amount=1000, address=DESTINATION):
+    return {
+        "operation": "sign",
+        "transaction_id": transaction_id * 64,
+        "amount": amount,
+        "fee": 0,
+        "outputs": [
+            {"address": address, "amount": amount, "change": False},
+            {"address": TEST_MAINNET_ADDRESS, "amount": 5000, "change": True},
+        ],
+    }
+
+
+def pending(amount=100_000_000):
+    entries = [
+        UtxoEntryReference.from_dict(
+            {
+                "address": TEST_MAINNET_ADDRESS,
+                "outpoint": {"transactionId": "a" * 64, "index": 0},
+                "amount": 1_000_000_000,
+                "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+                "blockDaaScore": 12345,
+                "isCoinbase": False,
+            }
+        )
+    ]
+    outputs = [PaymentOutput(Address(TEST_MAINNET_ADDRESS), amount)]
+    return list(Generator(entries, TEST_MAINNET_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+
+
+@pytest.fixture
+def policies():
+    registered = []
+    yield registered
+    for policy in registered:
+        remove_signing_policy(policy)
+
+
+class TestSigningPolicy:
+    def test_add_rejects_non_callable(self):
+        with pytest.raises(ValueError):
+            add_signing_policy(42)
+
+    def test_policy_receives_request(self, policies):
+        requests = []
+        policies.append(requests.append)
+        add_signing_policy(requests.append)
+
+        transaction = pending()
+        transaction.sign([PrivateKey(TEST_PRIVATE_KEY_HEX)])
+
+        assert requests[0]["operation"] == "sign"
+        assert requests[0]["transaction_id"] == transaction.id
+        assert requests[0]["fee"] == transaction.fee_amount
+        assert requests[0]["amount"] == 100_000_000
+        assert [output["change"] for output in requests[0]["outputs"]] == [False, True]
+        assert requests[0]["outputs"][0]["address"] == TEST_MAINNET_ADDRESS
+
+    def test_veto_raises_policy_violation(self, policies):
+        def veto(request):
+            return "withdrawals are paused"
+
+        policies.append(veto)
+        add_signing_policy(veto)
+        transaction = pending()
+
+        with pytest.raises(PolicyViolationError, match="withdrawals are paused") as err:
+            transaction.sign([PrivateKey(TEST_PRIVATE_KEY_HEX)])
+
+        assert err.value.transaction_id == transaction.id
+        assert err.value.reason == "withdrawals are paused"
+        assert not transaction.signatures()[0]["signed"]
+
+    def test_remove_signing_policy(self):
+        def veto(request):
+            return False
+
+        add_signing_policy(veto)
+        assert remove_signing_policy(veto)
+        assert not remove_signing_policy(veto)
+
+        pending().sign([PrivateKey(TEST_PRIVATE_KEY_HEX)])
+
+
+class TestSpendLimitPolicy:
+    def test_max_amount(self):
+        policy = SpendLimitPolicy(max_amount=1000)
+
+        assert policy(request(amount=1000)) is None
+        assert "exceeds" in policy(request("b", amount=1001))
+
+    def test_destinations(self):
+        assert "denied" in SpendLimitPolicy(denied_destinations=[DESTINATION])(request())
+        assert "not allowed" in SpendLimitPolicy(allowed_destinations=[TEST_MAINNET_ADDRESS])(request())
+        assert SpendLimitPolicy(allowed_destinations=[Address(TEST_MAINNET_ADDRESS)])(request(address=TEST_MAINNET_ADDRESS)) is None
+
+    def test_window_limit(self):
+        now = [0.0]
+        policy = SpendLimitPolicy(max_per_window=2500, window=3600, clock=lambda: now[0])
+
+        assert policy(request("a")) is None
+        assert policy(request("b")) is None
+        assert policy(request("a")) is None  # the same transaction counts once
+        assert "remaining 500" in policy(request("c"))
+        assert policy.spent == 2000
+
+        now[0] = 3600.0
+        assert policy(request("c")) is None
+        assert policy.spent == 1000
+
+    def test_rejects_invalid_window(self):
+        with pytest.raises(ValueError):
+            SpendLimitPolicy(window=0)
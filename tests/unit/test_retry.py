@@ -0,0 +1,62 @@
+import time
+
+import pytest
+
+from kaspa import RpcClient
+
+UNREACHABLE_URL = "ws://127.0.0.1:1"
+
+
+@pytest.fixture
+def client():
+    return RpcClient(url=UNREACHABLE_URL, network_id="testnet-10")
+
+
+async def elapsed(call):
+    started = time.monotonic()
+    with pytest.raises(Exception, match="not connected"):
+        await call()
+    return time.monotonic() - started
+
+
+async def test_disconnected_calls_are_retried(client):
+    client.set_retry_policy(attempts=3, base_delay=0.2, max_delay=1)
+    # Waits of 0.1-0.2s and 0.2-0.4s between the three attempts
+    assert await elapsed(client.get_block_count) >= 0.3
+
+
+async def test_retrying_is_disabled_by_default(client):
+    assert await elapsed(client.get_block_count) < 0.1
+
+
+async def test_disable(client):
+    client.set_retry_policy(attempts=3, base_delay=0.2)
+    client.set_retry_policy(attempts=None)
+    assert await elapsed(client.get_block_count) < 0.1
+
+
+async def test_side_effects_not_retried_by_default(client):
+    client.set_retry_policy(attempts=3, base_delay=0.2)
+    assert await elapsed(lambda: client.ban({"ip": "127.0.0.2"})) < 0.1
+
+
+async def test_method_overrides(client):
+    client.set_retry_policy(attempts=3, base_delay=0.2, methods={"get_block_count": None, "ban": {"attempts": 2}})
+    assert await elapsed(client.get_block_count) < 0.1
+    assert await elapsed(lambda: client.ban({"ip": "127.0.0.2"})) >= 0.1
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {"attempts": 0},
+        {"base_delay": 0},
+        {"max_delay": -1},
+        {"methods": {"ping": {"attempts": 0}}},
+        {"methods": {"ping": {"retries": 2}}},
+        {"methods": {"ping": 2}},
+    ],
+)
+def test_invalid_policy(client, options):
+    with pytest.raises(Exception):
+        client.set_retry_policy(**options)
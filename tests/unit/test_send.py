@@ -0,0 +1,64 @@
+import pytest
+
+from kaspa import PrivateKey, RpcClient, send, sweep_private_key
+
+PRIVATE_KEY = "389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69"
+
+
+def test_send_requires_connected_client():
+    address = PrivateKey(PRIVATE_KEY).to_address("testnet")
+
+    with pytest.raises(Exception, match="not connected"):
+        send(RpcClient(network_id="testnet-10"), PRIVATE_KEY, address, 1000)
+
+
+def test_send_rejects_network_mismatch():
+    address = PrivateKey(PRIVATE_KEY).to_address("mainnet")
+
+    with pytest.raises(Exception, match="does not belong"):
+        send("ws://127.0.0.1:17110", PRIVATE_KEY, address, 1000, network_id="testnet-10")
+
+
+def test_send_rejects_invalid_rpc():
+    address = PrivateKey(PRIVATE_KEY).to_address("testnet")
+
+    with pytest.raises(Exception, match="rpc must be"):
+        send(42, PRIVATE_KEY, address, 1000)
+
+
+def test_send_rejects_invalid_private_key():
+    address = PrivateKey(PRIVATE_KEY).to_address("testnet")
+
+    with pytest.raises(Exception):
+        send("ws://127.0.0.1:17110", "not-a-key", address, 1000)
+
+
+def test_sweep_private_key_requires_connected_client():
+    address = PrivateKey(PRIVATE_KEY).to_address("testnet")
+
+    with pytest.raises(Exception, match="not connected"):
+        sweep_private_key(RpcClient(network_id="testnet-10"), PRIVATE_KEY, address)
+
+
+def test_sweep_private_key_rejects_network_mismatch():
+    address = PrivateKey(PRIVATE_KEY).to_address("mainnet")
+
+    with pytest.raises(Exception, match="does not belong"):
+        sweep_private_key("ws://127.0.0.1:17110", PRIVATE_KEY, address, network_id="testnet-10")
+
+
+def test_sweep_private_key_rejects_invalid_private_key():
+    address = PrivateKey(PRIVATE_KEY).to_address("testnet")
+
+    with pytest.raises(Exception):
+        sweep_private_key("ws://127.0.0.1:17110", "not-a-key", address)
+
+
+def test_send_rejects_names_without_opt_in():
+    with pytest.raises(Exception):
+        send("ws://127.0.0.1:17110", PRIVATE_KEY, "alice.kas", 1000)
+
+
+def test_send_resolve_names_requires_resolver():
+    with pytest.raises(ValueError, match="no resolver"):
+        send("ws://127.0.0.1:17110", PRIVATE_KEY, "nobody.unknown-suffix", 1000, resolve_names=True)
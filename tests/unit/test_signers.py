@@ -0,0 +1,141 @@
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+from kaspa import Address, Generator, PaymentOutput, PrivateKey, RemoteSigner, UtxoEntryReference, sign_script_hash
+from kaspa.exceptions import ScriptVerificationError
+from kaspa.signers import RemoteSignerError
+from tests.conftest import TEST_MAINNET_ADDRESS, TEST_PRIVATE_KEY_HEX
+
+
+class SigningService:
+    """Local HTTP signing service answering with `respond(request)`."""
+
+    def __init__(self, respond):
+        self.requests: list[dict] = []
+        service = self
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_POST(self):
+                request = json.loads(self.rfile.read(int(self.headers["Content-Length"])))
+                service.requests.append(request)
+                body = json.dumps(respond(request)).encode()
+                self.send_response(200)
+                self.send_header("Content-Type", "application/json")
+                self.end_headers()
+                self.wfile.write(body)
+
+            def log_message(self, *args):
+                pass
+
+        self.server = HTTPServer(("127.0.0.1", 0), Handler)
+        self.url = f"http://127.0.0.1:{self.server.server_port}/sign"
+        threading.Thread(target=self.server.serve_forever, daemon=True).start()
+
+    def close(self):
+        self.server.shutdown()
+
+
+def sign_with(key: str, full_script: bool = False):
+    def respond(request):
+        signatures = []
+        for input in request["inputs"]:
+            script = sign_script_hash(input["sighash"], PrivateKey(key))
+            if full_script:
+                signatures.append({"index": input["index"], "signature_script": script})
+            else:
+                signatures.append({"index": input["index"], "signature": script[2:130]})
+        return {"signatures": signatures}
+
+    return respond
+
+
+@pytest.fixture
+def service(request):
+    service = SigningService(request.param)
+    yield service
+    service.close()
+
+
+def responds(respond):
+    return pytest.mark.parametrize("service", [respond], indirect=True)
+
+
+def pending():
+    entries = [
+        UtxoEntryReference.from_dict(
+            {
+                "address": TEST_MAINNET_ADDRESS,
+                "outpoint": {"transactionId": "a" * 64, "index": 0},
+                "amount": 1_000_000_000,
+                "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+                "blockDaaScore": 12345,
+                "isCoinbase": False,
+            }
+        )
+    ]
+    outputs = [PaymentOutput(Address(TEST_MAINNET_ADDRESS), 100_000_000)]
+    return list(Generator(entries, TEST_MAINNET_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+
+
+class TestRemoteSigner:
+    def test_rejects_invalid_settings(self):
+        with pytest.raises(ValueError):
+            RemoteSigner("https://signer.invalid/sign", timeout=0)
+        with pytest.raises(ValueError):
+            RemoteSigner("https://signer.invalid/sign", key="client.key")
+
+    @responds(sign_with(TEST_PRIVATE_KEY_HEX))
+    def test_sign(self, service):
+        transaction = pending()
+        RemoteSigner(service.url, key_id="hot-1").sign(transaction)
+
+        request = service.requests[0]
+        assert request["transaction_id"] == transaction.id
+        assert request["key_id"] == "hot-1"
+        assert request["inputs"][0]["sighash"] == transaction.signature_hash(0)
+        assert request["inputs"][0]["address"] == TEST_MAINNET_ADDRESS
+        assert request["inputs"][0]["ecdsa"] is False
+        assert all(input["valid"] for input in transaction.signatures())
+
+    @responds(sign_with(TEST_PRIVATE_KEY_HEX, full_script=True))
+    def test_sign_with_signature_scripts(self, service):
+        transaction = pending()
+        RemoteSigner(service.url).sign(transaction)
+
+        transaction.validate()
+
+    @responds(sign_with("1" * 64))
+    def test_wrong_key_fails_verification(self, service):
+        with pytest.raises(ScriptVerificationError):
+            RemoteSigner(service.url).sign(pending())
+
+    @responds(lambda request: {"signatures": []})
+    def test_unsigned_inputs_raise(self, service):
+        with pytest.raises(RemoteSignerError, match="unsigned"):
+            RemoteSigner(service.url).sign(pending())
+
+    @responds(lambda request: {"error": "limit exceeded"})
+    def test_service_error_raises(self, service):
+        with pytest.raises(RemoteSignerError, match="limit exceeded"):
+            RemoteSigner(service.url).sign(pending())
+
+    def test_connection_error_raises(self):
+        with pytest.raises(RemoteSignerError) as err:
+            RemoteSigner("http://127.0.0.1:1/sign", timeout=0.5).sign(pending())
+
+        assert err.value.status is None
+
+
+class TestSignatureHash:
+    def test_out_of_range_input_raises(self):
+        with pytest.raises(ValueError, match="out of range"):
+            pending().signature_hash(5)
+
+    def test_schnorr_and_ecdsa_hashes_differ(self):
+        transaction = pending()
+
+        assert len(transaction.signature_hash(0)) == 64
+        assert transaction.signature_hash(0) != transaction.signature_hash(0, ecdsa=True)
@@ -0,0 +1,32 @@
+import pytest
+
+from kaspa import RpcClient, ScriptPublicKey, Transaction, TransactionOutput
+
+
+@pytest.fixture
+def client():
+    return RpcClient(url="ws://127.0.0.1:17110", network_id="testnet-10")
+
+
+def test_submit_serialized_transaction_rejects_invalid_hex(client):
+    with pytest.raises(Exception):
+        client.submit_serialized_transaction("not-hex")
+
+
+def test_submit_serialized_transaction_rejects_truncated_transaction(client):
+    with pytest.raises(Exception, match="Invalid serialized transaction"):
+        client.submit_serialized_transaction("0000")
+
+
+def test_submit_serialized_transaction_rejects_truncated_bytes(client):
+    with pytest.raises(Exception, match="Invalid serialized transaction"):
+        client.submit_serialized_transaction(bytes(4))
+
+
+def test_submit_serialized_transaction_rejects_truncated_to_bytes(client):
+    output = TransactionOutput(1000, ScriptPublicKey(0, b"\x51"))
+    transaction = Transaction(0, [], [output], 0, bytes(20), 0, b"", 0)
+    data = transaction.to_bytes()
+
+    with pytest.raises(Exception, match="Invalid serialized transaction"):
+        client.submit_serialized_transaction(data[:-1])
@@ -0,0 +1,112 @@
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+from kaspa import testnet
+from kaspa.testnet import FaucetError
+
+ADDRESS = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+
+
+class Faucet:
+    """Local HTTP faucet answering with `status` and `response`."""
+
+    def __init__(self, status=200, response=None):
+        self.requests: list[dict] = []
+        faucet = self
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_POST(self):
+                faucet.requests.append(json.loads(self.rfile.read(int(self.headers["Content-Length"]))))
+                body = json.dumps(response if response is not None else {"transactionId": "b" * 64}).encode()
+                self.send_response(status)
+                self.send_header("Content-Type", "application/json")
+                self.end_headers()
+                self.wfile.write(body)
+
+            def log_message(self, *args):
+                pass
+
+        self.server = HTTPServer(("127.0.0.1", 0), Handler)
+        self.url = f"http://127.0.0.1:{self.server.server_port}/faucet"
+        threading.Thread(target=self.server.serve_forever, daemon=True).start()
+
+    def close(self):
+        self.server.shutdown()
+
+
+class FakeRpc:
+    """Answers UTXO queries from `entries`, advancing the DAA score by 50 per call."""
+
+    def __init__(self, entries):
+        self.entries = entries
+        self.daa_score = 1000
+
+    async def get_utxos_by_addresses(self, request):
+        return {"entries": self.entries}
+
+    async def get_server_info(self):
+        self.daa_score += 50
+        return {"virtualDaaScore": self.daa_score}
+
+    async def get_balance_by_address(self, request):
+        return {"balance": 0}
+
+
+def entry(amount, daa_score=1000):
+    return {
+        "address": ADDRESS,
+        "outpoint": {"transactionId": "a" * 64, "index": 0},
+        "utxoEntry": {"amount": amount, "blockDaaScore": daa_score, "isCoinbase": False},
+    }
+
+
+@pytest.fixture
+def faucet(request):
+    faucet = Faucet(**getattr(request, "param", {}))
+    yield faucet
+    faucet.close()
+
+
+class TestRequestFunds:
+    def test_posts_address_and_amount(self, faucet):
+        assert testnet.request_funds(ADDRESS, 1000, url=faucet.url) == "b" * 64
+        assert faucet.requests == [{"address": ADDRESS, "amount": 1000}]
+
+    @pytest.mark.parametrize("faucet", [{"status": 429}], indirect=True)
+    def test_http_error(self, faucet):
+        with pytest.raises(FaucetError) as info:
+            testnet.request_funds(ADDRESS, url=faucet.url)
+        assert info.value.status == 429
+
+    @pytest.mark.parametrize("faucet", [{"response": {"error": "rate limited"}}], indirect=True)
+    def test_refusal(self, faucet):
+        with pytest.raises(FaucetError, match="rate limited"):
+            testnet.request_funds(ADDRESS, url=faucet.url)
+
+    def test_faucet_url(self, monkeypatch):
+        monkeypatch.delenv("KASPA_FAUCET_URL", raising=False)
+        assert testnet.faucet_url("testnet-11") == testnet.FAUCETS["testnet-11"]
+        with pytest.raises(ValueError):
+            testnet.faucet_url("mainnet")
+        monkeypatch.setenv("KASPA_FAUCET_URL", "http://localhost/faucet")
+        assert testnet.faucet_url("mainnet") == "http://localhost/faucet"
+
+
+class TestWaitForFunds:
+    async def test_waits_for_maturity(self):
+        rpc = FakeRpc([entry(5000)])
+        entries = await testnet.wait_for_funds(rpc, ADDRESS, 5000, maturity=100, poll_interval=0)
+        assert entries == [entry(5000)]
+        assert rpc.daa_score >= 1100
+
+    async def test_timeout(self):
+        with pytest.raises(TimeoutError):
+            await testnet.wait_for_funds(FakeRpc([entry(10)]), ADDRESS, 5000, timeout=0.05, poll_interval=0.01)
+
+    async def test_fund(self, faucet):
+        entries = await testnet.fund(FakeRpc([entry(5000)]), ADDRESS, 5000, url=faucet.url)
+        assert len(entries) == 1
+        assert faucet.requests[0]["address"] == ADDRESS
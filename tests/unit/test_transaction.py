@@ -2,6 +2,8 @@
 Unit tests for Transaction creation, signing, and related functionality.
 """
 
+import math
+
 import pytest
 
 from kaspa import (
@@ -15,18 +17,24 @@ from kaspa import (
     UtxoEntryReference,
     PrivateKey,
     Address,
+    Fees,
     Generator,
+    GeneratorSummary,
     PaymentOutput,
     Hash,
     sign_transaction,
     create_input_signature,
+    create_multisig_address,
     create_transaction,
     create_transactions,
     estimate_transactions,
     calculate_transaction_mass,
     calculate_transaction_fee,
     maximum_standard_transaction_mass,
+    max_payload_size_for,
+    plan_payload,
     SighashType,
+    pay_to_script_hash_script,
 )
 
 
@@ -87,6 +95,22 @@ class TestScriptPublicKey:
         script = spk.script
         assert isinstance(script, str)
 
+    def test_create_script_public_key_from_bytearray_and_memoryview(self):
+        """Test creating a ScriptPublicKey from bytearray and memoryview."""
+        spk1 = ScriptPublicKey(0, bytearray([0x51]))
+        spk2 = ScriptPublicKey(0, memoryview(b"\x51"))
+        assert spk1 == spk2
+
+    def test_script_public_key_script_bytes_property(self):
+        """Test ScriptPublicKey script_bytes property."""
+        spk = ScriptPublicKey(0, "51")
+        assert spk.script_bytes == b"\x51"
+
+    def test_script_public_key_odd_hex_raises(self):
+        """Test odd-length hex strings are rejected."""
+        with pytest.raises(Exception):
+            ScriptPublicKey(0, "515")
+
 
 class TestTransactionOutput:
     """Tests for TransactionOutput class."""
@@ -133,6 +157,14 @@ class TestTransactionInput:
         assert input.sequence == 0xFFFFFFFF
         assert input.sig_op_count == 1
 
+    def test_transaction_input_signature_script_bytes(self):
+        """Test TransactionInput signature_script_bytes property."""
+        outpoint = TransactionOutpoint(Hash("a" * 64), 5)
+        input = TransactionInput(outpoint, bytes.fromhex("deadbeef"), 0, 1)
+
+        assert input.signature_script_bytes == bytes.fromhex("deadbeef")
+        assert input.signature_script_as_hex == "deadbeef"
+
 
 class TestTransaction:
     """Tests for Transaction class."""
@@ -193,6 +225,45 @@ class TestTransaction:
         assert isinstance(tx_id, str)
         assert len(tx_id) == 64  # 32 bytes hex
 
+    def test_transaction_payload_bytes(self):
+        """Test Transaction payload accepts and returns bytes."""
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, b"", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, b"\x51"))
+
+        tx = Transaction(0, [input], [output], 0, bytes(20), 0, b"\x01\x02", 0)
+
+        assert tx.payload_bytes == b"\x01\x02"
+        assert tx.payload == "0102"
+
+        tx.payload = bytearray(b"\x03")
+        assert tx.payload_bytes == b"\x03"
+
+        tx.subnetwork_id = bytes(20)
+        assert tx.subnetwork == "0" * 40
+
+    def test_transaction_bytes_round_trip(self):
+        """Test Transaction serializes to bytes and back."""
+        outpoint = TransactionOutpoint(Hash("ab" * 32), 3)
+        input = TransactionInput(outpoint, b"\x41" + bytes(65), 7, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, b"\x51"))
+        tx = Transaction(0, [input], [output], 5, bytes(20), 0, b"\x01\x02", 0)
+        tx.finalize()
+
+        data = tx.to_bytes()
+        assert isinstance(data, bytes)
+
+        restored = Transaction.from_bytes(data)
+        assert restored == tx
+        assert restored.id == tx.id
+        assert Transaction.from_bytes(memoryview(data)) == tx
+        assert Transaction.from_bytes(data.hex()) == tx
+
+    def test_transaction_from_bytes_invalid_raises(self):
+        """Test Transaction.from_bytes rejects data that is not a transaction."""
+        with pytest.raises(ValueError, match="data"):
+            Transaction.from_bytes(bytes(4))
+
     def test_transaction_is_coinbase(self):
         """Test Transaction is_coinbase method."""
         tx_hash = Hash("0" * 64)
@@ -242,7 +313,694 @@ class TestCreateTransaction:
     pass
 
 
+GENERATOR_ADDRESS = "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+
+
+def generator_entries(amount=1_000_000_000, count=1):
+    return [
+        UtxoEntryReference.from_dict({
+            "address": GENERATOR_ADDRESS,
+            "outpoint": {"transactionId": "a" * 64, "index": index},
+            "amount": amount,
+            "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        })
+        for index in range(count)
+    ]
+
+
 class TestGenerator:
     """Tests for Generator class."""
-    # TODO
-    pass
+
+    def test_change_address_callable(self):
+        """Test a callable change address is invoked once per Generator."""
+        calls = []
+        change_address = PrivateKey(SIGNER_KEY).to_address("mainnet").to_string()
+
+        def next_change_address():
+            calls.append(1)
+            return change_address
+
+        generator = Generator(
+            generator_entries(),
+            next_change_address,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        )
+        transactions = list(generator)
+
+        assert len(calls) == 1
+        assert transactions[-1].change_amount > 0
+        assert transactions[-1].change_entry.address.to_string() == change_address
+
+    def test_change_address_callable_per_rebuild(self):
+        """Test a rebuilt plan sends its change to a fresh address."""
+        addresses = iter([
+            PrivateKey(SIGNER_KEY).to_address("mainnet").to_string(),
+            PrivateKey(OTHER_SIGNER_KEY).to_address("mainnet").to_string(),
+        ])
+        plan = Generator(
+            generator_entries(),
+            lambda: next(addresses),
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ).estimate()
+        rebuilt = plan.rebuild(priority_fee=10_000)["plan"]
+
+        change = plan.steps[-1]["outputs"][-1]["address"]
+        rebuilt_change = rebuilt.steps[-1]["outputs"][-1]["address"]
+        assert change == PrivateKey(SIGNER_KEY).to_address("mainnet").to_string()
+        assert rebuilt_change == PrivateKey(OTHER_SIGNER_KEY).to_address("mainnet").to_string()
+
+    def test_change_address_string(self):
+        """Test change address accepts an address string."""
+        generator = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        )
+        assert len(list(generator)) == 1
+
+    def test_min_change_folds_change_into_fee(self):
+        """Test change below min_change is added to the fee."""
+        outputs = [PaymentOutput(Address(GENERATOR_ADDRESS), 999_000_000)]
+        baseline = list(Generator(generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+        assert 0 < baseline.change_amount < 100_000_000
+
+        generator = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=outputs,
+            min_change=100_000_000,
+        )
+        folded = list(generator)[-1]
+
+        assert folded.change_amount == 0
+        assert len(folded.transaction.outputs) == 1
+        assert folded.fee_amount == baseline.fee_amount + baseline.change_amount
+        assert generator.summary().fees == folded.fee_amount
+        assert generator.summary().final_transaction_id == folded.id
+
+    def test_min_change_keeps_change_above_threshold(self):
+        """Test change at or above min_change is kept."""
+        outputs = [PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)]
+        baseline = list(Generator(generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+        pending = list(Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=outputs,
+            min_change=baseline.change_amount,
+        ))[-1]
+
+        assert pending.id == baseline.id
+        assert pending.change_amount == baseline.change_amount
+
+    def test_min_change_receiver_pays(self):
+        """Test change below min_change covers the fees paid by the receiver."""
+        outputs = [PaymentOutput(Address(GENERATOR_ADDRESS), 999_000_000)]
+        baseline = list(Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=outputs,
+            priority_fee=Fees(1000, receiver_pays=True),
+        ))[-1]
+        assert baseline.change_amount > 0
+
+        folded = list(Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=outputs,
+            priority_fee=Fees(1000, receiver_pays=True),
+            min_change=100_000_000,
+        ))[-1]
+
+        assert folded.change_amount == 0
+        assert len(folded.transaction.outputs) == 1
+        received = folded.transaction.outputs[0].value
+        assert received > baseline.transaction.outputs[0].value
+        assert received + folded.fee_amount == 1_000_000_000
+
+    def test_min_change_estimate_transactions(self):
+        """Test the summary of estimate_transactions reflects the folded change."""
+        outputs = [PaymentOutput(Address(GENERATOR_ADDRESS), 999_000_000)]
+        baseline = estimate_transactions(generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs)
+        summary = estimate_transactions(
+            generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs, min_change=100_000_000
+        )
+
+        assert summary.fees > baseline.fees
+        assert summary.final_transaction_id != baseline.final_transaction_id
+
+    def test_split_outputs(self):
+        """Test split_outputs splits each payment output into equal parts."""
+        generator = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 300_000_001)],
+            split_outputs=3,
+        )
+        outputs = list(generator)[-1].transaction.outputs
+
+        assert [output.value for output in outputs[:3]] == [100_000_001, 100_000_000, 100_000_000]
+
+    def test_split_outputs_zero_raises(self):
+        """Test split_outputs=0 raises."""
+        with pytest.raises(Exception, match="greater than 0"):
+            Generator(
+                generator_entries(),
+                GENERATOR_ADDRESS,
+                network_id="mainnet",
+                outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 1000)],
+                split_outputs=0,
+            )
+
+    def test_estimate_transactions_split_outputs(self):
+        """Test estimate_transactions accepts split_outputs and min_change."""
+        summary = estimate_transactions(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 300_000_000)],
+            min_change=1000,
+            split_outputs=3,
+        )
+        assert summary.transactions == 1
+
+    def test_percentage_outputs(self):
+        """Test percentage_outputs routes a share of the payment to extra outputs."""
+        generator = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 100_000_000)],
+            percentage_outputs=[
+                {"address": GENERATOR_ADDRESS, "percent": 2.5},
+                {"address": Address(GENERATOR_ADDRESS), "percent": 0.1},
+            ],
+        )
+        outputs = list(generator)[-1].transaction.outputs
+
+        assert [output.value for output in outputs[:3]] == [97_400_000, 2_500_000, 100_000]
+
+    @pytest.mark.parametrize("rounding, share", [("down", 33), ("up", 34), ("nearest", 33)])
+    def test_percentage_outputs_rounding(self, rounding, share):
+        """Test percentage output amounts follow the rounding rule."""
+        generator = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 1001)],
+            percentage_outputs=[{"address": GENERATOR_ADDRESS, "percent": 3.3, "rounding": rounding}],
+        )
+        outputs = list(generator)[-1].transaction.outputs
+
+        assert [output.value for output in outputs[:2]] == [1001 - share, share]
+
+    @pytest.mark.parametrize("templates, match", [
+        ([{"address": GENERATOR_ADDRESS, "percent": 0}], "between 0 and 100"),
+        ([{"address": GENERATOR_ADDRESS, "percent": 60}, {"address": GENERATOR_ADDRESS, "percent": 40}], "less than 100"),
+        ([{"address": GENERATOR_ADDRESS, "percent": 1, "rounding": "half"}], "rounding"),
+        ([{"percent": 1}], "address"),
+    ])
+    def test_percentage_outputs_invalid_raises(self, templates, match):
+        """Test invalid percentage output templates raise ValueError."""
+        with pytest.raises(ValueError, match=match):
+            Generator(
+                generator_entries(),
+                GENERATOR_ADDRESS,
+                network_id="mainnet",
+                outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 1000)],
+                percentage_outputs=templates,
+            )
+
+    def test_change_entry(self):
+        """Test change_entry exposes the unconfirmed change output."""
+        pending = list(Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ))[-1]
+        entry = pending.change_entry
+
+        assert entry.amount == pending.change_amount
+        assert entry.outpoint.transaction_id == pending.id
+        assert entry.outpoint.index == len(pending.transaction.outputs) - 1
+
+    def test_change_entry_none_without_change(self):
+        """Test change_entry is None when change is folded into the fee."""
+        pending = list(Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 999_000_000)],
+            min_change=100_000_000,
+        ))[-1]
+
+        assert pending.change_amount == 0
+        assert pending.change_entry is None
+
+    def test_change_address_invalid_raises(self):
+        """Test an invalid change address raises."""
+        with pytest.raises(Exception):
+            Generator(generator_entries(), 42, network_id="mainnet")
+
+    def test_estimate_returns_plan(self):
+        """Test estimate returns a TransactionPlan describing each transaction."""
+        plan = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ).estimate()
+
+        assert isinstance(plan, GeneratorSummary)
+        assert len(plan.steps) == plan.transactions == 1
+        step = plan.steps[0]
+        assert step["type"] == "final"
+        assert step["id"] == plan.final_transaction_id
+        assert step["fee"] == plan.fees
+        assert step["inputs"][0]["transaction_id"] == "a" * 64
+        assert step["outputs"][0] == {"address": GENERATOR_ADDRESS, "amount": 500_000_000}
+        assert [entry.amount for entry in plan.inputs] == [1_000_000_000]
+        assert plan.to_dict()["steps"] == plan.steps
+
+    def test_plan_materialize(self):
+        """Test materialize returns the planned transactions."""
+        plan = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ).estimate()
+        transactions = plan.materialize()
+
+        assert [pending.id for pending in transactions] == [step["id"] for step in plan.steps]
+        assert transactions[-1].fee_amount == plan.fees
+
+    def test_plan_rebuild(self):
+        """Test rebuild returns the new plan and its differences."""
+        plan = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ).estimate()
+        diff = plan.rebuild(priority_fee=10_000)
+
+        assert diff["fees"] == 10_000
+        assert diff["transactions"] == 0
+        assert diff["added"] == diff["removed"] == []
+        assert diff["plan"].fees == plan.fees + 10_000
+
+    def test_plan_rebuild_keeps_batch_transactions(self):
+        """Test rebuild only generates the final transaction again."""
+        plan = Generator(
+            generator_entries(amount=10_000_000, count=150),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 1_000_000_000)],
+        ).estimate()
+        assert plan.transactions > 1
+
+        diff = plan.rebuild(priority_fee=10_000)
+        rebuilt = diff["plan"]
+
+        assert [step["id"] for step in rebuilt.steps[:-1]] == [step["id"] for step in plan.steps[:-1]]
+        assert diff["fees"] == 10_000
+        assert diff["transactions"] == 0
+        assert rebuilt.utxos == plan.utxos
+
+    def test_plan_rebuild_insufficient_funds_raises(self):
+        """Test rebuild raises when the snapshot cannot cover the new fee."""
+        plan = Generator(
+            generator_entries(),
+            GENERATOR_ADDRESS,
+            network_id="mainnet",
+            outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        ).estimate()
+        with pytest.raises(Exception):
+            plan.rebuild(priority_fee=600_000_000)
+
+
+class TestFees:
+    """Tests for Fees class."""
+
+    def test_absolute(self):
+        """Test absolute fees."""
+        fees = Fees(1000)
+        assert fees.amount == 1000
+        assert fees.fee_rate is None
+        assert not fees.receiver_pays
+        assert Fees(1000, receiver_pays=True).receiver_pays
+
+    def test_rate(self):
+        """Test fee rate and invalid rates."""
+        assert Fees.rate(2.5).fee_rate == 2.5
+        assert Fees.rate(2.5).amount is None
+        with pytest.raises(Exception):
+            Fees.rate(0)
+
+    def test_from_bucket(self):
+        """Test fee rate from a fee estimate bucket dict."""
+        fees = Fees.from_bucket({"feerate": 3.0, "estimatedSeconds": 1.0})
+        assert fees.fee_rate == 3.0
+        with pytest.raises(Exception):
+            Fees.from_bucket({"estimatedSeconds": 1.0})
+
+    def test_generator_priority_fee_modes(self):
+        """Test Generator accepts int and Fees priority fees."""
+        outputs = [PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)]
+        baseline = list(Generator(generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+        absolute = list(Generator(
+            generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs, priority_fee=Fees(10_000)
+        ))[-1]
+        plain = list(Generator(
+            generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs, priority_fee=10_000
+        ))[-1]
+        rated = list(Generator(
+            generator_entries(), GENERATOR_ADDRESS, network_id="mainnet", outputs=outputs, priority_fee=Fees.rate(10.0)
+        ))[-1]
+
+        assert absolute.fee_amount == baseline.fee_amount + 10_000
+        assert plain.fee_amount == absolute.fee_amount
+        assert rated.fee_amount > baseline.fee_amount
+
+    def test_generator_rate_conflicts_with_fee_rate(self):
+        """Test a rate Fees together with fee_rate raises."""
+        with pytest.raises(Exception, match="fee rate"):
+            Generator(
+                generator_entries(),
+                GENERATOR_ADDRESS,
+                network_id="mainnet",
+                fee_rate=1.0,
+                priority_fee=Fees.rate(2.0),
+            )
+
+
+SIGNER_KEY = "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef"
+
+
+def signer_pending():
+    key = PrivateKey(SIGNER_KEY)
+    address = key.to_address("mainnet")
+    entries = [
+        UtxoEntryReference.from_dict({
+            "address": address.to_string(),
+            "outpoint": {"transactionId": "b" * 64, "index": 0},
+            "amount": 1_000_000_000,
+            "scriptPublicKey": {"version": 0, "script": "20" + key.to_public_key().to_x_only_public_key().to_string() + "ac"},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        })
+    ]
+    pending = list(Generator(
+        entries,
+        address,
+        network_id="mainnet",
+        outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+    ))[-1]
+    return key, pending
+
+
+OTHER_SIGNER_KEY = "389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69"
+
+
+def two_signer_pending(inputs_per_key=4):
+    """A transaction spending `inputs_per_key` UTXOs of each of two keys."""
+    keys = [PrivateKey(SIGNER_KEY), PrivateKey(OTHER_SIGNER_KEY)]
+    entries = [
+        UtxoEntryReference.from_dict({
+            "address": key.to_address("mainnet").to_string(),
+            "outpoint": {"transactionId": f"{index:064x}", "index": 0},
+            "amount": 100_000_000,
+            "scriptPublicKey": {"version": 0, "script": "20" + key.to_public_key().to_x_only_public_key().to_string() + "ac"},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        })
+        for index, key in enumerate(keys * inputs_per_key)
+    ]
+    pending = list(Generator(
+        entries,
+        keys[0].to_address("mainnet"),
+        network_id="mainnet",
+        outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 700_000_000)],
+    ))[-1]
+    return keys, pending
+
+
+class TestPendingTransactionSign:
+    """Tests for PendingTransaction.sign()."""
+
+    def test_matches_sequential_signing(self):
+        """Test parallel signing signs the same inputs with the same keys as sign_input()."""
+        keys, parallel = two_signer_pending()
+        _, sequential = two_signer_pending()
+        parallel.sign(keys)
+        for index, entry in enumerate(sequential.get_utxo_entries()):
+            key = next(key for key in keys if key.to_address("mainnet").to_string() == entry.address.to_string())
+            sequential.sign_input(index, key)
+
+        assert parallel.id == sequential.id
+        assert parallel.signatures() == sequential.signatures()
+        assert all(input["valid"] for input in parallel.signatures())
+        parallel.validate()
+
+    def test_partial_signing_raises_by_default(self):
+        """Test inputs left unsigned raise unless the check is disabled."""
+        keys, pending = two_signer_pending()
+        with pytest.raises(Exception, match="not fully signed"):
+            pending.sign([keys[0]])
+
+        pending.sign([keys[0]], check_fully_signed=False)
+        signed = [input["signed"] for input in pending.signatures()]
+        assert signed.count(True) == len(signed) // 2
+
+    def test_keeps_filled_inputs(self):
+        """Test inputs filled beforehand count as signed and are kept."""
+        keys, pending = two_signer_pending(inputs_per_key=1)
+        other_index = next(
+            index for index, input in enumerate(pending.transaction.inputs)
+            if input.utxo.address.to_string() == keys[1].to_address("mainnet").to_string()
+        )
+        signature = pending.create_input_signature(other_index, keys[1])
+        pending.fill_input(other_index, signature)
+
+        pending.sign([keys[0]])
+
+        assert pending.transaction.inputs[other_index].signature_script_as_hex == signature
+        pending.validate()
+
+
+class TestPendingTransactionSignatures:
+    """Tests for PendingTransaction.signatures()."""
+
+    def test_unsigned(self):
+        """Test unsigned inputs report no signatures."""
+        _, pending = signer_pending()
+        report = pending.signatures()
+
+        assert len(report) == 1
+        assert report[0]["index"] == 0
+        assert report[0]["signed"] is False
+        assert report[0]["valid"] is False
+        assert report[0]["signatures"] == []
+
+    def test_signed(self):
+        """Test signed inputs report the signer key and sighash type."""
+        key, pending = signer_pending()
+        pending.sign([key])
+        report = pending.signatures()
+
+        assert report[0]["signed"] is True
+        assert report[0]["valid"] is True
+        assert report[0]["address"] == key.to_address("mainnet").to_string()
+        assert report[0]["signatures"] == [{
+            "public_key": key.to_public_key().to_x_only_public_key().to_string(),
+            "sighash_type": "All",
+            "valid": True,
+        }]
+
+    def test_wrong_key(self):
+        """Test a signature by another key is reported invalid."""
+        key, pending = signer_pending()
+        other = PrivateKey("389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69")
+        pending.fill_input(0, pending.create_input_signature(0, other))
+        report = pending.signatures()
+
+        assert report[0]["signed"] is True
+        assert report[0]["valid"] is False
+        assert report[0]["signatures"][0]["public_key"] is None
+
+
+class TestPendingTransactionValidate:
+    """Tests for PendingTransaction.validate()."""
+
+    def test_signed(self):
+        """Test a correctly signed transaction validates."""
+        key, pending = signer_pending()
+        pending.sign([key])
+        pending.validate()
+
+    def test_unsigned_raises(self):
+        """Test an unsigned input raises with its index."""
+        from kaspa.exceptions import ScriptVerificationError
+
+        _, pending = signer_pending()
+        with pytest.raises(ScriptVerificationError) as excinfo:
+            pending.validate()
+
+        assert excinfo.value.input_index == 0
+        assert excinfo.value.transaction_id == pending.id
+
+    def test_wrong_key_raises(self):
+        """Test a signature by another key raises."""
+        from kaspa.exceptions import ScriptVerificationError
+
+        _, pending = signer_pending()
+        other = PrivateKey("389840d7696e89c38856a066175e8e92697f0cf182b854c883237a50acaf1f69")
+        pending.fill_input(0, pending.create_input_signature(0, other))
+        with pytest.raises(ScriptVerificationError):
+            pending.validate()
+
+
+MULTISIG_KEYS = [PrivateKey(digit * 64) for digit in "123"]
+
+
+def multisig_pending():
+    """A 2-of-3 Schnorr multisig spend and its redeem script."""
+    x_only_keys = [key.to_public_key().to_x_only_public_key().to_string() for key in MULTISIG_KEYS]
+    redeem_script = bytes.fromhex("52" + "".join("20" + key for key in x_only_keys) + "53ae")
+    address = create_multisig_address(2, [key.to_public_key() for key in MULTISIG_KEYS], "mainnet").to_string()
+    entries = [
+        UtxoEntryReference.from_dict({
+            "address": address,
+            "outpoint": {"transactionId": "c" * 64, "index": 0},
+            "amount": 1_000_000_000,
+            "scriptPublicKey": {"version": 0, "script": pay_to_script_hash_script(redeem_script).script},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        })
+    ]
+    pending = list(Generator(
+        entries,
+        Address(GENERATOR_ADDRESS),
+        network_id="mainnet",
+        outputs=[PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)],
+        minimum_signatures=2,
+    ))[-1]
+    return redeem_script, pending
+
+
+class TestPendingTransactionFinalizeMultisig:
+    """Tests for PendingTransaction.finalize_multisig()."""
+
+    def key_hex(self, key):
+        return key.to_public_key().to_x_only_public_key().to_string()
+
+    def test_threshold_met(self):
+        """Test two distinct cosigners finalize a valid input."""
+        redeem_script, pending = multisig_pending()
+        signatures = [pending.create_input_signature(0, key) for key in (MULTISIG_KEYS[2], MULTISIG_KEYS[0])]
+        report = pending.finalize_multisig(0, redeem_script, signatures)
+
+        assert report["complete"] is True
+        assert report["required"] == 2
+        assert report["signed"] == 2
+        assert report["missing"] == [self.key_hex(MULTISIG_KEYS[1])]
+        pending.validate()
+
+    def test_missing_cosigner(self):
+        """Test a single signature reports the missing cosigners and leaves the input unsigned."""
+        redeem_script, pending = multisig_pending()
+        signature = pending.create_input_signature(0, MULTISIG_KEYS[1])
+        report = pending.finalize_multisig(0, redeem_script, [signature])
+
+        assert report["complete"] is False
+        assert report["signed"] == 1
+        assert report["cosigners"] == [self.key_hex(MULTISIG_KEYS[1])]
+        assert report["missing"] == [self.key_hex(MULTISIG_KEYS[0]), self.key_hex(MULTISIG_KEYS[2])]
+        assert not pending.signatures()[0]["signed"]
+
+    def test_duplicate_signature_does_not_count(self):
+        """Test the same cosigner signing twice does not meet the threshold."""
+        redeem_script, pending = multisig_pending()
+        signature = pending.create_input_signature(0, MULTISIG_KEYS[0])
+        report = pending.finalize_multisig(0, redeem_script, [signature, signature])
+
+        assert report["complete"] is False
+        assert report["signed"] == 1
+        assert report["duplicates"] == [self.key_hex(MULTISIG_KEYS[0])]
+
+    def test_unmatched_signature(self):
+        """Test a signature by a key outside the redeem script is not counted."""
+        redeem_script, pending = multisig_pending()
+        outsider = PrivateKey(SIGNER_KEY)
+        signatures = [pending.create_input_signature(0, key) for key in (MULTISIG_KEYS[0], outsider)]
+        report = pending.finalize_multisig(0, redeem_script, signatures)
+
+        assert report["complete"] is False
+        assert report["unmatched"] == 1
+
+    def test_wrong_redeem_script_raises(self):
+        """Test a redeem script not matching the spent output raises."""
+        redeem_script, pending = multisig_pending()
+        other = bytes([0x51]) + redeem_script[1:]  # 1-of-3 with the same keys
+        with pytest.raises(ValueError, match="does not hash"):
+            pending.finalize_multisig(0, other, [])
+
+    def test_not_multisig_raises(self):
+        """Test a redeem script that is not multisig raises."""
+        _, pending = multisig_pending()
+        with pytest.raises(ValueError, match="multisig"):
+            pending.finalize_multisig(0, bytes([0x51]), [])
+
+
+class TestPayloadPlanning:
+    """Tests for max_payload_size_for() and plan_payload()."""
+
+    def outputs(self):
+        return [PaymentOutput(Address(GENERATOR_ADDRESS), 500_000_000)]
+
+    def test_max_payload_size_fits(self):
+        """Test the maximum payload fits and one more byte does not."""
+        size = max_payload_size_for(generator_entries(), self.outputs(), "mainnet")
+
+        assert 0 < size < maximum_standard_transaction_mass()
+        assert plan_payload(size, generator_entries(), self.outputs(), "mainnet")["fits"]
+        assert not plan_payload(size + 1, generator_entries(), self.outputs(), "mainnet")["fits"]
+
+    def test_plan_payload(self):
+        """Test a small payload plan reports its mass and fee."""
+        plan = plan_payload(100, generator_entries(), self.outputs(), "mainnet")
+        empty = plan_payload(0, generator_entries(), self.outputs(), "mainnet")
+
+        assert plan["fits"]
+        assert plan["payload_size"] == 100
+        assert plan["mass"] > empty["mass"]
+        assert plan["max_mass"] == maximum_standard_transaction_mass()
+        assert plan["fee"] >= plan["mass"]
+
+    def test_plan_payload_fee_rate(self):
+        """Test fee_rate scales the fee."""
+        plan = plan_payload(100, generator_entries(), self.outputs(), "mainnet", fee_rate=10.0)
+        assert plan["fee"] == math.ceil(plan["mass"] * 10.0)
+
+    def test_plan_payload_too_large(self):
+        """Test an oversized payload does not fit and has no fee."""
+        plan = plan_payload(maximum_standard_transaction_mass(), generator_entries(), self.outputs(), "mainnet")
+
+        assert not plan["fits"]
+        assert plan["fee"] is None
+
+    def test_plan_payload_negative_fee_rate_raises(self):
+        """Test a negative fee_rate raises."""
+        with pytest.raises(Exception, match="fee_rate"):
+            plan_payload(100, generator_entries(), self.outputs(), "mainnet", fee_rate=-1.0)
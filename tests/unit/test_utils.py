@@ -2,10 +2,16 @@
 Unit tests for utility functions.
 """
 
+from decimal import Decimal
+
 import pytest
 
 from kaspa import (
+    Amount,
+    format_amount,
     kaspa_to_sompi,
+    parse_amount,
+    Fees,
     sompi_to_kaspa,
     sompi_to_kaspa_string_with_suffix,
     sign_message,
@@ -112,6 +118,97 @@ class TestRoundTrip:
         assert back == original
 
 
+class TestParseAmount:
+    """Tests for amount parsing."""
+
+    @pytest.mark.parametrize(
+        "amount, sompi",
+        [
+            (1500, 1500),
+            ("1.5 KAS", 150_000_000),
+            ("1.5kas", 150_000_000),
+            ("0.00000001 TKAS", 1),
+            ("1,500 sompi", 1500),
+            ("1_000 KAS", 100_000_000_000),
+            (".5 KAS", 50_000_000),
+            (Decimal("1.5"), 150_000_000),
+            (Decimal("1E+2"), 10_000_000_000),
+        ],
+    )
+    def test_parse_amount(self, amount, sompi):
+        """Test parsing the supported amount forms."""
+        assert parse_amount(amount) == sompi
+
+    def test_float_rejected(self):
+        """Test that float amounts are rejected."""
+        with pytest.raises(TypeError, match="Float amounts lose precision"):
+            parse_amount(1.5)
+
+    @pytest.mark.parametrize(
+        "amount, reason",
+        [
+            ("1.5", "missing unit"),
+            ("1.5 sompi", "whole numbers"),
+            ("0.000000001 KAS", "8 decimal places"),
+            ("-1 KAS", "must not be negative"),
+            ("1 BTC", "unknown unit"),
+            ("200000000000 KAS", "out of range"),
+            (-1, "non-negative"),
+        ],
+    )
+    def test_invalid_amount(self, amount, reason):
+        """Test that invalid amounts raise ValueError with the reason."""
+        with pytest.raises(ValueError, match=reason):
+            parse_amount(amount)
+
+    def test_fees_accepts_amount_string(self):
+        """Test that Fees accepts an amount string."""
+        assert Fees("0.01 KAS").amount == 1_000_000
+
+
+class TestFormatAmount:
+    """Tests for amount formatting."""
+
+    @pytest.mark.parametrize(
+        "amount, options, formatted",
+        [
+            (150_000_000, {}, "1.5 KAS"),
+            (123_456_750_000_000, {}, "1,234,567.5 KAS"),
+            (0, {}, "0 KAS"),
+            (1, {}, "0.00000001 KAS"),
+            ("1234.5 KAS", {"locale": "de_DE"}, "1.234,5 KAS"),
+            ("1234.5 KAS", {"locale": "fr-FR"}, "1\u00a0234,5 KAS"),
+            ("1234.5 KAS", {"locale": "de_CH"}, "1\u2019234.5 KAS"),
+            ("1234.5 KAS", {"thousands_separator": "", "suffix": False}, "1234.5"),
+            (149_999_999, {"decimals": 2}, "1.50 KAS"),
+            (100_000_000, {"decimals": 2, "unit": "TKAS"}, "1.00 TKAS"),
+            (1_234_567, {"unit": "sompi"}, "1,234,567 sompi"),
+        ],
+    )
+    def test_format_amount(self, amount, options, formatted):
+        """Test formatting with units, decimals and locales."""
+        assert format_amount(amount, **options) == formatted
+        assert Amount(amount).format(**options) == formatted
+
+    @pytest.mark.parametrize(
+        "options",
+        [{"unit": "BTC"}, {"decimals": 9}, {"unit": "sompi", "decimals": 1}, {"locale": "xx"}],
+    )
+    def test_invalid_options(self, options):
+        """Test that invalid options raise ValueError."""
+        with pytest.raises(ValueError):
+            format_amount(1, **options)
+
+    def test_amount(self):
+        """Test that Amount parses once and is accepted as an amount."""
+        amount = Amount("1.5 KAS")
+        assert amount.sompi == 150_000_000
+        assert int(amount) == 150_000_000
+        assert str(amount) == "1.5 KAS"
+        assert parse_amount(amount) == 150_000_000
+        assert Amount(amount) == amount
+
+
 class TestMessageSigning:
     """Tests for message signing and verification."""
 
@@ -196,6 +293,21 @@ class TestHash:
         result = hash_obj.to_string()
         assert isinstance(result, str)
 
+    def test_hash_bytes_roundtrip(self):
+        """Test Hash from_bytes / to_bytes round trip."""
+        data = bytes(range(32))
+        hash_obj = Hash.from_bytes(data)
+
+        assert hash_obj.to_bytes() == data
+        assert bytes(hash_obj) == data
+        assert hash_obj == Hash(data.hex())
+        assert Hash.from_bytes(memoryview(data)) == hash_obj
+
+    def test_hash_from_bytes_wrong_length_raises(self):
+        """Test Hash.from_bytes rejects non-32-byte input."""
+        with pytest.raises(Exception):
+            Hash.from_bytes(bytes(31))
+
 
 class TestAccountKind:
     """Tests for AccountKind class."""
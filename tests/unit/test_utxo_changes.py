@@ -0,0 +1,19 @@
+import asyncio
+
+import pytest
+
+from kaspa import UtxoChanges
+
+
+def test_changes_is_async_iterator(make_context):
+    changes = make_context().changes()
+
+    assert isinstance(changes, UtxoChanges)
+    assert changes.__aiter__() is changes
+
+
+async def test_changes_waits_for_changes(make_context):
+    changes = make_context().changes()
+
+    with pytest.raises(asyncio.TimeoutError):
+        await asyncio.wait_for(changes.__anext__(), timeout=0.2)
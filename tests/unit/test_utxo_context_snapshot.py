@@ -0,0 +1,201 @@
+import json
+
+import pytest
+
+from kaspa import (
+    Address,
+    Generator,
+    Hash,
+    PaymentOutput,
+    PrivateKey,
+    TransactionOutpoint,
+    UtxoContext,
+    UtxoEntryReference,
+)
+
+
+def test_snapshot_contents(make_context):
+    context = make_context()
+
+    snapshot = json.loads(context.snapshot())
+
+    assert snapshot["version"] == 1
+    assert snapshot["networkId"] == "testnet-10"
+    assert snapshot["addresses"] == []
+    assert snapshot["utxos"] == []
+    assert len(snapshot["checksum"]) == 64
+
+
+def test_save_writes_snapshot(tmp_path, make_context):
+    context = make_context()
+    path = tmp_path / "context.json"
+
+    context.save(str(path))
+
+    assert json.loads(path.read_text()) == json.loads(context.snapshot())
+
+
+def test_resume_rejects_tampered_snapshot(make_context):
+    context = make_context()
+    snapshot = json.loads(context.snapshot())
+    snapshot["daaScore"] = 123
+
+    with pytest.raises(Exception, match="checksum"):
+        context.resume(snapshot=json.dumps(snapshot))
+
+
+def test_resume_rejects_other_context_id(make_context):
+    snapshot = make_context().snapshot()
+    other = make_context(id="a" * 64)
+
+    with pytest.raises(Exception, match="context id"):
+        other.resume(snapshot=snapshot)
+
+
+def test_resume_rejects_other_network(make_context):
+    snapshot = make_context().snapshot()
+    other = make_context(network_id="mainnet")
+
+    with pytest.raises(Exception, match="network"):
+        other.resume(snapshot=snapshot)
+
+
+def test_resume_requires_single_source(tmp_path, make_context):
+    context = make_context()
+
+    with pytest.raises(Exception):
+        context.resume()
+    with pytest.raises(Exception):
+        context.resume(str(tmp_path / "missing.json"), snapshot=context.snapshot())
+
+
+def test_context_id_is_stable(make_context):
+    context = make_context(id="a" * 64)
+
+    assert context.id.to_string() == "a" * 64
+
+
+def test_descriptor_contents(make_context):
+    context = make_context(id="b" * 64)
+
+    descriptor = json.loads(context.descriptor())
+
+    assert descriptor["id"] == "b" * 64
+    assert descriptor["networkId"] == "testnet-10"
+    assert descriptor["addresses"] == []
+
+
+def test_in_flight_empty(make_context):
+    context = make_context()
+
+    assert context.in_flight == []
+    context.clear_in_flight()
+    assert context.in_flight == []
+
+
+def test_lock_and_unlock_outpoints(make_context):
+    context = make_context()
+    first = TransactionOutpoint(Hash("c" * 64), 0)
+    second = TransactionOutpoint(Hash("c" * 64), 1)
+
+    context.lock([first, second])
+    assert sorted(outpoint.index for outpoint in context.locked) == [0, 1]
+
+    context.unlock([first])
+    assert [outpoint.index for outpoint in context.locked] == [1]
+
+    context.unlock()
+    assert context.locked == []
+
+
+def test_lock_is_all_or_nothing(make_context):
+    context = make_context()
+    first = TransactionOutpoint(Hash("d" * 64), 0)
+    second = TransactionOutpoint(Hash("d" * 64), 1)
+    context.lock([first])
+
+    with pytest.raises(Exception, match="already locked"):
+        context.lock([second, first])
+
+    assert [outpoint.index for outpoint in context.locked] == [0]
+
+
+def shared_entries(key, count):
+    address = key.to_address("testnet-10")
+    return [
+        UtxoEntryReference.from_dict({
+            "address": address.to_string(),
+            "outpoint": {"transactionId": "e" * 64, "index": index},
+            "amount": 100_000_000,
+            "scriptPublicKey": {"version": 0, "script": "20" + key.to_public_key().to_x_only_public_key().to_string() + "ac"},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        })
+        for index in range(count)
+    ]
+
+
+def test_generators_do_not_share_priority_entries(make_context):
+    context = make_context()
+    key = PrivateKey("b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef")
+    address = key.to_address("testnet-10")
+    entries = shared_entries(key, 2)
+
+    def spend():
+        generator = Generator(
+            context,
+            address,
+            outputs=[PaymentOutput(Address(address.to_string()), 50_000_000)],
+            priority_entries=entries,
+        )
+        return generator, list(generator)[-1]
+
+    first, first_pending = spend()
+    second, second_pending = spend()
+
+    first_inputs = {entry.outpoint.index for entry in first_pending.get_utxo_entries()}
+    second_inputs = {entry.outpoint.index for entry in second_pending.get_utxo_entries()}
+    assert first_inputs and second_inputs
+    assert first_inputs.isdisjoint(second_inputs)
+    assert sorted(outpoint.index for outpoint in context.locked) == [0, 1]
+
+    # Both priority entries are taken, a third Generator has nothing to spend.
+    with pytest.raises(Exception):
+        spend()
+
+
+def test_generator_skips_locked_priority_entries(make_context):
+    context = make_context()
+    key = PrivateKey("b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef")
+    address = key.to_address("testnet-10")
+    entries = shared_entries(key, 2)
+    context.lock([entries[0].outpoint])
+
+    pending = list(Generator(
+        context,
+        address,
+        outputs=[PaymentOutput(Address(address.to_string()), 50_000_000)],
+        priority_entries=entries,
+    ))[-1]
+
+    assert [entry.outpoint.index for entry in pending.get_utxo_entries()] == [1]
+
+
+def test_compare_snapshots(make_context):
+    snapshot = make_context().snapshot()
+
+    diff = UtxoContext.compare_snapshots(snapshot, snapshot)
+
+    assert diff["added"] == []
+    assert diff["removed"] == []
+    assert diff["addedAddresses"] == []
+    assert diff["removedAddresses"] == []
+    assert diff["daaScore"][0] == diff["daaScore"][1]
+
+
+def test_compare_snapshots_rejects_other_context(make_context):
+    snapshot = make_context().snapshot()
+    other = make_context(id="a" * 64).snapshot()
+
+    with pytest.raises(Exception, match="context ids"):
+        UtxoContext.compare_snapshots(snapshot, other)
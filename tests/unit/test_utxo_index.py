@@ -0,0 +1,100 @@
+import pytest
+
+from kaspa import UtxoIndex
+
+ADDRESS_A = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+ADDRESS_B = "kaspatest:qqkl0ct62rv6dz74pff2kx5sfyasl4z28uekevau23g877r5gt6userwyrmtt"
+
+
+def entry(transaction_id: str, amount: int, daa_score: int, address: str = ADDRESS_A, index: int = 0) -> dict:
+    return {
+        "address": address,
+        "outpoint": {"transactionId": transaction_id * 64, "index": index},
+        "amount": amount,
+        "scriptPublicKey": {"version": 0, "script": "20" + "ab" * 32 + "ac"},
+        "blockDaaScore": daa_score,
+        "isCoinbase": False,
+    }
+
+
+def record(transaction_id: str, value: int, daa_score: int, kind: str = "incoming") -> dict:
+    return {
+        "id": transaction_id * 64,
+        "value": value,
+        "blockDaaScore": daa_score,
+        "binding": {"type": "custom", "data": "ctx"},
+        "network": "testnet-10",
+        "data": {"type": kind},
+    }
+
+
+class FakeContext:
+    def __init__(self, mature: list, pending: list):
+        self.id = "ctx"
+        self._mature = mature
+        self._pending = pending
+
+    @property
+    def mature_length(self):
+        return len(self._mature)
+
+    def mature_range(self, from_, to):
+        return self._mature[from_:to]
+
+    def pending(self):
+        return self._pending
+
+
+@pytest.fixture
+def index():
+    with UtxoIndex() as index:
+        yield index
+
+
+class TestUtxoIndex:
+    def test_load_and_query(self, index):
+        index.load(FakeContext([entry("a", 100, 10), entry("b", 50, 20, ADDRESS_B)], [entry("c", 7, 30)]))
+
+        assert index.balance() == 157
+        assert index.balance(address=ADDRESS_B) == 50
+        assert [utxo["amount"] for utxo in index.utxos(address=ADDRESS_A)] == [100, 7]
+        assert index.utxos()[0]["script_public_key"] == "0000" + "20" + "ab" * 32 + "ac"
+        assert index.addresses() == {ADDRESS_A: {"count": 2, "balance": 107}, ADDRESS_B: {"count": 1, "balance": 50}}
+
+    def test_load_replaces_unspent(self, index):
+        context = FakeContext([entry("a", 100, 10)], [])
+        index.load(context)
+        context._mature = [entry("b", 5, 11)]
+        index.load(context)
+
+        assert [utxo["amount"] for utxo in index.utxos()] == [5]
+
+    def test_balance_at_daa_score(self, index):
+        index.apply([entry("a", 100, 10), entry("b", 50, 20)], [], "ctx")
+        index.on_event({"type": "daa-score-change", "data": {"currentDaaScore": 30}})
+        index.apply([entry("c", 40, 30)], [entry("a", 100, 10)], "ctx")
+
+        assert index.balance() == 90
+        assert index.balance(daa_score=9) == 0
+        assert index.balance(daa_score=15) == 100
+        assert index.balance(daa_score=25) == 150
+        assert index.balance(daa_score=30) == 90
+        assert [utxo["spent"] for utxo in index.utxos(include_spent=True)] == [True, False, False]
+
+    def test_transaction_records(self, index):
+        index.on_event({"type": "pending", "data": record("a", 100, 10)})
+        index.on_event({"type": "maturity", "data": record("a", 100, 10)})
+        index.on_event({"type": "pending", "data": record("b", 30, 20, "outgoing")})
+
+        transactions = index.transactions()
+        assert [(tx["id"][0], tx["state"]) for tx in transactions] == [("a", "maturity"), ("b", "pending")]
+        assert index.transactions(state="pending")[0]["data"]["type"] == "outgoing"
+        assert [tx["id"][0] for tx in index.transactions(from_daa_score=15)] == ["b"]
+        assert [tx["id"][0] for tx in index.transactions(to_daa_score=15)] == ["a"]
+
+    def test_persists(self, tmp_path):
+        path = tmp_path / "index.db"
+        with UtxoIndex(path) as index:
+            index.apply([entry("a", 100, 10)], [], "ctx")
+        with UtxoIndex(path) as index:
+            assert index.balance() == 100
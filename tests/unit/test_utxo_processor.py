@@ -1,9 +1,88 @@
-from kaspa import NetworkId, UtxoProcessor
+import pytest
 
+from kaspa import NetworkId, Resolver, RpcClient, UtxoContext, UtxoEntryReference, UtxoProcessor
+from tests.conftest import TEST_MAINNET_ADDRESS
 
-def test_set_coinbase_transaction_maturity_daa_smoke():
-    UtxoProcessor.set_coinbase_transaction_maturity_daa(NetworkId("testnet-10"), 1000)
 
+def test_set_coinbase_transaction_maturity_daa_smoke(set_maturity_periods):
+    network_id = NetworkId("testnet-10")
+    set_maturity_periods(network_id, 1000, UtxoProcessor.get_user_transaction_maturity_daa(network_id))
 
-def test_set_user_transaction_maturity_daa_smoke():
-    UtxoProcessor.set_user_transaction_maturity_daa(NetworkId("testnet-10"), 100)
+
+def test_set_user_transaction_maturity_daa_smoke(set_maturity_periods):
+    network_id = NetworkId("testnet-10")
+    set_maturity_periods(network_id, UtxoProcessor.get_coinbase_transaction_maturity_daa(network_id), 100)
+
+
+def test_maturity_daa_getters(set_maturity_periods):
+    network_id = NetworkId("devnet")
+    set_maturity_periods(network_id, 2000, 20)
+
+    assert UtxoProcessor.get_coinbase_transaction_maturity_daa(network_id) == 2000
+    assert UtxoProcessor.get_user_transaction_maturity_daa(network_id) == 20
+
+
+def test_utxo_entry_maturity_daa_score(set_maturity_periods):
+    network_id = NetworkId("devnet")
+    set_maturity_periods(network_id, 2000, 20)
+    entry = {
+        "address": TEST_MAINNET_ADDRESS,
+        "outpoint": {"transactionId": "a" * 64, "index": 0},
+        "amount": 1000,
+        "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+        "blockDaaScore": 100,
+        "isCoinbase": False,
+    }
+
+    user = UtxoEntryReference.from_dict(entry)
+    coinbase = UtxoEntryReference.from_dict({**entry, "isCoinbase": True})
+    assert user.maturity_daa_score(network_id) == 120
+    assert coinbase.maturity_daa_score(network_id) == 2100
+    assert user.daa_until_mature(network_id, 110) == 10
+    assert user.daa_until_mature(network_id, 500) == 0
+
+
+def test_refresh_requires_running_processor():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    with pytest.raises(Exception, match="not running"):
+        processor.refresh()
+
+
+def test_rescan_requires_running_processor():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    context = UtxoContext(UtxoProcessor(client, NetworkId("testnet-10")))
+
+    with pytest.raises(Exception, match="not running"):
+        context.rescan()
+
+
+def test_duplicate_context_id_raises():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+    context = UtxoContext(processor, "a" * 64)
+
+    with pytest.raises(ValueError, match="id"):
+        UtxoContext(processor, "a" * 64)
+
+    assert context.id.to_string() == "a" * 64
+
+
+def test_context_id_reusable_after_drop():
+    import gc
+
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+    context = UtxoContext(processor, "b" * 64)
+    del context
+    gc.collect()
+
+    assert UtxoContext(processor, "b" * 64).id.to_string() == "b" * 64
+
+
+def test_is_synced_without_connection():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    assert processor.is_synced is False
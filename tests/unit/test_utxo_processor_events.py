@@ -1,6 +1,16 @@
+import gc
+
 import pytest
 
-from kaspa import NetworkId, Resolver, RpcClient, UtxoProcessor, UtxoProcessorEvent
+from kaspa import (
+    EventQueuePolicy,
+    NetworkId,
+    Resolver,
+    RpcClient,
+    UtxoContext,
+    UtxoProcessor,
+    UtxoProcessorEvent,
+)
 
 
 def test_add_event_listener_all_overload_smoke():
@@ -153,3 +163,131 @@ def test_add_event_listener_missing_callback_raises():
 
     with pytest.raises(Exception):
         processor.add_event_listener("connect")
+
+
+def test_add_event_listener_address_filter_smoke():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    def cb(event):
+        _ = event
+
+    address = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
+    processor.add_event_listener("balance", cb, addresses=[address])
+    processor.remove_event_listener("balance", cb)
+
+
+def test_add_event_listener_context_filter_smoke():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+    context = UtxoContext(processor)
+
+    def cb(event):
+        _ = event
+
+    processor.add_event_listener(["balance", "pending"], cb, contexts=[context])
+    processor.remove_event_listener(["balance", "pending"], cb)
+
+
+def test_add_event_listener_invalid_address_filter_raises():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    def cb(event):
+        _ = event
+
+    with pytest.raises(Exception):
+        processor.add_event_listener("balance", cb, addresses=["not-an-address"])
+
+
+def test_event_queue_defaults():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    assert processor.queue_depth == 0
+    assert processor.dropped_events == 0
+
+
+@pytest.mark.parametrize(
+    "policy",
+    ["block", "drop-oldest", "coalesce-balance", EventQueuePolicy.DropOldest],
+)
+def test_event_queue_policy_smoke(policy):
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(
+        client, NetworkId("testnet-10"), queue_capacity=16, queue_policy=policy
+    )
+
+    assert processor.queue_depth == 0
+
+
+def test_event_queue_zero_capacity_raises():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+
+    with pytest.raises(Exception):
+        UtxoProcessor(client, NetworkId("testnet-10"), queue_capacity=0)
+
+
+def test_event_queue_invalid_policy_raises():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+
+    with pytest.raises(Exception):
+        UtxoProcessor(client, NetworkId("testnet-10"), queue_policy="not-a-policy")
+
+
+def test_event_dedup_defaults():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+
+    assert processor.duplicate_events == 0
+
+
+def test_event_dedup_disabled():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"), dedup=False)
+
+    assert processor.duplicate_events == 0
+
+
+def test_event_dedup_path_compacts_log(tmp_path):
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    path = tmp_path / "seen.log"
+    path.write_text("+a:pending\n+a:reorg\n-a:reorg\n")
+
+    UtxoProcessor(client, NetworkId("testnet-10"), dedup_path=str(path))
+
+    assert path.read_text() == "+a:pending\n"
+
+
+def test_event_dedup_path_without_dedup_raises(tmp_path):
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+
+    with pytest.raises(Exception):
+        UtxoProcessor(
+            client,
+            NetworkId("testnet-10"),
+            dedup=False,
+            dedup_path=str(tmp_path / "seen.log"),
+        )
+
+
+def test_context_keeps_processor_reference():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+    context = UtxoContext(processor)
+
+    del processor
+    gc.collect()
+
+    assert isinstance(context.processor, UtxoProcessor)
+    assert not context.is_active
+
+
+def test_drop_unstarted_processor_smoke():
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10")
+    processor = UtxoProcessor(client, NetworkId("testnet-10"))
+    context = UtxoContext(processor)
+
+    del context
+    del processor
+    gc.collect()
@@ -0,0 +1,68 @@
+import pytest
+
+from kaspa import UtxoEntryReference, analyze_utxos
+
+ADDRESS = "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+
+
+def utxo_entry(index, amount, block_daa_score=12345, is_coinbase=False):
+    return UtxoEntryReference.from_dict({
+        "address": ADDRESS,
+        "outpoint": {"transactionId": "a" * 64, "index": index},
+        "amount": amount,
+        "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+        "blockDaaScore": block_daa_score,
+        "isCoinbase": is_coinbase,
+    })
+
+
+class TestAnalyzeUtxos:
+    def test_summary(self):
+        entries = [
+            utxo_entry(0, 1_000, block_daa_score=1_000),
+            utxo_entry(1, 300_000_000, block_daa_score=1_950, is_coinbase=True),
+            utxo_entry(2, 200_000_000, block_daa_score=1_999),
+            utxo_entry(3, 100_000_000, block_daa_score=2_000),
+        ]
+        summary = analyze_utxos(entries, daa_score=2_000, age_buckets=[10, 100])
+
+        assert summary["count"] == 4
+        assert summary["total"] == 600_001_000
+        assert summary["min"] == 1_000
+        assert summary["max"] == 300_000_000
+        assert summary["mean"] == 600_001_000 / 4
+        assert summary["median"] == 150_000_000
+        assert summary["dust"] == 1
+        assert summary["coinbase"] == 1
+        assert summary["age_buckets"] == [
+            {"max_age": 10, "count": 2, "amount": 300_000_000},
+            {"max_age": 100, "count": 1, "amount": 300_000_000},
+            {"max_age": None, "count": 1, "amount": 1_000},
+        ]
+
+    def test_default_daa_score_and_buckets(self):
+        summary = analyze_utxos([utxo_entry(0, 5, block_daa_score=10), utxo_entry(1, 7, block_daa_score=20)])
+        assert summary["median"] == 6
+        assert [bucket["count"] for bucket in summary["age_buckets"]] == [2, 0, 0, 0, 0, 0, 0]
+        assert summary["age_buckets"][-2]["max_age"] == 10_000_000
+
+    def test_dust_threshold(self):
+        entries = [utxo_entry(0, 500), utxo_entry(1, 1_000)]
+        assert analyze_utxos(entries, dust_threshold=1_000)["dust"] == 1
+        assert analyze_utxos(entries, dust_threshold=0)["dust"] == 0
+
+    def test_empty(self):
+        summary = analyze_utxos([])
+        assert summary["count"] == 0
+        assert summary["total"] == 0
+        assert summary["mean"] is None
+        assert summary["median"] is None
+        assert summary["min"] is None
+
+    def test_accepts_dicts(self):
+        entry = utxo_entry(0, 1_000)
+        assert analyze_utxos([entry.to_dict()])["total"] == 1_000
+
+    def test_rejects_unsorted_buckets(self):
+        with pytest.raises(ValueError, match="age_buckets"):
+            analyze_utxos([], age_buckets=[100, 10])
@@ -0,0 +1,83 @@
+"""
+Unit tests for argument validation error messages.
+"""
+
+import pytest
+
+from kaspa import (
+    Address,
+    Generator,
+    Hash,
+    NetworkId,
+    PrivateKey,
+    UtxoEntryReference,
+    kaspa_to_sompi,
+)
+from tests.conftest import TEST_MAINNET_ADDRESS
+
+
+def utxo_dict(**overrides):
+    entry = {
+        "address": TEST_MAINNET_ADDRESS,
+        "outpoint": {"transactionId": "a" * 64, "index": 0},
+        "amount": 1000,
+        "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+        "blockDaaScore": 12345,
+        "isCoinbase": False,
+    }
+    entry.update(overrides)
+    return entry
+
+
+class TestHexValidation:
+    """Tests for hex string arguments."""
+
+    def test_non_hex_character(self):
+        """Test that a non-hex character is reported with its position."""
+        with pytest.raises(ValueError, match=r"Invalid `key`: non-hex character 'z' at position 0"):
+            PrivateKey("z" * 64)
+
+    def test_wrong_length(self):
+        """Test that a hex string of the wrong length is reported."""
+        with pytest.raises(ValueError, match=r"Invalid `hex_str`: expected 64 hex characters, got 4"):
+            Hash("abcd")
+
+
+class TestAddressValidation:
+    """Tests for address arguments."""
+
+    def test_invalid_address(self):
+        """Test that an invalid address string raises ValueError."""
+        with pytest.raises(ValueError, match=r"Invalid `address`"):
+            Address("not-an-address")
+
+    def test_generator_network_mismatch(self):
+        """Test that a change address from another network is rejected."""
+        with pytest.raises(ValueError, match=r"Invalid `change_address`.*testnet-10"):
+            Generator([], TEST_MAINNET_ADDRESS, network_id=NetworkId("testnet-10"))
+
+
+class TestAmountValidation:
+    """Tests for amounts and DAA scores."""
+
+    def test_negative_amount(self):
+        """Test that a negative amount names the offending key."""
+        with pytest.raises(ValueError, match=r"Invalid `amount`: must not be negative, got -1"):
+            UtxoEntryReference.from_dict(utxo_dict(amount=-1))
+
+    def test_daa_score_out_of_range(self):
+        """Test that a DAA score beyond u64 is reported as out of range."""
+        with pytest.raises(ValueError, match=r"Invalid `blockDaaScore`: out of range"):
+            UtxoEntryReference.from_dict(utxo_dict(blockDaaScore=2**64))
+
+    def test_missing_key(self):
+        """Test that a missing key still raises KeyError."""
+        entry = utxo_dict()
+        del entry["amount"]
+        with pytest.raises(KeyError):
+            UtxoEntryReference.from_dict(entry)
+
+    def test_negative_kaspa_to_sompi(self):
+        """Test that converting a negative KAS amount raises ValueError."""
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(-1.0)
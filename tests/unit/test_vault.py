@@ -0,0 +1,104 @@
+import time
+
+import pytest
+
+from kaspa import (
+    Address,
+    Generator,
+    KeyVault,
+    PaymentOutput,
+    PrivateKey,
+    UtxoEntryReference,
+    sign_script_hash,
+)
+from tests.conftest import TEST_MAINNET_ADDRESS, TEST_PRIVATE_KEY_HEX, TEST_PUBLIC_KEY_HEX
+
+SECRET = "correct horse battery staple"
+SCRIPT_HASH = "a" * 64
+
+
+def pending():
+    entries = [
+        UtxoEntryReference.from_dict(
+            {
+                "address": TEST_MAINNET_ADDRESS,
+                "outpoint": {"transactionId": "a" * 64, "index": 0},
+                "amount": 1_000_000_000,
+                "scriptPublicKey": {"version": 0, "script": "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"},
+                "blockDaaScore": 12345,
+                "isCoinbase": False,
+            }
+        )
+    ]
+    outputs = [PaymentOutput(Address(TEST_MAINNET_ADDRESS), 100_000_000)]
+    return list(Generator(entries, TEST_MAINNET_ADDRESS, network_id="mainnet", outputs=outputs))[-1]
+
+
+@pytest.fixture
+def vault():
+    vault = KeyVault()
+    vault.import_key(PrivateKey(TEST_PRIVATE_KEY_HEX), SECRET)
+    return vault
+
+
+class TestKeyVault:
+    def test_import_returns_x_only_public_key(self):
+        vault = KeyVault()
+        assert vault.import_key(PrivateKey(TEST_PRIVATE_KEY_HEX), SECRET) == TEST_PUBLIC_KEY_HEX
+        assert vault.ids == [TEST_PUBLIC_KEY_HEX]
+
+    def test_import_rejects_empty_secret(self):
+        with pytest.raises(ValueError):
+            KeyVault().import_key(PrivateKey(TEST_PRIVATE_KEY_HEX), "")
+
+    def test_unknown_id(self, vault):
+        with pytest.raises(ValueError):
+            vault.key("b" * 64)
+
+    def test_remove(self, vault):
+        assert vault.remove(TEST_PUBLIC_KEY_HEX)
+        assert not vault.remove(TEST_PUBLIC_KEY_HEX)
+        assert vault.ids == []
+
+    def test_unlock_rejects_wrong_secret(self, vault):
+        with pytest.raises(ValueError):
+            vault.unlock(TEST_PUBLIC_KEY_HEX, "wrong")
+        assert not vault.is_unlocked(TEST_PUBLIC_KEY_HEX)
+
+    def test_unlock_expires(self, vault):
+        vault.unlock(TEST_PUBLIC_KEY_HEX, SECRET, ttl=0.05)
+        assert vault.is_unlocked(TEST_PUBLIC_KEY_HEX)
+        time.sleep(0.1)
+        assert not vault.is_unlocked(TEST_PUBLIC_KEY_HEX)
+
+    def test_lock(self, vault):
+        vault.unlock(TEST_PUBLIC_KEY_HEX, SECRET)
+        vault.lock()
+        assert not vault.is_unlocked(TEST_PUBLIC_KEY_HEX)
+
+
+class TestKeyHandle:
+    def test_signs_like_private_key(self, vault):
+        expected = sign_script_hash(SCRIPT_HASH, PrivateKey(TEST_PRIVATE_KEY_HEX))
+        handle = vault.key(TEST_PUBLIC_KEY_HEX, SECRET)
+        assert handle.id == TEST_PUBLIC_KEY_HEX
+        assert len(sign_script_hash(SCRIPT_HASH, handle)) == len(expected)
+
+    def test_locked_handle_without_secret_raises(self, vault):
+        with pytest.raises(Exception, match="locked"):
+            pending().sign([vault.key(TEST_PUBLIC_KEY_HEX)])
+
+    def test_wrong_secret_raises(self, vault):
+        with pytest.raises(ValueError):
+            pending().sign([vault.key(TEST_PUBLIC_KEY_HEX, "wrong")])
+
+    def test_sign_with_unlocked_key(self, vault):
+        vault.unlock(TEST_PUBLIC_KEY_HEX, SECRET, ttl=60)
+        transaction = pending()
+        transaction.sign([vault.key(TEST_PUBLIC_KEY_HEX)])
+        transaction.validate()
+
+    def test_sign_input_with_secret(self, vault):
+        transaction = pending()
+        transaction.sign_input(0, vault.key(TEST_PUBLIC_KEY_HEX, SECRET))
+        transaction.validate()
@@ -0,0 +1,117 @@
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+from kaspa import WebhookNotifier
+from kaspa.webhooks import verify_signature
+
+
+class Receiver:
+    """Local HTTP server recording webhook requests, failing the first `failures` ones."""
+
+    def __init__(self, failures: int = 0, status: int = 503):
+        self.requests: list[tuple[dict, bytes]] = []
+        self.failures = failures
+        receiver = self
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_POST(self):
+                body = self.rfile.read(int(self.headers["Content-Length"]))
+                if receiver.failures > 0:
+                    receiver.failures -= 1
+                    self.send_response(status)
+                else:
+                    receiver.requests.append((dict(self.headers), body))
+                    self.send_response(200)
+                self.end_headers()
+
+            def log_message(self, *args):
+                pass
+
+        self.server = HTTPServer(("127.0.0.1", 0), Handler)
+        self.url = f"http://127.0.0.1:{self.server.server_port}/hook"
+        threading.Thread(target=self.server.serve_forever, daemon=True).start()
+
+    def close(self):
+        self.server.shutdown()
+
+
+@pytest.fixture
+def receiver():
+    receiver = Receiver()
+    yield receiver
+    receiver.close()
+
+
+class FakeProcessor:
+    def __init__(self):
+        self.listeners = []
+
+    def add_event_listener(self, events, callback, **filters):
+        self.listeners.append((events, callback, filters))
+
+    def remove_event_listener(self, events, callback):
+        self.listeners = [listener for listener in self.listeners if listener[1] != callback]
+
+
+class TestWebhookNotifier:
+    def test_rejects_invalid_settings(self):
+        with pytest.raises(ValueError):
+            WebhookNotifier("http://127.0.0.1:1", max_retries=-1)
+        with pytest.raises(ValueError):
+            WebhookNotifier("http://127.0.0.1:1", timeout=0)
+
+    def test_delivers_signed_events(self, receiver):
+        notifier = WebhookNotifier(receiver.url, secret="secret")
+        notifier.notify({"type": "balance", "data": {"balance": {"mature": 100}}})
+        notifier.notify({"type": "maturity", "data": {"id": "abc"}})
+        notifier.close(timeout=10)
+
+        assert notifier.delivered == 2
+        assert [json.loads(body)["type"] for _, body in receiver.requests] == ["balance", "maturity"]
+        headers, body = receiver.requests[0]
+        assert json.loads(body)["data"] == {"balance": {"mature": 100}}
+        assert headers["X-Kaspa-Event"] == "balance"
+        assert verify_signature("secret", headers["X-Kaspa-Timestamp"], body, headers["X-Kaspa-Signature"])
+        assert not verify_signature("other", headers["X-Kaspa-Timestamp"], body, headers["X-Kaspa-Signature"])
+
+    def test_retries_server_errors(self):
+        receiver = Receiver(failures=2)
+        try:
+            notifier = WebhookNotifier(receiver.url, backoff=0.01)
+            notifier.notify({"type": "balance", "data": None})
+            notifier.close(timeout=10)
+        finally:
+            receiver.close()
+
+        assert notifier.delivered == 1
+        assert len(receiver.requests) == 1
+
+    def test_gives_up_on_client_errors(self):
+        receiver = Receiver(failures=1, status=400)
+        errors = []
+        try:
+            notifier = WebhookNotifier(receiver.url, backoff=0.01, on_error=lambda payload, err: errors.append(payload))
+            notifier.notify({"type": "balance", "data": None})
+            notifier.close(timeout=10)
+        finally:
+            receiver.close()
+
+        assert notifier.failed == 1
+        assert [payload["type"] for payload in errors] == ["balance"]
+        assert receiver.requests == []
+
+    def test_attach_and_detach(self, receiver):
+        processor = FakeProcessor()
+        notifier = WebhookNotifier(receiver.url)
+        notifier.attach(processor, addresses=["kaspa:qz..."])
+
+        events, callback, filters = processor.listeners[0]
+        assert events == ["balance", "maturity"]
+        assert filters == {"addresses": ["kaspa:qz..."]}
+
+        notifier.detach(processor)
+        assert processor.listeners == []
+        notifier.close(timeout=10)